@@ -23,8 +23,8 @@ fn small_box(text_content: &'static str, color: Color) {
                 g2_k_value: 3.0,
             },
             padding: Dp(8.0),
-            width: Some(DimensionValue::Fixed(Px(40))),
-            height: Some(DimensionValue::Fixed(Px(40))),
+            width: Some(DimensionValue::Fixed(Px(40.0))),
+            height: Some(DimensionValue::Fixed(Px(40.0))),
             ..Default::default()
         },
         None,
@@ -73,8 +73,8 @@ fn row_demo_line(title: &'static str, alignment: MainAxisAlignment) {
                             g2_k_value: 3.0,
                         },
                         padding: Dp(10.0),
-                        width: Some(DimensionValue::Fixed(Px(400))), // Sufficient Fixed Width
-                        height: Some(DimensionValue::Fixed(Px(70))),
+                        width: Some(DimensionValue::Fixed(Px(400.0))), // Sufficient Fixed Width
+                        height: Some(DimensionValue::Fixed(Px(70.0))),
                         ..Default::default()
                     },
                     None,
@@ -155,8 +155,8 @@ fn app() {
                     // Spacing
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(30)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(30.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     // row Alignment Demo Title
@@ -173,8 +173,8 @@ fn app() {
                     // Spacing
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(15)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(15.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     // RowAlignment Demo
@@ -182,40 +182,40 @@ fn app() {
                         as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(20)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(20.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| row_demo_line("Center", MainAxisAlignment::Center))
                         as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(20)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(20.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| row_demo_line("End", MainAxisAlignment::End))
                         as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(20)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(20.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| row_demo_line("SpaceEvenly", MainAxisAlignment::SpaceEvenly))
                         as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(20)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(20.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| row_demo_line("SpaceBetween", MainAxisAlignment::SpaceBetween))
                         as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| {
                         spacer(SpacerArgs {
-                            width: DimensionValue::Fixed(Px(0)),
-                            height: DimensionValue::Fixed(Px(20)),
+                            width: DimensionValue::Fixed(Px(0.0)),
+                            height: DimensionValue::Fixed(Px(20.0)),
                         })
                     }) as Box<dyn FnOnce() + Send + Sync>,
                     Box::new(|| row_demo_line("SpaceAround", MainAxisAlignment::SpaceAround))
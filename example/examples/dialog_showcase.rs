@@ -1,6 +1,6 @@
 use std::sync::{Arc, RwLock};
 
-use tessera_ui::{Color, DimensionValue, Dp, Px, Renderer};
+use tessera_ui::{Color, DimensionValue, Dp, Px, Renderer, TesseraRuntime, runtime::CloseDecision};
 use tessera_ui_basic_components::{
     alignment::{CrossAxisAlignment, MainAxisAlignment},
     button::{ButtonArgsBuilder, button},
@@ -27,6 +27,19 @@ fn app(app_state: Arc<RwLock<AppState>>) {
     let state_for_provider = app_state.clone();
     let state_for_main_content = app_state.clone();
     let state_for_dialog_content = app_state.clone();
+    let state_for_close_request = app_state.clone();
+
+    // Closing the window shows the dialog first, closing it again while the dialog is open quits
+    TesseraRuntime::write().on_close_requested(move || {
+        let mut state = state_for_close_request.write().unwrap();
+        if state.show_dialog {
+            CloseDecision::Close
+        } else {
+            state.show_dialog = true;
+            TesseraRuntime::request_redraw();
+            CloseDecision::Prevent
+        }
+    });
 
     dialog_provider(
         DialogProviderArgsBuilder::default()
@@ -121,7 +134,7 @@ fn app(app_state: Arc<RwLock<AppState>>) {
                                 || {
                                     spacer(
                                         SpacerArgsBuilder::default()
-                                            .height(DimensionValue::Fixed(Px(10)))
+                                            .height(DimensionValue::Fixed(Px(10.0)))
                                             .build()
                                             .unwrap(),
                                     );
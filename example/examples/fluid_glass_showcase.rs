@@ -25,8 +25,8 @@ fn small_box(color: Color) {
                 g2_k_value: 3.0,
             },
             padding: Dp(8.0),
-            width: Some(DimensionValue::Fixed(Px(40))),
-            height: Some(DimensionValue::Fixed(Px(40))),
+            width: Some(DimensionValue::Fixed(Px(40.0))),
+            height: Some(DimensionValue::Fixed(Px(40.0))),
             ..Default::default()
         },
         None,
@@ -82,7 +82,7 @@ fn app() {
                                     row(
                                         RowArgsBuilder::default()
                                             .main_axis_alignment(MainAxisAlignment::SpaceAround)
-                                            .width(DimensionValue::Fixed(Px(800)))
+                                            .width(DimensionValue::Fixed(Px(800.0)))
                                             .build()
                                             .unwrap(),
                                         [
@@ -103,8 +103,8 @@ fn app() {
                                 // Spacer
                                 (|| {
                                     spacer(SpacerArgs {
-                                        width: DimensionValue::Fixed(Px(0)),
-                                        height: DimensionValue::Fixed(Px(30)),
+                                        width: DimensionValue::Fixed(Px(0.0)),
+                                        height: DimensionValue::Fixed(Px(30.0)),
                                     })
                                 })
                                 .into_column_item(),
@@ -126,8 +126,8 @@ fn app() {
                                 // More colorful elements
                                 (|| {
                                     spacer(SpacerArgs {
-                                        width: DimensionValue::Fixed(Px(0)),
-                                        height: DimensionValue::Fixed(Px(20)),
+                                        width: DimensionValue::Fixed(Px(0.0)),
+                                        height: DimensionValue::Fixed(Px(20.0)),
                                     })
                                 })
                                 .into_column_item(),
@@ -169,8 +169,8 @@ fn app() {
                                     fluid_glass(
                                         FluidGlassArgsBuilder::default()
                                             .blur_radius(10.0)
-                                            .width(DimensionValue::Fixed(Px(350)))
-                                            .height(DimensionValue::Fixed(Px(250)))
+                                            .width(DimensionValue::Fixed(Px(350.0)))
+                                            .height(DimensionValue::Fixed(Px(250.0)))
                                             .shape(Shape::RoundedRectangle {
                                                 corner_radius: 20.0,
                                                 g2_k_value: 3.0,
@@ -187,8 +187,8 @@ fn app() {
                                     fluid_glass(
                                         FluidGlassArgsBuilder::default()
                                             .blur_radius(10.0)
-                                            .width(DimensionValue::Fixed(Px(350)))
-                                            .height(DimensionValue::Fixed(Px(250)))
+                                            .width(DimensionValue::Fixed(Px(350.0)))
+                                            .height(DimensionValue::Fixed(Px(250.0)))
                                             .shape(Shape::RoundedRectangle {
                                                 corner_radius: 20.0,
                                                 g2_k_value: 3.0,
@@ -44,6 +44,7 @@ fn app(ripple_state: Arc<RippleState>, image_resource: &ImageData) {
                         min: None,
                         max: None
                     },
+                    ..Default::default()
                 },
                 move || {
                     image(
@@ -39,6 +39,7 @@ fn app(switch_state: Arc<Mutex<GlassSwitchState>>) {
                         min: None,
                         max: None
                     },
+                    ..Default::default()
                 },
                 move || {
                     let on_toggle = {
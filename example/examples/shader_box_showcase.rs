@@ -0,0 +1,98 @@
+//! Draws a custom animated fragment shader with the `shader_box` component.
+//!
+//! The shader in `examples/shaders/wave.wgsl` is registered in a `ShaderQuadPipeline` for
+//! its uniform type, next to the built-in pipelines, then drawn behind some text.
+
+use encase::ShaderType;
+use tessera_ui::{Color, DimensionValue, Dp, Renderer, shader_source};
+use tessera_ui_basic_components::{
+    alignment::{CrossAxisAlignment, MainAxisAlignment},
+    column::{AsColumnItem, ColumnArgsBuilder, column},
+    pipelines::shader_quad::ShaderQuadPipeline,
+    shader_box::{ShaderBoxArgsBuilder, shader_box},
+    spacer::{SpacerArgs, spacer},
+    surface::{SurfaceArgsBuilder, surface},
+    text::{TextArgsBuilder, text},
+};
+use tessera_ui_macros::tessera;
+
+/// The uniforms of `wave.wgsl`, matching its `struct Uniforms`
+#[derive(ShaderType, Clone)]
+struct Wave {
+    color: glam::Vec4,
+    speed: f32,
+}
+
+/// A box filled with the wave shader, with a label on top
+fn wave_box(label: &'static str, color: glam::Vec4, speed: f32) {
+    shader_box(
+        ShaderBoxArgsBuilder::default()
+            .shader_id("wave")
+            .width(DimensionValue::Fixed(Dp(240.0).into()))
+            .height(DimensionValue::Fixed(Dp(80.0).into()))
+            .animated(true)
+            .build()
+            .unwrap(),
+        Wave { color, speed },
+        move || {
+            text(
+                TextArgsBuilder::default()
+                    .text(label.to_string())
+                    .color(Color::WHITE)
+                    .build()
+                    .unwrap(),
+            )
+        },
+    );
+}
+
+#[tessera]
+fn app() {
+    surface(
+        SurfaceArgsBuilder::default()
+            .color(Color::new(0.1, 0.1, 0.1, 1.0))
+            .width(DimensionValue::FILLED)
+            .height(DimensionValue::FILLED)
+            .build()
+            .unwrap(),
+        None,
+        || {
+            column(
+                ColumnArgsBuilder::default()
+                    .width(DimensionValue::FILLED)
+                    .height(DimensionValue::FILLED)
+                    .main_axis_alignment(MainAxisAlignment::Center)
+                    .cross_axis_alignment(CrossAxisAlignment::Center)
+                    .build()
+                    .unwrap(),
+                [
+                    (|| wave_box("Slow wave", glam::Vec4::new(0.2, 0.5, 0.9, 1.0), 1.0))
+                        .into_column_item(),
+                    (|| {
+                        spacer(SpacerArgs {
+                            width: DimensionValue::Fixed(Dp(0.0).into()),
+                            height: DimensionValue::Fixed(Dp(20.0).into()),
+                        })
+                    })
+                    .into_column_item(),
+                    (|| wave_box("Fast wave", glam::Vec4::new(0.9, 0.4, 0.2, 1.0), 4.0))
+                        .into_column_item(),
+                ],
+            )
+        },
+    );
+}
+
+pub fn main() -> Result<(), Box<dyn std::error::Error>> {
+    Renderer::run(app, |app| {
+        tessera_ui_basic_components::pipelines::register_pipelines(app);
+        let wave_pipeline =
+            ShaderQuadPipeline::<Wave>::new(&app.gpu, &app.config, app.sample_count).with_shader(
+                &app.gpu,
+                "wave",
+                shader_source!("examples/shaders/wave.wgsl"),
+            );
+        app.drawer.pipeline_registry.register(wave_pipeline);
+    })?;
+    Ok(())
+}
@@ -0,0 +1,15 @@
+// Drawn by a `ShaderQuadPipeline<Wave>`, which appends the `quad` and `uniforms` bindings
+// and the `QuadVertex` input.
+#import tessera::color
+
+struct Uniforms {
+    color: vec4<f32>,
+    speed: f32,
+};
+
+@fragment
+fn fs_main(in: QuadVertex) -> @location(0) vec4<f32> {
+    let wave = 0.5 + 0.5 * sin(in.uv.x * 6.28 + quad.time * uniforms.speed);
+    let rgb = to_linear_srgb(uniforms.color.rgb) * (0.6 + 0.4 * wave);
+    return vec4<f32>(rgb, uniforms.color.a);
+}
@@ -1,22 +1,21 @@
 use std::sync::Arc;
 
-use tessera_ui::{DimensionValue, Dp};
+use tessera_ui::{DimensionValue, Dp, TesseraRuntime, winit::window::Theme as Mode};
 use tessera_ui_basic_components::{
     scrollable::{ScrollableArgsBuilder, scrollable},
     surface::{SurfaceArgsBuilder, surface},
+    theme::{Theme, current_theme, provide_theme},
 };
 use tessera_ui_macros::tessera;
 
-use crate::{
-    app_state::AppState, component_showcase::component_showcase, material_colors::md_colors,
-};
+use crate::{app_state::AppState, component_showcase::component_showcase};
 
 /// Creates the main content area with organized component showcase
 #[tessera]
 fn main_content(state: Arc<AppState>) {
     surface(
         SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE)
+            .color(current_theme().color_scheme.surface)
             .padding(Dp(16.0))
             .build()
             .unwrap(),
@@ -33,26 +32,38 @@ pub fn app(state: Arc<AppState>) {
     let scroller_state_clone = state.scrollable_state.clone();
     let state_clone = state.clone();
 
-    surface(
-        // Main background surface
-        SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE)
-            .width(DimensionValue::Fill {
-                min: None,
-                max: None,
-            })
-            .build()
-            .unwrap(),
-        None, // Non-interactive background
-        move || {
-            // Main scrollable container
-            scrollable(
-                ScrollableArgsBuilder::default().build().unwrap(),
-                scroller_state_clone,
-                move || {
-                    main_content(state_clone.clone());
-                },
-            );
-        },
-    );
+    on_theme_changed(Box::new(|theme| {
+        log::info!("Theme changed to {theme:?}");
+    }));
+
+    // The theme follows the system, or the switch showcase once it is toggled
+    let theme = match TesseraRuntime::theme() {
+        Mode::Light => Theme::light(),
+        Mode::Dark => Theme::dark(),
+    };
+
+    provide_theme(theme, move || {
+        surface(
+            // Main background surface
+            SurfaceArgsBuilder::default()
+                .color(current_theme().color_scheme.surface)
+                .width(DimensionValue::Fill {
+                    min: None,
+                    max: None,
+                })
+                .build()
+                .unwrap(),
+            None, // Non-interactive background
+            move || {
+                // Main scrollable container
+                scrollable(
+                    ScrollableArgsBuilder::default().build().unwrap(),
+                    scroller_state_clone,
+                    move || {
+                        main_content(state_clone.clone());
+                    },
+                );
+            },
+        );
+    });
 }
@@ -8,13 +8,13 @@ use tessera_ui_basic_components::{
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
     text::{TextArgsBuilder, text},
+    theme::current_theme,
 };
 use tessera_ui_macros::tessera;
 
 use crate::{
     app_state::AppState,
     interactive_demo::interactive_demo,
-    material_colors::md_colors,
     misc::create_spacer,
     performance_display::perf_display,
     switch_showcase::switch_showcase,
@@ -28,7 +28,7 @@ fn surface_showcase(state: Arc<AppState>) {
     {
         surface(
             SurfaceArgsBuilder::default()
-                .color(md_colors::SURFACE_CONTAINER) // Material Design surface-container color
+                .color(current_theme().color_scheme.surface_container) // Material Design surface-container color
                 .shape(Shape::RoundedRectangle {
                     corner_radius: 25.0,
                     g2_k_value: 3.0,
@@ -54,7 +54,7 @@ fn surface_showcase(state: Arc<AppState>) {
                             TextArgsBuilder::default()
                                 .text("Surface Components".to_string())
                                 .size(tessera_ui::Dp(24.0))
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .build()
                                 .unwrap(),
                         )
@@ -173,7 +173,7 @@ fn fluid_glass_showcase(state: Arc<AppState>) {
     {
         surface(
             SurfaceArgsBuilder::default()
-                .color(md_colors::SURFACE_CONTAINER)
+                .color(current_theme().color_scheme.surface_container)
                 .shape(Shape::RoundedRectangle {
                     corner_radius: 25.0,
                     g2_k_value: 3.0,
@@ -199,7 +199,7 @@ fn fluid_glass_showcase(state: Arc<AppState>) {
                             TextArgsBuilder::default()
                                 .text("Fluid Glass Components".to_string())
                                 .size(tessera_ui::Dp(24.0))
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .build()
                                 .unwrap(),
                         )
@@ -304,7 +304,7 @@ fn text_editor_showcase(state: Arc<AppState>) {
 
     surface(
         SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE_CONTAINER)
+            .color(current_theme().color_scheme.surface_container)
             .shape(Shape::RoundedRectangle {
                 corner_radius: 25.0,
                 g2_k_value: 3.0,
@@ -332,7 +332,7 @@ fn text_editor_showcase(state: Arc<AppState>) {
                         TextArgsBuilder::default()
                             .text("Text Editor Components".to_string())
                             .size(tessera_ui::Dp(24.0))
-                            .color(md_colors::ON_SURFACE)
+                            .color(current_theme().color_scheme.on_surface)
                             .build()
                             .unwrap(),
                     )
@@ -354,7 +354,7 @@ fn interactive_showcase(state: Arc<AppState>) {
     let state_clone = state.clone();
     surface(
         SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE_CONTAINER) // Material Design surface-container color
+            .color(current_theme().color_scheme.surface_container) // Material Design surface-container color
             .shape(Shape::RoundedRectangle {
                 corner_radius: 25.0,
                 g2_k_value: 3.0,
@@ -378,7 +378,7 @@ fn performance_showcase(state: Arc<AppState>) {
 
     surface(
         SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE_CONTAINER) // Material Design surface-container color
+            .color(current_theme().color_scheme.surface_container) // Material Design surface-container color
             .shape(Shape::RoundedRectangle {
                 corner_radius: 25.0,
                 g2_k_value: 3.0,
@@ -406,7 +406,7 @@ fn performance_showcase(state: Arc<AppState>) {
                         TextArgsBuilder::default()
                             .text("Performance Monitoring".to_string())
                             .size(tessera_ui::Dp(24.0))
-                            .color(md_colors::ON_SURFACE)
+                            .color(current_theme().color_scheme.on_surface)
                             .build()
                             .unwrap(),
                     )
@@ -434,7 +434,7 @@ pub fn component_showcase(state: Arc<AppState>) {
         || {
             surface(
                 SurfaceArgsBuilder::default()
-                    .color(md_colors::PRIMARY_CONTAINER)
+                    .color(current_theme().color_scheme.primary_container)
                     .shape(Shape::RoundedRectangle {
                         corner_radius: 25.0,
                         g2_k_value: 3.0,
@@ -452,7 +452,7 @@ pub fn component_showcase(state: Arc<AppState>) {
                         TextArgsBuilder::default()
                             .text("Tessera UI Framework Component Showcase".to_string())
                             .size(tessera_ui::Dp(24.0))
-                            .color(md_colors::ON_SURFACE)
+                            .color(current_theme().color_scheme.on_surface)
                             .build()
                             .unwrap(),
                     );
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use tessera_ui::{Color, DimensionValue, Dp, Px};
+use tessera_ui::{DimensionValue, Dp, Px};
 use tessera_ui_basic_components::{
     button::{ButtonArgsBuilder, button},
     checkbox::{CheckboxArgsBuilder, checkbox},
@@ -11,10 +11,11 @@ use tessera_ui_basic_components::{
     row_ui,
     surface::{SurfaceArgsBuilder, surface},
     text::{TextArgsBuilder, text},
+    theme::current_theme,
 };
 use tessera_ui_macros::tessera;
 
-use crate::{app_state::AppState, material_colors::md_colors, misc::create_spacer};
+use crate::{app_state::AppState, misc::create_spacer};
 
 /// Demo component showcasing interactive surfaces and buttons
 #[tessera]
@@ -27,7 +28,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 TextArgsBuilder::default()
                     .text("Interactive Components Demo".to_string())
                     .size(tessera_ui::Dp(24.0))
-                    .color(md_colors::ON_SURFACE)
+                    .color(current_theme().color_scheme.on_surface)
                     .build()
                     .unwrap(),
             )
@@ -40,7 +41,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 TextArgsBuilder::default()
                     .text("Interactive Buttons with Hover Effects:".to_string())
                     .size(tessera_ui::Dp(18.0))
-                    .color(md_colors::ON_SURFACE_VARIANT)
+                    .color(current_theme().color_scheme.on_surface_variant)
                     .build()
                     .unwrap(),
             )
@@ -52,8 +53,13 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 let state = app_state.ripple_states.primary.clone();
                 button(
                     ButtonArgsBuilder::default()
-                        .color(md_colors::PRIMARY) // Material Design primary color
-                        .hover_color(Some(Color::new(0.3, 0.6, 0.9, 1.0))) // Lighter blue on hover
+                        .color(current_theme().color_scheme.primary) // Material Design primary color
+                        .hover_color(Some(
+                            current_theme()
+                                .color_scheme
+                                .primary
+                                .lerp_oklab(&current_theme().color_scheme.on_primary, 0.12),
+                        )) // Blend toward the content color on hover
                         .padding(Dp(12.0))
                         .on_click(Arc::new(|| {
                             println!("Primary button clicked!");
@@ -65,7 +71,6 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Primary Button (Hover Effect)".to_string())
-                                .color(md_colors::ON_SURFACE)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -83,8 +88,13 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 let state = app_state.ripple_states.success.clone();
                 button(
                     ButtonArgsBuilder::default()
-                        .color(md_colors::TERTIARY) // Material Design tertiary color
-                        .hover_color(Some(Color::new(0.2, 0.8, 0.4, 1.0))) // Lighter green on hover
+                        .color(current_theme().color_scheme.tertiary) // Material Design tertiary color
+                        .hover_color(Some(
+                            current_theme()
+                                .color_scheme
+                                .tertiary
+                                .lerp_oklab(&current_theme().color_scheme.on_tertiary, 0.12),
+                        )) // Blend toward the content color on hover
                         .padding(Dp(12.0))
                         .on_click(Arc::new(|| {
                             println!("Success button clicked!");
@@ -96,7 +106,6 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Success Button (Hover Effect)".to_string())
-                                .color(md_colors::ON_SURFACE)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -114,8 +123,13 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 let state = app_state.ripple_states.danger.clone();
                 button(
                     ButtonArgsBuilder::default()
-                        .color(md_colors::ERROR) // Material Design error color
-                        .hover_color(Some(Color::new(0.9, 0.3, 0.3, 1.0))) // Lighter red on hover
+                        .color(current_theme().color_scheme.error) // Material Design error color
+                        .hover_color(Some(
+                            current_theme()
+                                .color_scheme
+                                .error
+                                .lerp_oklab(&current_theme().color_scheme.on_error, 0.12),
+                        )) // Blend toward the content color on hover
                         .padding(Dp(12.0))
                         .on_click(Arc::new(|| {
                             println!("Danger button clicked!");
@@ -127,7 +141,6 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Danger Button (Hover Effect)".to_string())
-                                .color(md_colors::ON_SURFACE)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -143,7 +156,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 TextArgsBuilder::default()
                     .text("Animated Checkboxes with Custom Checkmark:".to_string())
                     .size(tessera_ui::Dp(18.0))
-                    .color(md_colors::ON_SURFACE_VARIANT)
+                    .color(current_theme().color_scheme.on_surface_variant)
                     .build()
                     .unwrap(),
             )
@@ -184,7 +197,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text(label.to_string())
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .build()
                                 .unwrap(),
                         )
@@ -208,7 +221,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Primary Glass Button".to_string())
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -231,7 +244,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Secondary Glass Button".to_string())
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -254,7 +267,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Success Glass Button".to_string())
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -277,7 +290,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Danger Glass Button".to_string())
-                                .color(md_colors::ON_SURFACE)
+                                .color(current_theme().color_scheme.on_surface)
                                 .size(Dp(16.0))
                                 .build()
                                 .unwrap(),
@@ -293,7 +306,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 TextArgsBuilder::default()
                     .text("Interactive Surfaces with Hover Effects:".to_string())
                     .size(tessera_ui::Dp(18.0))
-                    .color(md_colors::ON_SURFACE_VARIANT)
+                    .color(current_theme().color_scheme.on_surface_variant)
                     .build()
                     .unwrap(),
             )
@@ -305,14 +318,19 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                 let state = app_state.ripple_states.custom.clone();
                 surface(
                     SurfaceArgsBuilder::default()
-                        .color(md_colors::SECONDARY) // Material Design secondary color
-                        .hover_color(Some(Color::new(0.6, 0.7, 0.9, 1.0))) // Lighter color on hover
-                        .ripple_color(md_colors::RIPPLE) // Material Design ripple
+                        .color(current_theme().color_scheme.secondary) // Material Design secondary color
+                        .hover_color(Some(
+                            current_theme()
+                                .color_scheme
+                                .secondary
+                                .lerp_oklab(&current_theme().color_scheme.on_secondary, 0.12),
+                        )) // Blend toward the content color on hover
+                        .ripple_color(current_theme().color_scheme.on_secondary)
                         .padding(Dp(16.0))
-                        .width(DimensionValue::Fixed(Px(250)))
-                        .height(DimensionValue::Fixed(Px(80)))
+                        .width(DimensionValue::Fixed(Px(250.0)))
+                        .height(DimensionValue::Fixed(Px(80.0)))
                         .border_width(2.0)
-                        .border_color(Some(Color::new(1.0, 1.0, 1.0, 0.8))) // White border
+                        .border_color(Some(current_theme().color_scheme.outline))
                         .on_click(Some(Arc::new(|| {
                             println!("Custom interactive surface clicked!");
                         })))
@@ -323,7 +341,6 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                         text(
                             TextArgsBuilder::default()
                                 .text("Interactive Surface\nwith Hover Effect".to_string())
-                                .color(md_colors::ON_SURFACE)
                                 .size(Dp(14.0))
                                 .build()
                                 .unwrap(),
@@ -338,10 +355,10 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
         || {
             surface(
                 SurfaceArgsBuilder::default()
-                    .color(md_colors::SURFACE_VARIANT) // Material Design surface-variant
+                    .color(current_theme().color_scheme.surface_variant) // Material Design surface-variant
                     .padding(Dp(12.0))
-                    .width(DimensionValue::Fixed(Px(200)))
-                    .height(DimensionValue::Fixed(Px(60)))
+                    .width(DimensionValue::Fixed(Px(200.0)))
+                    .height(DimensionValue::Fixed(Px(60.0)))
                     .build()
                     .unwrap(),
                 None, // No ripple state - non-interactive
@@ -349,7 +366,7 @@ pub fn interactive_demo(app_state: Arc<AppState>) {
                     text(
                         TextArgsBuilder::default()
                             .text("Non-interactive Surface\n(No Hover Effect)".to_string())
-                            .color(md_colors::ON_SURFACE_VARIANT)
+                            .color(current_theme().color_scheme.on_surface_variant)
                             .size(Dp(14.0))
                             .build()
                             .unwrap(),
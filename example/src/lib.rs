@@ -3,7 +3,6 @@ mod app_state;
 mod component_showcase;
 mod content_section;
 mod interactive_demo;
-mod material_colors;
 mod misc;
 mod performance_display;
 mod switch_showcase;
@@ -6,7 +6,7 @@ pub fn create_spacer(height: i32) -> impl FnOnce() {
     move || {
         spacer(
             SpacerArgsBuilder::default()
-                .height(DimensionValue::Fixed(Px(height)))
+                .height(DimensionValue::Fixed(Px::new(height)))
                 .width(DimensionValue::Fill {
                     min: None,
                     max: None,
@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use tessera_ui::{DimensionValue, Dp};
+use tessera_ui::{
+    DimensionValue, Dp, TesseraRuntime, runtime::ThemePreference, winit::window::Theme,
+};
 use tessera_ui_basic_components::{
     column::ColumnArgsBuilder,
     column_ui,
@@ -10,23 +12,38 @@ use tessera_ui_basic_components::{
     surface::{SurfaceArgsBuilder, surface},
     switch::{SwitchArgsBuilder, SwitchState, switch},
     text::{TextArgsBuilder, text},
+    theme::current_theme,
 };
 use tessera_ui_macros::tessera;
 
-use crate::{material_colors::md_colors, misc::create_spacer};
+use crate::misc::create_spacer;
 
 #[tessera]
 pub fn switch_showcase(state: Arc<Mutex<SwitchState>>) {
+    // The switch shows the runtime theme, which follows the system until it is toggled
+    let dark = TesseraRuntime::theme() == Theme::Dark;
+    {
+        let mut state = state.lock();
+        if state.checked != dark {
+            state.toggle();
+        }
+    }
+
     let on_toggle = {
         let state = state.clone();
-        Arc::new(move |_| {
+        Arc::new(move |checked| {
             state.lock().toggle();
+            TesseraRuntime::set_theme_preference(if checked {
+                ThemePreference::Dark
+            } else {
+                ThemePreference::Light
+            });
         })
     };
 
     surface(
         SurfaceArgsBuilder::default()
-            .color(md_colors::SURFACE_CONTAINER)
+            .color(current_theme().color_scheme.surface_container)
             .padding(Dp(24.0))
             .width(DimensionValue::Fill {
                 min: None,
@@ -41,9 +58,9 @@ pub fn switch_showcase(state: Arc<Mutex<SwitchState>>) {
                 || {
                     text(
                         TextArgsBuilder::default()
-                            .text("Switch Component".to_string())
+                            .text("Switch Component (Dark Theme)".to_string())
                             .size(tessera_ui::Dp(24.0))
-                            .color(md_colors::ON_SURFACE)
+                            .color(current_theme().color_scheme.on_surface)
                             .build()
                             .unwrap(),
                     )
@@ -52,7 +69,7 @@ pub fn switch_showcase(state: Arc<Mutex<SwitchState>>) {
                 move || {
                     row_ui!(
                         RowArgsBuilder::default().build().unwrap(),
-                        || text("Off"),
+                        || text("Light"),
                         || (create_spacer(16))(),
                         move || {
                             let checked = state.lock().checked;
@@ -66,7 +83,7 @@ pub fn switch_showcase(state: Arc<Mutex<SwitchState>>) {
                             )
                         },
                         || (create_spacer(16))(),
-                        || text("On"),
+                        || text("Dark"),
                     )
                 }
             )
@@ -1,14 +1,13 @@
 use std::sync::Arc;
 
 use parking_lot::RwLock;
-use tessera_ui::{Color, DimensionValue, Px};
-use tessera_ui_basic_components::text_editor::{
-    TextEditorArgsBuilder, TextEditorState, text_editor,
+use tessera_ui::{DimensionValue, Px};
+use tessera_ui_basic_components::{
+    text_editor::{TextEditorArgsBuilder, TextEditorState, text_editor},
+    theme::current_theme,
 };
 use tessera_ui_macros::tessera;
 
-use crate::material_colors::md_colors;
-
 pub struct TextEditorsState {
     pub editor_state: Arc<RwLock<TextEditorState>>,
     pub editor_state_2: Arc<RwLock<TextEditorState>>,
@@ -28,17 +27,12 @@ impl TextEditorsState {
 pub fn text_editor_1(state: Arc<RwLock<TextEditorState>>) {
     text_editor(
         TextEditorArgsBuilder::default()
-            .height(Some(DimensionValue::Fixed(Px(120))))
+            .height(Some(DimensionValue::Fixed(Px(120.0))))
             .width(Some(DimensionValue::Fill {
                 min: None,
                 max: None,
             }))
-            .selection_color(Some(Color::new(
-                md_colors::PRIMARY.r,
-                md_colors::PRIMARY.g,
-                md_colors::PRIMARY.b,
-                0.4,
-            ))) // Material Design primary color with transparency
+            .selection_color(Some(current_theme().color_scheme.tertiary.with_alpha(0.4))) // Tertiary color with transparency
             .build()
             .unwrap(),
         state,
@@ -50,7 +44,7 @@ pub fn text_editor_1(state: Arc<RwLock<TextEditorState>>) {
 pub fn text_editor_2(state: Arc<RwLock<TextEditorState>>) {
     text_editor(
         TextEditorArgsBuilder::default()
-            .height(Some(DimensionValue::Fixed(Px(100))))
+            .height(Some(DimensionValue::Fixed(Px(100.0))))
             .width(Some(DimensionValue::Fill {
                 min: None,
                 max: None,
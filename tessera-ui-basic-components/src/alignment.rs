@@ -55,6 +55,7 @@ impl Default for MainAxisAlignment {
 /// - `Center`: Center children along the cross axis.
 /// - `End`: Align children to the end (right or bottom).
 /// - `Stretch`: Stretch children to fill the cross axis.
+/// - `Baseline`: Align the first baselines of children in a row.
 ///
 /// # Example
 /// ```rust,ignore
@@ -73,6 +74,10 @@ pub enum CrossAxisAlignment {
     End,
     /// Stretch children to fill the entire cross axis.
     Stretch,
+    /// Align children in a [`row`](crate::row::row) so that their first baselines line up,
+    /// e.g. texts of different sizes. Children without a baseline are aligned to the top.
+    /// In a column, children are aligned to the start.
+    Baseline,
 }
 
 impl Default for CrossAxisAlignment {
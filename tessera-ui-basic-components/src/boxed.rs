@@ -30,6 +30,9 @@ pub struct BoxedArgs {
     /// Height behavior for the boxed container.
     #[builder(default = "DimensionValue::Wrap { min: None, max: None }")]
     pub height: DimensionValue,
+    /// Optional ratio of width to height to keep, see [`Constraint::with_aspect_ratio`].
+    #[builder(default, setter(strip_option))]
+    pub aspect_ratio: Option<f32>,
 }
 
 impl Default for BoxedArgs {
@@ -98,7 +101,7 @@ impl<F: FnOnce() + Send + Sync + 'static> AsBoxedItem for F {
 ///
 /// boxed(BoxedArgs::default(), [|| text("Hello".to_string())]);
 /// ```
-#[tessera(render_fn=boxed_ui)]
+#[tessera]
 pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxedItem; N]) {
     let children_items: [BoxedItem; N] =
         children_items_input.map(|item_input| item_input.into_boxed_item());
@@ -110,21 +113,24 @@ pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxe
     }
 
     measure(Box::new(move |input| {
-        let boxed_intrinsic_constraint = Constraint::new(args.width, args.height);
+        let mut boxed_intrinsic_constraint = Constraint::new(args.width, args.height);
+        if let Some(aspect_ratio) = args.aspect_ratio {
+            boxed_intrinsic_constraint = boxed_intrinsic_constraint.with_aspect_ratio(aspect_ratio);
+        }
         let effective_constraint = boxed_intrinsic_constraint.merge(input.parent_constraint);
 
-        let mut max_child_width = Px(0);
-        let mut max_child_height = Px(0);
+        let mut max_child_width = Px(0.0);
+        let mut max_child_height = Px(0.0);
         let mut children_sizes = vec![None; N];
 
-        for i in 0..N {
+        for (i, child_size) in children_sizes.iter_mut().enumerate() {
             let Some(child_id) = input.children_ids.get(i).copied() else {
                 continue; // Skip if no child ID is available
             };
             let child_result = input.measure_child(child_id, &effective_constraint)?;
             max_child_width = max_child_width.max(child_result.width);
             max_child_height = max_child_height.max(child_result.height);
-            children_sizes[i] = Some(child_result);
+            *child_size = Some(child_result);
         }
 
         let final_width = match effective_constraint.width {
@@ -146,6 +152,7 @@ pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxe
                 }
                 w
             }
+            DimensionValue::Fraction(_) => max_child_width,
         };
 
         let final_height = match effective_constraint.height {
@@ -167,6 +174,7 @@ pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxe
                 }
                 h
             }
+            DimensionValue::Fraction(_) => max_child_height,
         };
 
         for (i, child_size_opt) in children_sizes.iter().enumerate() {
@@ -174,10 +182,10 @@ pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxe
                 let child_id = input.children_ids[i];
 
                 let (x, y) = match args.alignment {
-                    Alignment::TopStart => (Px(0), Px(0)),
-                    Alignment::TopCenter => ((final_width - child_size.width) / 2, Px(0)),
-                    Alignment::TopEnd => (final_width - child_size.width, Px(0)),
-                    Alignment::CenterStart => (Px(0), (final_height - child_size.height) / 2),
+                    Alignment::TopStart => (Px(0.0), Px(0.0)),
+                    Alignment::TopCenter => ((final_width - child_size.width) / 2, Px(0.0)),
+                    Alignment::TopEnd => (final_width - child_size.width, Px(0.0)),
+                    Alignment::CenterStart => (Px(0.0), (final_height - child_size.height) / 2),
                     Alignment::Center => (
                         (final_width - child_size.width) / 2,
                         (final_height - child_size.height) / 2,
@@ -186,7 +194,7 @@ pub fn boxed<const N: usize>(args: BoxedArgs, children_items_input: [impl AsBoxe
                         final_width - child_size.width,
                         (final_height - child_size.height) / 2,
                     ),
-                    Alignment::BottomStart => (Px(0), final_height - child_size.height),
+                    Alignment::BottomStart => (Px(0.0), final_height - child_size.height),
                     Alignment::BottomCenter => (
                         (final_width - child_size.width) / 2,
                         final_height - child_size.height,
@@ -23,27 +23,31 @@
 use std::sync::Arc;
 
 use derive_builder::Builder;
-use tessera_ui::{Color, DimensionValue, Dp};
+use tessera_ui::{
+    Color, DimensionValue, Dp,
+    accessibility::{Action, Role, Semantics},
+};
 use tessera_ui_macros::tessera;
 
 use crate::{
     ripple_state::RippleState,
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
 };
 
 /// Arguments for the `button` component.
 #[derive(Builder, Clone)]
 #[builder(pattern = "owned")]
 pub struct ButtonArgs {
-    /// The fill color of the button (RGBA).
-    #[builder(default = "Color::new(0.2, 0.5, 0.8, 1.0)")]
+    /// The fill color of the button (RGBA). Defaults to the theme's primary color.
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub color: Color,
     /// The hover color of the button (RGBA). If None, no hover effect is applied.
     #[builder(default)]
     pub hover_color: Option<Color>,
-    /// The shape of the button.
-    #[builder(default = "Shape::RoundedRectangle { corner_radius: 25.0, g2_k_value: 3.0 }")]
+    /// The shape of the button. Defaults to the theme's extra large corners.
+    #[builder(default = "current_theme().shapes.extra_large")]
     pub shape: Shape,
     /// The padding of the button.
     #[builder(default = "Dp(12.0)")]
@@ -56,8 +60,12 @@ pub struct ButtonArgs {
     pub height: Option<DimensionValue>,
     /// The click callback function
     pub on_click: Arc<dyn Fn() + Send + Sync>,
-    /// The ripple color (RGB) for the button.
-    #[builder(default = "Color::from_rgb(1.0, 1.0, 1.0)")]
+    /// The color of content, such as text, inside the button. If None, it matches `color`,
+    /// for example the theme's `on_primary` color on a primary button.
+    #[builder(default)]
+    pub content_color: Option<Color>,
+    /// The ripple color (RGB) for the button. Defaults to the theme's `on_primary` color.
+    #[builder(default = "current_theme().color_scheme.on_primary")]
     pub ripple_color: Color,
     /// Width of the border. If > 0, an outline will be drawn.
     #[builder(default = "0.0")]
@@ -65,6 +73,10 @@ pub struct ButtonArgs {
     /// Optional color for the border (RGBA). If None and border_width > 0, `color` will be used.
     #[builder(default)]
     pub border_color: Option<Color>,
+    /// An optional label announced by screen readers. If None, screen readers use the
+    /// button's content instead.
+    #[builder(default, setter(strip_option, into))]
+    pub accessibility_label: Option<String>,
 }
 
 impl std::fmt::Debug for ButtonArgs {
@@ -77,9 +89,11 @@ impl std::fmt::Debug for ButtonArgs {
             .field("width", &self.width)
             .field("height", &self.height)
             .field("on_click", &"<callback>")
+            .field("content_color", &self.content_color)
             .field("ripple_color", &self.ripple_color)
             .field("border_width", &self.border_width)
             .field("border_color", &self.border_color)
+            .field("accessibility_label", &self.accessibility_label)
             .finish()
     }
 }
@@ -148,6 +162,21 @@ impl Default for ButtonArgs {
 pub fn button(args: impl Into<ButtonArgs>, ripple_state: Arc<RippleState>, child: impl FnOnce()) {
     let button_args: ButtonArgs = args.into();
 
+    semantics(|| {
+        let on_click = button_args.on_click.clone();
+        let mut button_semantics = Semantics::new(Role::Button)
+            .with_action(Action::Click)
+            .on_action(move |action, _| {
+                if action == Action::Click {
+                    on_click();
+                }
+            });
+        if let Some(label) = &button_args.accessibility_label {
+            button_semantics = button_semantics.with_label(label.clone());
+        }
+        button_semantics
+    });
+
     // Create interactive surface for button
     surface(create_surface_args(&button_args), Some(ripple_state), child);
 }
@@ -173,45 +202,47 @@ fn create_surface_args(args: &ButtonArgs) -> crate::surface::SurfaceArgs {
         .padding(args.padding)
         .border_width(args.border_width)
         .border_color(args.border_color)
+        .content_color(args.content_color)
         .ripple_color(args.ripple_color)
         .on_click(Some(args.on_click.clone()))
         .build()
         .unwrap()
 }
 
-/// Convenience constructors for common button styles
+/// Convenience constructors for common button styles, colored from the current theme
 impl ButtonArgs {
-    /// Create a primary button with default blue styling
+    /// Create a primary button with the theme's primary color
     pub fn primary(on_click: Arc<dyn Fn() + Send + Sync>) -> Self {
-        ButtonArgsBuilder::default()
-            .color(Color::new(0.2, 0.5, 0.8, 1.0)) // Blue
-            .on_click(on_click)
-            .build()
-            .unwrap()
+        let scheme = current_theme().color_scheme;
+        Self::with_colors(scheme.primary, scheme.on_primary, on_click)
     }
 
-    /// Create a secondary button with gray styling
+    /// Create a secondary button with the theme's secondary color
     pub fn secondary(on_click: Arc<dyn Fn() + Send + Sync>) -> Self {
-        ButtonArgsBuilder::default()
-            .color(Color::new(0.6, 0.6, 0.6, 1.0)) // Gray
-            .on_click(on_click)
-            .build()
-            .unwrap()
+        let scheme = current_theme().color_scheme;
+        Self::with_colors(scheme.secondary, scheme.on_secondary, on_click)
     }
 
-    /// Create a success button with green styling
+    /// Create a success button with the theme's tertiary color
     pub fn success(on_click: Arc<dyn Fn() + Send + Sync>) -> Self {
-        ButtonArgsBuilder::default()
-            .color(Color::new(0.1, 0.7, 0.3, 1.0)) // Green
-            .on_click(on_click)
-            .build()
-            .unwrap()
+        let scheme = current_theme().color_scheme;
+        Self::with_colors(scheme.tertiary, scheme.on_tertiary, on_click)
     }
 
-    /// Create a danger button with red styling
+    /// Create a danger button with the theme's error color
     pub fn danger(on_click: Arc<dyn Fn() + Send + Sync>) -> Self {
+        let scheme = current_theme().color_scheme;
+        Self::with_colors(scheme.error, scheme.on_error, on_click)
+    }
+
+    fn with_colors(
+        color: Color,
+        ripple_color: Color,
+        on_click: Arc<dyn Fn() + Send + Sync>,
+    ) -> Self {
         ButtonArgsBuilder::default()
-            .color(Color::new(0.8, 0.2, 0.2, 1.0)) // Red
+            .color(color)
+            .ripple_color(ripple_color)
             .on_click(on_click)
             .build()
             .unwrap()
@@ -15,14 +15,15 @@
 //!
 //! Suitable for both simple and complex UI scenarios requiring a responsive, visually appealing checkbox.
 
-use std::{
-    sync::Arc,
-    time::{Duration, Instant},
-};
+use std::{sync::Arc, time::Duration};
 
 use derive_builder::Builder;
 use parking_lot::RwLock;
-use tessera_ui::{Color, DimensionValue, Dp};
+use tessera_ui::{
+    Color, DimensionValue, Dp,
+    accessibility::{Action, Role, Semantics},
+    animation::{Animatable, AnimationSpec, Easing},
+};
 use tessera_ui_macros::tessera;
 
 use crate::{
@@ -31,6 +32,7 @@ use crate::{
     checkmark::{CheckmarkArgsBuilder, checkmark},
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
 };
 
 #[derive(Clone)]
@@ -61,13 +63,13 @@ pub struct CheckboxArgs {
     #[builder(default = "Dp(24.0)")]
     pub size: Dp,
 
-    #[builder(default = "Color::new(0.8, 0.8, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.surface_variant")]
     pub color: Color,
 
-    #[builder(default = "Color::new(0.6, 0.7, 0.9, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub checked_color: Color,
 
-    #[builder(default = "Color::from_rgb_u8(119, 72, 146)")]
+    #[builder(default = "current_theme().color_scheme.on_primary")]
     pub checkmark_color: Color,
 
     #[builder(default = "5.0")]
@@ -76,7 +78,7 @@ pub struct CheckboxArgs {
     #[builder(default = "1.0")]
     pub checkmark_animation_progress: f32,
 
-    #[builder(default = "Shape::RoundedRectangle{ corner_radius: 4.0, g2_k_value: 3.0 }")]
+    #[builder(default = "current_theme().shapes.extra_small")]
     pub shape: Shape,
 
     #[builder(default)]
@@ -84,6 +86,10 @@ pub struct CheckboxArgs {
 
     #[builder(default = "None")]
     pub state: Option<Arc<CheckboxState>>,
+
+    /// An optional label announced by screen readers.
+    #[builder(default, setter(strip_option, into))]
+    pub accessibility_label: Option<String>,
 }
 
 impl Default for CheckboxArgs {
@@ -92,50 +98,35 @@ impl Default for CheckboxArgs {
     }
 }
 
-// Animation duration for the checkmark stroke (milliseconds)
-const CHECKMARK_ANIMATION_DURATION: Duration = Duration::from_millis(200);
+// Animation of the checkmark stroke
+const CHECKMARK_ANIMATION: AnimationSpec =
+    AnimationSpec::tween(Duration::from_millis(200), Easing::Linear);
 
 /// State for checkmark animation (similar风格 to `SwitchState`)
 pub struct CheckmarkState {
     pub checked: bool,
-    progress: f32,
-    last_toggle_time: Option<Instant>,
+    progress: Animatable<f32>,
 }
 
 impl CheckmarkState {
     pub fn new(initial_state: bool) -> Self {
         Self {
             checked: initial_state,
-            progress: if initial_state { 1.0 } else { 0.0 },
-            last_toggle_time: None,
+            progress: Animatable::new(if initial_state { 1.0 } else { 0.0 })
+                .with_spec(CHECKMARK_ANIMATION),
         }
     }
 
     /// Toggle checked state and start animation
     pub fn toggle(&mut self) {
         self.checked = !self.checked;
-        self.last_toggle_time = Some(Instant::now());
-    }
-
-    /// Update progress based on elapsed time
-    pub fn update_progress(&mut self) {
-        if let Some(start) = self.last_toggle_time {
-            let elapsed = start.elapsed();
-            let fraction =
-                (elapsed.as_secs_f32() / CHECKMARK_ANIMATION_DURATION.as_secs_f32()).min(1.0);
-            self.progress = if self.checked {
-                fraction
-            } else {
-                1.0 - fraction
-            };
-            if fraction >= 1.0 {
-                self.last_toggle_time = None; // Animation ends
-            }
-        }
+        self.progress
+            .animate_to(if self.checked { 1.0 } else { 0.0 });
     }
 
+    /// Current progress of the checkmark stroke, from 0.0 (hidden) to 1.0 (fully drawn)
     pub fn progress(&self) -> f32 {
-        self.progress
+        self.progress.value()
     }
 }
 
@@ -185,14 +176,6 @@ pub fn checkbox(args: impl Into<CheckboxArgs>) {
     // Optional external animation state, similar to Switch component pattern
     let state = args.state.clone();
 
-    // If a state is provided, set up an updater to advance the animation each frame
-    if let Some(state_for_handler) = state.clone() {
-        let checkmark_state = state_for_handler.checkmark.clone();
-        state_handler(Box::new(move |_input| {
-            checkmark_state.write().update_progress();
-        }));
-    }
-
     // Click handler: toggle animation state if present, otherwise simply forward toggle callback
     let on_click = {
         let state = state.clone();
@@ -209,6 +192,25 @@ pub fn checkbox(args: impl Into<CheckboxArgs>) {
         })
     };
 
+    let checked = state
+        .as_ref()
+        .map_or(args.checked, |state| state.checkmark.read().checked);
+    semantics(|| {
+        let on_action = on_click.clone();
+        let mut checkbox_semantics = Semantics::new(Role::CheckBox)
+            .with_toggled(checked)
+            .with_action(Action::Click)
+            .on_action(move |action, _| {
+                if action == Action::Click {
+                    on_action();
+                }
+            });
+        if let Some(label) = &args.accessibility_label {
+            checkbox_semantics = checkbox_semantics.with_label(label.clone());
+        }
+        checkbox_semantics
+    });
+
     let ripple_state = state.as_ref().map(|s| s.ripple.clone());
 
     surface(
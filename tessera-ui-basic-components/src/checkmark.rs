@@ -19,17 +19,17 @@
 //! See [`CheckmarkArgs`] for configuration options and usage examples in the [`checkmark`] function documentation.
 
 use derive_builder::Builder;
-use tessera_ui::{Color, ComputedData, Dp, Px};
+use tessera_ui::{Color, ComputedData, Dp};
 use tessera_ui_macros::tessera;
 
-use crate::pipelines::CheckmarkCommand;
+use crate::{pipelines::CheckmarkCommand, theme::current_theme};
 
 /// Arguments for the `checkmark` component.
 #[derive(Builder, Clone)]
 #[builder(pattern = "owned")]
 pub struct CheckmarkArgs {
     /// Color of the checkmark stroke
-    #[builder(default = "Color::new(0.0, 0.6, 0.0, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub color: Color,
 
     /// Width of the checkmark stroke in pixels
@@ -117,8 +117,8 @@ pub fn checkmark(args: impl Into<CheckmarkArgs>) {
         input.metadata_mut().push_draw_command(command.clone());
 
         Ok(ComputedData {
-            width: Px::new(size_px.to_f32() as i32),
-            height: Px::new(size_px.to_f32() as i32),
+            width: size_px,
+            height: size_px,
         })
     }));
 }
@@ -13,7 +13,10 @@
 //! Typical usage involves composing UI elements that should be laid out in a vertical sequence, with customizable
 //! alignment and spacing behaviors.
 use derive_builder::Builder;
-use tessera_ui::{ComputedData, Constraint, DimensionValue, Px, PxPosition, place_node};
+use tessera_ui::{
+    ComputedData, Constraint, DimensionValue, IntrinsicQuery, IntrinsicSize, MeasurementError,
+    NodeId, Px, PxPosition, place_node,
+};
 use tessera_ui_macros::tessera;
 
 use crate::alignment::{CrossAxisAlignment, MainAxisAlignment};
@@ -36,6 +39,14 @@ pub struct ColumnArgs {
     /// Cross axis alignment (horizontal alignment).
     #[builder(default = "CrossAxisAlignment::Start")]
     pub cross_axis_alignment: CrossAxisAlignment,
+    /// Sizes the column to the minimum or maximum intrinsic width of its children, within
+    /// its width constraint. `None` sizes it from the measured children.
+    #[builder(default)]
+    pub intrinsic_width: Option<IntrinsicSize>,
+    /// Sizes the column to the minimum or maximum intrinsic height of its children, within
+    /// its height constraint. `None` sizes it from the measured children.
+    #[builder(default)]
+    pub intrinsic_height: Option<IntrinsicSize>,
 }
 
 impl Default for ColumnArgs {
@@ -111,6 +122,7 @@ impl<F: FnOnce() + Send + Sync + 'static> AsColumnItem for (F, f32) {
 ///   - `width` and `height`: Define the size constraints of the column itself.
 ///   - `main_axis_alignment`: Controls how children are distributed along the vertical axis (e.g., `Start`, `Center`, `End`, `SpaceBetween`).
 ///   - `cross_axis_alignment`: Controls how children are aligned along the horizontal axis (e.g., `Start`, `Center`, `End`, `Stretch`).
+///   - `intrinsic_width` and `intrinsic_height`: Size the column to the minimum or maximum intrinsic size of its children, e.g. to give children that fill the width the width of the widest child.
 /// - `children_items_input`: An array of child components. Each child can be a simple closure, a `ColumnItem` for more complex configurations, or a tuple `(closure, weight)` to assign a flexible layout weight.
 ///
 /// # Example
@@ -135,6 +147,39 @@ impl<F: FnOnce() + Send + Sync + 'static> AsColumnItem for (F, f32) {
 ///     || text("Third item".to_string()),
 /// );
 /// ```
+///
+/// A column as wide as its widest child, so that children filling the width all get
+/// that width:
+///
+/// ```
+/// use tessera_ui::{DimensionValue, IntrinsicSize};
+/// use tessera_ui_basic_components::column::{column_ui, ColumnArgsBuilder};
+/// use tessera_ui_basic_components::surface::{surface, SurfaceArgsBuilder};
+/// use tessera_ui_basic_components::text::text;
+///
+/// column_ui!(
+///     ColumnArgsBuilder::default()
+///         .intrinsic_width(Some(IntrinsicSize::Max))
+///         .build()
+///         .unwrap(),
+///     || surface(
+///         SurfaceArgsBuilder::default()
+///             .width(DimensionValue::FILLED)
+///             .build()
+///             .unwrap(),
+///         None,
+///         || text("Short".to_string()),
+///     ),
+///     || surface(
+///         SurfaceArgsBuilder::default()
+///             .width(DimensionValue::FILLED)
+///             .build()
+///             .unwrap(),
+///         None,
+///         || text("A much longer item".to_string()),
+///     ),
+/// );
+/// ```
 #[tessera]
 pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsColumnItem; N]) {
     let children_items: [ColumnItem; N] =
@@ -148,21 +193,38 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
         child_weights.push(child_item.weight);
     }
 
+    let intrinsic_args = args.clone();
+    intrinsic(Box::new(move |input| {
+        column_intrinsic_size(
+            &intrinsic_args,
+            input.query,
+            input.cross_size,
+            input.children_ids,
+            |child_id, query, cross_size| input.child_intrinsic_size(child_id, query, cross_size),
+        )
+    }));
+
     measure(Box::new(move |input| {
         let column_intrinsic_constraint = Constraint::new(args.width, args.height);
         // This is the effective constraint for the column itself
-        let column_effective_constraint =
+        let mut column_effective_constraint =
             column_intrinsic_constraint.merge(input.parent_constraint);
+        apply_intrinsic_sizes(
+            &args,
+            &mut column_effective_constraint,
+            input.children_ids,
+            |child_id, query, cross_size| input.intrinsic_size(child_id, query, cross_size),
+        )?;
 
         let mut children_sizes = vec![None; N];
-        let mut max_child_width = Px(0);
+        let mut max_child_width = Px(0.0);
 
-        let should_use_weight_for_height = match column_effective_constraint.height {
-            DimensionValue::Fixed(_) => true,
-            DimensionValue::Fill { max: Some(_), .. } => true,
-            DimensionValue::Wrap { max: Some(_), .. } => true,
-            _ => false,
-        };
+        let should_use_weight_for_height = matches!(
+            column_effective_constraint.height,
+            DimensionValue::Fixed(_)
+                | DimensionValue::Fill { max: Some(_), .. }
+                | DimensionValue::Wrap { max: Some(_), .. }
+        );
 
         if should_use_weight_for_height {
             let available_height_for_children =
@@ -185,7 +247,7 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
                 }
             }
 
-            let mut total_height_of_unweighted_children = Px(0);
+            let mut total_height_of_unweighted_children = Px(0.0);
             for &child_idx in &unweighted_children_indices {
                 let Some(child_id) = input.children_ids.get(child_idx).copied() else {
                     continue;
@@ -211,13 +273,12 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
             }
 
             let remaining_height_for_weighted_children =
-                (available_height_for_children - total_height_of_unweighted_children).max(Px(0));
+                (available_height_for_children - total_height_of_unweighted_children).max(Px(0.0));
             if total_weight_sum > 0.0 {
                 for &child_idx in &weighted_children_indices {
                     let child_weight = child_weights[child_idx].unwrap_or(0.0);
-                    let allocated_height_for_child =
-                        Px((remaining_height_for_weighted_children.0 as f32
-                            * (child_weight / total_weight_sum)) as i32);
+                    let allocated_height_for_child = remaining_height_for_weighted_children
+                        .mul_f32(child_weight / total_weight_sum);
                     let child_id = input.children_ids[child_idx];
 
                     // Parent (column) offers its own effective width constraint.
@@ -239,7 +300,7 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
             let total_measured_children_height: Px = children_sizes
                 .iter()
                 .filter_map(|size_opt| size_opt.as_ref().map(|s| s.height))
-                .fold(Px(0), |acc, height| acc + height);
+                .fold(Px(0.0), |acc, height| acc + height);
 
             let final_column_height = match column_effective_constraint.height {
                 DimensionValue::Fixed(h) => h,
@@ -292,9 +353,9 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
             })
         } else {
             // Not using weight logic for height (column height is Wrap or Fill without max)
-            let mut total_children_measured_height = Px(0);
+            let mut total_children_measured_height = Px(0.0);
 
-            for i in 0..N {
+            for (i, child_size) in children_sizes.iter_mut().enumerate() {
                 let child_id = input.children_ids[i];
 
                 // Parent (column) offers its effective width and Wrap for height
@@ -310,7 +371,7 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
                 let child_result =
                     input.measure_child(child_id, &parent_offered_constraint_for_child)?;
 
-                children_sizes[i] = Some(child_result);
+                *child_size = Some(child_result);
                 total_children_measured_height += child_result.height;
                 max_child_width = max_child_width.max(child_result.width);
             }
@@ -340,6 +401,7 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
                     }
                     h
                 }
+                DimensionValue::Fraction(_) => total_children_measured_height,
             };
 
             let final_column_width = match column_effective_constraint.width {
@@ -369,6 +431,7 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
                     }
                     w
                 }
+                DimensionValue::Fraction(_) => max_child_width,
             };
 
             place_children_with_alignment(
@@ -395,6 +458,73 @@ pub fn column<const N: usize>(args: ColumnArgs, children_items_input: [impl AsCo
     }
 }
 
+/// Combines the intrinsic sizes of the children of a column: the widest child gives its
+/// width, and the children stacked on top of each other give its height.
+///
+/// A fixed width or height in the arguments is used as is, without querying the children.
+fn column_intrinsic_size(
+    args: &ColumnArgs,
+    query: IntrinsicQuery,
+    cross_size: Option<Px>,
+    children_ids: &[NodeId],
+    child_intrinsic_size: impl Fn(NodeId, IntrinsicQuery, Option<Px>) -> Result<Px, MeasurementError>,
+) -> Result<Px, MeasurementError> {
+    let dimension = if query.is_width() {
+        args.width
+    } else {
+        args.height
+    };
+    if let DimensionValue::Fixed(size) = dimension {
+        return Ok(size);
+    }
+    let mut size = Px(0.0);
+    for &child_id in children_ids {
+        let child_size = child_intrinsic_size(child_id, query, cross_size)?;
+        size = if !query.is_width() {
+            size + child_size
+        } else {
+            size.max(child_size)
+        };
+    }
+    Ok(dimension.clamp(size))
+}
+
+/// Replaces the width and height of the effective constraint with the intrinsic sizes
+/// requested in the arguments, kept within the bounds of the constraint.
+fn apply_intrinsic_sizes(
+    args: &ColumnArgs,
+    constraint: &mut Constraint,
+    children_ids: &[NodeId],
+    child_intrinsic_size: impl Fn(NodeId, IntrinsicQuery, Option<Px>) -> Result<Px, MeasurementError>,
+) -> Result<(), MeasurementError> {
+    if let Some(size) = args.intrinsic_width {
+        let width = column_intrinsic_size(
+            args,
+            IntrinsicQuery::width(size),
+            None,
+            children_ids,
+            &child_intrinsic_size,
+        )?;
+        constraint.width = DimensionValue::Fixed(constraint.width.clamp(width));
+    }
+    if let Some(size) = args.intrinsic_height {
+        let width = match constraint.width {
+            DimensionValue::Fixed(width) => Some(width),
+            _ => None,
+        };
+        let height = column_intrinsic_size(
+            args,
+            IntrinsicQuery::height(size),
+            width,
+            children_ids,
+            &child_intrinsic_size,
+        )?;
+        constraint.height = DimensionValue::Fixed(constraint.height.clamp(height));
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
 fn place_children_with_alignment(
     children_sizes: &[Option<ComputedData>],
     children_ids: &[tessera_ui::NodeId],
@@ -406,27 +536,27 @@ fn place_children_with_alignment(
     cross_axis_alignment: CrossAxisAlignment,
     child_count: usize,
 ) {
-    let available_space = (final_column_height - total_children_height).max(Px(0));
+    let available_space = (final_column_height - total_children_height).max(Px(0.0));
 
     let (mut current_y, spacing_between_children) = match main_axis_alignment {
-        MainAxisAlignment::Start => (Px(0), Px(0)),
-        MainAxisAlignment::Center => (available_space / 2, Px(0)),
-        MainAxisAlignment::End => (available_space, Px(0)),
+        MainAxisAlignment::Start => (Px(0.0), Px(0.0)),
+        MainAxisAlignment::Center => (available_space / 2, Px(0.0)),
+        MainAxisAlignment::End => (available_space, Px(0.0)),
         MainAxisAlignment::SpaceEvenly => {
             if child_count > 0 {
                 let s = available_space / (child_count as i32 + 1);
                 (s, s)
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
         MainAxisAlignment::SpaceBetween => {
             if child_count > 1 {
-                (Px(0), available_space / (child_count as i32 - 1))
+                (Px(0.0), available_space / (child_count as i32 - 1))
             } else if child_count == 1 {
-                (available_space / 2, Px(0))
+                (available_space / 2, Px(0.0))
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
         MainAxisAlignment::SpaceAround => {
@@ -434,7 +564,7 @@ fn place_children_with_alignment(
                 let s = available_space / (child_count as i32);
                 (s / 2, s)
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
     };
@@ -444,14 +574,14 @@ fn place_children_with_alignment(
             let child_id = children_ids[i];
 
             let x_offset = match cross_axis_alignment {
-                CrossAxisAlignment::Start => Px(0),
+                CrossAxisAlignment::Start | CrossAxisAlignment::Baseline => Px(0.0),
                 CrossAxisAlignment::Center => {
-                    (final_column_width - child_actual_size.width).max(Px(0)) / 2
+                    (final_column_width - child_actual_size.width).max(Px(0.0)) / 2
                 }
                 CrossAxisAlignment::End => {
-                    (final_column_width - child_actual_size.width).max(Px(0))
+                    (final_column_width - child_actual_size.width).max(Px(0.0))
                 }
-                CrossAxisAlignment::Stretch => Px(0),
+                CrossAxisAlignment::Stretch => Px(0.0),
             };
 
             place_node(child_id, PxPosition::new(x_offset, current_y), metadatas);
@@ -494,3 +624,76 @@ macro_rules! column_ui {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn child_sizes(
+        child_id: NodeId,
+        query: IntrinsicQuery,
+        _cross_size: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        let index = usize::from(child_id) as f32;
+        Ok(if query.is_width() {
+            Px(10.0 * index)
+        } else {
+            Px(5.0)
+        })
+    }
+
+    fn children_ids() -> Vec<NodeId> {
+        let mut arena = tessera_ui::Arena::new();
+        (0..3).map(|_| arena.new_node(())).collect()
+    }
+
+    #[test]
+    fn test_intrinsic_width_is_widest_child() {
+        let width = column_intrinsic_size(
+            &ColumnArgs::default(),
+            IntrinsicQuery::MaxWidth,
+            None,
+            &children_ids(),
+            child_sizes,
+        )
+        .unwrap();
+        assert_eq!(width, Px(30.0));
+    }
+
+    #[test]
+    fn test_intrinsic_height_is_sum_of_children_within_bounds() {
+        let args = ColumnArgsBuilder::default()
+            .height(DimensionValue::Wrap {
+                min: None,
+                max: Some(Px(12.0)),
+            })
+            .build()
+            .unwrap();
+        let height = column_intrinsic_size(
+            &args,
+            IntrinsicQuery::MinHeight,
+            None,
+            &children_ids(),
+            child_sizes,
+        )
+        .unwrap();
+        assert_eq!(height, Px(12.0));
+    }
+
+    #[test]
+    fn test_fixed_size_skips_children() {
+        let args = ColumnArgsBuilder::default()
+            .width(DimensionValue::Fixed(Px(7.0)))
+            .build()
+            .unwrap();
+        let width = column_intrinsic_size(
+            &args,
+            IntrinsicQuery::MinWidth,
+            None,
+            &children_ids(),
+            |_, _, _| Err(MeasurementError::NodeNotFoundInTree),
+        )
+        .unwrap();
+        assert_eq!(width, Px(7.0));
+    }
+}
@@ -12,10 +12,17 @@
 use std::sync::Arc;
 
 use derive_builder::Builder;
-use tessera_ui::{Color, DimensionValue, winit};
+use tessera_ui::{
+    DimensionValue,
+    accessibility::{Role, Semantics},
+    winit,
+};
 use tessera_ui_macros::tessera;
 
-use crate::surface::{SurfaceArgsBuilder, surface};
+use crate::{
+    surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
+};
 
 /// Arguments for the [`dialog_provider`] component.
 #[derive(Builder)]
@@ -91,7 +98,7 @@ pub fn dialog_provider(
         // and triggering the close request.
         surface(
             SurfaceArgsBuilder::default()
-                .color(Color::BLACK.with_alpha(0.5))
+                .color(current_theme().color_scheme.scrim.with_alpha(0.5))
                 .on_click(Some(args.on_close_request))
                 .width(DimensionValue::Fill {
                     min: None,
@@ -115,18 +122,27 @@ pub fn dialog_provider(
 
             // Check the consumed events for the 'Escape' key press.
             for event in events {
-                if event.state == winit::event::ElementState::Pressed {
-                    if let winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape) =
+                if event.state == winit::event::ElementState::Pressed
+                    && let winit::keyboard::PhysicalKey::Code(winit::keyboard::KeyCode::Escape) =
                         event.physical_key
-                    {
-                        (on_close_for_keyboard)();
-                    }
+                {
+                    (on_close_for_keyboard)();
                 }
             }
         }));
 
         // 2c. Dialog Content
         // The user-defined dialog content is rendered on top of everything.
-        dialog_content();
+        dialog_container(dialog_content);
     }
 }
+
+/// Wraps the dialog content in a node that announces it as a modal dialog.
+///
+/// Uses the default layout, so the content is measured and placed exactly as if it were a
+/// direct child of the provider.
+#[tessera]
+fn dialog_container(content: impl FnOnce()) {
+    semantics(|| Semantics::new(Role::Dialog).with_modal(true));
+    content();
+}
@@ -209,8 +209,8 @@ pub fn fluid_glass(
             child_measurement
         } else {
             ComputedData {
-                width: Px(0),
-                height: Px(0),
+                width: Px(0.0),
+                height: Px(0.0),
             }
         };
 
@@ -250,18 +250,19 @@ pub fn fluid_glass(
         let width = match effective_glass_constraint.width {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(min_width)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => max
                 .expect("Seems that you are trying to fill an infinite width, which is not allowed")
                 .max(min_width)
-                .max(min.unwrap_or(Px(0))),
+                .max(min.unwrap_or(Px(0.0))),
+            DimensionValue::Fraction(_) => min_width,
         };
         let height = match effective_glass_constraint.height {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(min_height)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => max
@@ -269,7 +270,8 @@ pub fn fluid_glass(
                     "Seems that you are trying to fill an infinite height, which is not allowed",
                 )
                 .max(min_height)
-                .max(min.unwrap_or(Px(0))),
+                .max(min.unwrap_or(Px(0.0))),
+            DimensionValue::Fraction(_) => min_height,
         };
         Ok(ComputedData { width, height })
     }));
@@ -287,25 +289,25 @@ pub fn fluid_glass(
                 input.requests.cursor_icon = CursorIcon::Pointer;
             }
 
-            if is_cursor_in {
-                if let Some(_event) = input.cursor_events.iter().find(|e| {
+            if is_cursor_in
+                && let Some(_event) = input.cursor_events.iter().find(|e| {
                     matches!(
                         e.content,
                         CursorEventContent::Pressed(PressKeyEventType::Left)
                     )
-                }) {
-                    if let Some(ripple_state) = &ripple_state {
-                        if let Some(pos) = input.cursor_position {
-                            let size = input.computed_data;
-                            let normalized_pos = [
-                                pos.x.to_f32() / size.width.to_f32(),
-                                pos.y.to_f32() / size.height.to_f32(),
-                            ];
-                            ripple_state.start_animation(normalized_pos);
-                        }
-                    }
-                    on_click();
+                })
+            {
+                if let Some(ripple_state) = &ripple_state
+                    && let Some(pos) = input.cursor_position
+                {
+                    let size = input.computed_data;
+                    let normalized_pos = [
+                        pos.x.to_f32() / size.width.to_f32(),
+                        pos.y.to_f32() / size.height.to_f32(),
+                    ];
+                    ripple_state.start_animation(normalized_pos);
                 }
+                on_click();
             }
         }));
     }
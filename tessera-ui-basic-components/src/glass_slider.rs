@@ -170,7 +170,7 @@ pub fn glass_slider(args: impl Into<GlassSliderArgs>, state: Arc<Mutex<GlassSlid
                 - (args.track_border_width.to_px().to_f32() * 2.0);
             surface(
                 SurfaceArgsBuilder::default()
-                    .width(DimensionValue::Fixed(Px(progress_width as i32)))
+                    .width(DimensionValue::Fixed(Px(progress_width)))
                     .height(DimensionValue::Fill {
                         min: None,
                         max: None,
@@ -199,9 +199,9 @@ pub fn glass_slider(args: impl Into<GlassSliderArgs>, state: Arc<Mutex<GlassSlid
         let mut state = state_handler_state.lock();
 
         let is_in_component = input.cursor_position.is_some_and(|cursor_pos| {
-            cursor_pos.x.0 >= 0
+            cursor_pos.x.0 >= 0.0
                 && cursor_pos.x.0 < input.computed_data.width.0
-                && cursor_pos.y.0 >= 0
+                && cursor_pos.y.0 >= 0.0
                 && cursor_pos.y.0 < input.computed_data.height.0
         });
 
@@ -224,7 +224,7 @@ pub fn glass_slider(args: impl Into<GlassSliderArgs>, state: Arc<Mutex<GlassSlid
 
                     if let Some(pos) = input.cursor_position {
                         let v =
-                            (pos.x.0 as f32 / input.computed_data.width.0 as f32).clamp(0.0, 1.0);
+                            (pos.x.to_f32() / input.computed_data.width.to_f32()).clamp(0.0, 1.0);
                         new_value = Some(v);
                     }
                 }
@@ -235,17 +235,17 @@ pub fn glass_slider(args: impl Into<GlassSliderArgs>, state: Arc<Mutex<GlassSlid
             }
         }
 
-        if state.is_dragging {
-            if let Some(pos) = input.cursor_position {
-                let v = (pos.x.0 as f32 / input.computed_data.width.0 as f32).clamp(0.0, 1.0);
-                new_value = Some(v);
-            }
+        if state.is_dragging
+            && let Some(pos) = input.cursor_position
+        {
+            let v = (pos.x.to_f32() / input.computed_data.width.to_f32()).clamp(0.0, 1.0);
+            new_value = Some(v);
         }
 
-        if let Some(v) = new_value {
-            if (v - args.value).abs() > f32::EPSILON {
-                on_change(v);
-            }
+        if let Some(v) = new_value
+            && (v - args.value).abs() > f32::EPSILON
+        {
+            on_change(v);
         }
     }));
 
@@ -261,7 +261,7 @@ pub fn glass_slider(args: impl Into<GlassSliderArgs>, state: Arc<Mutex<GlassSlid
             DimensionValue::Fixed(self_height),
         );
         input.measure_child(track_id, &track_constraint)?;
-        input.place_child(track_id, PxPosition::new(Px(0), Px(0)));
+        input.place_child(track_id, PxPosition::new(Px(0.0), Px(0.0)));
 
         Ok(ComputedData {
             width: self_width,
@@ -9,16 +9,15 @@
 //!
 //! See [`glass_switch()`](tessera-ui-basic-components/src/glass_switch.rs:142) for usage details and customization options.
 
-use std::{
-    sync::Arc,
-    time::{Duration, Instant},
-};
+use std::{sync::Arc, time::Duration};
 
 use derive_builder::Builder;
 use parking_lot::Mutex;
 use tessera_ui::{
     Color, ComputedData, Constraint, CursorEventContent, DimensionValue, Dp, PressKeyEventType,
-    PxPosition, winit::window::CursorIcon,
+    PxPosition,
+    animation::{Animatable, AnimationSpec, Easing},
+    winit::window::CursorIcon,
 };
 use tessera_ui_macros::tessera;
 
@@ -27,27 +26,31 @@ use crate::{
     shape_def::Shape,
 };
 
-const ANIMATION_DURATION: Duration = Duration::from_millis(150);
+const ANIMATION: AnimationSpec = AnimationSpec::tween(Duration::from_millis(150), Easing::Linear);
 
 /// State for the `glass_switch` component, handling animation.
 pub struct GlassSwitchState {
     pub checked: bool,
-    progress: Mutex<f32>,
-    last_toggle_time: Mutex<Option<Instant>>,
+    progress: Animatable<f32>,
 }
 
 impl GlassSwitchState {
     pub fn new(initial_state: bool) -> Self {
         Self {
             checked: initial_state,
-            progress: Mutex::new(if initial_state { 1.0 } else { 0.0 }),
-            last_toggle_time: Mutex::new(None),
+            progress: Animatable::new(if initial_state { 1.0 } else { 0.0 }).with_spec(ANIMATION),
         }
     }
 
     pub fn toggle(&mut self) {
         self.checked = !self.checked;
-        *self.last_toggle_time.lock() = Some(Instant::now());
+        self.progress
+            .animate_to(if self.checked { 1.0 } else { 0.0 });
+    }
+
+    /// Returns the animated thumb position, from 0.0 (off) to 1.0 (on).
+    pub fn progress(&self) -> f32 {
+        self.progress.value()
     }
 }
 
@@ -159,7 +162,7 @@ pub fn glass_switch(args: impl Into<GlassSwitchArgs>) {
     let progress = args
         .state
         .as_ref()
-        .map(|s| *s.lock().progress.lock())
+        .map(|s| s.lock().progress())
         .unwrap_or(if args.checked { 1.0 } else { 0.0 });
     let track_color = Color {
         r: args.track_off_color.r + (args.track_on_color.r - args.track_off_color.r) * progress,
@@ -204,24 +207,9 @@ pub fn glass_switch(args: impl Into<GlassSwitchArgs>) {
     let checked = args.checked;
 
     state_handler(Box::new(move |input| {
-        if let Some(state) = &state {
-            let state = state.lock();
-            let mut progress = state.progress.lock();
-            if let Some(last_toggle_time) = *state.last_toggle_time.lock() {
-                let elapsed = last_toggle_time.elapsed();
-                let animation_fraction =
-                    (elapsed.as_secs_f32() / ANIMATION_DURATION.as_secs_f32()).min(1.0);
-                *progress = if state.checked {
-                    animation_fraction
-                } else {
-                    1.0 - animation_fraction
-                };
-            }
-        }
-
         let size = input.computed_data;
         let is_cursor_in = if let Some(pos) = input.cursor_position {
-            pos.x.0 >= 0 && pos.x.0 < size.width.0 && pos.y.0 >= 0 && pos.y.0 < size.height.0
+            pos.x.0 >= 0.0 && pos.x.0 < size.width.0 && pos.y.0 >= 0.0 && pos.y.0 < size.height.0
         } else {
             false
         };
@@ -229,13 +217,13 @@ pub fn glass_switch(args: impl Into<GlassSwitchArgs>) {
             input.requests.cursor_icon = CursorIcon::Pointer;
         }
         for e in input.cursor_events.iter() {
-            if let CursorEventContent::Pressed(PressKeyEventType::Left) = &e.content {
-                if is_cursor_in {
-                    if let Some(state) = &state {
-                        state.lock().toggle();
-                    }
-                    on_toggle(!checked);
+            if let CursorEventContent::Pressed(PressKeyEventType::Left) = &e.content
+                && is_cursor_in
+            {
+                if let Some(state) = &state {
+                    state.lock().toggle();
                 }
+                on_toggle(!checked);
             }
         }
     }));
@@ -270,22 +258,19 @@ pub fn glass_switch(args: impl Into<GlassSwitchArgs>) {
         let progress = args
             .state
             .as_ref()
-            .map(|s| *s.lock().progress.lock())
+            .map(|s| s.lock().progress())
             .unwrap_or(if args.checked { 1.0 } else { 0.0 });
         // Place track at origin
         input.place_child(
             track_id,
-            PxPosition::new(tessera_ui::Px(0), tessera_ui::Px(0)),
+            PxPosition::new(tessera_ui::Px(0.0), tessera_ui::Px(0.0)),
         );
         // Place thumb according to progress
         let start_x = thumb_padding_px;
         let end_x = self_width_px - thumb_size.width - thumb_padding_px;
-        let thumb_x = start_x.0 as f32 + (end_x.0 - start_x.0) as f32 * progress;
+        let thumb_x = start_x.0 + (end_x.0 - start_x.0) * progress;
         let thumb_y = (self_height_px - thumb_size.height) / 2;
-        input.place_child(
-            thumb_id,
-            PxPosition::new(tessera_ui::Px(thumb_x as i32), thumb_y),
-        );
+        input.place_child(thumb_id, PxPosition::new(tessera_ui::Px(thumb_x), thumb_y));
         Ok(ComputedData {
             width: self_width_px,
             height: self_height_px,
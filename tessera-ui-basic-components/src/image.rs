@@ -70,12 +70,14 @@ pub struct ImageArgs {
     pub data: ImageData,
 
     /// An optional explicit width for the image. If `None`, the image's intrinsic
-    /// width will be used.
+    /// width will be used, or, if only the height is given, the width that keeps the
+    /// image's aspect ratio.
     #[builder(default, setter(strip_option))]
     pub width: Option<DimensionValue>,
 
     /// An optional explicit height for the image. If `None`, the image's intrinsic
-    /// height will be used.
+    /// height will be used, or, if only the width is given, the height that keeps the
+    /// image's aspect ratio.
     #[builder(default, setter(strip_option))]
     pub height: Option<DimensionValue>,
 }
@@ -118,7 +120,8 @@ impl From<ImageData> for ImageArgs {
 /// // Renders the image with its intrinsic size by passing `ImageData` directly.
 /// image(image_data.clone());
 ///
-/// // Renders the image with a fixed width using `ImageArgs`.
+/// // Renders the image with a fixed width using `ImageArgs`. The height follows the
+/// // image's aspect ratio.
 /// image(
 ///     ImageArgsBuilder::default()
 ///         .data(image_data)
@@ -132,48 +135,82 @@ pub fn image(args: impl Into<ImageArgs>) {
     let image_args: ImageArgs = args.into();
 
     measure(Box::new(move |input| {
-        let intrinsic_width = Px(image_args.data.width as i32);
-        let intrinsic_height = Px(image_args.data.height as i32);
-
-        let image_intrinsic_width = image_args.width.unwrap_or(DimensionValue::Wrap {
-            min: Some(intrinsic_width),
-            max: Some(intrinsic_width),
-        });
-        let image_intrinsic_height = image_args.height.unwrap_or(DimensionValue::Wrap {
-            min: Some(intrinsic_height),
-            max: Some(intrinsic_height),
-        });
-
-        let image_intrinsic_constraint =
+        let intrinsic_width = Px::from(image_args.data.width);
+        let intrinsic_height = Px::from(image_args.data.height);
+
+        // With only one dimension given, the other one follows the image's aspect ratio
+        let aspect_ratio = (image_args.width.is_some() != image_args.height.is_some()
+            && image_args.data.width > 0
+            && image_args.data.height > 0)
+            .then(|| image_args.data.width as f32 / image_args.data.height as f32);
+        let unspecified = |intrinsic_size| match aspect_ratio {
+            Some(_) => DimensionValue::WRAP,
+            None => DimensionValue::Wrap {
+                min: Some(intrinsic_size),
+                max: Some(intrinsic_size),
+            },
+        };
+        let image_intrinsic_width = image_args
+            .width
+            .unwrap_or_else(|| unspecified(intrinsic_width));
+        let image_intrinsic_height = image_args
+            .height
+            .unwrap_or_else(|| unspecified(intrinsic_height));
+
+        let mut image_intrinsic_constraint =
             Constraint::new(image_intrinsic_width, image_intrinsic_height);
+        if let Some(aspect_ratio) = aspect_ratio {
+            image_intrinsic_constraint = image_intrinsic_constraint.with_aspect_ratio(aspect_ratio);
+        }
         let effective_image_constraint = image_intrinsic_constraint.merge(input.parent_constraint);
 
         let width = match effective_image_constraint.width {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(intrinsic_width)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => {
                 let parent_max = input.parent_constraint.width.to_max_px(Px::MAX);
                 max.unwrap_or(parent_max)
-                    .max(min.unwrap_or(Px(0)))
+                    .max(min.unwrap_or(Px(0.0)))
                     .max(intrinsic_width)
             }
+            DimensionValue::Fraction(_) => intrinsic_width,
         };
 
         let height = match effective_image_constraint.height {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(intrinsic_height)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => {
                 let parent_max = input.parent_constraint.height.to_max_px(Px::MAX);
                 max.unwrap_or(parent_max)
-                    .max(min.unwrap_or(Px(0)))
+                    .max(min.unwrap_or(Px(0.0)))
                     .max(intrinsic_height)
             }
+            DimensionValue::Fraction(_) => intrinsic_height,
+        };
+
+        // A given dimension that wraps the image still decides the size of the other one
+        let (width, height) = match (aspect_ratio, effective_image_constraint) {
+            (
+                Some(aspect_ratio),
+                Constraint {
+                    width: w,
+                    height: h,
+                    ..
+                },
+            ) if !matches!((w, h), (DimensionValue::Fixed(_), DimensionValue::Fixed(_))) => {
+                if image_args.width.is_some() {
+                    (width, h.clamp(width / aspect_ratio))
+                } else {
+                    (w.clamp(height * aspect_ratio), height)
+                }
+            }
+            _ => (width, height),
         };
 
         let image_command = ImageCommand {
@@ -13,7 +13,9 @@
 //! }
 //! ```
 //!
-//! Then you can use the components in your UI.
+//! Then you can use the components in your UI. Their default colors, text sizes and
+//! shapes come from the [`theme`](crate::theme), which can be provided at the root of the
+//! application with [`theme::provide_theme`].
 //!
 //! # Example
 //!
@@ -21,7 +23,7 @@
 //! use std::sync::Arc;
 //! use parking_lot::RwLock;
 //!
-//! use tessera_ui::Dp;
+//! use tessera_ui::Sp;
 //! use tessera_ui_basic_components::{
 //!     button::{button, ButtonArgs},
 //!     text::text,
@@ -41,7 +43,7 @@
 //! );
 //!
 //! // Text editor example
-//! let editor_state = Arc::new(RwLock::new(TextEditorState::new(Dp(16.0), None)));
+//! let editor_state = Arc::new(RwLock::new(TextEditorState::new(Sp(16.0), None)));
 //! text_editor(TextEditorArgs::default(), editor_state.clone());
 //! ```
 
@@ -66,6 +68,7 @@ pub mod glass_switch;
 pub mod row;
 pub mod scrollable;
 pub mod selection_highlight_rect;
+pub mod shader_box;
 pub mod shape_def;
 pub mod slider;
 pub mod spacer;
@@ -74,3 +77,4 @@ pub mod switch;
 pub mod text;
 pub mod text_edit_core;
 pub mod text_editor;
+pub mod theme;
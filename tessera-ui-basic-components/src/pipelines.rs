@@ -2,8 +2,10 @@ pub mod blur;
 pub mod checkmark;
 pub mod contrast;
 pub(crate) mod fluid_glass;
+mod instance_buffer;
 pub mod mean;
 mod pos_misc;
+pub mod shader_quad;
 pub mod shape;
 mod text;
 
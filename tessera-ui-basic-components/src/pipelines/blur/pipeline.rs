@@ -1,6 +1,7 @@
 use encase::{ShaderType, UniformBuffer};
 use tessera_ui::{
-    renderer::compute::ComputablePipeline,
+    renderer::{ShaderSource, compute::ComputablePipeline},
+    shader_source,
     wgpu::{self, util::DeviceExt},
 };
 
@@ -14,16 +15,15 @@ struct BlurUniforms {
 }
 
 pub struct BlurPipeline {
+    shader: ShaderSource,
+    pipeline_layout: wgpu::PipelineLayout,
     pipeline: wgpu::ComputePipeline,
     bind_group_layout: wgpu::BindGroupLayout,
 }
 
 impl BlurPipeline {
     pub fn new(device: &wgpu::Device) -> Self {
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Blur Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("blur.wgsl").into()),
-        });
+        let shader = shader_source!("src/pipelines/blur/blur.wgsl");
 
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             entries: &[
@@ -73,22 +73,32 @@ impl BlurPipeline {
             push_constant_ranges: &[],
         });
 
-        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
-            label: Some("Blur Pipeline"),
-            layout: Some(&pipeline_layout),
-            module: &shader,
-            entry_point: Some("main"),
-            compilation_options: Default::default(),
-            cache: None,
-        });
+        let pipeline = create_pipeline(device, &pipeline_layout, &shader.module(device));
 
         Self {
+            shader,
+            pipeline_layout,
             pipeline,
             bind_group_layout,
         }
     }
 }
 
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+) -> wgpu::ComputePipeline {
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("Blur Pipeline"),
+        layout: Some(layout),
+        module,
+        entry_point: Some("main"),
+        compilation_options: Default::default(),
+        cache: None,
+    })
+}
+
 impl ComputablePipeline<BlurCommand> for BlurPipeline {
     fn dispatch(
         &mut self,
@@ -101,6 +111,12 @@ impl ComputablePipeline<BlurCommand> for BlurPipeline {
         input_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
     ) {
+        if let Some(pipeline) = self.shader.reload(device, |module| {
+            create_pipeline(device, &self.pipeline_layout, module)
+        }) {
+            self.pipeline = pipeline;
+        }
+
         let uniforms = BlurUniforms {
             radius: command.radius,
             direction_x: command.direction.0,
@@ -1,29 +1,44 @@
-struct CheckmarkUniforms {
-    size: vec2f,           // width, height of the checkmark area
-    color: vec4f,          // RGBA color of the checkmark
-    stroke_width: f32,     // thickness of the checkmark lines
-    progress: f32,         // animation progress (0.0 to 1.0)
-    padding: vec2f,        // padding around the checkmark
-};
-
-@group(0) @binding(0)
-var<uniform> checkmark_params: CheckmarkUniforms;
-
-struct VertexInput {
-    @location(0) position: vec3f,
-    @location(1) uv: vec2f,
+struct InstanceInput {
+    @location(0) ndc_rect: vec4f,      // top_left.xy, size.xy in NDC
+    @location(1) color: vec4f,         // RGBA color of the checkmark
+    @location(2) size: vec2f,          // width, height of the checkmark area
+    @location(3) padding: vec2f,       // padding around the checkmark
+    @location(4) stroke_width: f32,    // thickness of the checkmark lines
+    @location(5) progress: f32,        // animation progress (0.0 to 1.0)
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4f,
     @location(0) uv: vec2f,
+    @location(1) @interpolate(flat) color: vec4f,
+    @location(2) @interpolate(flat) size: vec2f,
+    @location(3) @interpolate(flat) padding: vec2f,
+    @location(4) @interpolate(flat) stroke_width: f32,
+    @location(5) @interpolate(flat) progress: f32,
 };
 
 @vertex
-fn vs_main(model: VertexInput) -> VertexOutput {
+fn vs_main(@builtin(vertex_index) idx: u32, instance: InstanceInput) -> VertexOutput {
+    // Corners of the quad, with uv from (0, 0) at the top left to (1, 1) at the bottom right
+    let uvs = array<vec2f, 6>(
+        vec2f(0.0, 0.0),
+        vec2f(0.0, 1.0),
+        vec2f(1.0, 1.0),
+        vec2f(0.0, 0.0),
+        vec2f(1.0, 1.0),
+        vec2f(1.0, 0.0)
+    );
+    let uv = uvs[idx];
+
     var out: VertexOutput;
-    out.clip_position = vec4f(model.position.xy, 0.0, 1.0);
-    out.uv = model.uv;
+    let position = instance.ndc_rect.xy + vec2f(uv.x, -uv.y) * instance.ndc_rect.zw;
+    out.clip_position = vec4f(position, 0.0, 1.0);
+    out.uv = uv;
+    out.color = instance.color;
+    out.size = instance.size;
+    out.padding = instance.padding;
+    out.stroke_width = instance.stroke_width;
+    out.progress = instance.progress;
     return out;
 }
 
@@ -62,11 +77,11 @@ fn get_path_length_at_point(p: vec2f, line1_start: vec2f, line1_end: vec2f, line
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4f {
-    let size = checkmark_params.size;
-    let color = checkmark_params.color;
-    let stroke_width = checkmark_params.stroke_width;
-    let progress = checkmark_params.progress;
-    let padding = checkmark_params.padding;
+    let size = in.size;
+    let color = in.color;
+    let stroke_width = in.stroke_width;
+    let progress = in.progress;
+    let padding = in.padding;
     
     // Convert UV coordinates to pixel coordinates within the padded area
     let padded_size = size - 2.0 * padding;
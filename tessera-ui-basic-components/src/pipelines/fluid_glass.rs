@@ -2,7 +2,8 @@ use encase::{ShaderType, UniformBuffer};
 use glam::{Vec2, Vec4};
 use tessera_ui::{
     PxPosition, PxSize,
-    renderer::DrawablePipeline,
+    renderer::{DrawablePipeline, ShaderSource},
+    shader_source,
     wgpu::{self, util::DeviceExt},
 };
 
@@ -51,6 +52,10 @@ struct GlassUniforms {
 /// let pipeline = FluidGlassPipeline::new(&device, &config, sample_count);
 /// ```
 pub(crate) struct FluidGlassPipeline {
+    shader: ShaderSource,
+    pipeline_layout: wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
     pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
     sampler: wgpu::Sampler,
@@ -58,10 +63,7 @@ pub(crate) struct FluidGlassPipeline {
 
 impl FluidGlassPipeline {
     pub fn new(gpu: &wgpu::Device, config: &wgpu::SurfaceConfiguration, sample_count: u32) -> Self {
-        let shader = gpu.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Fluid Glass Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("fluid_glass/glass.wgsl").into()),
-        });
+        let shader = shader_source!("src/pipelines/fluid_glass/glass.wgsl");
 
         let sampler = gpu.create_sampler(&wgpu::SamplerDescriptor {
             address_mode_u: wgpu::AddressMode::ClampToEdge,
@@ -111,43 +113,19 @@ impl FluidGlassPipeline {
             push_constant_ranges: &[],
         });
 
-        let pipeline = gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Fluid Glass Render Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[],
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: Default::default(),
-            }),
-            primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::TriangleList,
-                strip_index_format: None,
-                front_face: wgpu::FrontFace::Ccw,
-                cull_mode: Some(wgpu::Face::Back),
-                ..Default::default()
-            },
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: sample_count,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-            cache: None,
-        });
+        let pipeline = create_pipeline(
+            gpu,
+            &pipeline_layout,
+            &shader.module(gpu),
+            config.format,
+            sample_count,
+        );
 
         Self {
+            shader,
+            pipeline_layout,
+            format: config.format,
+            sample_count,
             pipeline,
             bind_group_layout,
             sampler,
@@ -155,7 +133,70 @@ impl FluidGlassPipeline {
     }
 }
 
+fn create_pipeline(
+    gpu: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> wgpu::RenderPipeline {
+    gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Fluid Glass Render Pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module,
+            entry_point: Some("vs_main"),
+            buffers: &[],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: Some(wgpu::Face::Back),
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview: None,
+        cache: None,
+    })
+}
+
 impl DrawablePipeline<FluidGlassCommand> for FluidGlassPipeline {
+    fn begin_frame(
+        &mut self,
+        gpu: &wgpu::Device,
+        _gpu_queue: &wgpu::Queue,
+        _config: &wgpu::SurfaceConfiguration,
+    ) {
+        if let Some(pipeline) = self.shader.reload(gpu, |module| {
+            create_pipeline(
+                gpu,
+                &self.pipeline_layout,
+                module,
+                self.format,
+                self.sample_count,
+            )
+        }) {
+            self.pipeline = pipeline;
+        }
+    }
+
     fn draw(
         &mut self,
         gpu: &wgpu::Device,
@@ -172,16 +213,16 @@ impl DrawablePipeline<FluidGlassCommand> for FluidGlassPipeline {
         let screen_h = config.height as f32;
 
         let rect_uv_bounds = [
-            start_pos.x.0 as f32 / screen_w,
-            start_pos.y.0 as f32 / screen_h,
-            (start_pos.x.0 + size.width.0) as f32 / screen_w,
-            (start_pos.y.0 + size.height.0) as f32 / screen_h,
+            start_pos.x.to_f32() / screen_w,
+            start_pos.y.to_f32() / screen_h,
+            (start_pos.x + size.width).to_f32() / screen_w,
+            (start_pos.y + size.height).to_f32() / screen_h,
         ];
 
         let uniforms = GlassUniforms {
             tint_color: args.tint_color.to_array().into(),
             rect_uv_bounds: rect_uv_bounds.into(),
-            rect_size_px: [size.width.0 as f32, size.height.0 as f32].into(),
+            rect_size_px: [size.width.to_f32(), size.height.to_f32()].into(),
             ripple_center: args.ripple_center.unwrap_or([0.0, 0.0]).into(),
             corner_radius: match args.shape {
                 crate::shape_def::Shape::RoundedRectangle { corner_radius, .. } => corner_radius,
@@ -1,3 +1,7 @@
+#import tessera::sdf
+#import tessera::color
+#import tessera::noise
+
 struct GlassUniforms {
     // Grouped by alignment to match Rust struct and std140 layout.
     // vec4s
@@ -72,77 +76,6 @@ fn normal_to_tangent(normal: vec2<f32>) -> vec2<f32> {
     return vec2<f32>(normal.y, -normal.x);
 }
 
-fn sdf_g2_rounded_box(p: vec2<f32>, b: vec2<f32>, r: f32, k: f32) -> f32 {
-    let q = abs(p) - b + r;
-    let v = max(q, vec2<f32>(0.0));
-
-    if abs(k - 2.0) < 0.001 {
-        return length(v) + min(max(q.x, q.y), 0.0) - r;
-    }
-
-    let dist_corner_shape = pow(pow(v.x, k) + pow(v.y, k), 1.0 / k);
-
-    return dist_corner_shape + min(max(q.x, q.y), 0.0) - r;
-}
-
-fn sdf_ellipse(p: vec2<f32>, r: vec2<f32>) -> f32 {
-    if r.x <= 0.0 || r.y <= 0.0 {
-        return 1.0e6;
-    }
-    return (length(p / r) - 1.0) * min(r.x, r.y);
-}
-
-fn grad_sd_g2_rounded_box(coord: vec2<f32>, half_size: vec2<f32>, r: f32, k: f32) -> vec2<f32> {
-    let inner_half_size = half_size - r;
-    let corner_coord = abs(coord) - inner_half_size;
-
-    if corner_coord.x >= 0.0 && corner_coord.y >= 0.0 {
-        let grad_dir = vec2<f32>(
-            pow(corner_coord.x + 0.0001, k - 1.0),
-            pow(corner_coord.y + 0.0001, k - 1.0)
-        );
-        return sign(coord) * normalize(grad_dir);
-    } else {
-        if corner_coord.x > corner_coord.y {
-            return sign(coord) * vec2<f32>(1.0, 0.0);
-        } else {
-            return sign(coord) * vec2<f32>(0.0, 1.0);
-        }
-    }
-}
-
-fn grad_sd_ellipse(coord: vec2<f32>, r: vec2<f32>) -> vec2<f32> {
-    return normalize(coord / (r * r));
-}
-
-fn to_linear_srgb(srgb: vec3<f32>) -> vec3<f32> {
-    let cutoff = vec3<f32>(0.04045);
-    let lower = srgb / vec3<f32>(12.92);
-    let higher = pow((srgb + vec3<f32>(0.055)) / vec3<f32>(1.055), vec3<f32>(2.4));
-    return select(higher, lower, srgb <= cutoff);
-}
-
-fn from_linear_srgb(linear: vec3<f32>) -> vec3<f32> {
-    let cutoff = vec3<f32>(0.0031308);
-    let lower = linear * vec3<f32>(12.92);
-    let higher = vec3<f32>(1.055) * pow(linear, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
-    return select(higher, lower, linear <= cutoff);
-}
-
-fn saturate_color(color: vec4<f32>, amount: f32) -> vec4<f32> {
-    let linear_srgb = to_linear_srgb(color.rgb);
-    let rgb_to_y = vec3<f32>(0.2126, 0.7152, 0.0722);
-    let y = dot(linear_srgb, rgb_to_y);
-    let gray = vec3<f32>(y);
-    let adjusted_linear_srgb = mix(gray, linear_srgb, amount);
-    let adjusted_srgb = from_linear_srgb(adjusted_linear_srgb);
-    return vec4<f32>(adjusted_srgb, color.a);
-}
-
-fn rand(co: vec2<f32>) -> f32 {
-    return fract(sin(dot(co.xy, vec2<f32>(12.9898, 78.233))) * 43758.5453);
-}
-
 fn refraction_color(local_coord: vec2<f32>, size: vec2<f32>, k: f32, rect_uv_start: vec2<f32>, px_to_uv_ratio: vec2<f32>) -> vec4<f32> {
     let half_size = size * 0.5;
     let centered_coord = local_coord - half_size;
@@ -4,10 +4,11 @@ use std::{
     sync::Arc,
 };
 
-use encase::{ShaderType, UniformBuffer};
-use glam::Vec4;
+use bytemuck::{Pod, Zeroable};
 use tessera_ui::{DrawCommand, PxPosition, PxSize, renderer::drawer::DrawablePipeline, wgpu};
 
+use crate::pipelines::{instance_buffer::InstanceBuffer, pos_misc::pixel_to_ndc};
+
 #[derive(Debug, Clone)]
 /// Image pixel data for rendering.
 ///
@@ -45,6 +46,18 @@ impl PartialEq for ImageData {
 
 impl Eq for ImageData {}
 
+impl ImageData {
+    /// Returns whether both show the same image, comparing the pixels only when they are
+    /// not shared.
+    fn same_image(&self, other: &Self) -> bool {
+        if Arc::ptr_eq(&self.data, &other.data) {
+            self.width == other.width && self.height == other.height
+        } else {
+            self == other
+        }
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 /// Command for rendering an image in a UI component.
 ///
@@ -64,19 +77,33 @@ impl DrawCommand for ImageCommand {
     }
 }
 
-#[derive(ShaderType)]
-struct ImageUniforms {
-    rect: Vec4,
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct ImageInstance {
+    /// Top left corner and size of the image in normalized device coordinates
+    ndc_rect: [f32; 4],
+    /// Whether the surface is BGRA, so the channels need swapping
     is_bgra: u32,
 }
 
-struct ImageResources {
-    bind_group: wgpu::BindGroup,
-    uniform_buffer: wgpu::Buffer,
+impl ImageInstance {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+        wgpu::vertex_attr_array![0 => Float32x4, 1 => Uint32];
+
+    fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<ImageInstance>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
 }
 
 /// Pipeline for rendering images in UI components.
 ///
+/// Each batch of images is uploaded at once, and consecutive commands showing the same
+/// image are drawn with a single instanced draw call.
+///
 /// # Example
 /// ```rust,ignore
 /// use tessera_ui_basic_components::pipelines::image::ImagePipeline;
@@ -85,7 +112,8 @@ struct ImageResources {
 pub struct ImagePipeline {
     pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
-    resources: HashMap<ImageData, ImageResources>,
+    resources: HashMap<ImageData, wgpu::BindGroup>,
+    instances: InstanceBuffer<ImageInstance>,
 }
 
 impl ImagePipeline {
@@ -117,16 +145,6 @@ impl ImagePipeline {
                     ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
                     count: None,
                 },
-                wgpu::BindGroupLayoutEntry {
-                    binding: 2,
-                    visibility: wgpu::ShaderStages::VERTEX | wgpu::ShaderStages::FRAGMENT,
-                    ty: wgpu::BindingType::Buffer {
-                        ty: wgpu::BufferBindingType::Uniform,
-                        has_dynamic_offset: false,
-                        min_binding_size: None,
-                    },
-                    count: None,
-                },
             ],
             label: Some("texture_bind_group_layout"),
         });
@@ -143,7 +161,7 @@ impl ImagePipeline {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: Some("vs_main"),
-                buffers: &[],
+                buffers: &[ImageInstance::desc()],
                 compilation_options: Default::default(),
             },
             fragment: Some(wgpu::FragmentState {
@@ -167,11 +185,106 @@ impl ImagePipeline {
             pipeline,
             bind_group_layout,
             resources: HashMap::new(),
+            instances: InstanceBuffer::new(device, "Image Instance Buffer"),
         }
     }
+
+    /// Returns the bind group of the image, uploading it the first time it is drawn.
+    fn bind_group(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        data: &ImageData,
+    ) -> &wgpu::BindGroup {
+        let Self {
+            resources,
+            bind_group_layout,
+            ..
+        } = self;
+        resources
+            .entry(data.clone())
+            .or_insert_with(|| upload(gpu, gpu_queue, config, bind_group_layout, data))
+    }
+}
+
+/// Uploads the pixels of an image to a texture and creates its bind group.
+fn upload(
+    gpu: &wgpu::Device,
+    gpu_queue: &wgpu::Queue,
+    config: &wgpu::SurfaceConfiguration,
+    bind_group_layout: &wgpu::BindGroupLayout,
+    data: &ImageData,
+) -> wgpu::BindGroup {
+    let texture_size = wgpu::Extent3d {
+        width: data.width,
+        height: data.height,
+        depth_or_array_layers: 1,
+    };
+    let diffuse_texture = gpu.create_texture(&wgpu::TextureDescriptor {
+        size: texture_size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: config.format,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
+        label: Some("diffuse_texture"),
+        view_formats: &[],
+    });
+
+    gpu_queue.write_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: &diffuse_texture,
+            mip_level: 0,
+            origin: wgpu::Origin3d::ZERO,
+            aspect: wgpu::TextureAspect::All,
+        },
+        &data.data,
+        wgpu::TexelCopyBufferLayout {
+            offset: 0,
+            bytes_per_row: Some(4 * data.width),
+            rows_per_image: Some(data.height),
+        },
+        texture_size,
+    );
+
+    let diffuse_texture_view = diffuse_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    let diffuse_sampler = gpu.create_sampler(&wgpu::SamplerDescriptor {
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        mag_filter: wgpu::FilterMode::Linear,
+        min_filter: wgpu::FilterMode::Nearest,
+        mipmap_filter: wgpu::FilterMode::Nearest,
+        ..Default::default()
+    });
+
+    gpu.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout: bind_group_layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&diffuse_texture_view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(&diffuse_sampler),
+            },
+        ],
+        label: Some("diffuse_bind_group"),
+    })
 }
 
 impl DrawablePipeline<ImageCommand> for ImagePipeline {
+    fn begin_frame(
+        &mut self,
+        _gpu: &wgpu::Device,
+        _gpu_queue: &wgpu::Queue,
+        _config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.instances.clear();
+    }
+
     fn draw(
         &mut self,
         gpu: &wgpu::Device,
@@ -181,110 +294,94 @@ impl DrawablePipeline<ImageCommand> for ImagePipeline {
         command: &ImageCommand,
         size: PxSize,
         start_pos: PxPosition,
-        _scene_texture_view: &wgpu::TextureView,
+        scene_texture_view: &wgpu::TextureView,
     ) {
-        let resources = self
-            .resources
-            .entry(command.data.clone())
-            .or_insert_with(|| {
-                let texture_size = wgpu::Extent3d {
-                    width: command.data.width,
-                    height: command.data.height,
-                    depth_or_array_layers: 1,
-                };
-                let diffuse_texture = gpu.create_texture(&wgpu::TextureDescriptor {
-                    size: texture_size,
-                    mip_level_count: 1,
-                    sample_count: 1,
-                    dimension: wgpu::TextureDimension::D2,
-                    format: config.format,
-                    usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
-                    label: Some("diffuse_texture"),
-                    view_formats: &[],
-                });
-
-                gpu_queue.write_texture(
-                    wgpu::TexelCopyTextureInfo {
-                        texture: &diffuse_texture,
-                        mip_level: 0,
-                        origin: wgpu::Origin3d::ZERO,
-                        aspect: wgpu::TextureAspect::All,
-                    },
-                    &command.data.data,
-                    wgpu::TexelCopyBufferLayout {
-                        offset: 0,
-                        bytes_per_row: Some(4 * command.data.width),
-                        rows_per_image: Some(command.data.height),
-                    },
-                    texture_size,
-                );
-
-                let diffuse_texture_view =
-                    diffuse_texture.create_view(&wgpu::TextureViewDescriptor::default());
-                let diffuse_sampler = gpu.create_sampler(&wgpu::SamplerDescriptor {
-                    address_mode_u: wgpu::AddressMode::ClampToEdge,
-                    address_mode_v: wgpu::AddressMode::ClampToEdge,
-                    address_mode_w: wgpu::AddressMode::ClampToEdge,
-                    mag_filter: wgpu::FilterMode::Linear,
-                    min_filter: wgpu::FilterMode::Nearest,
-                    mipmap_filter: wgpu::FilterMode::Nearest,
-                    ..Default::default()
-                });
-
-                let uniform_buffer = gpu.create_buffer(&wgpu::BufferDescriptor {
-                    label: Some("Image Uniform Buffer"),
-                    size: ImageUniforms::min_size().get(),
-                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                    mapped_at_creation: false,
-                });
-
-                let diffuse_bind_group = gpu.create_bind_group(&wgpu::BindGroupDescriptor {
-                    layout: &self.bind_group_layout,
-                    entries: &[
-                        wgpu::BindGroupEntry {
-                            binding: 0,
-                            resource: wgpu::BindingResource::TextureView(&diffuse_texture_view),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 1,
-                            resource: wgpu::BindingResource::Sampler(&diffuse_sampler),
-                        },
-                        wgpu::BindGroupEntry {
-                            binding: 2,
-                            resource: uniform_buffer.as_entire_binding(),
-                        },
-                    ],
-                    label: Some("diffuse_bind_group"),
-                });
+        self.draw_batch(
+            gpu,
+            gpu_queue,
+            config,
+            render_pass,
+            &[(command, size, start_pos)],
+            scene_texture_view,
+        );
+    }
 
-                ImageResources {
-                    bind_group: diffuse_bind_group,
-                    uniform_buffer,
-                }
-            });
+    fn draw_batch(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(&ImageCommand, PxSize, PxPosition)],
+        _scene_texture_view: &wgpu::TextureView,
+    ) {
+        if commands.is_empty() {
+            return;
+        }
 
         let is_bgra = matches!(
             config.format,
             wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb
         );
-        let uniforms = ImageUniforms {
-            rect: [
-                (start_pos.x.0 as f32 / config.width as f32) * 2.0 - 1.0
-                    + (size.width.0 as f32 / config.width as f32),
-                (start_pos.y.0 as f32 / config.height as f32) * -2.0 + 1.0
-                    - (size.height.0 as f32 / config.height as f32),
-                size.width.0 as f32 / config.width as f32,
-                size.height.0 as f32 / config.height as f32,
-            ]
-            .into(),
-            is_bgra: if is_bgra { 1 } else { 0 },
-        };
-        let mut buffer = UniformBuffer::new(Vec::new());
-        buffer.write(&uniforms).unwrap();
-        gpu_queue.write_buffer(&resources.uniform_buffer, 0, &buffer.into_inner());
+        let mut batch = Vec::with_capacity(commands.len());
+        for &(_, size, start_pos) in commands {
+            let ndc_pos = pixel_to_ndc(start_pos, [config.width, config.height]);
+            batch.push(ImageInstance {
+                ndc_rect: [
+                    ndc_pos[0],
+                    ndc_pos[1],
+                    size.width.to_f32() / config.width as f32 * 2.0,
+                    size.height.to_f32() / config.height as f32 * 2.0,
+                ],
+                is_bgra: is_bgra as u32,
+            });
+        }
 
+        let instances = self.instances.push(gpu, gpu_queue, &batch);
         render_pass.set_pipeline(&self.pipeline);
-        render_pass.set_bind_group(0, &resources.bind_group, &[]);
-        render_pass.draw(0..6, 0..1);
+        render_pass.set_vertex_buffer(0, self.instances.buffer().slice(..));
+
+        // Draw each run of commands showing the same image at once
+        let mut run_start = 0;
+        for run in commands.chunk_by(|(a, _, _), (b, _, _)| a.data.same_image(&b.data)) {
+            let bind_group = self.bind_group(gpu, gpu_queue, config, &run[0].0.data);
+            render_pass.set_bind_group(0, bind_group, &[]);
+            let run_end = run_start + run.len() as u32;
+            render_pass.draw(0..6, instances.start + run_start..instances.start + run_end);
+            run_start = run_end;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_image() {
+        let pixels = Arc::new(vec![255; 16]);
+        let image = ImageData {
+            data: pixels.clone(),
+            width: 2,
+            height: 2,
+        };
+        let shared = ImageData {
+            data: pixels,
+            width: 2,
+            height: 2,
+        };
+        let copy = ImageData {
+            data: Arc::new(vec![255; 16]),
+            width: 2,
+            height: 2,
+        };
+        let reshaped = ImageData {
+            width: 4,
+            height: 1,
+            ..image.clone()
+        };
+        assert!(image.same_image(&shared));
+        assert!(image.same_image(&copy));
+        assert!(!image.same_image(&reshaped));
     }
 }
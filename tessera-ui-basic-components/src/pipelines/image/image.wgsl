@@ -1,39 +1,32 @@
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) tex_coords: vec2<f32>,
+    @location(1) @interpolate(flat) is_bgra: u32,
 };
 
-struct Uniforms {
-    rect: vec4<f32>,
-    is_bgra: u32,
+struct InstanceInput {
+    @location(0) ndc_rect: vec4<f32>, // top_left.xy, size.xy in NDC
+    @location(1) is_bgra: u32,
 };
-@group(0) @binding(2)
-var<uniform> uniforms: Uniforms;
 
 @vertex
-fn vs_main(@builtin(vertex_index) in_vertex_index: u32) -> VertexOutput {
-    let vertices = array<vec2<f32>, 6>(
-        vec2<f32>(-1.0, -1.0),
-        vec2<f32>(1.0, -1.0),
-        vec2<f32>(1.0, 1.0),
-        vec2<f32>(-1.0, -1.0),
-        vec2<f32>(1.0, 1.0),
-        vec2<f32>(-1.0, 1.0),
-    );
-
+fn vs_main(@builtin(vertex_index) in_vertex_index: u32, instance: InstanceInput) -> VertexOutput {
+    // Corners of the quad, from (0, 0) at the top left to (1, 1) at the bottom right
     let tex_coords = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
         vec2<f32>(0.0, 1.0),
         vec2<f32>(1.0, 1.0),
-        vec2<f32>(1.0, 0.0),
-        vec2<f32>(0.0, 1.0),
-        vec2<f32>(1.0, 0.0),
         vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(1.0, 0.0),
     );
 
     var out: VertexOutput;
-    let pos = vertices[in_vertex_index] * uniforms.rect.zw + uniforms.rect.xy;
+    let corner = tex_coords[in_vertex_index];
+    let pos = instance.ndc_rect.xy + vec2<f32>(corner.x, -corner.y) * instance.ndc_rect.zw;
     out.clip_position = vec4<f32>(pos, 0.0, 1.0);
-    out.tex_coords = tex_coords[in_vertex_index];
+    out.tex_coords = corner;
+    out.is_bgra = instance.is_bgra;
     return out;
 }
 
@@ -45,7 +38,7 @@ var s_diffuse: sampler;
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var color = textureSample(t_diffuse, s_diffuse, in.tex_coords);
-    if uniforms.is_bgra == 1u {
+    if in.is_bgra == 1u {
         color = color.bgra;
     }
     return color;
@@ -0,0 +1,113 @@
+//! A vertex buffer of per-instance data, refilled every frame.
+//!
+//! Instanced pipelines push the instances of each batch they draw, and clear the buffer in
+//! `begin_frame`. The buffer grows as needed and is reused across frames, so drawing does not
+//! create a buffer per command.
+
+use std::{marker::PhantomData, ops::Range};
+
+use bytemuck::Pod;
+use tessera_ui::wgpu;
+
+/// Number of instances the buffer holds before it first grows.
+const INITIAL_CAPACITY: usize = 64;
+
+pub(crate) struct InstanceBuffer<T> {
+    label: &'static str,
+    buffer: wgpu::Buffer,
+    /// Number of instances that fit in `buffer`
+    capacity: usize,
+    /// Number of instances pushed to `buffer` this frame
+    len: usize,
+    _instance: PhantomData<T>,
+}
+
+impl<T: Pod> InstanceBuffer<T> {
+    pub fn new(gpu: &wgpu::Device, label: &'static str) -> Self {
+        Self {
+            label,
+            buffer: create_buffer::<T>(gpu, label, INITIAL_CAPACITY),
+            capacity: INITIAL_CAPACITY,
+            len: 0,
+            _instance: PhantomData,
+        }
+    }
+
+    /// Starts a new frame, making the whole buffer available again.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// Uploads `instances` and returns their range, to draw from [`buffer`](Self::buffer).
+    ///
+    /// When the buffer is full it is replaced with a larger one. Batches drawn earlier in the
+    /// frame keep the old buffer alive through their render pass, so their data is untouched.
+    pub fn push(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        instances: &[T],
+    ) -> Range<u32> {
+        if self.len + instances.len() > self.capacity {
+            self.capacity = (self.capacity * 2).max(instances.len()).next_power_of_two();
+            self.buffer = create_buffer::<T>(gpu, self.label, self.capacity);
+            self.len = 0;
+        }
+        let offset = (self.len * size_of::<T>()) as wgpu::BufferAddress;
+        gpu_queue.write_buffer(&self.buffer, offset, bytemuck::cast_slice(instances));
+        let start = self.len as u32;
+        self.len += instances.len();
+        start..self.len as u32
+    }
+
+    /// The buffer the last pushed instances were written to.
+    pub fn buffer(&self) -> &wgpu::Buffer {
+        &self.buffer
+    }
+}
+
+fn create_buffer<T>(gpu: &wgpu::Device, label: &'static str, capacity: usize) -> wgpu::Buffer {
+    gpu.create_buffer(&wgpu::BufferDescriptor {
+        label: Some(label),
+        size: (capacity * size_of::<T>()) as wgpu::BufferAddress,
+        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_returns_consecutive_ranges() {
+        let (gpu, gpu_queue) = wgpu::Device::noop(&Default::default());
+        let mut buffer = InstanceBuffer::<[f32; 4]>::new(&gpu, "Test Instance Buffer");
+        assert_eq!(buffer.push(&gpu, &gpu_queue, &[[0.0; 4]; 3]), 0..3);
+        assert_eq!(buffer.push(&gpu, &gpu_queue, &[[0.0; 4]; 2]), 3..5);
+
+        buffer.clear();
+        assert_eq!(buffer.push(&gpu, &gpu_queue, &[[0.0; 4]; 4]), 0..4);
+        assert_eq!(buffer.capacity, INITIAL_CAPACITY);
+    }
+
+    #[test]
+    fn test_push_grows_into_new_buffer() {
+        let (gpu, gpu_queue) = wgpu::Device::noop(&Default::default());
+        let mut buffer = InstanceBuffer::<[f32; 4]>::new(&gpu, "Test Instance Buffer");
+        buffer.push(&gpu, &gpu_queue, &[[0.0; 4]; INITIAL_CAPACITY - 1]);
+
+        // The batch does not fit, so it starts a larger buffer
+        assert_eq!(buffer.push(&gpu, &gpu_queue, &[[0.0; 4]; 2]), 0..2);
+        assert_eq!(buffer.capacity, INITIAL_CAPACITY * 2);
+        assert_eq!(
+            buffer.buffer().size(),
+            (INITIAL_CAPACITY * 2 * size_of::<[f32; 4]>()) as u64
+        );
+
+        // A batch larger than twice the capacity gets room for all of it
+        let large = vec![[0.0; 4]; INITIAL_CAPACITY * 5];
+        assert_eq!(buffer.push(&gpu, &gpu_queue, &large), 0..large.len() as u32);
+        assert!(buffer.capacity >= large.len());
+    }
+}
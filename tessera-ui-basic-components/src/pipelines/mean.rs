@@ -19,7 +19,7 @@
 use tessera_ui::{
     compute::{ComputeResourceRef, resource::ComputeResourceManager},
     renderer::compute::{ComputablePipeline, command::ComputeCommand},
-    wgpu,
+    shader_source, wgpu,
 };
 
 // --- Command ---
@@ -79,10 +79,7 @@ pub struct MeanPipeline {
 
 impl MeanPipeline {
     pub fn new(device: &wgpu::Device) -> Self {
-        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-            label: Some("Mean Shader"),
-            source: wgpu::ShaderSource::Wgsl(include_str!("mean/mean.wgsl").into()),
-        });
+        let shader = shader_source!("src/pipelines/mean/mean.wgsl").module(device);
 
         let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             entries: &[
@@ -1,3 +1,5 @@
+#import tessera::color
+
 struct MeanResult {
     total_luminance: atomic<u32>,
     total_pixels: atomic<u32>,
@@ -7,11 +9,6 @@ struct MeanResult {
 @group(0) @binding(1) var<storage, read_write> result: MeanResult;
 @group(0) @binding(2) var dest_texture: texture_storage_2d<rgba8unorm, write>;
 
-// A simple function to convert RGBA to luminance
-fn luminance(color: vec4<f32>) -> f32 {
-    return dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
-}
-
 @compute @workgroup_size(8, 8, 1)
 fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
     let texture_size = textureDimensions(source_texture);
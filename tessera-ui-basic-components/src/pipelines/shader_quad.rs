@@ -0,0 +1,596 @@
+//! A render pipeline for custom fragment shaders drawn over a component's bounds.
+//!
+//! A [`ShaderQuadPipeline`] spares custom effects the bind group layouts, uniform buffers
+//! and vertex stage of a hand-written pipeline. A shader only declares its uniforms as
+//! `struct Uniforms`, matching a Rust struct deriving [`encase::ShaderType`], and a
+//! fragment entry point `fs_main` taking a `QuadVertex`. The pipeline appends the rest:
+//!
+//! - `quad`: the `position` and `size` of the quad and the `screen_size`, in pixels, and
+//!   the frame clock `time`, in seconds
+//! - `uniforms`: the `Uniforms` of the command
+//! - `background` and `background_sampler`: the content drawn before the quad, when the
+//!   command samples the background
+//! - `QuadVertex`: the `uv` in the quad from 0 to 1, the `local_position` in the quad in
+//!   pixels and the `screen_uv` for sampling `background`
+//!
+//! ```wgsl
+//! struct Uniforms {
+//!     color: vec4<f32>,
+//!     speed: f32,
+//! };
+//!
+//! @fragment
+//! fn fs_main(in: QuadVertex) -> @location(0) vec4<f32> {
+//!     let wave = 0.5 + 0.5 * sin(in.uv.x * 6.28 + quad.time * uniforms.speed);
+//!     return vec4<f32>(uniforms.color.rgb * wave, 1.0);
+//! }
+//! ```
+//!
+//! A pipeline draws the shaders of one uniform type, each registered under an id, and is
+//! drawn with the [`shader_box`](crate::shader_box::shader_box) component.
+//!
+//! ```rust,ignore
+//! use encase::ShaderType;
+//! use tessera_ui::shader_source;
+//! use tessera_ui_basic_components::pipelines::shader_quad::ShaderQuadPipeline;
+//!
+//! #[derive(ShaderType, Clone)]
+//! struct Wave {
+//!     color: glam::Vec4,
+//!     speed: f32,
+//! }
+//!
+//! let pipeline = ShaderQuadPipeline::<Wave>::new(&app.gpu, &app.config, app.sample_count)
+//!     .with_shader(&app.gpu, "wave", shader_source!("src/wave.wgsl"));
+//! app.drawer.pipeline_registry.register(pipeline);
+//! ```
+//!
+//! See `example/examples/shader_box_showcase.rs` for a complete application.
+//!
+//! The uniforms of every command drawn in a frame share one buffer, bound at a different
+//! offset for each command, which is reused across frames.
+
+use std::{
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    num::NonZeroU64,
+};
+
+use encase::{ShaderType, UniformBuffer, internal::WriteInto};
+use glam::Vec2;
+use log::error;
+use tessera_ui::{
+    BarrierRequirement, PxPosition, PxSize,
+    renderer::{DrawCommand, DrawablePipeline, ShaderSource},
+    wgpu,
+};
+
+/// The bindings and vertex stage appended to every shader.
+const QUAD_WGSL: &str = include_str!("shader_quad/quad.wgsl");
+
+/// Number of commands the uniform buffer holds before it first grows.
+const INITIAL_CAPACITY: u64 = 16;
+
+/// Draws the shader registered as `shader_id` in the [`ShaderQuadPipeline<U>`] over the
+/// bounds of the component.
+///
+/// Usually pushed by the [`shader_box`](crate::shader_box::shader_box) component.
+#[derive(Clone)]
+pub struct ShaderQuadCommand<U> {
+    /// The id the shader was registered with.
+    pub shader_id: &'static str,
+    /// The uniforms bound as `uniforms`.
+    pub uniforms: U,
+    /// The time bound as `quad.time`, in seconds.
+    pub time: f32,
+    /// Whether the shader samples `background`, which needs a copy of the scene.
+    pub sample_background: bool,
+}
+
+impl<U: Send + Sync + 'static> DrawCommand for ShaderQuadCommand<U> {
+    fn barrier(&self) -> Option<BarrierRequirement> {
+        self.sample_background
+            .then_some(BarrierRequirement::SampleBackground)
+    }
+}
+
+#[derive(ShaderType)]
+struct QuadUniforms {
+    position: Vec2,
+    size: Vec2,
+    screen_size: Vec2,
+    time: f32,
+}
+
+struct QuadShader {
+    source: ShaderSource,
+    pipeline: wgpu::RenderPipeline,
+}
+
+/// Where the uniforms of one command go in the shared uniform buffer.
+///
+/// Each command gets a slot of `stride` bytes, `quad` at its start and `uniforms` at
+/// `uniforms_offset`. Both offsets are multiples of the device's uniform offset alignment,
+/// as dynamic offsets must be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SlotLayout {
+    quad_size: u64,
+    uniforms_offset: u64,
+    uniforms_size: u64,
+    stride: u64,
+}
+
+impl SlotLayout {
+    fn new(alignment: u64, quad_size: u64, uniforms_size: u64) -> Self {
+        let uniforms_offset = quad_size.next_multiple_of(alignment);
+        Self {
+            quad_size,
+            uniforms_offset,
+            uniforms_size,
+            stride: (uniforms_offset + uniforms_size).next_multiple_of(alignment),
+        }
+    }
+
+    /// Serializes the uniforms of one command into a slot.
+    fn write(&self, quad: &QuadUniforms, uniforms: &(impl ShaderType + WriteInto)) -> Vec<u8> {
+        let mut slot = Vec::with_capacity(self.stride as usize);
+        let mut buffer = UniformBuffer::new(&mut slot);
+        buffer.write(quad).unwrap();
+        slot.resize(self.uniforms_offset as usize, 0);
+        let mut uniforms_bytes = UniformBuffer::new(Vec::<u8>::new());
+        uniforms_bytes.write(uniforms).unwrap();
+        slot.extend_from_slice(&uniforms_bytes.into_inner());
+        slot.resize(self.stride as usize, 0);
+        slot
+    }
+}
+
+/// The uniform buffer shared by the commands of a frame, and its bind group.
+struct UniformSlots {
+    layout: SlotLayout,
+    buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    /// Number of slots that fit in `buffer`
+    capacity: u64,
+    /// Number of slots written this frame
+    len: u64,
+}
+
+impl UniformSlots {
+    fn new(
+        gpu: &wgpu::Device,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        layout: SlotLayout,
+    ) -> Self {
+        let (buffer, bind_group) = create_slots(gpu, bind_group_layout, layout, INITIAL_CAPACITY);
+        Self {
+            layout,
+            buffer,
+            bind_group,
+            capacity: INITIAL_CAPACITY,
+            len: 0,
+        }
+    }
+
+    /// Uploads `slot` and returns the dynamic offsets of `quad` and `uniforms` in it.
+    ///
+    /// When the buffer is full it is replaced with a larger one. Commands drawn earlier in
+    /// the frame keep the old buffer alive through their render pass.
+    fn push(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        slot: &[u8],
+    ) -> [u32; 2] {
+        if self.len == self.capacity {
+            self.capacity *= 2;
+            (self.buffer, self.bind_group) =
+                create_slots(gpu, bind_group_layout, self.layout, self.capacity);
+            self.len = 0;
+        }
+        let offset = self.len * self.layout.stride;
+        gpu_queue.write_buffer(&self.buffer, offset, slot);
+        self.len += 1;
+        [offset as u32, (offset + self.layout.uniforms_offset) as u32]
+    }
+}
+
+fn create_slots(
+    gpu: &wgpu::Device,
+    bind_group_layout: &wgpu::BindGroupLayout,
+    layout: SlotLayout,
+    capacity: u64,
+) -> (wgpu::Buffer, wgpu::BindGroup) {
+    let buffer = gpu.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("Shader Quad Uniform Buffer"),
+        size: capacity * layout.stride,
+        usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        mapped_at_creation: false,
+    });
+    let binding = |binding, size| wgpu::BindGroupEntry {
+        binding,
+        resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+            buffer: &buffer,
+            offset: 0,
+            size: NonZeroU64::new(size),
+        }),
+    };
+    let bind_group = gpu.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout: bind_group_layout,
+        entries: &[
+            binding(0, layout.quad_size),
+            binding(1, layout.uniforms_size),
+        ],
+        label: Some("shader_quad_uniforms_bind_group"),
+    });
+    (buffer, bind_group)
+}
+
+/// Draws [`ShaderQuadCommand<U>`] with fragment shaders that share the uniform type `U`,
+/// see the [module documentation](self).
+pub struct ShaderQuadPipeline<U> {
+    shaders: HashMap<&'static str, QuadShader>,
+    pipeline_layout: wgpu::PipelineLayout,
+    uniforms_layout: wgpu::BindGroupLayout,
+    background_layout: wgpu::BindGroupLayout,
+    uniforms: UniformSlots,
+    /// Bound as `background` for commands that do not sample it
+    placeholder_background: wgpu::BindGroup,
+    sampler: wgpu::Sampler,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+    /// Shader ids drawn without a registered shader, reported once each
+    missing: HashSet<&'static str>,
+    _uniforms: PhantomData<fn(&U)>,
+}
+
+impl<U: ShaderType + WriteInto> ShaderQuadPipeline<U> {
+    /// Creates a pipeline without shaders, add them with [`with_shader`](Self::with_shader).
+    pub fn new(gpu: &wgpu::Device, config: &wgpu::SurfaceConfiguration, sample_count: u32) -> Self {
+        let sampler = gpu.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let slot_layout = SlotLayout::new(
+            gpu.limits().min_uniform_buffer_offset_alignment as u64,
+            QuadUniforms::min_size().get(),
+            U::min_size().get(),
+        );
+        let uniform_entry = |binding, size| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: true,
+                min_binding_size: NonZeroU64::new(size),
+            },
+            count: None,
+        };
+        let uniforms_layout = gpu.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                uniform_entry(0, slot_layout.quad_size),
+                uniform_entry(1, slot_layout.uniforms_size),
+            ],
+            label: Some("shader_quad_uniforms_bind_group_layout"),
+        });
+        let background_layout = gpu.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        multisampled: false,
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+            label: Some("shader_quad_background_bind_group_layout"),
+        });
+
+        let pipeline_layout = gpu.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Shader Quad Pipeline Layout"),
+            bind_group_layouts: &[&uniforms_layout, &background_layout],
+            push_constant_ranges: &[],
+        });
+
+        let placeholder = gpu
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("Shader Quad Placeholder Background"),
+                size: wgpu::Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: config.format,
+                usage: wgpu::TextureUsages::TEXTURE_BINDING,
+                view_formats: &[],
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let placeholder_background =
+            background_bind_group(gpu, &background_layout, &placeholder, &sampler);
+
+        Self {
+            shaders: HashMap::new(),
+            pipeline_layout,
+            uniforms: UniformSlots::new(gpu, &uniforms_layout, slot_layout),
+            uniforms_layout,
+            background_layout,
+            placeholder_background,
+            sampler,
+            format: config.format,
+            sample_count,
+            missing: HashSet::new(),
+            _uniforms: PhantomData,
+        }
+    }
+
+    /// Adds a fragment shader, drawn by commands with the same `shader_id`.
+    ///
+    /// The shader is reloaded from disk with the `shader-hot-reload` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an import of the shader cannot be resolved.
+    pub fn with_shader(
+        mut self,
+        gpu: &wgpu::Device,
+        shader_id: &'static str,
+        source: ShaderSource,
+    ) -> Self {
+        let source = source.with_appended(QUAD_WGSL);
+        let pipeline = create_pipeline(
+            gpu,
+            &self.pipeline_layout,
+            &source.module(gpu),
+            self.format,
+            self.sample_count,
+        );
+        self.shaders
+            .insert(shader_id, QuadShader { source, pipeline });
+        self
+    }
+}
+
+fn background_bind_group(
+    gpu: &wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    view: &wgpu::TextureView,
+    sampler: &wgpu::Sampler,
+) -> wgpu::BindGroup {
+    gpu.create_bind_group(&wgpu::BindGroupDescriptor {
+        layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(sampler),
+            },
+        ],
+        label: Some("shader_quad_background_bind_group"),
+    })
+}
+
+fn create_pipeline(
+    gpu: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> wgpu::RenderPipeline {
+    gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Shader Quad Render Pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module,
+            entry_point: Some("vs_main"),
+            buffers: &[],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive: wgpu::PrimitiveState {
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            strip_index_format: None,
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: Some(wgpu::Face::Back),
+            ..Default::default()
+        },
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview: None,
+        cache: None,
+    })
+}
+
+impl<U> DrawablePipeline<ShaderQuadCommand<U>> for ShaderQuadPipeline<U>
+where
+    U: ShaderType + WriteInto + Send + Sync + 'static,
+{
+    fn begin_frame(
+        &mut self,
+        gpu: &wgpu::Device,
+        _gpu_queue: &wgpu::Queue,
+        _config: &wgpu::SurfaceConfiguration,
+    ) {
+        self.uniforms.len = 0;
+        for shader in self.shaders.values_mut() {
+            if let Some(pipeline) = shader.source.reload(gpu, |module| {
+                create_pipeline(
+                    gpu,
+                    &self.pipeline_layout,
+                    module,
+                    self.format,
+                    self.sample_count,
+                )
+            }) {
+                shader.pipeline = pipeline;
+            }
+        }
+    }
+
+    fn draw(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        command: &ShaderQuadCommand<U>,
+        size: PxSize,
+        start_pos: PxPosition,
+        scene_texture_view: &wgpu::TextureView,
+    ) {
+        let Some(shader) = self.shaders.get(command.shader_id) else {
+            if self.missing.insert(command.shader_id) {
+                error!(
+                    "No shader registered as {:?} in ShaderQuadPipeline<{}>, its commands are skipped",
+                    command.shader_id,
+                    std::any::type_name::<U>()
+                );
+            }
+            return;
+        };
+
+        let quad = QuadUniforms {
+            position: [start_pos.x.to_f32(), start_pos.y.to_f32()].into(),
+            size: [size.width.to_f32(), size.height.to_f32()].into(),
+            screen_size: [config.width as f32, config.height as f32].into(),
+            time: command.time,
+        };
+        let slot = self.uniforms.layout.write(&quad, &command.uniforms);
+        let offsets = self
+            .uniforms
+            .push(gpu, gpu_queue, &self.uniforms_layout, &slot);
+        // The scene texture changes between frames, so only commands sampling it need a
+        // bind group of their own
+        let background = command.sample_background.then(|| {
+            background_bind_group(
+                gpu,
+                &self.background_layout,
+                scene_texture_view,
+                &self.sampler,
+            )
+        });
+
+        render_pass.set_pipeline(&shader.pipeline);
+        render_pass.set_bind_group(0, &self.uniforms.bind_group, &offsets);
+        render_pass.set_bind_group(
+            1,
+            background.as_ref().unwrap_or(&self.placeholder_background),
+            &[],
+        );
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tessera_ui::renderer::shader::compose;
+
+    use super::*;
+
+    #[derive(ShaderType)]
+    struct Wave {
+        color: glam::Vec4,
+        speed: f32,
+    }
+
+    const WAVE_WGSL: &str = "#import tessera::color
+
+struct Uniforms {
+    color: vec4<f32>,
+    speed: f32,
+};
+
+@fragment
+fn fs_main(in: QuadVertex) -> @location(0) vec4<f32> {
+    let wave = 0.5 + 0.5 * sin(in.uv.x * 6.28 + quad.time * uniforms.speed);
+    return vec4<f32>(to_linear_srgb(uniforms.color.rgb) * wave, 1.0);
+}
+";
+
+    #[test]
+    fn test_slot_layout_aligns_offsets() {
+        // The quad is three vec2 and a f32, padded to the vec2 alignment
+        assert_eq!(QuadUniforms::min_size().get(), 32);
+        assert_eq!(Wave::min_size().get(), 32);
+
+        let layout = SlotLayout::new(256, 32, 32);
+        assert_eq!(layout.uniforms_offset, 256);
+        assert_eq!(layout.stride, 512);
+        let layout = SlotLayout::new(64, 32, 80);
+        assert_eq!(layout.uniforms_offset, 64);
+        assert_eq!(layout.stride, 192);
+    }
+
+    #[test]
+    fn test_slot_contains_both_uniforms() {
+        let layout = SlotLayout::new(64, 32, 32);
+        let quad = QuadUniforms {
+            position: Vec2::new(1.0, 2.0),
+            size: Vec2::new(3.0, 4.0),
+            screen_size: Vec2::new(5.0, 6.0),
+            time: 7.0,
+        };
+        let wave = Wave {
+            color: glam::Vec4::new(8.0, 9.0, 10.0, 11.0),
+            speed: 12.0,
+        };
+        let slot = layout.write(&quad, &wave);
+        assert_eq!(slot.len(), layout.stride as usize);
+        let floats: &[f32] = bytemuck::cast_slice(&slot);
+        assert_eq!(&floats[..7], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        assert!(floats[7..16].iter().all(|&padding| padding == 0.0));
+        assert_eq!(&floats[16..21], &[8.0, 9.0, 10.0, 11.0, 12.0]);
+    }
+
+    #[test]
+    fn test_composed_shader_is_valid() {
+        let source = compose(&format!("{WAVE_WGSL}\n{QUAD_WGSL}")).unwrap();
+        let module = naga::front::wgsl::parse_str(&source).unwrap();
+        naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .unwrap();
+        for entry_point in ["vs_main", "fs_main"] {
+            assert!(
+                module.entry_points.iter().any(|e| e.name == entry_point),
+                "missing {entry_point}"
+            );
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Appended to every shader of a `ShaderQuadPipeline`. The shader itself declares
+// `struct Uniforms` and `fn fs_main(in: QuadVertex) -> @location(0) vec4<f32>`.
+
+struct Quad {
+    // Top left corner of the quad in the window, in pixels
+    position: vec2<f32>,
+    // Size of the quad, in pixels
+    size: vec2<f32>,
+    // Size of the window, in pixels
+    screen_size: vec2<f32>,
+    // Frame clock time, in seconds
+    time: f32,
+};
+
+@group(0) @binding(0) var<uniform> quad: Quad;
+@group(0) @binding(1) var<uniform> uniforms: Uniforms;
+@group(1) @binding(0) var background: texture_2d<f32>;
+@group(1) @binding(1) var background_sampler: sampler;
+
+struct QuadVertex {
+    @builtin(position) clip_position: vec4<f32>,
+    // Position in the quad, from (0, 0) at the top left to (1, 1) at the bottom right
+    @location(0) uv: vec2<f32>,
+    // Position in the quad, in pixels
+    @location(1) local_position: vec2<f32>,
+    // Position in the window from (0, 0) to (1, 1), for sampling `background`
+    @location(2) screen_uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> QuadVertex {
+    let corners = array<vec2<f32>, 6>(
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(0.0, 1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(0.0, 0.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(1.0, 0.0)
+    );
+    let uv = corners[idx];
+    let local_position = uv * quad.size;
+    let screen_uv = (quad.position + local_position) / quad.screen_size;
+
+    var out: QuadVertex;
+    out.clip_position = vec4<f32>(screen_uv.x * 2.0 - 1.0, 1.0 - screen_uv.y * 2.0, 0.0, 1.0);
+    out.uv = uv;
+    out.local_position = local_position;
+    out.screen_uv = screen_uv;
+    return out;
+}
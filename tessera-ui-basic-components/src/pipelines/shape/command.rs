@@ -1,6 +1,8 @@
 use tessera_ui::{Color, DrawCommand, PxPosition, PxSize};
 
-use super::{ShapeUniforms, ShapeVertex};
+use crate::pipelines::pos_misc::pixel_to_ndc;
+
+use super::ShapeInstance;
 
 /// Represents a shape drawable
 #[derive(Debug, Clone)]
@@ -79,6 +81,21 @@ pub enum ShapeCommand {
     },
 }
 
+impl ShapeCommand {
+    /// Returns whether this is an outline at most one pixel wide.
+    ///
+    /// Hairlines are snapped to whole pixels when drawn, otherwise a border laid out at a
+    /// fractional position is smeared over two half-covered pixels.
+    pub(crate) fn is_hairline(&self) -> bool {
+        match self {
+            ShapeCommand::OutlinedRect { border_width, .. }
+            | ShapeCommand::RippleOutlinedRect { border_width, .. }
+            | ShapeCommand::OutlinedEllipse { border_width, .. } => *border_width <= 1.0,
+            _ => false,
+        }
+    }
+}
+
 impl DrawCommand for ShapeCommand {
     fn barrier(&self) -> Option<tessera_ui::BarrierRequirement> {
         // No specific barrier requirements for shape commands
@@ -131,28 +148,29 @@ impl Default for RippleProps {
     }
 }
 
-pub struct ShapeCommandComputed {
-    pub(crate) vertices: Vec<ShapeVertex>,
-    pub(crate) uniforms: ShapeUniforms,
-}
-
-impl ShapeCommandComputed {
-    pub fn from_command(command: ShapeCommand, size: PxSize, position: PxPosition) -> Self {
-        match command {
+impl ShapeInstance {
+    /// Creates the instance drawing `command` at `position` with `size`, on a screen of
+    /// `screen_size` pixels.
+    pub fn from_command(
+        command: &ShapeCommand,
+        size: PxSize,
+        position: PxPosition,
+        screen_size: [u32; 2],
+    ) -> Self {
+        let mut instance = match *command {
             ShapeCommand::Rect {
                 color,
                 corner_radius,
                 g2_k_value,
                 shadow,
-            } => rect_to_computed_draw_command(
-                size,
-                position,
+            } => rect_instance(
                 color, // RGBA
                 corner_radius,
                 g2_k_value,
                 shadow,
                 0.0, // border_width for fill is 0
                 0.0, // render_mode for fill is 0.0
+                None,
             ),
             ShapeCommand::OutlinedRect {
                 color,
@@ -160,15 +178,14 @@ impl ShapeCommandComputed {
                 g2_k_value,
                 shadow,
                 border_width,
-            } => rect_to_computed_draw_command(
-                size,
-                position,
+            } => rect_instance(
                 color, // RGBA, This color is for the border
                 corner_radius,
                 g2_k_value,
                 shadow,
                 border_width,
                 1.0, // render_mode for outline is 1.0
+                None,
             ),
             ShapeCommand::RippleRect {
                 color,
@@ -176,16 +193,14 @@ impl ShapeCommandComputed {
                 g2_k_value,
                 shadow,
                 ripple,
-            } => ripple_rect_to_computed_draw_command(
-                size,
-                position,
+            } => rect_instance(
                 color,
                 corner_radius,
                 g2_k_value,
                 shadow,
                 0.0, // border_width for fill is 0
-                0.0, // render_mode for fill is 0.0
-                ripple,
+                3.0, // render_mode for ripple fill is 3.0
+                Some(ripple),
             ),
             ShapeCommand::RippleOutlinedRect {
                 color,
@@ -194,204 +209,104 @@ impl ShapeCommandComputed {
                 shadow,
                 border_width,
                 ripple,
-            } => ripple_rect_to_computed_draw_command(
-                size,
-                position,
+            } => rect_instance(
                 color,
                 corner_radius,
                 g2_k_value,
                 shadow,
                 border_width,
-                1.0, // render_mode for outline is 1.0
-                ripple,
+                4.0, // render_mode for ripple outline is 4.0
+                Some(ripple),
             ),
-            ShapeCommand::Ellipse { color, shadow } => rect_to_computed_draw_command(
-                size, position, color,
-                -1.0, // Use negative corner_radius to signify an ellipse to the shader
+            ShapeCommand::Ellipse { color, shadow } => rect_instance(
+                color, -1.0, // Use negative corner_radius to signify an ellipse to the shader
                 0.0, shadow, 0.0, // border_width for fill is 0
                 0.0, // render_mode for fill
+                None,
             ),
             ShapeCommand::OutlinedEllipse {
                 color,
                 shadow,
                 border_width,
-            } => rect_to_computed_draw_command(
-                size,
-                position,
+            } => rect_instance(
                 color,
                 -1.0, // Use negative corner_radius to signify an ellipse to the shader
                 0.0,
                 shadow,
                 border_width,
                 1.0, // render_mode for outline
+                None,
             ),
-        }
+        };
+
+        let top_left = pixel_to_ndc(position, screen_size);
+        instance.ndc_rect = [
+            top_left[0],
+            top_left[1],
+            size.width.to_f32() / screen_size[0] as f32 * 2.0,
+            size.height.to_f32() / screen_size[1] as f32 * 2.0,
+        ];
+        instance.size_cr_border_width[0] = size.width.to_f32();
+        instance.size_cr_border_width[1] = size.height.to_f32();
+        instance
+    }
+
+    /// Returns whether the instance casts a visible shadow.
+    pub(crate) fn has_shadow(&self) -> bool {
+        self.shadow_color[3] > 0.0 && self.render_params[2] > 0.0
+    }
+
+    /// Returns the instance drawing the shadow of this one.
+    pub(crate) fn shadow(&self) -> Self {
+        let mut shadow = *self;
+        shadow.render_params[3] = 2.0;
+        shadow
     }
 }
 
-/// Helper function to create Shape DrawCommand for both Rect and OutlinedRect
-fn rect_to_computed_draw_command(
-    size: PxSize,
-    position: PxPosition,
+/// Creates the instance of a rectangle or ellipse, without its position and size.
+fn rect_instance(
     primary_color_rgba: Color,
     corner_radius: f32,
     g2_k_value: f32,
     shadow: Option<ShadowProps>,
     border_width: f32,
     render_mode: f32,
-) -> ShapeCommandComputed {
-    let width = size.width;
-    let height = size.height;
-
-    let rect_local_pos = [
-        [-0.5, -0.5], // Top-Left
-        [0.5, -0.5],  // Top-Right
-        [0.5, 0.5],   // Bottom-Right
-        [-0.5, 0.5],  // Bottom-Left
-    ];
-
-    let vertex_color_placeholder_rgb = [0.0, 0.0, 0.0];
-    let top_left = position.to_f32_arr3();
-    let top_right = [top_left[0] + width.to_f32(), top_left[1], top_left[2]];
-    let bottom_right = [
-        top_left[0] + width.to_f32(),
-        top_left[1] + height.to_f32(),
-        top_left[2],
-    ];
-    let bottom_left = [top_left[0], top_left[1] + height.to_f32(), top_left[2]];
-
-    let vertices = vec![
-        ShapeVertex {
-            position: top_left,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[0],
-        },
-        ShapeVertex {
-            position: top_right,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[1],
-        },
-        ShapeVertex {
-            position: bottom_right,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[2],
-        },
-        ShapeVertex {
-            position: bottom_left,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[3],
-        },
-    ];
-
+    ripple: Option<RippleProps>,
+) -> ShapeInstance {
     let (shadow_rgba_color, shadow_offset_vec, shadow_smooth_val) = if let Some(s_props) = shadow {
         (s_props.color, s_props.offset, s_props.smoothness)
     } else {
         (Color::TRANSPARENT, [0.0, 0.0], 0.0)
     };
 
-    let uniforms = ShapeUniforms {
-        size_cr_border_width: [width.to_f32(), height.to_f32(), corner_radius, border_width].into(),
-        primary_color: primary_color_rgba.to_array().into(),
-        shadow_color: shadow_rgba_color.to_array().into(),
-        render_params: [
-            shadow_offset_vec[0],
-            shadow_offset_vec[1],
-            shadow_smooth_val,
-            render_mode,
-        ]
-        .into(),
-        ripple_params: [0.0, 0.0, 0.0, 0.0].into(),
-        ripple_color: [0.0, 0.0, 0.0, 0.0].into(),
-        g2_k_value,
-    };
-
-    ShapeCommandComputed { vertices, uniforms }
-}
-
-/// Helper function to create Shape DrawCommand for ripple effects
-fn ripple_rect_to_computed_draw_command(
-    size: PxSize,
-    position: PxPosition,
-    primary_color_rgba: Color,
-    corner_radius: f32,
-    g2_k_value: f32,
-    shadow: Option<ShadowProps>,
-    border_width: f32,
-    render_mode: f32,
-    ripple: RippleProps,
-) -> ShapeCommandComputed {
-    let width = size.width;
-    let height = size.height;
-
-    let rect_local_pos = [
-        [-0.5, -0.5], // Top-Left
-        [0.5, -0.5],  // Top-Right
-        [0.5, 0.5],   // Bottom-Right
-        [-0.5, 0.5],  // Bottom-Left
-    ];
-
-    let vertex_color_placeholder_rgb = [0.0, 0.0, 0.0];
-    let top_left = position.to_f32_arr3();
-    let top_right = [top_left[0] + width.to_f32(), top_left[1], top_left[2]];
-    let bottom_right = [
-        top_left[0] + width.to_f32(),
-        top_left[1] + height.to_f32(),
-        top_left[2],
-    ];
-    let bottom_left = [top_left[0], top_left[1] + height.to_f32(), top_left[2]];
-
-    let vertices = vec![
-        ShapeVertex {
-            position: top_left,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[0],
-        },
-        ShapeVertex {
-            position: top_right,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[1],
-        },
-        ShapeVertex {
-            position: bottom_right,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[2],
-        },
-        ShapeVertex {
-            position: bottom_left,
-            color: vertex_color_placeholder_rgb,
-            local_pos: rect_local_pos[3],
-        },
-    ];
-
-    let (shadow_rgba_color, shadow_offset_vec, shadow_smooth_val) = if let Some(s_props) = shadow {
-        (s_props.color.into(), s_props.offset, s_props.smoothness)
+    let (ripple_params, ripple_color) = if let Some(ripple) = ripple {
+        (
+            [
+                ripple.center[0],
+                ripple.center[1],
+                ripple.radius,
+                ripple.alpha,
+            ],
+            [ripple.color.r, ripple.color.g, ripple.color.b, 0.0],
+        )
     } else {
-        ([0.0, 0.0, 0.0, 0.0], [0.0, 0.0], 0.0)
+        ([0.0; 4], [0.0; 4])
     };
 
-    let ripple_render_mode = if render_mode == 0.0 { 3.0 } else { 4.0 };
-
-    let uniforms = ShapeUniforms {
-        size_cr_border_width: [width.to_f32(), height.to_f32(), corner_radius, border_width].into(),
-        primary_color: primary_color_rgba.to_array().into(),
-        shadow_color: shadow_rgba_color.into(),
+    ShapeInstance {
+        ndc_rect: [0.0; 4],
+        size_cr_border_width: [0.0, 0.0, corner_radius, border_width],
+        primary_color: primary_color_rgba.to_array(),
+        shadow_color: shadow_rgba_color.to_array(),
         render_params: [
             shadow_offset_vec[0],
             shadow_offset_vec[1],
             shadow_smooth_val,
-            ripple_render_mode,
-        ]
-        .into(),
-        ripple_params: [
-            ripple.center[0],
-            ripple.center[1],
-            ripple.radius,
-            ripple.alpha,
-        ]
-        .into(),
-        ripple_color: [ripple.color.r, ripple.color.g, ripple.color.b, 0.0].into(),
+            render_mode,
+        ],
+        ripple_params,
+        ripple_color,
         g2_k_value,
-    };
-
-    ShapeCommandComputed { vertices, uniforms }
+    }
 }
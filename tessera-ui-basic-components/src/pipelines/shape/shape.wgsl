@@ -1,4 +1,6 @@
-struct ShapeUniforms {
+#import tessera::sdf
+
+struct ShapeParams {
     size_cr_border_width: vec4f, // size.xy, corner_radius, border_width
     primary_color: vec4f,      // fill_color or border_color
     shadow_color: vec4f,       // shadow_color
@@ -9,69 +11,56 @@ struct ShapeUniforms {
     g2_k_value: f32, // G2 exponent for rounded corners
 };
 
-@group(0) @binding(0)
-var<uniform> shape_params: ShapeUniforms;
-
-struct VertexInput {
-    @location(0) position: vec3f,
-    @location(1) color: vec3f,
-    @location(2) local_pos_in: vec2f,
+struct InstanceInput {
+    @location(0) ndc_rect: vec4f, // top_left.xy, size.xy in NDC
+    @location(1) size_cr_border_width: vec4f,
+    @location(2) primary_color: vec4f,
+    @location(3) shadow_color: vec4f,
+    @location(4) render_params: vec4f,
+    @location(5) ripple_params: vec4f,
+    @location(6) ripple_color: vec4f,
+    @location(7) g2_k_value: f32,
 };
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4f,
-    @location(0) color: vec3f,
-    @location(1) local_pos_out: vec2f,
+    @location(0) local_pos_out: vec2f,
+    @location(1) @interpolate(flat) size_cr_border_width: vec4f,
+    @location(2) @interpolate(flat) primary_color: vec4f,
+    @location(3) @interpolate(flat) shadow_color: vec4f,
+    @location(4) @interpolate(flat) render_params: vec4f,
+    @location(5) @interpolate(flat) ripple_params: vec4f,
+    @location(6) @interpolate(flat) ripple_color: vec4f,
+    @location(7) @interpolate(flat) g2_k_value: f32,
 };
 
 @vertex
-fn vs_main(model: VertexInput) -> VertexOutput {
+fn vs_main(@builtin(vertex_index) idx: u32, instance: InstanceInput) -> VertexOutput {
+    // Corners of the quad from (0, 0) at the top left to (1, 1) at the bottom right
+    let corners = array<vec2f, 6>(
+        vec2f(0.0, 0.0),
+        vec2f(0.0, 1.0),
+        vec2f(1.0, 1.0),
+        vec2f(0.0, 0.0),
+        vec2f(1.0, 1.0),
+        vec2f(1.0, 0.0)
+    );
+    let corner = corners[idx];
+
     var out: VertexOutput;
-    out.color = model.color;
-    out.clip_position = vec4f(model.position.xy, 0.0, 1.0);
-    out.local_pos_out = model.local_pos_in;
+    let position = instance.ndc_rect.xy + vec2f(corner.x, -corner.y) * instance.ndc_rect.zw;
+    out.clip_position = vec4f(position, 0.0, 1.0);
+    out.local_pos_out = corner - 0.5;
+    out.size_cr_border_width = instance.size_cr_border_width;
+    out.primary_color = instance.primary_color;
+    out.shadow_color = instance.shadow_color;
+    out.render_params = instance.render_params;
+    out.ripple_params = instance.ripple_params;
+    out.ripple_color = instance.ripple_color;
+    out.g2_k_value = instance.g2_k_value;
     return out;
 }
 
-// Helper function for G2-like SDF using p-norm for rounded corners
-// p: point to sample
-// b: half-size of the box
-// r: corner radius
-// k: exponent for p-norm (k=2.0 for G1 circle, k>2.0 for G2-like superellipse)
-fn sdf_g2_rounded_box(p: vec2f, b: vec2f, r: f32, k: f32) -> f32 {
-    let q = abs(p) - b + r;
-
-    let v_x = max(q.x, 0.0);
-    let v_y = max(q.y, 0.0);
-
-    var dist_corner_shape: f32;
-    // Use a small epsilon for comparing k to 2.0 to handle potential float inaccuracies
-    if abs(k - 2.0) < 0.001 { // G1 behavior (standard circle)
-        dist_corner_shape = length(vec2f(v_x, v_y));
-    } else { // G2-like behavior with exponent k
-        if v_x == 0.0 && v_y == 0.0 {
-            dist_corner_shape = 0.0;
-        } else {
-            dist_corner_shape = pow(pow(v_x, k) + pow(v_y, k), 1.0 / k);
-        }
-    }
-
-    return dist_corner_shape + min(max(q.x, q.y), 0.0) - r;
-}
-
-// SDF for an ellipse
-// p: point to sample
-// r: radii of the ellipse
-fn sdf_ellipse(p: vec2f, r: vec2f) -> f32 {
-    if r.x <= 0.0 || r.y <= 0.0 {
-        // Return a large value to prevent rendering if radii are invalid
-        return 1.0e6;
-    }
-    // Scales the distance to be in pixel units, which is important for anti-aliasing.
-    return (length(p / r) - 1.0) * min(r.x, r.y);
-}
-
-
 // Calculate ripple effect based on distance from ripple center
 fn calculate_ripple_effect(dist_to_center: f32, ripple_radius: f32) -> f32 {
     if ripple_radius <= 0.0 {
@@ -92,6 +81,15 @@ fn calculate_ripple_effect(dist_to_center: f32, ripple_radius: f32) -> f32 {
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4f {
+    let shape_params = ShapeParams(
+        in.size_cr_border_width,
+        in.primary_color,
+        in.shadow_color,
+        in.render_params,
+        in.ripple_params,
+        in.ripple_color,
+        in.g2_k_value,
+    );
     let size = shape_params.size_cr_border_width.xy;
     let corner_radius = shape_params.size_cr_border_width.z;
     let border_width = shape_params.size_cr_border_width.w;
@@ -15,7 +15,9 @@ use std::sync::OnceLock;
 
 use glyphon::fontdb;
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
-use tessera_ui::{Color, DrawablePipeline, PxPosition, PxSize, wgpu};
+use tessera_ui::{
+    AlignmentLines, Color, DrawablePipeline, Px, PxPosition, PxSize, Sp, TesseraRuntime, wgpu,
+};
 
 pub use command::{TextCommand, TextConstraint};
 
@@ -72,7 +74,6 @@ pub struct GlyphonTextRender {
     /// Glyphon font atlas, a heavy-weight, shared resource.
     atlas: glyphon::TextAtlas,
     /// Glyphon cache, a heavy-weight, shared resource.
-    #[allow(unused)]
     cache: glyphon::Cache,
     /// Glyphon viewport, holds screen-size related buffers.
     viewport: glyphon::Viewport,
@@ -80,6 +81,10 @@ pub struct GlyphonTextRender {
     swash_cache: glyphon::SwashCache,
     /// The multisample state, needed for creating temporary renderers.
     msaa: wgpu::MultisampleState,
+    /// The scale factor the glyphs in the atlas were rasterized for.
+    scale_factor: f64,
+    /// The font scale the glyphs in the atlas were rasterized for.
+    font_scale: f64,
 }
 
 impl GlyphonTextRender {
@@ -97,7 +102,7 @@ impl GlyphonTextRender {
         sample_count: u32,
     ) -> Self {
         let cache = glyphon::Cache::new(gpu);
-        let atlas = glyphon::TextAtlas::new(gpu, queue, &cache, config.format);
+        let atlas = Self::new_atlas(gpu, queue, &cache, config.format);
         let viewport = glyphon::Viewport::new(gpu, &cache);
         let swash_cache = glyphon::SwashCache::new();
         let msaa = wgpu::MultisampleState {
@@ -112,12 +117,46 @@ impl GlyphonTextRender {
             viewport,
             swash_cache,
             msaa,
+            scale_factor: TesseraRuntime::scale_factor(),
+            font_scale: TesseraRuntime::font_scale(),
         }
     }
+
+    /// Creates a glyph atlas that writes text colors to the target unchanged.
+    ///
+    /// Render targets hold sRGB values in a non-sRGB format, just like [`Color`], so the
+    /// sRGB to linear conversion of [`glyphon::ColorMode::Accurate`] must not be applied.
+    fn new_atlas(
+        gpu: &wgpu::Device,
+        queue: &wgpu::Queue,
+        cache: &glyphon::Cache,
+        format: wgpu::TextureFormat,
+    ) -> glyphon::TextAtlas {
+        glyphon::TextAtlas::with_color_mode(gpu, queue, cache, format, glyphon::ColorMode::Web)
+    }
 }
 
 #[allow(unused_variables)]
 impl DrawablePipeline<TextCommand> for GlyphonTextRender {
+    /// Refreshes the glyph atlas when the scale factor or the font scale changed.
+    ///
+    /// Every glyph is rasterized at a new size after such a change, so the glyphs cached
+    /// for the old size would only waste atlas space.
+    fn begin_frame(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+    ) {
+        let scale_factor = TesseraRuntime::scale_factor();
+        let font_scale = TesseraRuntime::font_scale();
+        if scale_factor != self.scale_factor || font_scale != self.font_scale {
+            self.scale_factor = scale_factor;
+            self.font_scale = font_scale;
+            self.atlas = Self::new_atlas(gpu, gpu_queue, &self.cache, config.format);
+        }
+    }
+
     /// Draws text in a UI component using the Glyphon engine.
     ///
     /// # Parameters
@@ -185,7 +224,7 @@ impl DrawablePipeline<TextCommand> for GlyphonTextRender {
 /// ```rust,ignore
 /// use tessera_ui_basic_components::pipelines::text::TextData;
 ///
-/// let data = TextData::new("Hello".to_string(), color, 16.0, 1.2, constraint);
+/// let data = TextData::new("Hello".to_string(), color, Sp(16.0), Sp(19.2), constraint);
 /// ```
 #[derive(Debug, Clone)]
 pub struct TextData {
@@ -193,6 +232,8 @@ pub struct TextData {
     text_buffer: glyphon::Buffer,
     /// text area size
     pub size: [u32; 2],
+    /// color of glyphs without a color of their own
+    default_color: Color,
 }
 
 impl TextData {
@@ -201,27 +242,23 @@ impl TextData {
     /// # Parameters
     /// - `text`: The text string.
     /// - `color`: The text color.
-    /// - `size`: Font size.
-    /// - `line_height`: Line height.
+    /// - `size`: Font size, scaled by the font scale and the scale factor.
+    /// - `line_height`: Line height, scaled like `size`.
     /// - `constraint`: Text constraint for layout.
     pub fn new(
         text: String,
         color: Color,
-        size: f32,
-        line_height: f32,
+        size: Sp,
+        line_height: Sp,
         constraint: TextConstraint,
     ) -> TextData {
         // Create text buffer
         let mut text_buffer = glyphon::Buffer::new(
             &mut write_font_system(),
-            glyphon::Metrics::new(size, line_height),
-        );
-        let color = glyphon::Color::rgba(
-            (color.r * 255.0) as u8,
-            (color.g * 255.0) as u8,
-            (color.b * 255.0) as u8,
-            (color.a * 255.0) as u8,
+            glyphon::Metrics::new(size.to_pixels_f32(), line_height.to_pixels_f32()),
         );
+        let [r, g, b, a] = color.to_rgba_u8();
+        let color = glyphon::Color::rgba(r, g, b, a);
         text_buffer.set_wrap(&mut write_font_system(), glyphon::Wrap::Glyph);
         text_buffer.set_size(
             &mut write_font_system(),
@@ -251,6 +288,7 @@ impl TextData {
         Self {
             text_buffer,
             size: [run_width as u32, line_height as u32],
+            default_color: Color::BLACK,
         }
     }
 
@@ -269,16 +307,40 @@ impl TextData {
         Self {
             text_buffer,
             size: [run_width as u32, line_height as u32],
+            default_color: Color::BLACK,
         }
     }
 
+    /// Returns the baselines of the first and last laid out lines, relative to the top of
+    /// the text area.
+    pub fn alignment_lines(&self) -> AlignmentLines {
+        let mut baselines = self.text_buffer.layout_runs().map(|run| run.line_y);
+        let first_baseline = baselines.next();
+        let last_baseline = baselines.last().or(first_baseline);
+        AlignmentLines {
+            first_baseline: first_baseline.map(Px::from_f32),
+            last_baseline: last_baseline.map(Px::from_f32),
+        }
+    }
+
+    /// Sets the color of glyphs that were shaped without a color, such as the text of an
+    /// editor buffer passed to [`TextData::from_buffer`]. Defaults to black.
+    pub fn with_default_color(mut self, color: Color) -> Self {
+        self.default_color = color;
+        self
+    }
+
     /// Get the glyphon text area from the text data
+    ///
+    /// The origin is snapped to whole pixels, so glyph baselines stay crisp even when the
+    /// text is laid out at a fractional position.
     fn text_area(&'_ self, start_pos: PxPosition) -> glyphon::TextArea<'_> {
+        let start_pos = PxPosition::new(start_pos.x.round(), start_pos.y.round());
         let bounds = glyphon::TextBounds {
-            left: start_pos.x.raw(),
-            top: start_pos.y.raw(),
-            right: start_pos.x.raw() + self.size[0] as i32,
-            bottom: start_pos.y.raw() + self.size[1] as i32,
+            left: start_pos.x.to_i32(),
+            top: start_pos.y.to_i32(),
+            right: start_pos.x.to_i32() + self.size[0] as i32,
+            bottom: start_pos.y.to_i32() + self.size[1] as i32,
         };
         glyphon::TextArea {
             buffer: &self.text_buffer,
@@ -286,7 +348,10 @@ impl TextData {
             top: start_pos.y.to_f32(),
             scale: 1.0,
             bounds,
-            default_color: glyphon::Color::rgb(0, 0, 0), // Black by default
+            default_color: {
+                let [r, g, b, a] = self.default_color.to_rgba_u8();
+                glyphon::Color::rgba(r, g, b, a)
+            },
             custom_glyphs: &[],
         }
     }
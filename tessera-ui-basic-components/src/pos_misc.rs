@@ -23,19 +23,19 @@ pub fn is_position_in_rect(
 #[test]
 fn test_is_position_in_component() {
     let size = ComputedData {
-        width: Px(100),
-        height: Px(50),
+        width: Px(100.0),
+        height: Px(50.0),
     };
     assert!(is_position_in_component(
         size,
-        PxPosition::new(Px(50), Px(25))
+        PxPosition::new(Px(50.0), Px(25.0))
     ));
     assert!(!is_position_in_component(
         size,
-        PxPosition::new(Px(150), Px(25))
+        PxPosition::new(Px(150.0), Px(25.0))
     ));
     assert!(!is_position_in_component(
         size,
-        PxPosition::new(Px(50), Px(75))
+        PxPosition::new(Px(50.0), Px(75.0))
     ));
 }
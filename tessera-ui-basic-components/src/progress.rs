@@ -8,12 +8,16 @@
 //! Typical usage involves specifying the progress value and optional appearance parameters.
 //! The component is designed for integration into Tessera UI applications.
 use derive_builder::Builder;
-use tessera_ui::{Color, ComputedData, Constraint, DimensionValue, Dp, Px, PxPosition};
+use tessera_ui::{
+    Color, ComputedData, Constraint, DimensionValue, Dp, Px, PxPosition,
+    accessibility::{Role, Semantics},
+};
 use tessera_ui_macros::tessera;
 
 use crate::{
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
 };
 
 /// Arguments for the `progress` component.
@@ -33,16 +37,20 @@ pub struct ProgressArgs {
     pub height: Dp,
 
     /// The color of the active part of the track.
-    #[builder(default = "Color::new(0.2, 0.5, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub progress_color: Color,
 
     /// The color of the inactive part of the track.
-    #[builder(default = "Color::new(0.8, 0.8, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.surface_variant")]
     pub track_color: Color,
 
     /// The shape of the progress bar.
-    #[builder(default = "Shape::RoundedRectangle{ corner_radius: 4.0, g2_k_value: 3.0 }")]
+    #[builder(default = "current_theme().shapes.extra_small")]
     pub shape: Shape,
+
+    /// An optional label announced by screen readers.
+    #[builder(default, setter(strip_option, into))]
+    pub accessibility_label: Option<String>,
 }
 
 #[tessera]
@@ -72,6 +80,18 @@ pub struct ProgressArgs {
 pub fn progress(args: impl Into<ProgressArgs>) {
     let args: ProgressArgs = args.into();
 
+    semantics(|| {
+        let mut progress_semantics = Semantics::new(Role::ProgressIndicator).with_numeric_value(
+            args.value.clamp(0.0, 1.0) as f64,
+            0.0,
+            1.0,
+        );
+        if let Some(label) = &args.accessibility_label {
+            progress_semantics = progress_semantics.with_label(label.clone());
+        }
+        progress_semantics
+    });
+
     // Child 1: The background track. It's drawn first.
     surface(
         SurfaceArgsBuilder::default()
@@ -123,16 +143,16 @@ pub fn progress(args: impl Into<ProgressArgs>) {
             DimensionValue::Fixed(self_height),
         );
         input.measure_child(track_id, &track_constraint)?;
-        input.place_child(track_id, PxPosition::new(Px(0), Px(0)));
+        input.place_child(track_id, PxPosition::new(Px(0.0), Px(0.0)));
 
         // Measure and place the progress fill based on the `value`.
-        let progress_width = Px((self_width.to_f32() * args.value.clamp(0.0, 1.0)) as i32);
+        let progress_width = self_width.mul_f32(args.value.clamp(0.0, 1.0));
         let progress_constraint = Constraint::new(
             DimensionValue::Fixed(progress_width),
             DimensionValue::Fixed(self_height),
         );
         input.measure_child(progress_id, &progress_constraint)?;
-        input.place_child(progress_id, PxPosition::new(Px(0), Px(0)));
+        input.place_child(progress_id, PxPosition::new(Px(0.0), Px(0.0)));
 
         // The progress component itself is a container, its size is defined by the args.
         Ok(ComputedData {
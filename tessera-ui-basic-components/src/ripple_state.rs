@@ -1,4 +1,11 @@
-use std::sync::atomic;
+use std::{sync::atomic, time::Duration};
+
+use parking_lot::Mutex;
+use tessera_ui::animation::{Animatable, AnimationSpec, Easing};
+
+/// The ripple expands linearly over 600 ms.
+const RIPPLE_ANIMATION: AnimationSpec =
+    AnimationSpec::tween(Duration::from_millis(600), Easing::Linear);
 
 ///
 /// `RippleState` manages the animation and hover state for ripple effects in interactive UI components.
@@ -25,14 +32,10 @@ use std::sync::atomic;
 /// ```
 ///
 pub struct RippleState {
-    /// Whether the ripple animation is currently active.
-    pub is_animating: atomic::AtomicBool,
-    /// The animation start time, stored as milliseconds since the Unix epoch.
-    pub start_time: atomic::AtomicU64,
-    /// The X coordinate of the click position, stored as fixed-point (multiplied by 1000).
-    pub click_pos_x: atomic::AtomicI32,
-    /// The Y coordinate of the click position, stored as fixed-point (multiplied by 1000).
-    pub click_pos_y: atomic::AtomicI32,
+    /// The ripple progress, animated from 0.0 to 1.0 on the shared frame clock.
+    progress: Mutex<Animatable<f32>>,
+    /// The normalized click position the ripple originates from.
+    click_pos: Mutex<[f32; 2]>,
     /// Whether the pointer is currently hovering over the component.
     pub is_hovered: atomic::AtomicBool,
 }
@@ -54,10 +57,8 @@ impl RippleState {
     /// ```
     pub fn new() -> Self {
         Self {
-            is_animating: atomic::AtomicBool::new(false),
-            start_time: atomic::AtomicU64::new(0),
-            click_pos_x: atomic::AtomicI32::new(0),
-            click_pos_y: atomic::AtomicI32::new(0),
+            progress: Mutex::new(Animatable::new(0.0).with_spec(RIPPLE_ANIMATION)),
+            click_pos: Mutex::new([0.0, 0.0]),
             is_hovered: atomic::AtomicBool::new(false),
         }
     }
@@ -75,17 +76,10 @@ impl RippleState {
     /// state.start_animation([0.5, 0.5]);
     /// ```
     pub fn start_animation(&self, click_pos: [f32; 2]) {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-
-        self.start_time.store(now, atomic::Ordering::SeqCst);
-        self.click_pos_x
-            .store((click_pos[0] * 1000.0) as i32, atomic::Ordering::SeqCst);
-        self.click_pos_y
-            .store((click_pos[1] * 1000.0) as i32, atomic::Ordering::SeqCst);
-        self.is_animating.store(true, atomic::Ordering::SeqCst);
+        *self.click_pos.lock() = click_pos;
+        let mut progress = self.progress.lock();
+        progress.snap_to(0.0);
+        progress.animate_to(1.0);
     }
 
     /// Returns the current progress of the ripple animation and the origin position.
@@ -93,6 +87,7 @@ impl RippleState {
     /// Returns `Some((progress, [x, y]))` if the animation is active, where:
     /// - `progress` is a value in `[0.0, 1.0)` representing the animation progress.
     /// - `[x, y]` is the normalized origin of the ripple.
+    ///
     /// Returns `None` if the animation is not active or has completed.
     ///
     /// # Example
@@ -105,31 +100,11 @@ impl RippleState {
     /// }
     /// ```
     pub fn get_animation_progress(&self) -> Option<(f32, [f32; 2])> {
-        let is_animating = self.is_animating.load(atomic::Ordering::SeqCst);
-
-        if !is_animating {
+        let progress = self.progress.lock();
+        if !progress.is_running() {
             return None;
         }
-
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
-        let start = self.start_time.load(atomic::Ordering::SeqCst);
-        let elapsed_ms = now.saturating_sub(start);
-        let progress = (elapsed_ms as f32) / 600.0; // 600ms animation
-
-        if progress >= 1.0 {
-            self.is_animating.store(false, atomic::Ordering::SeqCst);
-            return None;
-        }
-
-        let click_pos = [
-            self.click_pos_x.load(atomic::Ordering::SeqCst) as f32 / 1000.0,
-            self.click_pos_y.load(atomic::Ordering::SeqCst) as f32 / 1000.0,
-        ];
-
-        Some((progress, click_pos))
+        Some((progress.value(), *self.click_pos.lock()))
     }
 
     /// Sets the hover state for the ripple.
@@ -28,7 +28,10 @@
 //! );
 //! ```
 use derive_builder::Builder;
-use tessera_ui::{ComputedData, Constraint, DimensionValue, Px, PxPosition, place_node};
+use tessera_ui::{
+    ComputedData, Constraint, DimensionValue, IntrinsicQuery, IntrinsicSize, MeasurementError,
+    NodeId, Px, PxPosition, place_node,
+};
 use tessera_ui_macros::tessera;
 
 use crate::alignment::{CrossAxisAlignment, MainAxisAlignment};
@@ -51,6 +54,14 @@ pub struct RowArgs {
     /// Cross axis alignment (vertical alignment).
     #[builder(default = "CrossAxisAlignment::Start")]
     pub cross_axis_alignment: CrossAxisAlignment,
+    /// Sizes the row to the minimum or maximum intrinsic width of its children, within
+    /// its width constraint. `None` sizes it from the measured children.
+    #[builder(default)]
+    pub intrinsic_width: Option<IntrinsicSize>,
+    /// Sizes the row to the minimum or maximum intrinsic height of its children, within
+    /// its height constraint. `None` sizes it from the measured children.
+    #[builder(default)]
+    pub intrinsic_height: Option<IntrinsicSize>,
 }
 
 impl Default for RowArgs {
@@ -129,7 +140,10 @@ impl<F: FnOnce() + Send + Sync + 'static> AsRowItem for (F, f32) {
 ///   - `main_axis_alignment`: Determines how children are distributed along the horizontal
 ///     axis (e.g., `Start`, `Center`, `End`, `SpaceBetween`).
 ///   - `cross_axis_alignment`: Determines how children are aligned along the vertical
-///     axis (e.g., `Start`, `Center`, `End`, `Stretch`).
+///     axis (e.g., `Start`, `Center`, `End`, `Stretch`, or `Baseline` to line up the first
+///     baselines of texts).
+///   - `intrinsic_width` and `intrinsic_height`: Size the row to the minimum or maximum
+///     intrinsic size of its children, e.g. to make all children as tall as the tallest one.
 ///
 /// * `children_items_input`: An array of child components to be displayed in the row.
 ///   Children can be simple closures, or they can be wrapped in `RowItem` to provide
@@ -150,6 +164,7 @@ impl<F: FnOnce() + Send + Sync + 'static> AsRowItem for (F, f32) {
 ///     cross_axis_alignment: CrossAxisAlignment::Center,
 ///     width: DimensionValue::Fill { min: None, max: None },
 ///     height: DimensionValue::Fixed(Dp(50.0).into()),
+///     ..Default::default()
 /// };
 ///
 /// row_ui!(args,
@@ -158,6 +173,23 @@ impl<F: FnOnce() + Send + Sync + 'static> AsRowItem for (F, f32) {
 ///     || text("Third".to_string()),
 /// );
 /// ```
+///
+/// A title and a caption of different sizes, sitting on the same baseline:
+///
+/// ```
+/// use tessera_ui_basic_components::{row::{row_ui, RowArgsBuilder}, text::{text, TextArgsBuilder}};
+/// use tessera_ui_basic_components::alignment::CrossAxisAlignment;
+/// use tessera_ui::Sp;
+///
+/// row_ui!(
+///     RowArgsBuilder::default()
+///         .cross_axis_alignment(CrossAxisAlignment::Baseline)
+///         .build()
+///         .unwrap(),
+///     || text(TextArgsBuilder::default().text("Title".to_string()).size(Sp(32.0)).build().unwrap()),
+///     || text(TextArgsBuilder::default().text("caption".to_string()).size(Sp(12.0)).build().unwrap()),
+/// );
+/// ```
 #[tessera]
 pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem; N]) {
     let children_items: [RowItem; N] =
@@ -171,21 +203,38 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
         child_weights.push(child_item.weight);
     }
 
+    let intrinsic_args = args.clone();
+    intrinsic(Box::new(move |input| {
+        row_intrinsic_size(
+            &intrinsic_args,
+            input.query,
+            input.cross_size,
+            input.children_ids,
+            |child_id, query, cross_size| input.child_intrinsic_size(child_id, query, cross_size),
+        )
+    }));
+
     measure(Box::new(move |input| {
         let row_intrinsic_constraint = Constraint::new(args.width, args.height);
         // This is the effective constraint for the row itself
-        let row_effective_constraint = row_intrinsic_constraint.merge(input.parent_constraint);
+        let mut row_effective_constraint = row_intrinsic_constraint.merge(input.parent_constraint);
+        apply_intrinsic_sizes(
+            &args,
+            &mut row_effective_constraint,
+            input.children_ids,
+            |child_id, query, cross_size| input.intrinsic_size(child_id, query, cross_size),
+        )?;
 
         let mut children_sizes = vec![None; N];
-        let mut max_child_height = Px(0);
+        let mut max_child_height = Px(0.0);
 
         // For row, main axis is horizontal, so check width for weight distribution
-        let should_use_weight_for_width = match row_effective_constraint.width {
-            DimensionValue::Fixed(_) => true,
-            DimensionValue::Fill { max: Some(_), .. } => true,
-            DimensionValue::Wrap { max: Some(_), .. } => true,
-            _ => false,
-        };
+        let should_use_weight_for_width = matches!(
+            row_effective_constraint.width,
+            DimensionValue::Fixed(_)
+                | DimensionValue::Fill { max: Some(_), .. }
+                | DimensionValue::Wrap { max: Some(_), .. }
+        );
 
         if should_use_weight_for_width {
             let available_width_for_children = row_effective_constraint.width.get_max().unwrap();
@@ -207,7 +256,7 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                 }
             }
 
-            let mut total_width_of_unweighted_children = Px(0);
+            let mut total_width_of_unweighted_children = Px(0.0);
             for &child_idx in &unweighted_children_indices {
                 let child_id = input.children_ids[child_idx];
 
@@ -230,13 +279,12 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
             }
 
             let remaining_width_for_weighted_children =
-                (available_width_for_children - total_width_of_unweighted_children).max(Px(0));
+                (available_width_for_children - total_width_of_unweighted_children).max(Px(0.0));
             if total_weight_sum > 0.0 {
                 for &child_idx in &weighted_children_indices {
                     let child_weight = child_weights[child_idx].unwrap_or(0.0);
-                    let allocated_width_for_child =
-                        Px((remaining_width_for_weighted_children.0 as f32
-                            * (child_weight / total_weight_sum)) as i32);
+                    let allocated_width_for_child = remaining_width_for_weighted_children
+                        .mul_f32(child_weight / total_weight_sum);
                     let child_id = input.children_ids[child_idx];
 
                     // Parent (row) offers Fixed allocated width and its own effective height constraint to weighted children
@@ -254,6 +302,16 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                 }
             }
 
+            let baseline_offsets = (args.cross_axis_alignment == CrossAxisAlignment::Baseline)
+                .then(|| {
+                    baseline_offsets(&children_sizes, input.children_ids, |child_id| {
+                        input.child_alignment_lines(child_id).first_baseline
+                    })
+                });
+            if let Some((_, baseline_aligned_height)) = &baseline_offsets {
+                max_child_height = max_child_height.max(*baseline_aligned_height);
+            }
+
             let final_row_width = available_width_for_children;
             // row's height is determined by its own effective constraint, or by wrapping content if no explicit max.
             let final_row_height = match row_effective_constraint.height {
@@ -275,7 +333,7 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
             let total_measured_children_width: Px = children_sizes
                 .iter()
                 .filter_map(|size_opt| size_opt.as_ref().map(|s| s.width))
-                .fold(Px(0), |acc, width| acc + width);
+                .fold(Px(0.0), |acc, width| acc + width);
 
             place_children_with_alignment(
                 &children_sizes,
@@ -286,6 +344,9 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                 total_measured_children_width,
                 args.main_axis_alignment,
                 args.cross_axis_alignment,
+                baseline_offsets
+                    .as_ref()
+                    .map(|(offsets, _)| offsets.as_slice()),
                 N,
             );
 
@@ -295,9 +356,9 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
             })
         } else {
             // Not using weight logic for width (row width is Wrap or Fill without max)
-            let mut total_children_measured_width = Px(0);
+            let mut total_children_measured_width = Px(0.0);
 
-            for i in 0..N {
+            for (i, child_size) in children_sizes.iter_mut().enumerate() {
                 let child_id = input.children_ids[i];
 
                 // Parent (row) offers Wrap for width and its effective height
@@ -309,6 +370,7 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                         },
                         DimensionValue::Fill { max, .. } => DimensionValue::Wrap { min: None, max },
                         DimensionValue::Wrap { max, .. } => DimensionValue::Wrap { min: None, max },
+                        DimensionValue::Fraction(_) => DimensionValue::WRAP,
                     },
                     row_effective_constraint.height,
                 );
@@ -317,11 +379,21 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                 let child_result =
                     input.measure_child(child_id, &parent_offered_constraint_for_child)?;
 
-                children_sizes[i] = Some(child_result);
+                *child_size = Some(child_result);
                 total_children_measured_width += child_result.width;
                 max_child_height = max_child_height.max(child_result.height);
             }
 
+            let baseline_offsets = (args.cross_axis_alignment == CrossAxisAlignment::Baseline)
+                .then(|| {
+                    baseline_offsets(&children_sizes, input.children_ids, |child_id| {
+                        input.child_alignment_lines(child_id).first_baseline
+                    })
+                });
+            if let Some((_, baseline_aligned_height)) = &baseline_offsets {
+                max_child_height = max_child_height.max(*baseline_aligned_height);
+            }
+
             // Determine row's final size based on its own constraints and content
             let final_row_width = match row_effective_constraint.width {
                 DimensionValue::Fixed(w) => w,
@@ -348,6 +420,7 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                     }
                     w
                 }
+                DimensionValue::Fraction(_) => total_children_measured_width,
             };
 
             let final_row_height = match row_effective_constraint.height {
@@ -374,6 +447,7 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                     }
                     h
                 }
+                DimensionValue::Fraction(_) => max_child_height,
             };
 
             place_children_with_alignment(
@@ -385,6 +459,9 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
                 total_children_measured_width,
                 args.main_axis_alignment,
                 args.cross_axis_alignment,
+                baseline_offsets
+                    .as_ref()
+                    .map(|(offsets, _)| offsets.as_slice()),
                 N,
             );
 
@@ -400,7 +477,101 @@ pub fn row<const N: usize>(args: RowArgs, children_items_input: [impl AsRowItem;
     }
 }
 
+/// Combines the intrinsic sizes of the children of a row: the children placed side by
+/// side give its width, and the tallest child gives its height.
+///
+/// A fixed width or height in the arguments is used as is, without querying the children.
+fn row_intrinsic_size(
+    args: &RowArgs,
+    query: IntrinsicQuery,
+    cross_size: Option<Px>,
+    children_ids: &[NodeId],
+    child_intrinsic_size: impl Fn(NodeId, IntrinsicQuery, Option<Px>) -> Result<Px, MeasurementError>,
+) -> Result<Px, MeasurementError> {
+    let dimension = if query.is_width() {
+        args.width
+    } else {
+        args.height
+    };
+    if let DimensionValue::Fixed(size) = dimension {
+        return Ok(size);
+    }
+    let mut size = Px(0.0);
+    for &child_id in children_ids {
+        let child_size = child_intrinsic_size(child_id, query, cross_size)?;
+        size = if query.is_width() {
+            size + child_size
+        } else {
+            size.max(child_size)
+        };
+    }
+    Ok(dimension.clamp(size))
+}
+
+/// Replaces the width and height of the effective constraint with the intrinsic sizes
+/// requested in the arguments, kept within the bounds of the constraint.
+fn apply_intrinsic_sizes(
+    args: &RowArgs,
+    constraint: &mut Constraint,
+    children_ids: &[NodeId],
+    child_intrinsic_size: impl Fn(NodeId, IntrinsicQuery, Option<Px>) -> Result<Px, MeasurementError>,
+) -> Result<(), MeasurementError> {
+    if let Some(size) = args.intrinsic_width {
+        let width = row_intrinsic_size(
+            args,
+            IntrinsicQuery::width(size),
+            None,
+            children_ids,
+            &child_intrinsic_size,
+        )?;
+        constraint.width = DimensionValue::Fixed(constraint.width.clamp(width));
+    }
+    if let Some(size) = args.intrinsic_height {
+        let width = match constraint.width {
+            DimensionValue::Fixed(width) => Some(width),
+            _ => None,
+        };
+        let height = row_intrinsic_size(
+            args,
+            IntrinsicQuery::height(size),
+            width,
+            children_ids,
+            &child_intrinsic_size,
+        )?;
+        constraint.height = DimensionValue::Fixed(constraint.height.clamp(height));
+    }
+    Ok(())
+}
+
+/// Computes the vertical offset of each child that lines up the first baselines of the
+/// children, and the height they span together.
+///
+/// Children without a baseline are placed at the top.
+fn baseline_offsets(
+    children_sizes: &[Option<ComputedData>],
+    children_ids: &[NodeId],
+    child_baseline: impl Fn(NodeId) -> Option<Px>,
+) -> (Vec<Px>, Px) {
+    let baselines: Vec<_> = children_ids
+        .iter()
+        .map(|&child_id| child_baseline(child_id))
+        .collect();
+    let shared_baseline = baselines.iter().flatten().copied().max().unwrap_or(Px(0.0));
+
+    let mut offsets = Vec::with_capacity(children_sizes.len());
+    let mut height = Px(0.0);
+    for (size, baseline) in children_sizes.iter().zip(&baselines) {
+        let offset = baseline.map_or(Px(0.0), |baseline| shared_baseline - baseline);
+        if let Some(size) = size {
+            height = height.max(offset + size.height);
+        }
+        offsets.push(offset);
+    }
+    (offsets, height)
+}
+
 /// A helper function to place children with alignment (horizontal layout).
+#[allow(clippy::too_many_arguments)]
 fn place_children_with_alignment(
     children_sizes: &[Option<ComputedData>],
     children_ids: &[tessera_ui::NodeId],
@@ -410,30 +581,31 @@ fn place_children_with_alignment(
     total_children_width: Px,
     main_axis_alignment: MainAxisAlignment,
     cross_axis_alignment: CrossAxisAlignment,
+    baseline_offsets: Option<&[Px]>,
     child_count: usize,
 ) {
-    let available_space = (final_row_width - total_children_width).max(Px(0));
+    let available_space = (final_row_width - total_children_width).max(Px(0.0));
 
     // Calculate start position and spacing on the main axis (horizontal for row)
     let (mut current_x, spacing_between_children) = match main_axis_alignment {
-        MainAxisAlignment::Start => (Px(0), Px(0)),
-        MainAxisAlignment::Center => (available_space / 2, Px(0)),
-        MainAxisAlignment::End => (available_space, Px(0)),
+        MainAxisAlignment::Start => (Px(0.0), Px(0.0)),
+        MainAxisAlignment::Center => (available_space / 2, Px(0.0)),
+        MainAxisAlignment::End => (available_space, Px(0.0)),
         MainAxisAlignment::SpaceEvenly => {
             if child_count > 0 {
                 let s = available_space / (child_count as i32 + 1);
                 (s, s)
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
         MainAxisAlignment::SpaceBetween => {
             if child_count > 1 {
-                (Px(0), available_space / (child_count as i32 - 1))
+                (Px(0.0), available_space / (child_count as i32 - 1))
             } else if child_count == 1 {
-                (available_space / 2, Px(0))
+                (available_space / 2, Px(0.0))
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
         MainAxisAlignment::SpaceAround => {
@@ -441,7 +613,7 @@ fn place_children_with_alignment(
                 let s = available_space / (child_count as i32);
                 (s / 2, s)
             } else {
-                (Px(0), Px(0))
+                (Px(0.0), Px(0.0))
             }
         }
     };
@@ -452,12 +624,17 @@ fn place_children_with_alignment(
 
             // Calculate position on the cross axis (vertical for row)
             let y_offset = match cross_axis_alignment {
-                CrossAxisAlignment::Start => Px(0),
+                CrossAxisAlignment::Start => Px(0.0),
                 CrossAxisAlignment::Center => {
-                    (final_row_height - child_actual_size.height).max(Px(0)) / 2
+                    (final_row_height - child_actual_size.height).max(Px(0.0)) / 2
+                }
+                CrossAxisAlignment::End => {
+                    (final_row_height - child_actual_size.height).max(Px(0.0))
+                }
+                CrossAxisAlignment::Stretch => Px(0.0),
+                CrossAxisAlignment::Baseline => {
+                    baseline_offsets.map_or(Px(0.0), |offsets| offsets[i])
                 }
-                CrossAxisAlignment::End => (final_row_height - child_actual_size.height).max(Px(0)),
-                CrossAxisAlignment::Stretch => Px(0),
             };
 
             place_node(child_id, PxPosition::new(current_x, y_offset), metadatas);
@@ -501,3 +678,34 @@ macro_rules! row_ui {
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline_offsets_line_up_baselines() {
+        let mut arena = tessera_ui::Arena::new();
+        let ids: Vec<NodeId> = (0..3).map(|_| arena.new_node(())).collect();
+        let sizes = [
+            Some(ComputedData {
+                width: Px(10.0),
+                height: Px(40.0),
+            }),
+            Some(ComputedData {
+                width: Px(10.0),
+                height: Px(15.0),
+            }),
+            Some(ComputedData {
+                width: Px(10.0),
+                height: Px(20.0),
+            }),
+        ];
+        let baselines = [Some(Px(30.0)), Some(Px(12.0)), None];
+        let (offsets, height) = baseline_offsets(&sizes, &ids, |child_id| {
+            baselines[ids.iter().position(|&id| id == child_id).unwrap()]
+        });
+        assert_eq!(offsets, vec![Px(0.0), Px(18.0), Px(0.0)]);
+        assert_eq!(height, Px(40.0));
+    }
+}
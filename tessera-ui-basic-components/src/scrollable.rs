@@ -16,12 +16,13 @@
 //! See [`scrollable()`] for usage details and code samples.
 mod scrollbar;
 
-use std::{sync::Arc, time::Instant};
+use std::{sync::Arc, time::Duration};
 
 use derive_builder::Builder;
 use parking_lot::RwLock;
 use tessera_ui::{
     Color, ComputedData, Constraint, CursorEventContent, DimensionValue, Dp, Px, PxPosition,
+    animation::{Animatable, AnimationSpec, Easing},
 };
 use tessera_ui_macros::tessera;
 
@@ -31,6 +32,7 @@ use crate::{
     boxed_ui,
     pos_misc::is_position_in_component,
     scrollable::scrollbar::{ScrollBarArgs, ScrollBarState, scrollbar_h, scrollbar_v},
+    theme::current_theme,
 };
 
 #[derive(Debug, Builder, Clone)]
@@ -59,13 +61,13 @@ pub struct ScrollableArgs {
     #[builder(default = "ScrollBarBehavior::AlwaysVisible")]
     pub scrollbar_behavior: ScrollBarBehavior,
     /// The color of the scrollbar track.
-    #[builder(default = "Color::new(0.0, 0.0, 0.0, 0.1)")]
+    #[builder(default = "current_theme().color_scheme.on_surface.with_alpha(0.1)")]
     pub scrollbar_track_color: Color,
     /// The color of the scrollbar thumb.
-    #[builder(default = "Color::new(0.0, 0.0, 0.0, 0.3)")]
+    #[builder(default = "current_theme().color_scheme.on_surface.with_alpha(0.3)")]
     pub scrollbar_thumb_color: Color,
     /// The color of the scrollbar thumb when hovered.
-    #[builder(default = "Color::new(0.0, 0.0, 0.0, 0.5)")]
+    #[builder(default = "current_theme().color_scheme.on_surface.with_alpha(0.5)")]
     pub scrollbar_thumb_hover_color: Color,
     /// The layout of the scrollbar relative to the content.
     #[builder(default = "ScrollBarLayout::Alongside")]
@@ -124,16 +126,12 @@ impl ScrollableState {
 
 #[derive(Clone, Debug)]
 struct ScrollableStateInner {
-    /// The current position of the child component (for rendering)
-    child_position: PxPosition,
-    /// The target position of the child component (scrolling destination)
-    target_position: PxPosition,
+    /// The position of the child component, animated towards the scrolling destination
+    child_position: Animatable<PxPosition>,
     /// The child component size
     child_size: ComputedData,
     /// The visible area size
     visible_size: ComputedData,
-    /// Last frame time for delta time calculation
-    last_frame_time: Option<Instant>,
 }
 
 impl Default for ScrollableStateInner {
@@ -146,67 +144,40 @@ impl ScrollableStateInner {
     /// Creates a new ScrollableState with default values.
     pub fn new() -> Self {
         Self {
-            child_position: PxPosition::ZERO,
-            target_position: PxPosition::ZERO,
+            child_position: Animatable::new(PxPosition::ZERO),
             child_size: ComputedData::ZERO,
             visible_size: ComputedData::ZERO,
-            last_frame_time: None,
         }
     }
 
-    /// Updates the scroll position based on time-based interpolation
-    /// Returns true if the position changed (needs redraw)
-    fn update_scroll_position(&mut self, smoothing: f32) -> bool {
-        let current_time = Instant::now();
-
-        // Calculate delta time
-        let delta_time = if let Some(last_time) = self.last_frame_time {
-            current_time.duration_since(last_time).as_secs_f32()
+    /// Derives the scroll animation from a smoothing factor.
+    ///
+    /// A smoothing of `0.0` jumps to the target immediately; higher values produce
+    /// longer, gently decelerating scroll animations.
+    fn set_scroll_smoothing(&mut self, smoothing: f32) {
+        let spec = if smoothing <= 0.0 {
+            AnimationSpec::Snap
         } else {
-            0.016 // Assume 60fps for first frame
+            // Roughly the time the former per-frame interpolation took to settle at 60fps
+            let seconds = 5.0 / ((1.0 - smoothing).max(0.01) * 60.0);
+            AnimationSpec::tween(Duration::from_secs_f32(seconds), Easing::EaseOut)
         };
+        self.child_position.set_spec(spec);
+    }
 
-        self.last_frame_time = Some(current_time);
-
-        // Calculate the difference between target and current position
-        let diff_x = self.target_position.x.to_f32() - self.child_position.x.to_f32();
-        let diff_y = self.target_position.y.to_f32() - self.child_position.y.to_f32();
-
-        // If we're close enough to target, snap to it
-        if diff_x.abs() < 1.0 && diff_y.abs() < 1.0 {
-            if self.child_position != self.target_position {
-                self.child_position = self.target_position;
-                return true;
-            }
-            return false;
-        }
-
-        // Use simple velocity-based movement for consistent behavior
-        // Higher smoothing = slower movement
-        let mut movement_factor = (1.0 - smoothing) * delta_time * 60.0;
-
-        // CRITICAL FIX: Clamp the movement factor to a maximum of 1.0.
-        // A factor greater than 1.0 causes the interpolation to overshoot the target,
-        // leading to oscillations that grow exponentially, causing the value explosion
-        // and overflow panic seen in the logs. Clamping ensures stability by
-        // preventing the position from moving past the target in a single frame.
-        if movement_factor > 1.0 {
-            movement_factor = 1.0;
-        }
-        let old_position = self.child_position;
-
-        self.child_position = PxPosition {
-            x: Px::saturating_from_f32(self.child_position.x.to_f32() + diff_x * movement_factor),
-            y: Px::saturating_from_f32(self.child_position.y.to_f32() + diff_y * movement_factor),
-        };
+    /// Returns the current (animated) position of the child component
+    fn child_position(&self) -> PxPosition {
+        self.child_position.value()
+    }
 
-        // Return true if position changed significantly
-        old_position != self.child_position
+    /// Returns the target position of the child component (scrolling destination)
+    fn target_position(&self) -> PxPosition {
+        *self.child_position.target()
     }
 
     /// Sets a new target position for scrolling
     fn set_target_position(&mut self, target: PxPosition) {
-        self.target_position = target;
+        self.child_position.animate_to(target);
     }
 }
 
@@ -313,7 +284,7 @@ pub fn scrollable(
     let scrollbar_args_v = ScrollBarArgs {
         total: state.inner.read().child_size.height,
         visible: state.inner.read().visible_size.height,
-        offset: state.inner.read().child_position.y,
+        offset: state.inner.read().child_position().y,
         thickness: Dp(8.0), // Default scrollbar thickness
         state: state.inner.clone(),
         scrollbar_behavior: args.scrollbar_behavior.clone(),
@@ -325,7 +296,7 @@ pub fn scrollable(
     let scrollbar_args_h = ScrollBarArgs {
         total: state.inner.read().child_size.width,
         visible: state.inner.read().visible_size.width,
-        offset: state.inner.read().child_position.x,
+        offset: state.inner.read().child_position().x,
         thickness: Dp(8.0), // Default scrollbar thickness
         state: state.inner.clone(),
         scrollbar_behavior: args.scrollbar_behavior.clone(),
@@ -501,10 +472,7 @@ fn scrollable_inner(
         let state = state.clone();
         measure(Box::new(move |input| {
             // Merge constraints with parent constraints
-            let arg_constraint = Constraint {
-                width: args.width,
-                height: args.height,
-            };
+            let arg_constraint = Constraint::new(args.width, args.height);
             let merged_constraint = input.parent_constraint.merge(&arg_constraint);
             // Now calculate the constraints to child
             let mut child_constraint = merged_constraint;
@@ -528,11 +496,11 @@ fn scrollable_inner(
             // Update the child position and size in the state
             state.write().child_size = child_measurement;
 
-            // Update scroll position based on time and get current position for rendering
+            // Get the current animated position for rendering
             let current_child_position = {
                 let mut state_guard = state.write();
-                state_guard.update_scroll_position(args.scroll_smoothing);
-                state_guard.child_position
+                state_guard.set_scroll_smoothing(args.scroll_smoothing);
+                state_guard.child_position()
             };
 
             // Place child at current interpolated position
@@ -551,6 +519,7 @@ fn scrollable_inner(
                     width
                 }
                 DimensionValue::Fill { min: _, max } => max.unwrap(),
+                DimensionValue::Fraction(_) => child_measurement.width,
             };
             let height = match merged_constraint.height {
                 DimensionValue::Fixed(h) => h,
@@ -565,6 +534,7 @@ fn scrollable_inner(
                     height
                 }
                 DimensionValue::Fill { min: _, max } => max.unwrap(),
+                DimensionValue::Fraction(_) => child_measurement.height,
             };
             // Pack the size into ComputedData
             let computed_data = ComputedData { width, height };
@@ -600,7 +570,7 @@ fn scrollable_inner(
                 let scroll_delta_y = event.delta_y;
 
                 // Calculate new target position using saturating arithmetic
-                let current_target = state_guard.target_position;
+                let current_target = state_guard.target_position();
                 let new_target = current_target.saturating_offset(
                     Px::saturating_from_f32(scroll_delta_x),
                     Px::saturating_from_f32(scroll_delta_y),
@@ -638,7 +608,7 @@ fn scrollable_inner(
 
             // Apply bound constraints to the child position
             // To make sure we constrain the target position at least once per frame
-            let target = state.read().target_position;
+            let target = state.read().target_position();
             let child_size = state.read().child_size;
             let constrained_position = constrain_position(
                 target,
@@ -652,9 +622,6 @@ fn scrollable_inner(
             // Block cursor events to prevent propagation
             input.cursor_events.clear();
         }
-
-        // Update scroll position based on time (only once per frame, after handling events)
-        state.write().update_scroll_position(args.scroll_smoothing);
     }));
 
     // Add child component
@@ -135,7 +135,7 @@ pub fn scrollbar_v(args: impl Into<ScrollBarArgs>, state: Arc<RwLock<ScrollBarSt
         let thumb_node_id = input.children_ids[1];
         input.measure_child(thumb_node_id, &Constraint::NONE)?; // No constraints need since it's size is fixed
         // place thumb
-        input.place_child(thumb_node_id, [0, thumb_y as i32].into());
+        input.place_child(thumb_node_id, PxPosition::new(Px::ZERO, Px(thumb_y)));
         // Return the size of the scrollbar track
         Ok(size)
     }));
@@ -157,7 +157,7 @@ pub fn scrollbar_v(args: impl Into<ScrollBarArgs>, state: Arc<RwLock<ScrollBarSt
             // The scrollable range of the thumb within the track
             let thumb_scrollable_range = track_height - thumb_height;
             if thumb_scrollable_range <= Px::ZERO {
-                return args.state.read().target_position;
+                return args.state.read().target_position();
             }
 
             // Adjust cursor position to be relative to the thumb's center
@@ -352,7 +352,7 @@ pub fn scrollbar_h(args: impl Into<ScrollBarArgs>, state: Arc<RwLock<ScrollBarSt
         let thumb_node_id = input.children_ids[1];
         input.measure_child(thumb_node_id, &Constraint::NONE)?;
         // place thumb
-        input.place_child(thumb_node_id, [thumb_x as i32, 0].into());
+        input.place_child(thumb_node_id, PxPosition::new(Px(thumb_x), Px::ZERO));
         // Return the size of the scrollbar track
         Ok(size)
     }));
@@ -374,7 +374,7 @@ pub fn scrollbar_h(args: impl Into<ScrollBarArgs>, state: Arc<RwLock<ScrollBarSt
             // The scrollable range of the thumb within the track
             let thumb_scrollable_range = track_width - thumb_width;
             if thumb_scrollable_range <= Px::ZERO {
-                return args.state.read().target_position;
+                return args.state.read().target_position();
             }
 
             // Adjust cursor position to be relative to the thumb's center
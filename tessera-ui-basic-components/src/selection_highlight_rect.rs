@@ -30,8 +30,8 @@ use crate::pipelines::ShapeCommand;
 /// // Renders a selection highlight rectangle with a width of 100px, a height of 20px,
 /// // and a semi-transparent blue color.
 /// selection_highlight_rect(
-///     Px(100),
-///     Px(20),
+///     Px(100.0),
+///     Px(20.0),
 ///     Color::new(0.2, 0.4, 1.0, 0.3),
 /// );
 /// ```
@@ -0,0 +1,147 @@
+//! Provides the `shader_box` component, which fills its bounds with a custom fragment shader.
+//!
+//! The shader is registered in a [`ShaderQuadPipeline`](crate::pipelines::shader_quad::ShaderQuadPipeline)
+//! under an id, see the [pipeline documentation](crate::pipelines::shader_quad) for how to
+//! write one. The component draws it with the given uniforms, behind an optional child,
+//! and can run it every frame for animated effects.
+use derive_builder::Builder;
+use encase::{ShaderType, internal::WriteInto};
+use tessera_ui::{
+    ComputedData, Constraint, DimensionValue, Px, PxPosition, TesseraRuntime, animation,
+};
+use tessera_ui_macros::tessera;
+
+use crate::pipelines::shader_quad::ShaderQuadCommand;
+
+/// Arguments for the `shader_box` component.
+#[derive(Clone, Debug, Builder)]
+#[builder(pattern = "owned")]
+pub struct ShaderBoxArgs {
+    /// The id the shader was registered with in its pipeline.
+    pub shader_id: &'static str,
+    /// Width behavior of the box.
+    #[builder(default = "DimensionValue::Wrap { min: None, max: None }")]
+    pub width: DimensionValue,
+    /// Height behavior of the box.
+    #[builder(default = "DimensionValue::Wrap { min: None, max: None }")]
+    pub height: DimensionValue,
+    /// Whether the shader samples `background`, the content drawn before the box.
+    ///
+    /// Sampling needs a copy of the scene, so leave this off unless the shader reads it.
+    #[builder(default)]
+    pub sample_background: bool,
+    /// Whether to redraw every frame, for shaders animated with `quad.time`.
+    #[builder(default)]
+    pub animated: bool,
+}
+
+/// Converts a shader id into [`ShaderBoxArgs`] with the default settings.
+impl From<&'static str> for ShaderBoxArgs {
+    fn from(shader_id: &'static str) -> Self {
+        ShaderBoxArgsBuilder::default()
+            .shader_id(shader_id)
+            .build()
+            .unwrap()
+    }
+}
+
+#[tessera]
+/// Fills its bounds with a fragment shader and draws `child` on top.
+///
+/// The shader is looked up by id in the `ShaderQuadPipeline<U>` registered for the type of
+/// `uniforms`, which are bound to the shader as `uniforms`. The position and size of the box,
+/// the screen size and the frame clock time are bound as `quad`.
+///
+/// # Example
+///
+/// ```no_run
+/// use encase::ShaderType;
+/// use tessera_ui::DimensionValue;
+/// use tessera_ui_basic_components::{
+///     shader_box::{ShaderBoxArgsBuilder, shader_box},
+///     text::text,
+/// };
+///
+/// #[derive(ShaderType, Clone)]
+/// struct Wave {
+///     color: glam::Vec4,
+///     speed: f32,
+/// }
+///
+/// let uniforms = Wave {
+///     color: glam::Vec4::new(0.2, 0.5, 0.9, 1.0),
+///     speed: 2.0,
+/// };
+/// shader_box(
+///     ShaderBoxArgsBuilder::default()
+///         .shader_id("wave")
+///         .width(DimensionValue::FILLED)
+///         .animated(true)
+///         .build()
+///         .unwrap(),
+///     uniforms,
+///     || text("Hello"),
+/// );
+/// ```
+///
+/// # Arguments
+///
+/// * `args` - The shader id and layout of the box, or just the shader id as a `&'static str`.
+/// * `uniforms` - The uniforms of the shader, a struct deriving [`encase::ShaderType`] that
+///   matches its `struct Uniforms`.
+/// * `child` - The content drawn on top of the shader, sized within the box.
+pub fn shader_box<U>(args: impl Into<ShaderBoxArgs>, uniforms: U, child: impl FnOnce())
+where
+    U: ShaderType + WriteInto + Clone + Send + Sync + 'static,
+{
+    let args: ShaderBoxArgs = args.into();
+    child();
+
+    measure(Box::new(move |input| {
+        let effective_constraint =
+            Constraint::new(args.width, args.height).merge(input.parent_constraint);
+
+        let child_size = match input.children_ids.first().copied() {
+            Some(child_id) => {
+                let size = input.measure_child(child_id, &effective_constraint)?;
+                input.place_child(child_id, PxPosition::ZERO);
+                size
+            }
+            None => ComputedData::ZERO,
+        };
+
+        if args.animated {
+            TesseraRuntime::request_redraw();
+        }
+        input.metadata_mut().push_draw_command(ShaderQuadCommand {
+            shader_id: args.shader_id,
+            uniforms: uniforms.clone(),
+            time: animation::now().as_secs_f32(),
+            sample_background: args.sample_background,
+        });
+
+        let width = match effective_constraint.width {
+            DimensionValue::Fixed(w) => w,
+            DimensionValue::Wrap { min, max } => min
+                .unwrap_or(Px(0.0))
+                .max(child_size.width)
+                .min(max.unwrap_or(Px::MAX)),
+            DimensionValue::Fill { min, max } => {
+                max.unwrap_or(child_size.width).max(min.unwrap_or(Px(0.0)))
+            }
+            DimensionValue::Fraction(_) => child_size.width,
+        };
+        let height = match effective_constraint.height {
+            DimensionValue::Fixed(h) => h,
+            DimensionValue::Wrap { min, max } => min
+                .unwrap_or(Px(0.0))
+                .max(child_size.height)
+                .min(max.unwrap_or(Px::MAX)),
+            DimensionValue::Fill { min, max } => {
+                max.unwrap_or(child_size.height).max(min.unwrap_or(Px(0.0)))
+            }
+            DimensionValue::Fraction(_) => child_size.height,
+        };
+        Ok(ComputedData { width, height })
+    }));
+}
@@ -18,15 +18,21 @@ use derive_builder::Builder;
 use parking_lot::Mutex;
 use tessera_ui::{
     Color, ComputedData, Constraint, CursorEventContent, DimensionValue, Dp, Px, PxPosition,
-    focus_state::Focus, winit::window::CursorIcon,
+    accessibility::{Action, ActionData, Role, Semantics},
+    focus_state::Focus,
+    winit::window::CursorIcon,
 };
 use tessera_ui_macros::tessera;
 
 use crate::{
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
 };
 
+/// How much an accessibility increment or decrement changes the value.
+const ACCESSIBILITY_STEP: f32 = 0.05;
+
 ///
 /// Stores the interactive state for the [`slider`] component, such as whether the slider is currently being dragged by the user.
 /// This struct should be managed via [`Arc<Mutex<SliderState>>`] and passed to the [`slider`] function to enable correct interaction handling.
@@ -81,16 +87,20 @@ pub struct SliderArgs {
     pub track_height: Dp,
 
     /// The color of the active part of the track (progress fill).
-    #[builder(default = "Color::new(0.2, 0.5, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub active_track_color: Color,
 
     /// The color of the inactive part of the track (background).
-    #[builder(default = "Color::new(0.8, 0.8, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.surface_variant")]
     pub inactive_track_color: Color,
 
     /// Disable interaction.
     #[builder(default = "false")]
     pub disabled: bool,
+
+    /// An optional label announced by screen readers.
+    #[builder(default, setter(strip_option, into))]
+    pub accessibility_label: Option<String>,
 }
 
 #[tessera]
@@ -143,6 +153,41 @@ pub struct SliderArgs {
 pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
     let args: SliderArgs = args.into();
 
+    semantics(|| {
+        let on_action = args.on_change.clone();
+        let value = args.value;
+        let mut slider_semantics = Semantics::new(Role::Slider)
+            .with_numeric_value(value as f64, 0.0, 1.0)
+            .with_numeric_step(ACCESSIBILITY_STEP as f64)
+            .with_disabled(args.disabled)
+            .with_focusable(!args.disabled)
+            .with_focused(state.lock().focus.is_focused());
+        if !args.disabled {
+            let state = state.clone();
+            slider_semantics = slider_semantics
+                .with_action(Action::Increment)
+                .with_action(Action::Decrement)
+                .with_action(Action::SetValue)
+                .on_action(move |action, data| {
+                    let new_value = match (action, data) {
+                        (Action::Focus, _) => {
+                            state.lock().focus.request_focus();
+                            return;
+                        }
+                        (Action::Increment, _) => value + ACCESSIBILITY_STEP,
+                        (Action::Decrement, _) => value - ACCESSIBILITY_STEP,
+                        (Action::SetValue, Some(ActionData::NumericValue(v))) => v as f32,
+                        _ => return,
+                    };
+                    on_action(new_value.clamp(0.0, 1.0));
+                });
+        }
+        if let Some(label) = &args.accessibility_label {
+            slider_semantics = slider_semantics.with_label(label.clone());
+        }
+        slider_semantics
+    });
+
     // Background track (inactive part) - capsule shape
     surface(
         SurfaceArgsBuilder::default()
@@ -161,7 +206,7 @@ pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
             let progress_width = args.width.to_px().to_f32() * args.value;
             surface(
                 SurfaceArgsBuilder::default()
-                    .width(DimensionValue::Fixed(Px(progress_width as i32)))
+                    .width(DimensionValue::Fixed(Px(progress_width)))
                     .height(DimensionValue::Fill {
                         min: None,
                         max: None,
@@ -190,9 +235,9 @@ pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
         let mut state = state_handler_state.lock();
 
         let is_in_component = input.cursor_position.is_some_and(|cursor_pos| {
-            cursor_pos.x.0 >= 0
+            cursor_pos.x.0 >= 0.0
                 && cursor_pos.x.0 < input.computed_data.width.0
-                && cursor_pos.y.0 >= 0
+                && cursor_pos.y.0 >= 0.0
                 && cursor_pos.y.0 < input.computed_data.height.0
         });
 
@@ -215,7 +260,7 @@ pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
 
                     if let Some(pos) = input.cursor_position {
                         let v =
-                            (pos.x.0 as f32 / input.computed_data.width.0 as f32).clamp(0.0, 1.0);
+                            (pos.x.to_f32() / input.computed_data.width.to_f32()).clamp(0.0, 1.0);
                         new_value = Some(v);
                     }
                 }
@@ -226,17 +271,17 @@ pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
             }
         }
 
-        if state.is_dragging {
-            if let Some(pos) = input.cursor_position {
-                let v = (pos.x.0 as f32 / input.computed_data.width.0 as f32).clamp(0.0, 1.0);
-                new_value = Some(v);
-            }
+        if state.is_dragging
+            && let Some(pos) = input.cursor_position
+        {
+            let v = (pos.x.to_f32() / input.computed_data.width.to_f32()).clamp(0.0, 1.0);
+            new_value = Some(v);
         }
 
-        if let Some(v) = new_value {
-            if (v - args.value).abs() > f32::EPSILON {
-                on_change(v);
-            }
+        if let Some(v) = new_value
+            && (v - args.value).abs() > f32::EPSILON
+        {
+            on_change(v);
         }
     }));
 
@@ -252,7 +297,7 @@ pub fn slider(args: impl Into<SliderArgs>, state: Arc<Mutex<SliderState>>) {
             DimensionValue::Fixed(self_height),
         );
         input.measure_child(track_id, &track_constraint)?;
-        input.place_child(track_id, PxPosition::new(Px(0), Px(0)));
+        input.place_child(track_id, PxPosition::new(Px(0.0), Px(0.0)));
 
         Ok(ComputedData {
             width: self_width,
@@ -37,7 +37,7 @@ use tessera_ui_macros::tessera;
 /// // Expanding spacer (fills available width)
 /// spacer(SpacerArgs {
 ///     width: DimensionValue::Fill { min: None, max: None },
-///     height: DimensionValue::Fixed(Px(0)),
+///     height: DimensionValue::Fixed(Px(0.0)),
 /// });
 /// ```
 #[derive(Default, Clone, Copy, Builder)]
@@ -45,13 +45,13 @@ use tessera_ui_macros::tessera;
 pub struct SpacerArgs {
     /// The desired width behavior of the spacer.
     ///
-    /// Defaults to `Fixed(Px(0))`. Use `Fill { min: None, max: None }` for an expanding spacer.
-    #[builder(default = "DimensionValue::Fixed(Px(0))")]
+    /// Defaults to `Fixed(Px(0.0))`. Use `Fill { min: None, max: None }` for an expanding spacer.
+    #[builder(default = "DimensionValue::Fixed(Px(0.0))")]
     pub width: DimensionValue,
     /// The desired height behavior of the spacer.
     ///
-    /// Defaults to `Fixed(Px(0))`. Use `Fill { min: None, max: None }` for an expanding spacer.
-    #[builder(default = "DimensionValue::Fixed(Px(0))")]
+    /// Defaults to `Fixed(Px(0.0))`. Use `Fill { min: None, max: None }` for an expanding spacer.
+    #[builder(default = "DimensionValue::Fixed(Px(0.0))")]
     pub height: DimensionValue,
 }
 
@@ -90,7 +90,7 @@ impl SpacerArgs {
                 min: None,
                 max: None,
             })
-            .height(DimensionValue::Fixed(Px(0))) // Default height if only filling width
+            .height(DimensionValue::Fixed(Px(0.0))) // Default height if only filling width
             .build()
             .unwrap()
     }
@@ -104,7 +104,7 @@ impl SpacerArgs {
     /// ```
     pub fn fill_height() -> Self {
         SpacerArgsBuilder::default()
-            .width(DimensionValue::Fixed(Px(0))) // Default width if only filling height
+            .width(DimensionValue::Fixed(Px(0.0))) // Default width if only filling height
             .height(DimensionValue::Fill {
                 min: None,
                 max: None,
@@ -139,7 +139,7 @@ impl From<Px> for SpacerArgs {
     /// ```
     /// use tessera_ui_basic_components::spacer::SpacerArgs;
     /// use tessera_ui::Px;
-    /// let args = SpacerArgs::from(Px(16));
+    /// let args = SpacerArgs::from(Px(16.0));
     /// ```
     fn from(value: Px) -> Self {
         SpacerArgsBuilder::default()
@@ -193,7 +193,7 @@ pub fn spacer(args: impl Into<SpacerArgs>) {
 
         let final_spacer_width = match effective_spacer_constraint.width {
             DimensionValue::Fixed(w) => w,
-            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0)), // Spacer has no content, so it's its min or 0.
+            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0.0)), // Spacer has no content, so it's its min or 0.
             DimensionValue::Fill { min, max: _ } => {
                 // If the effective constraint is Fill, it means the parent allows filling.
                 // However, a simple spacer has no content to expand beyond its minimum.
@@ -205,14 +205,16 @@ pub fn spacer(args: impl Into<SpacerArgs>) {
                 // If parent was Wrap, merge would result in Fill{min,max} (if spacer was Fill).
                 // If parent was Fill{p_min, p_max}, merge would result in Fill{combined_min, combined_max}.
                 // In all Fill cases, the spacer itself doesn't "push" for more than its min.
-                min.unwrap_or(Px(0))
+                min.unwrap_or(Px(0.0))
             }
+            DimensionValue::Fraction(_) => Px(0.0),
         };
 
         let final_spacer_height = match effective_spacer_constraint.height {
             DimensionValue::Fixed(h) => h,
-            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0)),
-            DimensionValue::Fill { min, max: _ } => min.unwrap_or(Px(0)),
+            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fill { min, max: _ } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fraction(_) => Px(0.0),
         };
 
         Ok(ComputedData {
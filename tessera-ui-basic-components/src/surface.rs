@@ -27,6 +27,7 @@ use crate::{
     pos_misc::is_position_in_component,
     ripple_state::RippleState,
     shape_def::Shape,
+    theme::{current_content_color, current_theme, provide_content_color},
 };
 
 ///
@@ -39,7 +40,7 @@ use crate::{
 ///
 /// # Fields
 ///
-/// - `color`: The fill color of the surface (RGBA). Defaults to a blue-gray.
+/// - `color`: The fill color of the surface (RGBA). Defaults to the theme's surface color.
 /// - `hover_color`: The color displayed when the surface is hovered. If `None`, no hover effect is applied.
 /// - `shape`: The geometric shape of the surface (e.g., rounded rectangle, ellipse).
 /// - `shadow`: Optional shadow properties for elevation effects.
@@ -49,7 +50,8 @@ use crate::{
 /// - `border_width`: Width of the border. If greater than 0, an outline is drawn.
 /// - `border_color`: Optional color for the border. If `None` and `border_width > 0`, uses `color`.
 /// - `on_click`: Optional callback for click events. If set, the surface becomes interactive and shows a ripple effect.
-/// - `ripple_color`: The color of the ripple effect for interactive surfaces.
+/// - `content_color`: Optional color for content inside the surface. If `None`, it matches `color`.
+/// - `ripple_color`: The color of the ripple effect for interactive surfaces. Defaults to the theme's `on_surface` color.
 ///
 /// # Example
 ///
@@ -80,7 +82,7 @@ use crate::{
 #[builder(pattern = "owned")]
 pub struct SurfaceArgs {
     /// The fill color of the surface (RGBA).
-    #[builder(default = "Color::new(0.4745, 0.5255, 0.7961, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.surface")]
     pub color: Color,
     /// The hover color of the surface (RGBA). If None, no hover effect is applied.
     #[builder(default)]
@@ -100,6 +102,10 @@ pub struct SurfaceArgs {
     /// Optional explicit height behavior for the surface. Defaults to Wrap {min: None, max: None} if None.
     #[builder(default, setter(strip_option))]
     pub height: Option<DimensionValue>,
+    /// Optional ratio of width to height to keep, e.g. `16.0 / 9.0` for a card that fills
+    /// the width, or `1.0` for a square. See [`Constraint::with_aspect_ratio`].
+    #[builder(default, setter(strip_option))]
+    pub aspect_ratio: Option<f32>,
     /// Width of the border. If > 0, an outline will be drawn.
     #[builder(default = "0.0")]
     pub border_width: f32,
@@ -109,8 +115,13 @@ pub struct SurfaceArgs {
     /// Optional click callback function. If provided, surface becomes interactive with ripple effect.
     #[builder(default)]
     pub on_click: Option<Arc<dyn Fn() + Send + Sync>>,
+    /// The color of content, such as text, inside the surface. If None, the theme's
+    /// [`content_color_for`](crate::theme::ColorScheme::content_color_for) `color` is used,
+    /// or the enclosing content color if `color` is fully transparent.
+    #[builder(default)]
+    pub content_color: Option<Color>,
     /// The ripple color (RGB) for interactive surfaces.
-    #[builder(default = "Color::from_rgb(1.0, 1.0, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.on_surface")]
     pub ripple_color: Color,
 }
 
@@ -163,7 +174,14 @@ impl Default for SurfaceArgs {
 ///
 #[tessera]
 pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child: impl FnOnce()) {
-    (child)();
+    let content_color = args.content_color.unwrap_or_else(|| {
+        if args.color.a == 0.0 {
+            current_content_color()
+        } else {
+            current_theme().color_scheme.content_color_for(args.color)
+        }
+    });
+    provide_content_color(content_color, child);
     let ripple_state_for_measure = ripple_state.clone();
     let args_measure_clone = args.clone();
 
@@ -177,8 +195,12 @@ pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child:
             min: None,
             max: None,
         });
-        let surface_intrinsic_constraint =
+        let mut surface_intrinsic_constraint =
             Constraint::new(surface_intrinsic_width, surface_intrinsic_height);
+        if let Some(aspect_ratio) = args_measure_clone.aspect_ratio {
+            surface_intrinsic_constraint =
+                surface_intrinsic_constraint.with_aspect_ratio(aspect_ratio);
+        }
         // Merge with parent_constraint to get effective_surface_constraint
         let effective_surface_constraint =
             surface_intrinsic_constraint.merge(input.parent_constraint);
@@ -208,8 +230,8 @@ pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child:
             child_measurement
         } else {
             ComputedData {
-                width: Px(0),
-                height: Px(0),
+                width: Px(0.0),
+                height: Px(0.0),
             }
         };
         // Add drawable for the surface
@@ -218,10 +240,9 @@ pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child:
             .map(|state| state.is_hovered())
             .unwrap_or(false);
 
-        let effective_color = if is_hovered && args_measure_clone.hover_color.is_some() {
-            args_measure_clone.hover_color.unwrap()
-        } else {
-            args_measure_clone.color
+        let effective_color = match args_measure_clone.hover_color {
+            Some(hover_color) if is_hovered => hover_color,
+            _ => args_measure_clone.color,
         };
 
         let drawable = if args_measure_clone.on_click.is_some() {
@@ -339,18 +360,19 @@ pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child:
         let width = match effective_surface_constraint.width {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(min_width)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => max
                 .expect("Seems that you are trying to fill an infinite width, which is not allowed")
                 .max(min_width)
-                .max(min.unwrap_or(Px(0))),
+                .max(min.unwrap_or(Px(0.0))),
+            DimensionValue::Fraction(_) => min_width,
         };
         let height = match effective_surface_constraint.height {
             DimensionValue::Fixed(value) => value,
             DimensionValue::Wrap { min, max } => min
-                .unwrap_or(Px(0))
+                .unwrap_or(Px(0.0))
                 .max(min_height)
                 .min(max.unwrap_or(Px::MAX)),
             DimensionValue::Fill { min, max } => max
@@ -358,7 +380,8 @@ pub fn surface(args: SurfaceArgs, ripple_state: Option<Arc<RippleState>>, child:
                     "Seems that you are trying to fill an infinite height, which is not allowed",
                 )
                 .max(min_height)
-                .max(min.unwrap_or(Px(0))),
+                .max(min.unwrap_or(Px(0.0))),
+            DimensionValue::Fraction(_) => min_height,
         };
         Ok(ComputedData { width, height })
     }));
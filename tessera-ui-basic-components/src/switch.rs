@@ -18,16 +18,16 @@
 //!
 //! See [`SwitchArgs`], [`SwitchState`], and [`switch()`] for details and usage examples.
 
-use std::{
-    sync::Arc,
-    time::{Duration, Instant},
-};
+use std::{sync::Arc, time::Duration};
 
 use derive_builder::Builder;
 use parking_lot::Mutex;
 use tessera_ui::{
     Color, ComputedData, Constraint, CursorEventContent, DimensionValue, Dp, PressKeyEventType,
-    PxPosition, winit::window::CursorIcon,
+    PxPosition,
+    accessibility::{Action, Role, Semantics},
+    animation::{Animatable, AnimationSpec, Easing},
+    winit::window::CursorIcon,
 };
 use tessera_ui_macros::tessera;
 
@@ -35,9 +35,10 @@ use crate::{
     pipelines::ShapeCommand,
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
+    theme::current_theme,
 };
 
-const ANIMATION_DURATION: Duration = Duration::from_millis(150);
+const ANIMATION: AnimationSpec = AnimationSpec::tween(Duration::from_millis(150), Easing::Linear);
 
 ///
 /// Represents the state for the `switch` component, including checked status and animation progress.
@@ -65,8 +66,7 @@ const ANIMATION_DURATION: Duration = Duration::from_millis(150);
 /// ```
 pub struct SwitchState {
     pub checked: bool,
-    progress: Mutex<f32>,
-    last_toggle_time: Mutex<Option<Instant>>,
+    progress: Animatable<f32>,
 }
 
 impl SwitchState {
@@ -77,15 +77,20 @@ impl SwitchState {
     pub fn new(initial_state: bool) -> Self {
         Self {
             checked: initial_state,
-            progress: Mutex::new(if initial_state { 1.0 } else { 0.0 }),
-            last_toggle_time: Mutex::new(None),
+            progress: Animatable::new(if initial_state { 1.0 } else { 0.0 }).with_spec(ANIMATION),
         }
     }
 
-    /// Toggles the checked state and updates the animation timestamp.
+    /// Toggles the checked state and starts animating the thumb.
     pub fn toggle(&mut self) {
         self.checked = !self.checked;
-        *self.last_toggle_time.lock() = Some(Instant::now());
+        self.progress
+            .animate_to(if self.checked { 1.0 } else { 0.0 });
+    }
+
+    /// Returns the animated thumb position, from 0.0 (off) to 1.0 (on).
+    pub fn progress(&self) -> f32 {
+        self.progress.value()
     }
 }
 
@@ -136,17 +141,21 @@ pub struct SwitchArgs {
     #[builder(default = "Dp(32.0)")]
     pub height: Dp,
 
-    #[builder(default = "Color::new(0.8, 0.8, 0.8, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.surface_variant")]
     pub track_color: Color,
 
-    #[builder(default = "Color::new(0.6, 0.7, 0.9, 1.0)")]
+    #[builder(default = "current_theme().color_scheme.primary")]
     pub track_checked_color: Color,
 
-    #[builder(default = "Color::WHITE")]
+    #[builder(default = "current_theme().color_scheme.surface")]
     pub thumb_color: Color,
 
     #[builder(default = "Dp(3.0)")]
     pub thumb_padding: Dp,
+
+    /// An optional label announced by screen readers.
+    #[builder(default, setter(strip_option, into))]
+    pub accessibility_label: Option<String>,
 }
 
 impl Default for SwitchArgs {
@@ -184,6 +193,23 @@ pub fn switch(args: impl Into<SwitchArgs>) {
     let args: SwitchArgs = args.into();
     let thumb_size = Dp(args.height.0 - (args.thumb_padding.0 * 2.0));
 
+    semantics(|| {
+        let on_action = args.on_toggle.clone();
+        let checked = args.checked;
+        let mut switch_semantics = Semantics::new(Role::Switch)
+            .with_toggled(checked)
+            .with_action(Action::Click)
+            .on_action(move |action, _| {
+                if action == Action::Click {
+                    on_action(!checked);
+                }
+            });
+        if let Some(label) = &args.accessibility_label {
+            switch_semantics = switch_semantics.with_label(label.clone());
+        }
+        switch_semantics
+    });
+
     surface(
         SurfaceArgsBuilder::default()
             .width(DimensionValue::Fixed(thumb_size.to_px()))
@@ -197,30 +223,12 @@ pub fn switch(args: impl Into<SwitchArgs>) {
     );
 
     let on_toggle = args.on_toggle.clone();
-    let state = args.state.clone();
     let checked = args.checked;
 
     state_handler(Box::new(move |input| {
-        if let Some(state) = &state {
-            let state = state.lock();
-            let mut progress = state.progress.lock();
-
-            if let Some(last_toggle_time) = *state.last_toggle_time.lock() {
-                let elapsed = last_toggle_time.elapsed();
-                let animation_fraction =
-                    (elapsed.as_secs_f32() / ANIMATION_DURATION.as_secs_f32()).min(1.0);
-
-                *progress = if state.checked {
-                    animation_fraction
-                } else {
-                    1.0 - animation_fraction
-                };
-            }
-        }
-
         let size = input.computed_data;
         let is_cursor_in = if let Some(pos) = input.cursor_position {
-            pos.x.0 >= 0 && pos.x.0 < size.width.0 && pos.y.0 >= 0 && pos.y.0 < size.height.0
+            pos.x.0 >= 0.0 && pos.x.0 < size.width.0 && pos.y.0 >= 0.0 && pos.y.0 < size.height.0
         } else {
             false
         };
@@ -230,10 +238,10 @@ pub fn switch(args: impl Into<SwitchArgs>) {
         }
 
         for e in input.cursor_events.iter() {
-            if let CursorEventContent::Pressed(PressKeyEventType::Left) = &e.content {
-                if is_cursor_in {
-                    on_toggle(!checked);
-                }
+            if let CursorEventContent::Pressed(PressKeyEventType::Left) = &e.content
+                && is_cursor_in
+            {
+                on_toggle(!checked);
             }
         }
     }));
@@ -259,19 +267,16 @@ pub fn switch(args: impl Into<SwitchArgs>) {
         let progress = args
             .state
             .as_ref()
-            .map(|s| *s.lock().progress.lock())
+            .map(|s| s.lock().progress())
             .unwrap_or(if args.checked { 1.0 } else { 0.0 });
 
         let start_x = thumb_padding_px;
         let end_x = self_width_px - thumb_size.width - thumb_padding_px;
-        let thumb_x = start_x.0 as f32 + (end_x.0 - start_x.0) as f32 * progress;
+        let thumb_x = start_x.0 + (end_x.0 - start_x.0) * progress;
 
         let thumb_y = (self_height_px - thumb_size.height) / 2;
 
-        input.place_child(
-            thumb_id,
-            PxPosition::new(tessera_ui::Px(thumb_x as i32), thumb_y),
-        );
+        input.place_child(thumb_id, PxPosition::new(tessera_ui::Px(thumb_x), thumb_y));
 
         let track_color = if args.checked {
             args.track_checked_color
@@ -280,7 +285,7 @@ pub fn switch(args: impl Into<SwitchArgs>) {
         };
         let track_command = ShapeCommand::Rect {
             color: track_color,
-            corner_radius: (self_height_px.0 as f32) / 2.0,
+            corner_radius: self_height_px.to_f32() / 2.0,
             g2_k_value: 2.0, // Use G1 corners here specifically
             shadow: None,
         };
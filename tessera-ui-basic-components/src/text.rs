@@ -20,20 +20,26 @@
 //! Custom styling:
 //! ```
 //! use tessera_ui_basic_components::text::{text, TextArgsBuilder};
-//! use tessera_ui::{Color, Dp};
+//! use tessera_ui::{Color, Sp};
 //! let args = TextArgsBuilder::default()
 //!     .text("Styled".to_string())
 //!     .color(Color::from_rgb(0.2, 0.4, 0.8))
-//!     .size(Dp(32.0))
+//!     .size(Sp(32.0))
 //!     .build()
 //!     .unwrap();
 //! text(args);
 //! ```
 use derive_builder::Builder;
-use tessera_ui::{Color, ComputedData, DimensionValue, Dp, Px};
+use tessera_ui::{
+    Color, ComputedData, DimensionValue, IntrinsicQuery, Px, Sp,
+    accessibility::{Role, Semantics},
+};
 use tessera_ui_macros::tessera;
 
-use crate::pipelines::{TextCommand, TextConstraint, TextData};
+use crate::{
+    pipelines::{TextCommand, TextConstraint, TextData},
+    theme::{TextStyle, current_content_color, current_theme},
+};
 
 /// Configuration arguments for the `text` component.
 ///
@@ -43,8 +49,8 @@ use crate::pipelines::{TextCommand, TextConstraint, TextData};
 /// # Fields
 ///
 /// - `text`: The string content to be displayed
-/// - `color`: Text color (defaults to black)
-/// - `size`: Font size in density-independent pixels (defaults to 25.0 dp)
+/// - `color`: Text color (defaults to the content color of the enclosing container)
+/// - `size`: Font size in scalable pixels (defaults to the theme's `body_large` size)
 /// - `line_height`: Optional line height override (defaults to 1.2 × font size)
 ///
 /// # Builder Pattern
@@ -62,19 +68,19 @@ use crate::pipelines::{TextCommand, TextConstraint, TextData};
 ///     .text("Hello, World!".to_string())
 ///     .build()
 ///     .unwrap();
-/// // Uses: black color, 25.0 dp size, 30.0 dp line height (1.2 × size)
+/// // Uses: the content color and `body_large` size, line height 1.2 × size
 /// ```
 ///
 /// ## Customized text styling
 /// ```
 /// use tessera_ui_basic_components::text::{TextArgs, TextArgsBuilder};
-/// use tessera_ui::{Color, Dp};
+/// use tessera_ui::{Color, Sp};
 ///
 /// let args = TextArgsBuilder::default()
 ///     .text("Styled Text".to_string())
 ///     .color(Color::from_rgb(0.2, 0.4, 0.8)) // Blue color
-///     .size(Dp(32.0))                        // Larger font
-///     .line_height(Dp(40.0))                 // Custom line height
+///     .size(Sp(32.0))                        // Larger font
+///     .line_height(Sp(40.0))                 // Custom line height
 ///     .build()
 ///     .unwrap();
 /// ```
@@ -82,12 +88,12 @@ use crate::pipelines::{TextCommand, TextConstraint, TextData};
 /// ## Using automatic line height calculation
 /// ```
 /// use tessera_ui_basic_components::text::{TextArgs, TextArgsBuilder};
-/// use tessera_ui::Dp;
+/// use tessera_ui::Sp;
 ///
 /// let args = TextArgsBuilder::default()
 ///     .text("Auto Line Height".to_string())
-///     .size(Dp(50.0))
-///     // line_height will automatically be Dp(60.0) (1.2 × 50.0)
+///     .size(Sp(50.0))
+///     // line_height will automatically be Sp(60.0) (1.2 × 50.0)
 ///     .build()
 ///     .unwrap();
 /// ```
@@ -113,20 +119,24 @@ pub struct TextArgs {
 
     /// The color of the text.
     ///
-    /// Defaults to `Color::BLACK` if not specified. The color is applied uniformly
-    /// to all characters in the text string.
-    #[builder(default = "Color::BLACK")]
+    /// Defaults to the [content color](crate::theme::current_content_color) of the
+    /// enclosing container, such as a surface, if not specified. The color is applied
+    /// uniformly to all characters in the text string.
+    #[builder(default = "current_content_color()")]
     pub color: Color,
 
-    /// The font size in density-independent pixels (dp).
+    /// The font size in scalable pixels (sp).
     ///
-    /// Defaults to `Dp(25.0)` if not specified. This size is automatically scaled
-    /// based on the device's pixel density to ensure consistent visual appearance
-    /// across different screen densities.
-    #[builder(default = "Dp(25.0)")]
-    pub size: Dp,
+    /// Defaults to the current theme's [`body_large`](crate::theme::Typography::body_large)
+    /// size if not specified. This size is automatically scaled
+    /// based on the device's pixel density and on the user's font scale, see
+    /// [`TesseraRuntime::font_scale`](tessera_ui::TesseraRuntime::font_scale).
+    ///
+    /// A [`Dp`](tessera_ui::Dp) is accepted as well and treated as the same number of sp.
+    #[builder(default = "current_theme().typography.body_large.size", setter(into))]
+    pub size: Sp,
 
-    /// Optional override for line height in density-independent pixels (dp).
+    /// Optional override for line height in scalable pixels (sp).
     ///
     /// If not specified (None), the line height will automatically be calculated as
     /// 1.2 times the font size, which provides good readability for most text.
@@ -137,25 +147,44 @@ pub struct TextArgs {
     /// # Example
     /// ```
     /// use tessera_ui_basic_components::text::TextArgsBuilder;
-    /// use tessera_ui::Dp;
+    /// use tessera_ui::Sp;
     ///
     /// // Automatic line height (1.2 × size)
     /// let auto = TextArgsBuilder::default()
     ///     .text("Auto spacing".to_string())
-    ///     .size(Dp(20.0))  // line_height will be Dp(24.0)
+    ///     .size(Sp(20.0))  // line_height will be Sp(24.0)
     ///     .build()
     ///     .unwrap();
     ///
     /// // Custom line height
     /// let custom = TextArgsBuilder::default()
     ///     .text("Custom spacing".to_string())
-    ///     .size(Dp(20.0))
-    ///     .line_height(Dp(30.0))  // Explicit line height
+    ///     .size(Sp(20.0))
+    ///     .line_height(Sp(30.0))  // Explicit line height
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[builder(default, setter(into, strip_option))]
+    pub line_height: Option<Sp>,
+}
+
+impl TextArgsBuilder {
+    /// Sets the font size and the line height from a [`TextStyle`] of the theme's type scale.
+    ///
+    /// # Example
+    /// ```
+    /// use tessera_ui_basic_components::{text::TextArgsBuilder, theme::current_theme};
+    ///
+    /// let headline = TextArgsBuilder::default()
+    ///     .text("Welcome".to_string())
+    ///     .style(current_theme().typography.headline_medium)
     ///     .build()
     ///     .unwrap();
+    /// assert_eq!(headline.size, current_theme().typography.headline_medium.size);
     /// ```
-    #[builder(default, setter(strip_option))]
-    pub line_height: Option<Dp>,
+    pub fn style(self, style: TextStyle) -> Self {
+        self.size(style.size).line_height(style.line_height)
+    }
 }
 
 /// Converts a [`String`] into [`TextArgs`] using the builder pattern.
@@ -199,12 +228,12 @@ impl From<&str> for TextArgs {
 /// # Example
 /// ```
 /// use tessera_ui_basic_components::text::{text, TextArgs, TextArgsBuilder};
-/// use tessera_ui::Dp;
-/// // a simple hello world text, in black
+/// use tessera_ui::Sp;
+/// // a simple hello world text, in the content color
 /// let args = TextArgsBuilder::default()
 ///     .text("Hello, World!".to_string())
-///     .size(Dp(50.0)) // Example using Dp
-///     // line_height will be Dp(60.0) (1.2 * size) by default
+///     .size(Sp(50.0))
+///     // line_height will be Sp(60.0) (1.2 * size) by default
 ///     .build()
 ///     .unwrap();
 /// text(args);
@@ -212,26 +241,52 @@ impl From<&str> for TextArgs {
 #[tessera]
 pub fn text(args: impl Into<TextArgs>) {
     let text_args: TextArgs = args.into();
+    semantics(|| Semantics::new(Role::Label).with_value(text_args.text.clone()));
+
+    let intrinsic_args = text_args.clone();
+    intrinsic(Box::new(move |input| {
+        let max_width = match input.query {
+            // Laid out at zero width, the text wraps at every opportunity, so its widest line
+            // is the widest run that cannot be broken
+            IntrinsicQuery::MinWidth => Some(0.0),
+            IntrinsicQuery::MaxWidth => None,
+            IntrinsicQuery::MinHeight | IntrinsicQuery::MaxHeight => {
+                input.cross_size.map(|width| width.to_f32())
+            }
+        };
+        let [width, height] = layout_text(
+            &intrinsic_args,
+            TextConstraint {
+                max_width,
+                max_height: None,
+            },
+        )
+        .size;
+        Ok(if input.query.is_width() {
+            width
+        } else {
+            height
+        }
+        .into())
+    }));
+
     measure(Box::new(move |input| {
         let max_width: Option<Px> = match input.parent_constraint.width {
             DimensionValue::Fixed(w) => Some(w),
             DimensionValue::Wrap { max, .. } => max, // Use max from Wrap
             DimensionValue::Fill { max, .. } => max, // Use max from Fill
+            DimensionValue::Fraction(_) => None,
         };
 
         let max_height: Option<Px> = match input.parent_constraint.height {
             DimensionValue::Fixed(h) => Some(h),
             DimensionValue::Wrap { max, .. } => max, // Use max from Wrap
             DimensionValue::Fill { max, .. } => max, // Use max from Fill
+            DimensionValue::Fraction(_) => None,
         };
 
-        let line_height = text_args.line_height.unwrap_or(Dp(text_args.size.0 * 1.2));
-
-        let text_data = TextData::new(
-            text_args.text.clone(),
-            text_args.color,
-            text_args.size.to_pixels_f32(),
-            line_height.to_pixels_f32(),
+        let text_data = layout_text(
+            &text_args,
             TextConstraint {
                 max_width: max_width.map(|px| px.to_f32()),
                 max_height: max_height.map(|px| px.to_f32()),
@@ -239,6 +294,7 @@ pub fn text(args: impl Into<TextArgs>) {
         );
 
         let size = text_data.size;
+        input.set_alignment_lines(text_data.alignment_lines());
         let drawable = TextCommand { data: text_data };
 
         // Use the new unified command system to add the text rendering command
@@ -250,3 +306,15 @@ pub fn text(args: impl Into<TextArgs>) {
         })
     }));
 }
+
+/// Lays out the text of `args` within `constraint`.
+fn layout_text(args: &TextArgs, constraint: TextConstraint) -> TextData {
+    let line_height = args.line_height.unwrap_or(Sp(args.size.0 * 1.2));
+    TextData::new(
+        args.text.clone(),
+        args.color,
+        args.size,
+        line_height,
+        constraint,
+    )
+}
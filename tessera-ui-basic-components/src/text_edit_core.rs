@@ -16,12 +16,19 @@
 
 mod cursor;
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::{
+        Arc,
+        atomic::{self, AtomicBool},
+    },
+    time::{Duration, Instant},
+};
 
 use glyphon::Edit;
 use parking_lot::RwLock;
 use tessera_ui::{
-    Clipboard, Color, ComputedData, DimensionValue, Dp, Px, PxPosition, focus_state::Focus, winit,
+    Clipboard, Color, ComputedData, DimensionValue, Px, PxPosition, Sp, TesseraRuntime,
+    focus_state::Focus, timer::set_interval, winit,
 };
 use tessera_ui_macros::tessera;
 use unicode_segmentation::UnicodeSegmentation;
@@ -29,8 +36,12 @@ use unicode_segmentation::UnicodeSegmentation;
 use crate::{
     pipelines::{TextCommand, TextConstraint, TextData, write_font_system},
     selection_highlight_rect::selection_highlight_rect,
+    theme::current_theme,
 };
 
+/// How long the cursor stays visible, then hidden, while blinking.
+const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
+
 /// Definition of a rectangular selection highlight
 #[derive(Clone, Debug)]
 /// Defines a rectangular region for text selection highlighting.
@@ -71,18 +82,28 @@ pub enum ClickType {
 /// ```
 /// use std::sync::Arc;
 /// use parking_lot::RwLock;
-/// use tessera_ui::Dp;
+/// use tessera_ui::Sp;
 /// use tessera_ui_basic_components::text_edit_core::{TextEditorState, text_edit_core};
 ///
-/// let state = Arc::new(RwLock::new(TextEditorState::new(Dp(16.0), None)));
+/// let state = Arc::new(RwLock::new(TextEditorState::new(Sp(16.0), None)));
 /// // Use `text_edit_core(state.clone())` inside your component tree.
 /// ```
 pub struct TextEditorState {
-    line_height: Px,
+    /// Font size, converted to pixels whenever the scale factor or the font scale changes
+    font_size: Sp,
+    /// Line height, converted to pixels whenever the scale factor or the font scale changes
+    line_height: Sp,
+    /// The scale factor and the font scale the editor metrics were last computed with
+    scales: (f64, f64),
     pub(crate) editor: glyphon::Editor<'static>,
-    bink_timer: Instant,
+    /// Whether the blinking cursor is currently in its visible phase
+    cursor_visible: Arc<AtomicBool>,
+    /// Bumped whenever the blink restarts, which restarts the blink interval
+    cursor_blink_generation: u64,
     focus_handler: Focus,
     pub(crate) selection_color: Color,
+    pub(crate) text_color: Color,
+    pub(crate) cursor_color: Color,
     pub(crate) current_selection_rects: Vec<RectDef>,
     // Click tracking for double/triple click detection
     last_click_time: Option<Instant>,
@@ -98,41 +119,46 @@ impl TextEditorState {
     ///
     /// # Arguments
     ///
-    /// * `size` - Font size in Dp.
-    /// * `line_height` - Optional line height in Dp. If `None`, uses 1.2x the font size.
+    /// * `size` - Font size in Sp.
+    /// * `line_height` - Optional line height in Sp. If `None`, uses 1.2x the font size.
     ///
     /// # Example
     /// ```
-    /// use tessera_ui::Dp;
+    /// use tessera_ui::Sp;
     /// use tessera_ui_basic_components::text_edit_core::TextEditorState;
-    /// let state = TextEditorState::new(Dp(16.0), None);
+    /// let state = TextEditorState::new(Sp(16.0), None);
     /// ```
-    pub fn new(size: Dp, line_height: Option<Dp>) -> Self {
-        Self::with_selection_color(size, line_height, Color::new(0.5, 0.7, 1.0, 0.4))
+    pub fn new(size: Sp, line_height: Option<Sp>) -> Self {
+        let selection_color = current_theme().color_scheme.primary.with_alpha(0.4);
+        Self::with_selection_color(size, line_height, selection_color)
     }
 
     /// Creates a new `TextEditorState` with a custom selection highlight color.
     ///
     /// # Arguments
     ///
-    /// * `size` - Font size in Dp.
-    /// * `line_height` - Optional line height in Dp.
+    /// * `size` - Font size in Sp.
+    /// * `line_height` - Optional line height in Sp.
     /// * `selection_color` - Color used for selection highlight.
-    pub fn with_selection_color(size: Dp, line_height: Option<Dp>, selection_color: Color) -> Self {
-        let final_line_height = line_height.unwrap_or(Dp(size.0 * 1.2));
-        let line_height_px: Px = final_line_height.into();
+    pub fn with_selection_color(size: Sp, line_height: Option<Sp>, selection_color: Color) -> Self {
+        let final_line_height = line_height.unwrap_or(Sp(size.0 * 1.2));
         let mut buffer = glyphon::Buffer::new(
             &mut write_font_system(),
-            glyphon::Metrics::new(size.to_pixels_f32(), line_height_px.to_f32()),
+            Self::metrics(size, final_line_height),
         );
         buffer.set_wrap(&mut write_font_system(), glyphon::Wrap::Glyph);
         let editor = glyphon::Editor::new(buffer);
         Self {
-            line_height: line_height_px,
+            font_size: size,
+            line_height: final_line_height,
+            scales: Self::scales(),
             editor,
-            bink_timer: Instant::now(),
+            cursor_visible: Arc::new(AtomicBool::new(true)),
+            cursor_blink_generation: 0,
             focus_handler: Focus::new(),
             selection_color,
+            text_color: current_theme().color_scheme.on_surface,
+            cursor_color: current_theme().color_scheme.primary,
             current_selection_rects: Vec::new(),
             last_click_time: None,
             last_click_position: None,
@@ -144,7 +170,18 @@ impl TextEditorState {
 
     /// Returns the line height in pixels.
     pub fn line_height(&self) -> Px {
-        self.line_height
+        self.line_height.into()
+    }
+
+    /// Converts the font size and line height to glyphon metrics at the current scale factor
+    /// and font scale.
+    fn metrics(font_size: Sp, line_height: Sp) -> glyphon::Metrics {
+        glyphon::Metrics::new(font_size.to_pixels_f32(), line_height.to_px().to_f32())
+    }
+
+    /// Returns the current scale factor and font scale.
+    fn scales() -> (f64, f64) {
+        (TesseraRuntime::scale_factor(), TesseraRuntime::font_scale())
     }
 
     /// Returns the current text buffer as `TextData`, applying the given layout constraints.
@@ -153,6 +190,16 @@ impl TextEditorState {
     ///
     /// * `constraint` - Layout constraints for text rendering.
     pub fn text_data(&mut self, constraint: TextConstraint) -> TextData {
+        // Re-shape the text at the new size if the window moved to another monitor, or
+        // the zoom or the font scale changed
+        let scales = Self::scales();
+        if scales != self.scales {
+            self.scales = scales;
+            let metrics = Self::metrics(self.font_size, self.line_height);
+            self.editor.with_buffer_mut(|buffer| {
+                buffer.set_metrics(&mut write_font_system(), metrics);
+            });
+        }
         self.editor.with_buffer_mut(|buffer| {
             buffer.set_size(
                 &mut write_font_system(),
@@ -168,7 +215,7 @@ impl TextEditorState {
             glyphon::cosmic_text::BufferRef::Arc(buffer) => (**buffer).clone(),
         };
 
-        TextData::from_buffer(text_buffer)
+        TextData::from_buffer(text_buffer).with_default_color(self.text_color)
     }
 
     /// Returns a reference to the internal focus handler.
@@ -186,19 +233,35 @@ impl TextEditorState {
         &self.editor
     }
 
+    /// Returns the current content of the editor, with lines joined by `\n`.
+    pub fn text(&self) -> String {
+        self.editor.with_buffer(|buffer| {
+            buffer
+                .lines
+                .iter()
+                .map(|line| line.text())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
     /// Returns a mutable reference to the underlying `glyphon::Editor`.
     pub fn editor_mut(&mut self) -> &mut glyphon::Editor<'static> {
         &mut self.editor
     }
 
-    /// Returns the current blink timer instant (for cursor blinking).
-    pub fn bink_timer(&self) -> Instant {
-        self.bink_timer
+    /// Returns whether the blinking cursor is currently in its visible phase.
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible.load(atomic::Ordering::Relaxed)
     }
 
-    /// Resets the blink timer to the current instant.
-    pub fn update_bink_timer(&mut self) {
-        self.bink_timer = Instant::now();
+    /// Shows the cursor and restarts its blink cycle.
+    ///
+    /// Call this after the cursor moved or the text changed, so the cursor stays visible
+    /// while the user is typing.
+    pub fn reset_cursor_blink(&mut self) {
+        self.cursor_visible.store(true, atomic::Ordering::Relaxed);
+        self.cursor_blink_generation += 1;
     }
 
     /// Returns the current selection highlight color.
@@ -220,6 +283,24 @@ impl TextEditorState {
         self.selection_color = color;
     }
 
+    /// Sets the text color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The new text color.
+    pub fn set_text_color(&mut self, color: Color) {
+        self.text_color = color;
+    }
+
+    /// Sets the cursor color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The new cursor color.
+    pub fn set_cursor_color(&mut self, color: Color) {
+        self.cursor_color = color;
+    }
+
     /// Handles a mouse click event and determines the click type (single, double, triple).
     ///
     /// Used for text selection and word/line selection logic.
@@ -234,7 +315,7 @@ impl TextEditorState {
     /// The detected [`ClickType`].
     pub fn handle_click(&mut self, position: PxPosition, timestamp: Instant) -> ClickType {
         const DOUBLE_CLICK_TIME_MS: u128 = 500; // 500ms for double click
-        const CLICK_DISTANCE_THRESHOLD: Px = Px(5); // 5 pixels tolerance for position
+        const CLICK_DISTANCE_THRESHOLD: Px = Px(5.0); // 5 pixels tolerance for position
 
         let click_type = if let (Some(last_time), Some(last_pos)) =
             (self.last_click_time, self.last_click_position)
@@ -263,6 +344,7 @@ impl TextEditorState {
 
         self.last_click_time = Some(timestamp);
         self.last_click_position = Some(position);
+        self.reset_cursor_blink();
         self.is_dragging = false;
 
         click_type
@@ -313,10 +395,10 @@ impl TextEditorState {
 /// ```
 /// use std::sync::Arc;
 /// use parking_lot::RwLock;
-/// use tessera_ui::Dp;
+/// use tessera_ui::Sp;
 /// use tessera_ui_basic_components::text_edit_core::{TextEditorState, text_edit_core};
 ///
-/// let state = Arc::new(RwLock::new(TextEditorState::new(Dp(16.0), None)));
+/// let state = Arc::new(RwLock::new(TextEditorState::new(Sp(16.0), None)));
 /// text_edit_core(state.clone());
 /// ```
 pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
@@ -329,6 +411,7 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                 DimensionValue::Fixed(w) => Some(w),
                 DimensionValue::Wrap { max, .. } => max,
                 DimensionValue::Fill { max, .. } => max,
+                DimensionValue::Fraction(_) => None,
             };
 
             // For proper scrolling behavior, we need to respect height constraints
@@ -337,6 +420,7 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                 DimensionValue::Fixed(h) => Some(h), // Respect explicit fixed heights
                 DimensionValue::Wrap { max, .. } => max, // Respect max height for wrapping
                 DimensionValue::Fill { max, .. } => max,
+                DimensionValue::Fraction(_) => None,
             };
 
             let text_data = state_clone.write().text_data(TextConstraint {
@@ -352,8 +436,8 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                     for run in buffer.layout_runs() {
                         let line_i = run.line_i;
                         let _line_y = run.line_y; // Px
-                        let line_top = Px(run.line_top as i32); // Px
-                        let line_height = Px(run.line_height as i32); // Px
+                        let line_top = Px(run.line_top); // Px
+                        let line_height = Px(run.line_height); // Px
 
                         // Highlight selection
                         if line_i >= start.line && line_i <= end.line {
@@ -362,8 +446,8 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                                 // Guess x offset based on characters
                                 let cluster = &run.text[glyph.start..glyph.end];
                                 let total = cluster.grapheme_indices(true).count();
-                                let mut c_x = Px(glyph.x as i32);
-                                let c_w = Px((glyph.w / total as f32) as i32);
+                                let mut c_x = Px(glyph.x);
+                                let c_w = Px(glyph.w / total as f32);
                                 for (i, c) in cluster.grapheme_indices(true) {
                                     let c_start = glyph.start + i;
                                     let c_end = glyph.start + i + c.len();
@@ -383,7 +467,7 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                                         selection_rects.push(RectDef {
                                             x: min_val,
                                             y: line_top,
-                                            width: (max_val - min_val).max(Px(0)),
+                                            width: (max_val - min_val).max(Px(0.0)),
                                             height: line_height,
                                         });
                                     }
@@ -393,8 +477,7 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
 
                             if run.glyphs.is_empty() && end.line > line_i {
                                 // Highlight all of internal empty lines
-                                range_opt =
-                                    Some((Px(0), buffer.size().0.map_or(Px(0), |w| Px(w as i32))));
+                                range_opt = Some((Px(0.0), buffer.size().0.map_or(Px(0.0), Px)));
                             }
 
                             if let Some((mut min_val, mut max_val)) = range_opt.take() {
@@ -402,15 +485,15 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                                 if end.line > line_i {
                                     // Draw to end of line
                                     if run.rtl {
-                                        min_val = Px(0);
+                                        min_val = Px(0.0);
                                     } else {
-                                        max_val = buffer.size().0.map_or(Px(0), |w| Px(w as i32));
+                                        max_val = buffer.size().0.map_or(Px(0.0), Px);
                                     }
                                 }
                                 selection_rects.push(RectDef {
                                     x: min_val,
                                     y: line_top,
-                                    width: (max_val - min_val).max(Px(0)),
+                                    width: (max_val - min_val).max(Px(0.0)),
                                     height: line_height,
                                 });
                             }
@@ -432,10 +515,10 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
 
             // --- Filter and clip selection rects to visible area ---
             // Only show highlight rects that are (partially) within the visible area
-            let visible_x0 = Px(0);
-            let visible_y0 = Px(0);
-            let visible_x1 = max_width_pixels.unwrap_or(Px(i32::MAX));
-            let visible_y1 = max_height_pixels.unwrap_or(Px(i32::MAX));
+            let visible_x0 = Px(0.0);
+            let visible_y0 = Px(0.0);
+            let visible_x1 = max_width_pixels.unwrap_or(Px::MAX);
+            let visible_y1 = max_height_pixels.unwrap_or(Px::MAX);
             selection_rects = selection_rects
                 .into_iter()
                 .filter_map(|mut rect| {
@@ -456,8 +539,8 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                         let new_y1 = rect_y1.min(visible_y1);
                         rect.x = new_x;
                         rect.y = new_y;
-                        rect.width = (new_x1 - new_x).max(Px(0));
-                        rect.height = (new_y1 - new_y).max(Px(0));
+                        rect.width = (new_x1 - new_x).max(Px(0.0));
+                        rect.height = (new_y1 - new_y).max(Px(0.0));
                         Some(rect)
                     }
                 })
@@ -467,7 +550,8 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
 
             // Handle cursor positioning (cursor comes after selection rects)
             if let Some(cursor_pos_raw) = state_clone.read().editor.cursor_position() {
-                let cursor_pos = PxPosition::new(Px(cursor_pos_raw.0), Px(cursor_pos_raw.1));
+                let cursor_pos =
+                    PxPosition::new(Px::new(cursor_pos_raw.0), Px::new(cursor_pos_raw.1));
                 let cursor_node_index = selection_rects_len;
                 if let Some(cursor_node_id) = input.children_ids.get(cursor_node_index).copied() {
                     let _ = input.measure_child(cursor_node_id, input.parent_constraint);
@@ -475,6 +559,7 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
                 }
             }
 
+            input.set_alignment_lines(text_data.alignment_lines());
             let drawable = TextCommand {
                 data: text_data.clone(),
             };
@@ -508,7 +593,32 @@ pub fn text_edit_core(state: Arc<RwLock<TextEditorState>>) {
 
     // Cursor rendering (only when focused)
     if state.read().focus_handler().is_focused() {
-        cursor::cursor(state.read().line_height(), state.read().bink_timer());
+        let (visible, line_height, color) = {
+            let guard = state.read();
+            // Keyed by the blink generation, so resetting the blink restarts the interval
+            // and unfocusing the editor cancels it
+            let cursor_visible = guard.cursor_visible.clone();
+            set_interval(
+                ("text_edit_core_cursor_blink", guard.cursor_blink_generation),
+                CURSOR_BLINK_INTERVAL,
+                move || {
+                    cursor_visible.fetch_xor(true, atomic::Ordering::Relaxed);
+                },
+            );
+            (
+                guard.cursor_visible(),
+                guard.line_height(),
+                guard.cursor_color,
+            )
+        };
+        if visible {
+            cursor::cursor(line_height, color);
+        }
+    } else {
+        state
+            .read()
+            .cursor_visible
+            .store(true, atomic::Ordering::Relaxed);
     }
 }
 
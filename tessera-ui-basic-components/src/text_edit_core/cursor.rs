@@ -1,10 +1,9 @@
 //! Text cursor component for the text edit core system.
 //!
-//! This module provides a blinking cursor component used within text editing interfaces.
-//! The cursor provides visual feedback for text insertion point and blinks at regular
-//! intervals to maintain user attention.
-
-use std::time::Instant;
+//! This module provides the cursor component used within text editing interfaces.
+//! The cursor provides visual feedback for text insertion point. Blinking is driven by
+//! [`TextEditorState`](super::TextEditorState), which only builds the cursor during the
+//! visible phase of the blink cycle.
 
 use tessera_ui::{Color, ComputedData, Dp, Px};
 use tessera_ui_macros::tessera;
@@ -14,53 +13,45 @@ use crate::pipelines::ShapeCommand;
 /// Width of the text cursor in device-independent pixels.
 const CURSOR_WIDRH: Dp = Dp(2.5);
 
-/// A blinking cursor component for text editing interfaces.
+/// A cursor component for text editing interfaces.
 ///
-/// This component renders a vertical line cursor that blinks on and off at regular
-/// intervals to indicate the text insertion point. The cursor automatically handles
-/// its own blinking animation based on the provided timer.
+/// This component renders a vertical line cursor to indicate the text insertion point.
 ///
 /// # Parameters
 ///
 /// * `height_px` - The height of the cursor in pixels, typically matching the line height
-/// * `bink_timer` - Timer used to control the blinking animation cycle
+/// * `color` - The color of the cursor
 ///
 /// # Blinking Behavior
 ///
-/// The cursor follows a 1-second blinking cycle:
+/// The cursor follows a 1-second blinking cycle driven by a
+/// [`set_interval`](tessera_ui::timer::set_interval) timer in `text_edit_core`:
 /// - Visible for 500ms
 /// - Hidden for 500ms
-/// - Repeats continuously
+/// - Restarts in the visible phase whenever the user clicks or edits
 ///
 /// # Example
 ///
 /// ```rust,ignore
-/// use std::time::Instant;
-/// use tessera_ui::Px;
+/// use tessera_ui::{Color, Px};
 ///
-/// // Create a cursor with line height and current time
-/// cursor(Px(20.0), Instant::now());
+/// // Create a cursor with the line height
+/// cursor(Px(20.0), Color::BLACK);
 /// ```
 ///
 /// # Rendering
 ///
-/// The cursor is rendered as a solid black rectangle with:
+/// The cursor is rendered as a solid rectangle with:
 /// - Fixed width of 2.5 device-independent pixels
 /// - Variable height matching the text line height
 /// - No corner radius (sharp rectangular appearance)
 /// - No shadow effects
 #[tessera]
-pub(super) fn cursor(height_px: Px, bink_timer: Instant) {
-    // Skip rendering the cursor during the "off" phase of the blink cycle
-    // to create the blinking effect (visible for 500ms, hidden for 500ms)
-    if bink_timer.elapsed().as_millis() % 1000 < 500 {
-        return;
-    }
-
+pub(super) fn cursor(height_px: Px, color: Color) {
     measure(Box::new(move |input| {
         // Create a rectangular cursor shape with fixed width and variable height
         let drawable = ShapeCommand::Rect {
-            color: Color::BLACK,
+            color,
             corner_radius: 0.0,
             g2_k_value: 3.0, // Use G2-like corners
             shadow: None,
@@ -23,7 +23,9 @@ use derive_builder::Builder;
 use glyphon::{Action, Edit};
 use parking_lot::RwLock;
 use tessera_ui::{
-    Color, CursorEventContent, DimensionValue, Dp, ImeRequest, Px, PxPosition, winit,
+    Color, CursorEventContent, DimensionValue, Dp, ImeRequest, Px, PxPosition,
+    accessibility::{self, ActionData, Role, Semantics},
+    winit,
 };
 use tessera_ui_macros::tessera;
 
@@ -33,6 +35,7 @@ use crate::{
     shape_def::Shape,
     surface::{SurfaceArgsBuilder, surface},
     text_edit_core::{ClickType, map_key_event_to_action, text_edit_core},
+    theme::current_theme,
 };
 
 /// State structure for the text editor, managing text content, cursor, selection, and editing logic.
@@ -44,8 +47,8 @@ use crate::{
 /// # Example
 /// ```
 /// use tessera_ui_basic_components::text_editor::TextEditorState;
-/// use tessera_ui::Dp;
-/// let state = TextEditorState::new(Dp(14.0), None);
+/// use tessera_ui::Sp;
+/// let state = TextEditorState::new(Sp(14.0), None);
 /// ```
 pub use crate::text_edit_core::TextEditorState;
 
@@ -54,26 +57,26 @@ pub use crate::text_edit_core::TextEditorState;
 /// # Example
 /// ```
 /// use tessera_ui_basic_components::text_editor::{TextEditorArgs, TextEditorArgsBuilder, TextEditorState};
-/// use tessera_ui::{Dp, DimensionValue, Px};
+/// use tessera_ui::{DimensionValue, Px, Sp};
 /// use std::sync::Arc;
 /// use parking_lot::RwLock;
 ///
 /// // Create a text editor with a fixed width and height.
 /// let editor_args_fixed = TextEditorArgsBuilder::default()
-///     .width(Some(DimensionValue::Fixed(Px(200)))) // pixels
-///     .height(Some(DimensionValue::Fixed(Px(100)))) // pixels
+///     .width(Some(DimensionValue::Fixed(Px(200.0)))) // pixels
+///     .height(Some(DimensionValue::Fixed(Px(100.0)))) // pixels
 ///     .build()
 ///     .unwrap();
 ///
 /// // Create a text editor that fills available width up to 500px, with a min width of 50px
 /// let editor_args_fill_wrap = TextEditorArgsBuilder::default()
-///     .width(Some(DimensionValue::Fill { min: Some(Px(50)), max: Some(Px(500)) })) // pixels
+///     .width(Some(DimensionValue::Fill { min: Some(Px(50.0)), max: Some(Px(500.0)) })) // pixels
 ///     .height(Some(DimensionValue::Wrap { min: None, max: None }))
 ///     .build()
 ///     .unwrap();
 ///
 /// // Create the editor state
-/// let editor_state = Arc::new(RwLock::new(TextEditorState::new(Dp(10.0), None)));
+/// let editor_state = Arc::new(RwLock::new(TextEditorState::new(Sp(10.0), None)));
 ///
 /// // text_editor(editor_args_fixed, editor_state.clone());
 /// // text_editor(editor_args_fill_wrap, editor_state.clone());
@@ -93,15 +96,17 @@ pub use crate::text_edit_core::TextEditorState;
 /// - `shape`: Shape of the editor container (e.g., rounded rectangle).
 /// - `padding`: Inner padding (Dp).
 /// - `selection_color`: Highlight color for selected text.
+/// - `text_color`, `cursor_color`: Colors of the text and of the cursor.
 ///
+/// Colors left unset are taken from the current [theme](crate::theme).
 /// # Example
 /// ```
 /// use tessera_ui_basic_components::text_editor::{TextEditorArgs, TextEditorArgsBuilder};
 /// use tessera_ui::{Dp, DimensionValue, Px};
 ///
 /// let args = TextEditorArgsBuilder::default()
-///     .width(Some(DimensionValue::Fixed(Px(300))))
-///     .height(Some(DimensionValue::Fill { min: Some(Px(50)), max: Some(Px(500)) }))
+///     .width(Some(DimensionValue::Fixed(Px(300.0))))
+///     .height(Some(DimensionValue::Fill { min: Some(Px(50.0)), max: Some(Px(500.0)) }))
 ///     .background_color(Some(tessera_ui::Color::WHITE))
 ///     .padding(Dp(8.0))
 ///     .build()
@@ -120,30 +125,41 @@ pub struct TextEditorArgs {
     /// Minimum height in density-independent pixels. Defaults to line height + padding if not specified.
     #[builder(default = "None")]
     pub min_height: Option<Dp>,
-    /// Background color of the text editor (RGBA). Defaults to light gray.
+    /// Background color of the text editor (RGBA). Defaults to the theme's
+    /// `surface_container_highest` color.
     #[builder(default = "None")]
     pub background_color: Option<Color>,
     /// Border width in pixels. Defaults to 1.0.
     #[builder(default = "1.0")]
     pub border_width: f32,
-    /// Border color (RGBA). Defaults to gray.
+    /// Border color (RGBA). Defaults to the theme's outline color.
     #[builder(default = "None")]
     pub border_color: Option<Color>,
-    /// The shape of the text editor container.
-    #[builder(default = "Shape::RoundedRectangle { corner_radius: 4.0, g2_k_value: 3.0 }")]
+    /// The shape of the text editor container. Defaults to the theme's extra small corners.
+    #[builder(default = "current_theme().shapes.extra_small")]
     pub shape: Shape,
     /// Padding inside the text editor. Defaults to 5.0 Dp.
     #[builder(default = "Dp(5.0)")]
     pub padding: Dp,
-    /// Border color when focused (RGBA). Defaults to blue.
+    /// Border color when focused (RGBA). Defaults to the theme's primary color.
     #[builder(default = "None")]
     pub focus_border_color: Option<Color>,
-    /// Background color when focused (RGBA). Defaults to white.
+    /// Background color when focused (RGBA). Defaults to the theme's surface color.
     #[builder(default = "None")]
     pub focus_background_color: Option<Color>,
-    /// Color for text selection highlight (RGBA). Defaults to light blue with transparency.
-    #[builder(default = "Some(Color::new(0.5, 0.7, 1.0, 0.4))")]
+    /// Color for text selection highlight (RGBA). Defaults to the theme's primary color with
+    /// transparency.
+    #[builder(default = "Some(current_theme().color_scheme.primary.with_alpha(0.4))")]
     pub selection_color: Option<Color>,
+    /// Color of the text (RGBA). Defaults to the theme's `on_surface` color.
+    #[builder(default = "None")]
+    pub text_color: Option<Color>,
+    /// Color of the cursor (RGBA). Defaults to the theme's primary color.
+    #[builder(default = "None")]
+    pub cursor_color: Option<Color>,
+    /// An optional label announced by screen readers.
+    #[builder(default = "None")]
+    pub accessibility_label: Option<String>,
 }
 
 /// A text editor component with two-layer architecture:
@@ -156,17 +172,17 @@ pub struct TextEditorArgs {
 ///
 /// ```
 /// use tessera_ui_basic_components::text_editor::{text_editor, TextEditorArgs, TextEditorArgsBuilder, TextEditorState};
-/// use tessera_ui::{Dp, DimensionValue, Px};
+/// use tessera_ui::{DimensionValue, Px, Sp};
 /// use std::sync::Arc;
 /// use parking_lot::RwLock;
 ///
 /// let args = TextEditorArgsBuilder::default()
-///     .width(Some(DimensionValue::Fixed(Px(300))))
-///     .height(Some(DimensionValue::Fill { min: Some(Px(50)), max: Some(Px(500)) }))
+///     .width(Some(DimensionValue::Fixed(Px(300.0))))
+///     .height(Some(DimensionValue::Fill { min: Some(Px(50.0)), max: Some(Px(500.0)) }))
 ///     .build()
 ///     .unwrap();
 ///
-/// let state = Arc::new(RwLock::new(TextEditorState::new(Dp(12.0), None)));
+/// let state = Arc::new(RwLock::new(TextEditorState::new(Sp(12.0), None)));
 /// // text_editor(args, state);
 /// ```
 /// Multi-line text editor component with full state management, cursor, selection, and IME support.
@@ -190,28 +206,69 @@ pub struct TextEditorArgs {
 /// # Example
 /// ```
 /// use tessera_ui_basic_components::text_editor::{text_editor, TextEditorArgs, TextEditorArgsBuilder, TextEditorState};
-/// use tessera_ui::{Dp, DimensionValue, Px};
+/// use tessera_ui::{DimensionValue, Px, Sp};
 /// use std::sync::Arc;
 /// use parking_lot::RwLock;
 ///
 /// let args = TextEditorArgsBuilder::default()
-///     .width(Some(DimensionValue::Fixed(Px(300))))
-///     .height(Some(DimensionValue::Fill { min: Some(Px(50)), max: Some(Px(500)) }))
+///     .width(Some(DimensionValue::Fixed(Px(300.0))))
+///     .height(Some(DimensionValue::Fill { min: Some(Px(50.0)), max: Some(Px(500.0)) }))
 ///     .build()
 ///     .unwrap();
 ///
-/// let state = Arc::new(RwLock::new(TextEditorState::new(Dp(12.0), None)));
+/// let state = Arc::new(RwLock::new(TextEditorState::new(Sp(12.0), None)));
 /// text_editor(args, state);
 /// ```
 #[tessera]
 pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditorState>>) {
     let editor_args: TextEditorArgs = args.into();
 
-    // Update the state with the selection color from args
-    if let Some(selection_color) = editor_args.selection_color {
-        state.write().set_selection_color(selection_color);
+    // Update the state with the colors from args
+    {
+        let scheme = current_theme().color_scheme;
+        let mut state = state.write();
+        if let Some(selection_color) = editor_args.selection_color {
+            state.set_selection_color(selection_color);
+        }
+        state.set_text_color(editor_args.text_color.unwrap_or(scheme.on_surface));
+        state.set_cursor_color(editor_args.cursor_color.unwrap_or(scheme.primary));
     }
 
+    // Publish the content and focus state to assistive technology
+    semantics(|| {
+        let (text, focused) = {
+            let state = state.read();
+            (state.text(), state.focus_handler().is_focused())
+        };
+        let state_for_action = state.clone();
+        let mut editor_semantics = Semantics::new(Role::MultilineTextInput)
+            .with_value(text)
+            .with_focusable(true)
+            .with_focused(focused)
+            .with_action(accessibility::Action::ReplaceSelectedText)
+            .on_action(move |action, data| {
+                let mut state = state_for_action.write();
+                match (action, data) {
+                    (accessibility::Action::Focus, _) => {
+                        state.focus_handler_mut().request_focus();
+                    }
+                    (accessibility::Action::ReplaceSelectedText, Some(ActionData::Value(text))) => {
+                        for c in text.chars() {
+                            state
+                                .editor_mut()
+                                .action(&mut write_font_system(), Action::Insert(c));
+                        }
+                        state.reset_cursor_blink();
+                    }
+                    _ => {}
+                }
+            });
+        if let Some(label) = &editor_args.accessibility_label {
+            editor_semantics = editor_semantics.with_label(label.clone());
+        }
+        editor_semantics
+    });
+
     // surface layer - provides visual container and minimum size guarantee
     {
         let state_for_surface = state.clone();
@@ -270,13 +327,13 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                     if let Some(cursor_pos) = cursor_pos_option {
                         // Calculate the relative position within the text area
                         let padding_px: Px = editor_args.padding.into();
-                        let border_width_px = Px(editor_args.border_width as i32); // Assuming border_width is integer pixels
+                        let border_width_px = Px(editor_args.border_width);
 
                         let text_relative_x_px = cursor_pos.x - padding_px - border_width_px;
                         let text_relative_y_px = cursor_pos.y - padding_px - border_width_px;
 
                         // Only process if the click is within the text area (non-negative relative coords)
-                        if text_relative_x_px >= Px(0) && text_relative_y_px >= Px(0) {
+                        if text_relative_x_px >= Px(0.0) && text_relative_y_px >= Px(0.0) {
                             let text_relative_pos =
                                 PxPosition::new(text_relative_x_px, text_relative_y_px);
                             // Determine click type and handle accordingly
@@ -290,8 +347,8 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                                     state_for_handler.write().editor_mut().action(
                                         &mut write_font_system(),
                                         Action::Click {
-                                            x: text_relative_pos.x.0,
-                                            y: text_relative_pos.y.0,
+                                            x: text_relative_pos.x.to_i32(),
+                                            y: text_relative_pos.y.to_i32(),
                                         },
                                     );
                                 }
@@ -300,8 +357,8 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                                     state_for_handler.write().editor_mut().action(
                                         &mut write_font_system(),
                                         Action::DoubleClick {
-                                            x: text_relative_pos.x.0,
-                                            y: text_relative_pos.y.0,
+                                            x: text_relative_pos.x.to_i32(),
+                                            y: text_relative_pos.y.to_i32(),
                                         },
                                     );
                                 }
@@ -310,8 +367,8 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                                     state_for_handler.write().editor_mut().action(
                                         &mut write_font_system(),
                                         Action::TripleClick {
-                                            x: text_relative_pos.x.0,
-                                            y: text_relative_pos.y.0,
+                                            x: text_relative_pos.x.to_i32(),
+                                            y: text_relative_pos.y.to_i32(),
                                         },
                                     );
                                 }
@@ -329,12 +386,12 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                     && let Some(cursor_pos) = cursor_pos_option
                 {
                     let padding_px: Px = editor_args.padding.into();
-                    let border_width_px = Px(editor_args.border_width as i32);
+                    let border_width_px = Px(editor_args.border_width);
 
                     let text_relative_x_px = cursor_pos.x - padding_px - border_width_px;
                     let text_relative_y_px = cursor_pos.y - padding_px - border_width_px;
 
-                    if text_relative_x_px >= Px(0) && text_relative_y_px >= Px(0) {
+                    if text_relative_x_px >= Px(0.0) && text_relative_y_px >= Px(0.0) {
                         let current_pos_px =
                             PxPosition::new(text_relative_x_px, text_relative_y_px);
                         let last_pos_px = state_for_handler.read().last_click_position();
@@ -344,8 +401,8 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                             state_for_handler.write().editor_mut().action(
                                 &mut write_font_system(),
                                 Action::Drag {
-                                    x: current_pos_px.x.0,
-                                    y: current_pos_px.y.0,
+                                    x: current_pos_px.x.to_i32(),
+                                    y: current_pos_px.y.to_i32(),
                                 },
                             );
 
@@ -429,6 +486,7 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                             &mut write_font_system(),
                             glyphon::Action::Motion(glyphon::cosmic_text::Motion::BufferEnd),
                         );
+                        state.reset_cursor_blink();
                     } else {
                         // Original logic for other keys
                         let mut all_actions = Vec::new();
@@ -451,6 +509,7 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
                             for action in all_actions {
                                 state.editor_mut().action(&mut write_font_system(), action);
                             }
+                            state.reset_cursor_blink();
                         }
                     }
                     // Block all keyboard events to prevent propagation
@@ -463,6 +522,7 @@ pub fn text_editor(args: impl Into<TextEditorArgs>, state: Arc<RwLock<TextEditor
 
                     for event in ime_events {
                         let mut state = state_for_handler.write();
+                        state.reset_cursor_blink();
                         match event {
                             winit::event::Ime::Commit(text) => {
                                 // Clear preedit string if it exists
@@ -526,7 +586,7 @@ fn create_surface_args(
     } else {
         // Use default with minimum
         builder = builder.width(DimensionValue::Wrap {
-            min: args.min_width.map(|dp| dp.into()).or(Some(Px(120))), // Default minimum width 120px
+            min: args.min_width.map(|dp| dp.into()).or(Some(Px(120.0))), // Default minimum width 120px
             max: None,
         });
     }
@@ -541,7 +601,7 @@ fn create_surface_args(
         let min_height_px = args
             .min_height
             .map(|dp| dp.into())
-            .unwrap_or(line_height_px + padding_px * 2 + Px(10)); // +10 for comfortable spacing
+            .unwrap_or(line_height_px + padding_px * 2 + Px(10.0)); // +10 for comfortable spacing
         builder = builder.height(DimensionValue::Wrap {
             min: Some(min_height_px),
             max: None,
@@ -563,13 +623,14 @@ fn determine_background_color(
     args: &TextEditorArgs,
     state: &Arc<RwLock<TextEditorState>>,
 ) -> Color {
+    let scheme = current_theme().color_scheme;
     if state.read().focus_handler().is_focused() {
         args.focus_background_color
             .or(args.background_color)
-            .unwrap_or(Color::WHITE) // Default white when focused
+            .unwrap_or(scheme.surface)
     } else {
         args.background_color
-            .unwrap_or(Color::new(0.95, 0.95, 0.95, 1.0)) // Default light gray when not focused
+            .unwrap_or(scheme.surface_container_highest)
     }
 }
 
@@ -583,12 +644,13 @@ fn determine_border_color(
     args: &TextEditorArgs,
     state: &Arc<RwLock<TextEditorState>>,
 ) -> Option<Color> {
+    let scheme = current_theme().color_scheme;
     if state.read().focus_handler().is_focused() {
         args.focus_border_color
             .or(args.border_color)
-            .or(Some(Color::new(0.0, 0.5, 1.0, 1.0))) // Default blue focus border
+            .or(Some(scheme.primary))
     } else {
-        args.border_color.or(Some(Color::new(0.7, 0.7, 0.7, 1.0))) // Default gray border
+        args.border_color.or(Some(scheme.outline))
     }
 }
 
@@ -597,8 +659,8 @@ impl TextEditorArgs {
     /// Creates a simple text editor with default styling.
     ///
     /// - Minimum width: 120dp
-    /// - Background: white
-    /// - Border: 1px gray, rounded rectangle
+    /// - Background: the theme's surface color
+    /// - Border: 1px in the theme's outline color, extra small corners
     ///
     /// # Example
     /// ```
@@ -606,22 +668,20 @@ impl TextEditorArgs {
     /// let args = TextEditorArgs::simple();
     /// ```
     pub fn simple() -> Self {
+        let theme = current_theme();
         TextEditorArgsBuilder::default()
             .min_width(Some(Dp(120.0)))
-            .background_color(Some(Color::WHITE))
+            .background_color(Some(theme.color_scheme.surface))
             .border_width(1.0)
-            .border_color(Some(Color::new(0.7, 0.7, 0.7, 1.0)))
-            .shape(Shape::RoundedRectangle {
-                corner_radius: 4.0,
-                g2_k_value: 3.0,
-            })
+            .border_color(Some(theme.color_scheme.outline))
+            .shape(theme.shapes.extra_small)
             .build()
             .unwrap()
     }
 
     /// Creates a text editor with an emphasized border for better visibility.
     ///
-    /// - Border: 2px, blue focus border
+    /// - Border: 2px, focus border in the theme's primary color
     ///
     /// # Example
     /// ```
@@ -631,7 +691,7 @@ impl TextEditorArgs {
     pub fn outlined() -> Self {
         Self::simple()
             .with_border_width(2.0)
-            .with_focus_border_color(Color::new(0.0, 0.5, 1.0, 1.0))
+            .with_focus_border_color(current_theme().color_scheme.primary)
     }
 
     /// Creates a text editor with no border (minimal style).
@@ -646,7 +706,7 @@ impl TextEditorArgs {
     pub fn minimal() -> Self {
         TextEditorArgsBuilder::default()
             .min_width(Some(Dp(120.0)))
-            .background_color(Some(Color::WHITE))
+            .background_color(Some(current_theme().color_scheme.surface))
             .border_width(0.0)
             .shape(Shape::RoundedRectangle {
                 corner_radius: 0.0,
@@ -665,7 +725,7 @@ impl TextEditorArgs {
     /// ```
     /// use tessera_ui_basic_components::text_editor::TextEditorArgs;
     /// use tessera_ui::{DimensionValue, Px};
-    /// let args = TextEditorArgs::simple().with_width(DimensionValue::Fixed(Px(200)));
+    /// let args = TextEditorArgs::simple().with_width(DimensionValue::Fixed(Px(200.0)));
     /// ```
     pub fn with_width(mut self, width: DimensionValue) -> Self {
         self.width = Some(width);
@@ -678,7 +738,7 @@ impl TextEditorArgs {
     /// ```
     /// use tessera_ui_basic_components::text_editor::TextEditorArgs;
     /// use tessera_ui::{DimensionValue, Px};
-    /// let args = TextEditorArgs::simple().with_height(DimensionValue::Fixed(Px(100)));
+    /// let args = TextEditorArgs::simple().with_height(DimensionValue::Fixed(Px(100.0)));
     /// ```
     pub fn with_height(mut self, height: DimensionValue) -> Self {
         self.height = Some(height);
@@ -0,0 +1,261 @@
+//! Theming for the basic components.
+//!
+//! This module defines [`Theme`], the set of design tokens the basic components read their
+//! default colors, text sizes, shapes and shadows from, and the functions to provide it to
+//! a part of the component tree.
+//!
+//! A theme is made of four groups of tokens:
+//!
+//! - [`ColorScheme`]: semantic colors, with [light](ColorScheme::light) and
+//!   [dark](ColorScheme::dark) variants
+//! - [`Typography`]: a type scale of [`TextStyle`]s, from display to label text
+//! - [`Shapes`]: corner tokens, from extra small to extra large
+//! - [`Elevation`]: shadows for the elevation levels 0 to 5
+//!
+//! Next to the theme, containers provide a content color with [`provide_content_color`]:
+//! a [`surface`](crate::surface::surface) makes the text inside of it use the matching
+//! `on_*` color of its background, read with [`current_content_color`].
+//!
+//! # Providing a Theme
+//!
+//! Without a provided theme, components use the default light or dark theme, following the
+//! [theme of the operating system](tessera_ui::TesseraRuntime::theme).
+//!
+//! To use a custom theme, wrap the root of the application in [`provide_theme`]. Every
+//! component built inside of it reads the theme with [`current_theme`]. Calling
+//! `provide_theme` again deeper in the tree overrides the theme for that subtree only:
+//!
+//! ```
+//! use tessera_ui_basic_components::{
+//!     text::text,
+//!     theme::{Theme, current_theme, provide_theme},
+//! };
+//!
+//! provide_theme(Theme::light(), || {
+//!     text("Light text");
+//!
+//!     // A dark card inside the light app
+//!     provide_theme(Theme::dark(), || {
+//!         assert!(current_theme().color_scheme.is_dark);
+//!         text("Dark text");
+//!     });
+//! });
+//! ```
+//!
+//! # Switching Themes
+//!
+//! The component tree is rebuilt every frame, and component arguments pick up their
+//! defaults from the current theme while they are built. Switching the theme is therefore
+//! just a matter of providing another one, for example following the light or dark theme
+//! of the runtime. [`TesseraRuntime::set_theme_preference`] forces one of them:
+//!
+//! [`TesseraRuntime::set_theme_preference`]: tessera_ui::TesseraRuntime::set_theme_preference
+//!
+//! ```
+//! use tessera_ui::{Color, TesseraRuntime, winit::window::Theme as Mode};
+//! use tessera_ui_basic_components::theme::{ColorScheme, Theme, provide_theme};
+//!
+//! fn app() {
+//!     let accent = Color::from_hex("#00897B").unwrap();
+//!     let theme = match TesseraRuntime::theme() {
+//!         Mode::Light => Theme::light(),
+//!         Mode::Dark => Theme::dark(),
+//!     };
+//!     let color_scheme = ColorScheme {
+//!         primary: accent,
+//!         ..theme.color_scheme
+//!     };
+//!     provide_theme(theme.with_color_scheme(color_scheme), || {
+//!         // ... the rest of the application ...
+//!     });
+//! }
+//! # app();
+//! ```
+//!
+//! # Reading the Theme
+//!
+//! Component defaults are resolved when their arguments are built, so arguments should be
+//! built inside the `provide_theme` scope they belong to. The theme is a
+//! [composition local](tessera_ui::context), so [`current_theme`] also returns the theme
+//! of a component inside its measure function and state handler.
+
+mod color_scheme;
+mod content_color;
+mod elevation;
+mod shapes;
+mod typography;
+
+use std::sync::{Arc, LazyLock};
+
+use tessera_ui::{
+    TesseraRuntime,
+    context::{current, provide},
+    winit,
+};
+
+pub use color_scheme::ColorScheme;
+pub use content_color::{current_content_color, provide_content_color};
+pub use elevation::Elevation;
+pub use shapes::Shapes;
+pub use typography::{TextStyle, Typography};
+
+/// The design tokens used by the basic components.
+///
+/// See the [module documentation](self) for how to provide and read a theme.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::Color;
+/// use tessera_ui_basic_components::theme::{ColorScheme, Theme};
+///
+/// // The dark theme with a custom primary color
+/// let theme = Theme::dark().with_color_scheme(ColorScheme {
+///     primary: Color::from_hex("#80CBC4").unwrap(),
+///     ..ColorScheme::dark()
+/// });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    /// The semantic colors.
+    pub color_scheme: ColorScheme,
+    /// The type scale.
+    pub typography: Typography,
+    /// The corner tokens.
+    pub shapes: Shapes,
+    /// The shadows for each elevation level.
+    pub elevation: Elevation,
+}
+
+impl Theme {
+    /// Creates the default light theme.
+    pub fn light() -> Self {
+        Self {
+            color_scheme: ColorScheme::light(),
+            typography: Typography::default(),
+            shapes: Shapes::default(),
+            elevation: Elevation::default(),
+        }
+    }
+
+    /// Creates the default dark theme.
+    pub fn dark() -> Self {
+        Self {
+            color_scheme: ColorScheme::dark(),
+            ..Self::light()
+        }
+    }
+
+    /// Returns this theme with its color scheme replaced.
+    pub fn with_color_scheme(mut self, color_scheme: ColorScheme) -> Self {
+        self.color_scheme = color_scheme;
+        self
+    }
+
+    /// Returns this theme with its type scale replaced.
+    pub fn with_typography(mut self, typography: Typography) -> Self {
+        self.typography = typography;
+        self
+    }
+
+    /// Returns this theme with its corner tokens replaced.
+    pub fn with_shapes(mut self, shapes: Shapes) -> Self {
+        self.shapes = shapes;
+        self
+    }
+
+    /// Returns this theme with its elevation tokens replaced.
+    pub fn with_elevation(mut self, elevation: Elevation) -> Self {
+        self.elevation = elevation;
+        self
+    }
+}
+
+impl Default for Theme {
+    /// Returns the light theme.
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// The theme used outside of any [`provide_theme`] scope when the runtime theme is light.
+static LIGHT_THEME: LazyLock<Arc<Theme>> = LazyLock::new(|| Arc::new(Theme::light()));
+
+/// The theme used outside of any [`provide_theme`] scope when the runtime theme is dark.
+static DARK_THEME: LazyLock<Arc<Theme>> = LazyLock::new(|| Arc::new(Theme::dark()));
+
+/// The composition local holding the theme provided by [`provide_theme`].
+struct ProvidedTheme(Arc<Theme>);
+
+/// Provides `theme` to every component built by `content`.
+///
+/// The theme overrides the one provided by any enclosing `provide_theme` call, for the
+/// duration of `content` only.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui_basic_components::{
+///     text::text,
+///     theme::{Theme, provide_theme},
+/// };
+///
+/// provide_theme(Theme::dark(), || {
+///     text("Hello from the dark side");
+/// });
+/// ```
+pub fn provide_theme(theme: impl Into<Arc<Theme>>, content: impl FnOnce()) {
+    provide(ProvidedTheme(theme.into()), content);
+}
+
+/// Returns the theme provided by the innermost enclosing [`provide_theme`] call.
+///
+/// If there is none, this is the default light or dark theme, following
+/// [`TesseraRuntime::theme`]. Applications that do not provide a theme therefore switch
+/// between light and dark together with the operating system.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::{TesseraRuntime, runtime::ThemePreference};
+/// use tessera_ui_basic_components::theme::{Theme, current_theme};
+///
+/// // No theme was provided
+/// TesseraRuntime::set_theme_preference(ThemePreference::Dark);
+/// assert_eq!(*current_theme(), Theme::dark());
+/// ```
+pub fn current_theme() -> Arc<Theme> {
+    current::<ProvidedTheme>()
+        .map(|theme| theme.0.clone())
+        .unwrap_or_else(|| match TesseraRuntime::theme() {
+            winit::window::Theme::Light => LIGHT_THEME.clone(),
+            winit::window::Theme::Dark => DARK_THEME.clone(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nested_themes() {
+        assert!(!current_theme().color_scheme.is_dark);
+        provide_theme(Theme::dark(), || {
+            assert!(current_theme().color_scheme.is_dark);
+            provide_theme(Theme::light(), || {
+                assert!(!current_theme().color_scheme.is_dark);
+            });
+            assert!(current_theme().color_scheme.is_dark);
+        });
+        assert!(!current_theme().color_scheme.is_dark);
+    }
+
+    #[test]
+    fn test_scope_survives_panic() {
+        let result = std::panic::catch_unwind(|| {
+            provide_theme(Theme::dark(), || panic!("content failed"));
+        });
+        assert!(result.is_err());
+        assert_eq!(*current_theme(), Theme::light());
+    }
+}
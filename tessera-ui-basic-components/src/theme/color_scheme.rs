@@ -0,0 +1,227 @@
+//! Semantic color tokens.
+
+use tessera_ui::Color;
+
+/// The semantic colors of a [`Theme`](super::Theme).
+///
+/// Every `on_*` color is meant for content, such as text and icons, drawn on top of the
+/// color of the same name. The default light and dark schemes follow the Material Design 3
+/// baseline palette.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui_basic_components::theme::ColorScheme;
+///
+/// let scheme = ColorScheme::light();
+/// // Content colors are readable on their containers
+/// assert!(scheme.on_primary.contrast_ratio(&scheme.primary) >= 4.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorScheme {
+    /// Whether this is a dark color scheme, with light content on dark surfaces.
+    pub is_dark: bool,
+    /// The color of prominent components, such as filled buttons and active states.
+    pub primary: Color,
+    /// Content on [`primary`](Self::primary).
+    pub on_primary: Color,
+    /// A less prominent fill for primary content.
+    pub primary_container: Color,
+    /// Content on [`primary_container`](Self::primary_container).
+    pub on_primary_container: Color,
+    /// The color of less prominent components.
+    pub secondary: Color,
+    /// Content on [`secondary`](Self::secondary).
+    pub on_secondary: Color,
+    /// A less prominent fill for secondary content.
+    pub secondary_container: Color,
+    /// Content on [`secondary_container`](Self::secondary_container).
+    pub on_secondary_container: Color,
+    /// A contrasting accent color.
+    pub tertiary: Color,
+    /// Content on [`tertiary`](Self::tertiary).
+    pub on_tertiary: Color,
+    /// A less prominent fill for tertiary content.
+    pub tertiary_container: Color,
+    /// Content on [`tertiary_container`](Self::tertiary_container).
+    pub on_tertiary_container: Color,
+    /// The color of errors and destructive actions.
+    pub error: Color,
+    /// Content on [`error`](Self::error).
+    pub on_error: Color,
+    /// A less prominent fill for error content.
+    pub error_container: Color,
+    /// Content on [`error_container`](Self::error_container).
+    pub on_error_container: Color,
+    /// The color behind all other content.
+    pub background: Color,
+    /// Content on [`background`](Self::background).
+    pub on_background: Color,
+    /// The default color of surfaces.
+    pub surface: Color,
+    /// Content on [`surface`](Self::surface) and on the surface containers.
+    pub on_surface: Color,
+    /// An alternative surface color, for example for inactive tracks.
+    pub surface_variant: Color,
+    /// Less prominent content on surfaces.
+    pub on_surface_variant: Color,
+    /// A surface container with slightly more emphasis than the surface.
+    pub surface_container_low: Color,
+    /// The default color of containers on a surface, such as cards.
+    pub surface_container: Color,
+    /// A surface container with more emphasis.
+    pub surface_container_high: Color,
+    /// The surface container with the most emphasis, for example for text fields.
+    pub surface_container_highest: Color,
+    /// The color of borders and dividers.
+    pub outline: Color,
+    /// A subtle border color, for decorative dividers.
+    pub outline_variant: Color,
+    /// The color of shadows.
+    pub shadow: Color,
+    /// The color of scrims behind modal content. It is applied with transparency.
+    pub scrim: Color,
+    /// A surface color contrasting with [`surface`](Self::surface), for example for
+    /// snackbars.
+    pub inverse_surface: Color,
+    /// Content on [`inverse_surface`](Self::inverse_surface).
+    pub inverse_on_surface: Color,
+    /// The primary color to use on [`inverse_surface`](Self::inverse_surface).
+    pub inverse_primary: Color,
+}
+
+impl ColorScheme {
+    /// Creates the default light color scheme.
+    pub fn light() -> Self {
+        Self {
+            is_dark: false,
+            primary: hex(0x6750A4),
+            on_primary: hex(0xFFFFFF),
+            primary_container: hex(0xEADDFF),
+            on_primary_container: hex(0x21005D),
+            secondary: hex(0x625B71),
+            on_secondary: hex(0xFFFFFF),
+            secondary_container: hex(0xE8DEF8),
+            on_secondary_container: hex(0x1D192B),
+            tertiary: hex(0x7D5260),
+            on_tertiary: hex(0xFFFFFF),
+            tertiary_container: hex(0xFFD8E4),
+            on_tertiary_container: hex(0x31111D),
+            error: hex(0xB3261E),
+            on_error: hex(0xFFFFFF),
+            error_container: hex(0xF9DEDC),
+            on_error_container: hex(0x410E0B),
+            background: hex(0xFEF7FF),
+            on_background: hex(0x1D1B20),
+            surface: hex(0xFEF7FF),
+            on_surface: hex(0x1D1B20),
+            surface_variant: hex(0xE7E0EC),
+            on_surface_variant: hex(0x49454F),
+            surface_container_low: hex(0xF7F2FA),
+            surface_container: hex(0xF3EDF7),
+            surface_container_high: hex(0xECE6F0),
+            surface_container_highest: hex(0xE6E0E9),
+            outline: hex(0x79747E),
+            outline_variant: hex(0xCAC4D0),
+            shadow: hex(0x000000),
+            scrim: hex(0x000000),
+            inverse_surface: hex(0x322F35),
+            inverse_on_surface: hex(0xF5EFF7),
+            inverse_primary: hex(0xD0BCFF),
+        }
+    }
+
+    /// Creates the default dark color scheme.
+    pub fn dark() -> Self {
+        Self {
+            is_dark: true,
+            primary: hex(0xD0BCFF),
+            on_primary: hex(0x381E72),
+            primary_container: hex(0x4F378B),
+            on_primary_container: hex(0xEADDFF),
+            secondary: hex(0xCCC2DC),
+            on_secondary: hex(0x332D41),
+            secondary_container: hex(0x4A4458),
+            on_secondary_container: hex(0xE8DEF8),
+            tertiary: hex(0xEFB8C8),
+            on_tertiary: hex(0x492532),
+            tertiary_container: hex(0x633B48),
+            on_tertiary_container: hex(0xFFD8E4),
+            error: hex(0xF2B8B5),
+            on_error: hex(0x601410),
+            error_container: hex(0x8C1D18),
+            on_error_container: hex(0xF9DEDC),
+            background: hex(0x141218),
+            on_background: hex(0xE6E0E9),
+            surface: hex(0x141218),
+            on_surface: hex(0xE6E0E9),
+            surface_variant: hex(0x49454F),
+            on_surface_variant: hex(0xCAC4D0),
+            surface_container_low: hex(0x1D1B20),
+            surface_container: hex(0x211F26),
+            surface_container_high: hex(0x2B2930),
+            surface_container_highest: hex(0x36343B),
+            outline: hex(0x938F99),
+            outline_variant: hex(0x49454F),
+            shadow: hex(0x000000),
+            scrim: hex(0x000000),
+            inverse_surface: hex(0xE6E0E9),
+            inverse_on_surface: hex(0x322F35),
+            inverse_primary: hex(0x6750A4),
+        }
+    }
+
+    /// Returns the content color to use on `background`.
+    ///
+    /// Backgrounds matching one of the scheme's fill colors get the corresponding `on_*`
+    /// color. Any other background gets black or white, whichever contrasts more.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    /// use tessera_ui_basic_components::theme::ColorScheme;
+    ///
+    /// let scheme = ColorScheme::light();
+    /// assert_eq!(scheme.content_color_for(scheme.primary), scheme.on_primary);
+    /// assert_eq!(scheme.content_color_for(Color::BLACK), Color::WHITE);
+    /// ```
+    pub fn content_color_for(&self, background: Color) -> Color {
+        let pairs = [
+            (self.primary, self.on_primary),
+            (self.primary_container, self.on_primary_container),
+            (self.secondary, self.on_secondary),
+            (self.secondary_container, self.on_secondary_container),
+            (self.tertiary, self.on_tertiary),
+            (self.tertiary_container, self.on_tertiary_container),
+            (self.error, self.on_error),
+            (self.error_container, self.on_error_container),
+            (self.background, self.on_background),
+            (self.surface, self.on_surface),
+            (self.surface_variant, self.on_surface_variant),
+            (self.surface_container_low, self.on_surface),
+            (self.surface_container, self.on_surface),
+            (self.surface_container_high, self.on_surface),
+            (self.surface_container_highest, self.on_surface),
+            (self.inverse_surface, self.inverse_on_surface),
+        ];
+        pairs
+            .into_iter()
+            .find(|(fill, _)| *fill == background)
+            .map(|(_, content)| content)
+            .unwrap_or_else(|| background.contrasting_text_color())
+    }
+}
+
+impl Default for ColorScheme {
+    /// Returns the light color scheme.
+    fn default() -> Self {
+        Self::light()
+    }
+}
+
+/// Creates an opaque color from a `0xRRGGBB` literal.
+fn hex(rgb: u32) -> Color {
+    let [_, r, g, b] = rgb.to_be_bytes();
+    Color::from_rgb_u8(r, g, b)
+}
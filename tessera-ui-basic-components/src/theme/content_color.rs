@@ -0,0 +1,40 @@
+//! The color of content drawn on a container.
+
+use tessera_ui::{
+    Color,
+    context::{current, provide},
+};
+
+use super::current_theme;
+
+/// The composition local holding the color provided by [`provide_content_color`].
+struct ContentColor(Color);
+
+/// Provides `color` as the content color of every component built by `content`.
+///
+/// Containers such as [`surface`](crate::surface::surface) call this with the content
+/// color matching their background, so that text and icons inside of them are readable
+/// without setting their color explicitly.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::Color;
+/// use tessera_ui_basic_components::theme::{current_content_color, provide_content_color};
+///
+/// provide_content_color(Color::WHITE, || {
+///     assert_eq!(current_content_color(), Color::WHITE);
+/// });
+/// ```
+pub fn provide_content_color(color: Color, content: impl FnOnce()) {
+    provide(ContentColor(color), content);
+}
+
+/// Returns the color provided by the innermost enclosing [`provide_content_color`] call,
+/// or the current theme's [`on_surface`](super::ColorScheme::on_surface) color if there
+/// is none.
+pub fn current_content_color() -> Color {
+    current::<ContentColor>()
+        .map(|color| color.0)
+        .unwrap_or_else(|| current_theme().color_scheme.on_surface)
+}
@@ -0,0 +1,77 @@
+//! Elevation and shadow tokens.
+
+use tessera_ui::Color;
+
+use crate::pipelines::ShadowProps;
+
+/// The shadows of a [`Theme`](super::Theme), one per elevation level.
+///
+/// Level 0 has no shadow. Higher levels lift a surface further above the content behind
+/// it, with a larger and softer shadow.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui_basic_components::{
+///     surface::{SurfaceArgsBuilder, surface},
+///     theme::current_theme,
+/// };
+///
+/// surface(
+///     SurfaceArgsBuilder::default()
+///         .shadow(current_theme().elevation.level2)
+///         .build()
+///         .unwrap(),
+///     None,
+///     || {},
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Elevation {
+    /// No elevation.
+    pub level0: Option<ShadowProps>,
+    /// Slightly raised, for example for cards.
+    pub level1: Option<ShadowProps>,
+    /// Raised, for example for hovered cards.
+    pub level2: Option<ShadowProps>,
+    /// Floating, for example for dialogs.
+    pub level3: Option<ShadowProps>,
+    /// Floating higher, for example for dragged items.
+    pub level4: Option<ShadowProps>,
+    /// The highest level.
+    pub level5: Option<ShadowProps>,
+}
+
+impl Elevation {
+    /// Returns the shadow of an elevation level. Levels above 5 use the level 5 shadow.
+    pub fn level(&self, level: u8) -> Option<ShadowProps> {
+        match level {
+            0 => self.level0,
+            1 => self.level1,
+            2 => self.level2,
+            3 => self.level3,
+            4 => self.level4,
+            _ => self.level5,
+        }
+    }
+}
+
+impl Default for Elevation {
+    fn default() -> Self {
+        let shadow = |offset: f32, smoothness: f32, alpha: f32| {
+            Some(ShadowProps {
+                color: Color::BLACK.with_alpha(alpha),
+                offset: [0.0, offset],
+                smoothness,
+            })
+        };
+        Self {
+            level0: None,
+            level1: shadow(1.0, 2.0, 0.2),
+            level2: shadow(2.0, 4.0, 0.25),
+            level3: shadow(4.0, 6.0, 0.25),
+            level4: shadow(6.0, 8.0, 0.3),
+            level5: shadow(8.0, 12.0, 0.3),
+        }
+    }
+}
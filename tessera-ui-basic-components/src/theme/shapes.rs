@@ -0,0 +1,59 @@
+//! Corner tokens.
+
+use crate::shape_def::Shape;
+
+/// The corner tokens of a [`Theme`](super::Theme), from the least to the most rounded.
+///
+/// Small components, such as checkboxes and text fields, use the smaller tokens, while
+/// large containers, such as dialogs, use the larger ones.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui_basic_components::{shape_def::Shape, theme::Shapes};
+///
+/// let shapes = Shapes::default();
+/// assert_eq!(
+///     shapes.medium,
+///     Shape::RoundedRectangle { corner_radius: 12.0, g2_k_value: 3.0 }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shapes {
+    /// Slightly rounded corners, for example for checkboxes and text fields.
+    pub extra_small: Shape,
+    /// Small corners, for example for chips.
+    pub small: Shape,
+    /// Medium corners, for example for cards.
+    pub medium: Shape,
+    /// Large corners, for example for sheets.
+    pub large: Shape,
+    /// Strongly rounded corners, for example for buttons and dialogs.
+    pub extra_large: Shape,
+}
+
+impl Shapes {
+    /// Creates corner tokens with the given corner radii and corner curvature.
+    ///
+    /// See [`Shape::RoundedRectangle`] for the meaning of `g2_k_value`.
+    pub fn new(radii: [f32; 5], g2_k_value: f32) -> Self {
+        let [extra_small, small, medium, large, extra_large] =
+            radii.map(|corner_radius| Shape::RoundedRectangle {
+                corner_radius,
+                g2_k_value,
+            });
+        Self {
+            extra_small,
+            small,
+            medium,
+            large,
+            extra_large,
+        }
+    }
+}
+
+impl Default for Shapes {
+    fn default() -> Self {
+        Self::new([4.0, 8.0, 12.0, 16.0, 28.0], 3.0)
+    }
+}
@@ -0,0 +1,102 @@
+//! Type scale tokens.
+
+use tessera_ui::Sp;
+
+/// A font size together with its line height.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::Sp;
+/// use tessera_ui_basic_components::{
+///     text::{TextArgsBuilder, text},
+///     theme::{TextStyle, current_theme},
+/// };
+///
+/// let caption = TextStyle::new(Sp(12.0), Sp(16.0));
+///
+/// // Styles are applied to text with `TextArgsBuilder::style`
+/// text(
+///     TextArgsBuilder::default()
+///         .text("Title".to_string())
+///         .style(current_theme().typography.title_large)
+///         .build()
+///         .unwrap(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextStyle {
+    /// The font size.
+    pub size: Sp,
+    /// The distance between the baselines of two lines.
+    pub line_height: Sp,
+}
+
+impl TextStyle {
+    /// Creates a text style from a font size and a line height.
+    pub const fn new(size: Sp, line_height: Sp) -> Self {
+        Self { size, line_height }
+    }
+}
+
+/// The type scale of a [`Theme`](super::Theme).
+///
+/// Styles go from the largest, `display_large`, to the smallest, `label_small`. Display
+/// and headline styles are for short, prominent text, title styles for medium emphasis,
+/// body styles for running text and label styles for text inside components, such as
+/// buttons. The default scale follows Material Design 3.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Typography {
+    /// The largest display text.
+    pub display_large: TextStyle,
+    /// Medium display text.
+    pub display_medium: TextStyle,
+    /// Small display text.
+    pub display_small: TextStyle,
+    /// Large headlines.
+    pub headline_large: TextStyle,
+    /// Medium headlines.
+    pub headline_medium: TextStyle,
+    /// Small headlines.
+    pub headline_small: TextStyle,
+    /// Large titles.
+    pub title_large: TextStyle,
+    /// Medium titles.
+    pub title_medium: TextStyle,
+    /// Small titles.
+    pub title_small: TextStyle,
+    /// Large body text, the default style of [`text`](crate::text::text).
+    pub body_large: TextStyle,
+    /// Medium body text.
+    pub body_medium: TextStyle,
+    /// Small body text.
+    pub body_small: TextStyle,
+    /// Large labels, for example on buttons.
+    pub label_large: TextStyle,
+    /// Medium labels.
+    pub label_medium: TextStyle,
+    /// Small labels.
+    pub label_small: TextStyle,
+}
+
+impl Default for Typography {
+    fn default() -> Self {
+        Self {
+            display_large: TextStyle::new(Sp(57.0), Sp(64.0)),
+            display_medium: TextStyle::new(Sp(45.0), Sp(52.0)),
+            display_small: TextStyle::new(Sp(36.0), Sp(44.0)),
+            headline_large: TextStyle::new(Sp(32.0), Sp(40.0)),
+            headline_medium: TextStyle::new(Sp(28.0), Sp(36.0)),
+            headline_small: TextStyle::new(Sp(24.0), Sp(32.0)),
+            title_large: TextStyle::new(Sp(22.0), Sp(28.0)),
+            title_medium: TextStyle::new(Sp(16.0), Sp(24.0)),
+            title_small: TextStyle::new(Sp(14.0), Sp(20.0)),
+            body_large: TextStyle::new(Sp(16.0), Sp(24.0)),
+            body_medium: TextStyle::new(Sp(14.0), Sp(20.0)),
+            body_small: TextStyle::new(Sp(12.0), Sp(16.0)),
+            label_large: TextStyle::new(Sp(14.0), Sp(20.0)),
+            label_medium: TextStyle::new(Sp(12.0), Sp(16.0)),
+            label_small: TextStyle::new(Sp(11.0), Sp(16.0)),
+        }
+    }
+}
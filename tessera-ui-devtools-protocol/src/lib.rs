@@ -0,0 +1,148 @@
+//! # Tessera Devtools Protocol
+//!
+//! The messages exchanged between a Tessera application serving devtools and the clients
+//! inspecting it, such as the `tessera-ui-devtools` terminal inspector.
+//!
+//! This crate only depends on `serde`, so clients can speak the protocol without building
+//! the renderer. Applications serve it with the `devtools` feature of `tessera-ui`, which
+//! re-exports these types.
+//!
+//! ## Protocol
+//!
+//! Messages are JSON objects, one per line, in both directions. The server sends
+//! [`DevtoolsMessage`]s, and clients send [`DevtoolsCommand`]s, for example:
+//!
+//! ```text
+//! {"command":"highlight","node":12}
+//! {"command":"set_debug_overlay","enabled":true}
+//! ```
+//!
+//! The server only keeps the latest frame for each client: a client that reads slower
+//! than frames are rendered misses frames rather than falling behind.
+
+mod snapshot;
+
+use std::net::{Ipv4Addr, SocketAddr};
+#[cfg(unix)]
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+pub use snapshot::{
+    ConstraintSnapshot, DimensionSnapshot, LocationSnapshot, NodeSnapshot, PositionSnapshot,
+    SizeSnapshot, TreeSnapshot,
+};
+
+/// Where the devtools server listens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DevtoolsAddress {
+    /// A TCP address, usually on localhost.
+    Tcp(SocketAddr),
+    /// The path of a Unix socket. A stale socket at this path is replaced, but any other
+    /// kind of file is left alone and the server fails to start.
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl DevtoolsAddress {
+    /// The port used by the devtools client unless told otherwise.
+    pub const DEFAULT_PORT: u16 = 7878;
+
+    /// Returns the TCP address of `port` on the loopback interface.
+    ///
+    /// ```
+    /// use tessera_ui_devtools_protocol::DevtoolsAddress;
+    ///
+    /// let address = DevtoolsAddress::localhost(DevtoolsAddress::DEFAULT_PORT);
+    /// assert_eq!(
+    ///     address,
+    ///     DevtoolsAddress::Tcp("127.0.0.1:7878".parse().unwrap())
+    /// );
+    /// ```
+    pub fn localhost(port: u16) -> Self {
+        Self::Tcp(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+    }
+}
+
+/// Timings and counts of one rendered frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct FrameStats {
+    /// Time spent building the component tree, in milliseconds.
+    pub build_ms: f64,
+    /// Time spent measuring the tree and collecting its commands, in milliseconds.
+    pub layout_ms: f64,
+    /// Time spent rendering the commands, in milliseconds.
+    pub render_ms: f64,
+    /// The number of nodes in the tree.
+    pub node_count: usize,
+    /// The number of draw and compute commands rendered.
+    pub command_count: usize,
+}
+
+/// A message sent by the devtools server.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DevtoolsMessage {
+    /// A frame was rendered.
+    Frame {
+        /// The laid out tree of the frame.
+        snapshot: TreeSnapshot,
+        /// How long the frame took.
+        stats: FrameStats,
+    },
+}
+
+/// A command sent by a devtools client.
+///
+/// ```
+/// use tessera_ui_devtools_protocol::DevtoolsCommand;
+///
+/// let command: DevtoolsCommand = serde_json::from_str(r#"{"command":"highlight","node":3}"#)?;
+/// assert_eq!(command, DevtoolsCommand::Highlight { node: Some(3) });
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum DevtoolsCommand {
+    /// Highlights a node on top of the UI, or removes the highlight.
+    Highlight {
+        /// The [`id`](NodeSnapshot::id) of the node.
+        node: Option<u64>,
+    },
+    /// Shows or hides the layout debug overlay.
+    SetDebugOverlay {
+        /// Whether the overlay is shown.
+        enabled: bool,
+    },
+    /// Toggles the layout debug overlay.
+    ToggleDebugOverlay,
+    /// Asks for a new frame, even if nothing changed.
+    RequestFrame,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_are_json_lines() {
+        let message = DevtoolsMessage::Frame {
+            snapshot: TreeSnapshot::default(),
+            stats: FrameStats {
+                node_count: 2,
+                ..Default::default()
+            },
+        };
+        let json = serde_json::to_string(&message).unwrap();
+        assert!(json.starts_with(r#"{"type":"frame","snapshot":{"root":null}"#));
+        assert!(!json.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<DevtoolsMessage>(&json).unwrap(),
+            message
+        );
+
+        let command: DevtoolsCommand =
+            serde_json::from_str(r#"{"command":"toggle_debug_overlay"}"#).unwrap();
+        assert_eq!(command, DevtoolsCommand::ToggleDebugOverlay);
+    }
+}
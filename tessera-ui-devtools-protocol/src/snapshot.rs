@@ -0,0 +1,178 @@
+//! Serializable snapshots of a laid out component tree.
+//!
+//! Sizes and positions are in physical pixels, as used by the layout of the application.
+
+use serde::{Deserialize, Serialize};
+
+/// A copy of a laid out component tree that can be serialized, e.g. to JSON.
+///
+/// ```
+/// use tessera_ui_devtools_protocol::TreeSnapshot;
+///
+/// let snapshot = TreeSnapshot::default();
+/// assert!(snapshot.root.is_none());
+///
+/// let json = snapshot.to_json();
+/// assert_eq!(TreeSnapshot::from_json(&json).unwrap(), snapshot);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct TreeSnapshot {
+    /// The root node, `None` if the tree is empty.
+    pub root: Option<NodeSnapshot>,
+}
+
+impl TreeSnapshot {
+    /// Serializes the snapshot to pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("snapshots are always serializable")
+    }
+
+    /// Parses a snapshot serialized with [`to_json`](Self::to_json).
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the number of nodes in the snapshot.
+    pub fn node_count(&self) -> usize {
+        self.root.as_ref().map_or(0, NodeSnapshot::node_count)
+    }
+
+    /// Returns the node with the given [`id`](NodeSnapshot::id), if any.
+    pub fn find(&self, id: u64) -> Option<&NodeSnapshot> {
+        self.root.as_ref().and_then(|root| root.find(id))
+    }
+
+    /// Returns the first node, in build order, with the given test tag.
+    pub fn find_by_test_tag(&self, test_tag: &str) -> Option<&NodeSnapshot> {
+        self.root
+            .as_ref()
+            .and_then(|root| root.find_by(&|node| node.test_tag.as_deref() == Some(test_tag)))
+    }
+}
+
+/// Where the component of a [`NodeSnapshot`] was defined and called.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocationSnapshot {
+    /// The module path of the component function.
+    pub module_path: String,
+    /// The file defining the component function.
+    pub file: String,
+    /// The line defining the component function.
+    pub line: u32,
+    /// The file the component function was called from.
+    pub caller_file: String,
+    /// The line the component function was called from.
+    pub caller_line: u32,
+    /// The column the component function was called from.
+    pub caller_column: u32,
+}
+
+/// The size of a [`NodeSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct SizeSnapshot {
+    /// The width, in pixels.
+    pub width: f32,
+    /// The height, in pixels.
+    pub height: f32,
+}
+
+/// The position of a [`NodeSnapshot`].
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct PositionSnapshot {
+    /// The horizontal position, in pixels.
+    pub x: f32,
+    /// The vertical position, in pixels.
+    pub y: f32,
+}
+
+/// How one dimension of a node was constrained, see [`ConstraintSnapshot`].
+///
+/// Bounds are `None` when the dimension is unbounded on that side.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DimensionSnapshot {
+    /// A fixed size, in pixels.
+    Fixed(f32),
+    /// As small as the content, within bounds.
+    Wrap {
+        /// The smallest size, in pixels.
+        min: Option<f32>,
+        /// The largest size, in pixels.
+        max: Option<f32>,
+    },
+    /// As large as the available space, within bounds.
+    Fill {
+        /// The smallest size, in pixels.
+        min: Option<f32>,
+        /// The largest size, in pixels.
+        max: Option<f32>,
+    },
+    /// A fraction of the available space.
+    Fraction(f32),
+}
+
+/// The constraint a [`NodeSnapshot`] was measured with.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ConstraintSnapshot {
+    /// The width constraint.
+    pub width: DimensionSnapshot,
+    /// The height constraint.
+    pub height: DimensionSnapshot,
+    /// The ratio of width to height to keep, if any.
+    pub aspect_ratio: Option<f32>,
+}
+
+/// A node of a [`TreeSnapshot`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeSnapshot {
+    /// The key of the node.
+    ///
+    /// Keys follow the component that created the node from frame to frame, even when
+    /// siblings called from elsewhere appear or disappear.
+    pub id: u64,
+    /// The name of the component function that created the node.
+    pub fn_name: String,
+    /// The test tag of the node, if any.
+    pub test_tag: Option<String>,
+    /// Where the component was defined and called, `None` for nodes not created by the
+    /// `#[tessera]` macro.
+    pub location: Option<LocationSnapshot>,
+    /// The measured size, `None` if the node was not measured.
+    pub size: Option<SizeSnapshot>,
+    /// The position relative to the parent, `None` if the node was not placed.
+    pub rel_position: Option<PositionSnapshot>,
+    /// The position relative to the window, `None` if the node was not placed.
+    pub abs_position: Option<PositionSnapshot>,
+    /// The constraint the node was measured with, `None` if it was not measured.
+    pub constraint: Option<ConstraintSnapshot>,
+    /// The type names of the commands the node emitted, in order.
+    pub commands: Vec<String>,
+    /// The child nodes, in order.
+    pub children: Vec<NodeSnapshot>,
+}
+
+impl NodeSnapshot {
+    /// Returns the number of nodes in this subtree, including this node.
+    pub fn node_count(&self) -> usize {
+        1 + self
+            .children
+            .iter()
+            .map(NodeSnapshot::node_count)
+            .sum::<usize>()
+    }
+
+    /// Returns the node with the given id in this subtree, if any.
+    pub fn find(&self, id: u64) -> Option<&NodeSnapshot> {
+        self.find_by(&|node| node.id == id)
+    }
+
+    /// Returns the first node of this subtree, in build order, matching `predicate`.
+    pub fn find_by(&self, predicate: &dyn Fn(&NodeSnapshot) -> bool) -> Option<&NodeSnapshot> {
+        if predicate(self) {
+            return Some(self);
+        }
+        self.children
+            .iter()
+            .find_map(|child| child.find_by(predicate))
+    }
+}
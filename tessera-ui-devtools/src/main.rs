@@ -0,0 +1,352 @@
+//! Terminal inspector for running Tessera applications.
+//!
+//! Connects to the devtools server of an application started with
+//! `TesseraConfig::devtools`, shows its component tree with the layout of every node and
+//! the statistics of the last frame, and can highlight nodes or toggle the debug overlay
+//! in the application.
+//!
+//! ```text
+//! tessera-ui-devtools [ADDRESS]
+//! ```
+//!
+//! `ADDRESS` is a `host:port` pair, `127.0.0.1:7878` by default, or on Unix the path of a
+//! socket.
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    sync::mpsc,
+    thread,
+    time::Duration,
+};
+
+use ratatui::{
+    DefaultTerminal, Frame,
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style, Stylize},
+    text::Line,
+    widgets::{Block, List, ListItem, ListState, Paragraph},
+};
+use tessera_ui_devtools_protocol::{
+    DevtoolsAddress, DevtoolsCommand, DevtoolsMessage, DimensionSnapshot, FrameStats, NodeSnapshot,
+    PositionSnapshot, TreeSnapshot,
+};
+
+/// What the connection thread reports to the UI.
+enum Update {
+    Message(Box<DevtoolsMessage>),
+    Disconnected,
+}
+
+/// A node of the tree, as one line of the tree view.
+struct Row {
+    id: u64,
+    depth: usize,
+    label: String,
+}
+
+struct App {
+    snapshot: TreeSnapshot,
+    stats: FrameStats,
+    rows: Vec<Row>,
+    list: ListState,
+    highlighted: Option<u64>,
+    connected: bool,
+    writer: Box<dyn Write>,
+}
+
+impl App {
+    fn new(writer: Box<dyn Write>) -> Self {
+        Self {
+            snapshot: TreeSnapshot::default(),
+            stats: FrameStats::default(),
+            rows: Vec::new(),
+            list: ListState::default(),
+            highlighted: None,
+            connected: true,
+            writer,
+        }
+    }
+
+    fn selected_id(&self) -> Option<u64> {
+        self.list
+            .selected()
+            .and_then(|index| self.rows.get(index))
+            .map(|row| row.id)
+    }
+
+    fn update(&mut self, update: Update) {
+        match update {
+            Update::Message(message) => match *message {
+                DevtoolsMessage::Frame { snapshot, stats } => {
+                    // Keep the selection on the same node across frames
+                    let selected = self.selected_id();
+                    self.rows.clear();
+                    if let Some(root) = &snapshot.root {
+                        flatten(root, 0, &mut self.rows);
+                    }
+                    let index = selected
+                        .and_then(|id| self.rows.iter().position(|row| row.id == id))
+                        .or((!self.rows.is_empty()).then_some(0));
+                    self.list.select(index);
+                    self.snapshot = snapshot;
+                    self.stats = stats;
+                }
+            },
+            Update::Disconnected => self.connected = false,
+        }
+    }
+
+    /// Sends `command` to the application.
+    fn send(&mut self, command: &DevtoolsCommand) -> io::Result<()> {
+        let mut line = serde_json::to_vec(command)?;
+        line.push(b'\n');
+        self.writer.write_all(&line)
+    }
+
+    /// Handles a key press, returns `false` to quit.
+    fn handle_key(&mut self, code: KeyCode) -> io::Result<bool> {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Home | KeyCode::Char('g') => self.list.select_first(),
+            KeyCode::End | KeyCode::Char('G') => self.list.select_last(),
+            KeyCode::Enter | KeyCode::Char('h') => {
+                let node = self
+                    .selected_id()
+                    .filter(|id| self.highlighted != Some(*id));
+                self.highlighted = node;
+                self.send(&DevtoolsCommand::Highlight { node })?;
+            }
+            KeyCode::Char('c') => {
+                self.highlighted = None;
+                self.send(&DevtoolsCommand::Highlight { node: None })?;
+            }
+            KeyCode::Char('o') => self.send(&DevtoolsCommand::ToggleDebugOverlay)?,
+            KeyCode::Char('r') => self.send(&DevtoolsCommand::RequestFrame)?,
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [tree, details] =
+            Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+                .areas(body);
+
+        let status = if self.connected {
+            format!(
+                "build {:.2}ms  layout {:.2}ms  render {:.2}ms  nodes {}  commands {}",
+                self.stats.build_ms,
+                self.stats.layout_ms,
+                self.stats.render_ms,
+                self.stats.node_count,
+                self.stats.command_count,
+            )
+        } else {
+            "disconnected".to_string()
+        };
+        frame.render_widget(Line::from(status).bold(), header);
+
+        let items: Vec<_> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let item = ListItem::new(format!("{}{}", "  ".repeat(row.depth), row.label));
+                if self.highlighted == Some(row.id) {
+                    item.magenta()
+                } else {
+                    item
+                }
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(" Component tree "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, tree, &mut self.list);
+
+        let lines = self
+            .selected_id()
+            .and_then(|id| self.snapshot.find(id))
+            .map(details_lines)
+            .unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title(" Node ")),
+            details,
+        );
+
+        frame.render_widget(
+            Line::from("↑/↓ select  enter highlight  c clear  o overlay  r refresh  q quit").dim(),
+            footer,
+        );
+    }
+}
+
+/// Appends a row for `node` and its descendants.
+fn flatten(node: &NodeSnapshot, depth: usize, rows: &mut Vec<Row>) {
+    let size = node.size.map_or_else(
+        || "unmeasured".to_string(),
+        |size| format!("{}x{}", px(size.width), px(size.height)),
+    );
+    rows.push(Row {
+        id: node.id,
+        depth,
+        label: match &node.test_tag {
+            Some(tag) => format!("{} [{tag}]  {size}", node.fn_name),
+            None => format!("{}  {size}", node.fn_name),
+        },
+    });
+    for child in &node.children {
+        flatten(child, depth + 1, rows);
+    }
+}
+
+fn px(px: f32) -> String {
+    format!("{}", px.round() as i64)
+}
+
+fn bound(bound: Option<f32>) -> String {
+    bound.map(px).unwrap_or_default()
+}
+
+fn dimension(dimension: &DimensionSnapshot) -> String {
+    match dimension {
+        DimensionSnapshot::Fixed(value) => format!("fixed({})", px(*value)),
+        DimensionSnapshot::Wrap { min, max } => format!("wrap({}..{})", bound(*min), bound(*max)),
+        DimensionSnapshot::Fill { min, max } => format!("fill({}..{})", bound(*min), bound(*max)),
+        DimensionSnapshot::Fraction(fraction) => format!("fraction({fraction})"),
+    }
+}
+
+/// Describes the layout and commands of `node`.
+fn details_lines(node: &NodeSnapshot) -> Vec<Line<'static>> {
+    let position = |position: Option<PositionSnapshot>| {
+        position.map_or_else(
+            || "not placed".to_string(),
+            |position| format!("{}, {}", px(position.x), px(position.y)),
+        )
+    };
+    let mut lines = vec![
+        Line::from(node.fn_name.clone()).bold(),
+        Line::from(format!("id           {}", node.id)),
+        Line::from(format!(
+            "test tag     {}",
+            node.test_tag.as_deref().unwrap_or("none")
+        )),
+        Line::from(format!(
+            "size         {}",
+            node.size.map_or_else(
+                || "unmeasured".to_string(),
+                |size| format!("{} x {}", px(size.width), px(size.height))
+            )
+        )),
+        Line::from(format!("position     {}", position(node.rel_position))),
+        Line::from(format!("absolute     {}", position(node.abs_position))),
+    ];
+    match &node.constraint {
+        Some(constraint) => {
+            lines.push(Line::from(format!(
+                "width        {}",
+                dimension(&constraint.width)
+            )));
+            lines.push(Line::from(format!(
+                "height       {}",
+                dimension(&constraint.height)
+            )));
+            if let Some(ratio) = constraint.aspect_ratio {
+                lines.push(Line::from(format!("aspect ratio {ratio}")));
+            }
+        }
+        None => lines.push(Line::from("constraint   unmeasured")),
+    }
+    if let Some(location) = &node.location {
+        lines.push(Line::from(format!(
+            "defined      {} ({}:{})",
+            location.module_path, location.file, location.line
+        )));
+        lines.push(Line::from(format!(
+            "called at    {}:{}:{}",
+            location.caller_file, location.caller_line, location.caller_column
+        )));
+    }
+    lines.push(Line::from(format!("commands     {}", node.commands.len())));
+    lines.extend(
+        node.commands
+            .iter()
+            .map(|command| Line::from(format!("  {command}")).dim()),
+    );
+    lines
+}
+
+/// Connects to the devtools server at `address`, returning a reader and a writer.
+fn connect(address: &str) -> io::Result<(Box<dyn Read + Send>, Box<dyn Write>)> {
+    #[cfg(unix)]
+    if address.contains('/') {
+        let stream = UnixStream::connect(address)?;
+        return Ok((Box::new(stream.try_clone()?), Box::new(stream)));
+    }
+    let stream = TcpStream::connect(address)?;
+    Ok((Box::new(stream.try_clone()?), Box::new(stream)))
+}
+
+/// Forwards the messages of the server to the UI until it disconnects.
+fn read_messages(reader: Box<dyn Read + Send>, sender: mpsc::Sender<Update>) {
+    for line in BufReader::new(reader).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        // Unknown messages come from newer servers, skip them
+        if let Ok(message) = serde_json::from_str(&line)
+            && sender.send(Update::Message(Box::new(message))).is_err()
+        {
+            return;
+        }
+    }
+    let _ = sender.send(Update::Disconnected);
+}
+
+fn run(
+    terminal: &mut DefaultTerminal,
+    app: &mut App,
+    updates: mpsc::Receiver<Update>,
+) -> io::Result<()> {
+    loop {
+        while let Ok(update) = updates.try_recv() {
+            app.update(update);
+        }
+        terminal.draw(|frame| app.draw(frame))?;
+        if event::poll(Duration::from_millis(50))?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+            && !app.handle_key(key.code)?
+        {
+            return Ok(());
+        }
+    }
+}
+
+fn main() -> io::Result<()> {
+    let address = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| format!("127.0.0.1:{}", DevtoolsAddress::DEFAULT_PORT));
+    let (reader, writer) = connect(&address)
+        .map_err(|e| io::Error::new(e.kind(), format!("failed to connect to {address}: {e}")))?;
+    let (sender, updates) = mpsc::channel();
+    thread::spawn(move || read_messages(reader, sender));
+
+    let mut app = App::new(writer);
+    let mut terminal = ratatui::init();
+    let result = run(&mut terminal, &mut app, updates);
+    ratatui::restore();
+    result
+}
@@ -50,8 +50,8 @@ pub fn app(state: Arc<AppState>) {
     let padding_spacer = (move || {
         spacer(
             SpacerArgsBuilder::default()
-                .width(tessera_ui::DimensionValue::Fixed(Px(500)))
-                .height(tessera_ui::DimensionValue::Fixed(Px(500)))
+                .width(tessera_ui::DimensionValue::Fixed(Px(500.0)))
+                .height(tessera_ui::DimensionValue::Fixed(Px(500.0)))
                 .build()
                 .unwrap(),
         )
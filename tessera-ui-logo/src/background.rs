@@ -161,13 +161,15 @@ pub fn background<const N: usize>(args: BackgroundArgs, children: [BoxedItem; N]
         let args = &args_clone;
         let width = match input.parent_constraint.width {
             DimensionValue::Fixed(v) => v,
-            DimensionValue::Wrap { max, .. } => max.unwrap_or(Px(0)),
-            DimensionValue::Fill { max, .. } => max.unwrap_or(Px(0)),
+            DimensionValue::Wrap { max, .. } => max.unwrap_or(Px(0.0)),
+            DimensionValue::Fill { max, .. } => max.unwrap_or(Px(0.0)),
+            DimensionValue::Fraction(_) => Px(0.0),
         };
         let height = match input.parent_constraint.height {
             DimensionValue::Fixed(v) => v,
-            DimensionValue::Wrap { max, .. } => max.unwrap_or(Px(0)),
-            DimensionValue::Fill { max, .. } => max.unwrap_or(Px(0)),
+            DimensionValue::Wrap { max, .. } => max.unwrap_or(Px(0.0)),
+            DimensionValue::Fill { max, .. } => max.unwrap_or(Px(0.0)),
+            DimensionValue::Fraction(_) => Px(0.0),
         };
 
         input
@@ -183,10 +185,10 @@ pub fn background<const N: usize>(args: BackgroundArgs, children: [BoxedItem; N]
                 let child_size = input.measure_child(child_id, &child_constraint)?;
 
                 let (x, y) = match args.alignment {
-                    Alignment::TopStart => (Px(0), Px(0)),
-                    Alignment::TopCenter => ((width - child_size.width) / 2, Px(0)),
-                    Alignment::TopEnd => (width - child_size.width, Px(0)),
-                    Alignment::CenterStart => (Px(0), (height - child_size.height) / 2),
+                    Alignment::TopStart => (Px(0.0), Px(0.0)),
+                    Alignment::TopCenter => ((width - child_size.width) / 2, Px(0.0)),
+                    Alignment::TopEnd => (width - child_size.width, Px(0.0)),
+                    Alignment::CenterStart => (Px(0.0), (height - child_size.height) / 2),
                     Alignment::Center => (
                         (width - child_size.width) / 2,
                         (height - child_size.height) / 2,
@@ -194,7 +196,7 @@ pub fn background<const N: usize>(args: BackgroundArgs, children: [BoxedItem; N]
                     Alignment::CenterEnd => {
                         (width - child_size.width, (height - child_size.height) / 2)
                     }
-                    Alignment::BottomStart => (Px(0), height - child_size.height),
+                    Alignment::BottomStart => (Px(0.0), height - child_size.height),
                     Alignment::BottomCenter => {
                         ((width - child_size.width) / 2, height - child_size.height)
                     }
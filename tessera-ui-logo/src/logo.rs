@@ -4,6 +4,8 @@ use derive_builder::Builder;
 use rand::{Rng, SeedableRng};
 use tessera_ui::{
     ComputedData, DrawCommand, DrawablePipeline, Px, PxPosition, PxSize,
+    renderer::ShaderSource,
+    shader_source,
     wgpu::{self, util::DeviceExt},
 };
 use tessera_ui_macros::tessera;
@@ -61,13 +63,17 @@ pub struct CrystalShardArgs {
 }
 
 pub struct CrystalPipeline {
+    shader: ShaderSource,
+    pipeline_layout: wgpu::PipelineLayout,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
     pipeline: wgpu::RenderPipeline,
     bind_group_layout: wgpu::BindGroupLayout,
 }
 
 impl CrystalPipeline {
     pub fn new(gpu: &wgpu::Device, config: &wgpu::SurfaceConfiguration, sample_count: u32) -> Self {
-        let shader = gpu.create_shader_module(wgpu::include_wgsl!("../shaders/crystal.wgsl"));
+        let shader = shader_source!("shaders/crystal.wgsl");
 
         let bind_group_layout = gpu.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             entries: &[wgpu::BindGroupLayoutEntry {
@@ -89,44 +95,83 @@ impl CrystalPipeline {
             push_constant_ranges: &[],
         });
 
-        let pipeline = gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Crystal Pipeline"),
-            layout: Some(&pipeline_layout),
-            vertex: wgpu::VertexState {
-                module: &shader,
-                entry_point: Some("vs_main"),
-                buffers: &[GpuVertex::desc()],
-                compilation_options: Default::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: &shader,
-                entry_point: Some("fs_main"),
-                targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
-                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
-                    write_mask: wgpu::ColorWrites::ALL,
-                })],
-                compilation_options: Default::default(),
-            }),
-            primitive: wgpu::PrimitiveState::default(),
-            depth_stencil: None,
-            multisample: wgpu::MultisampleState {
-                count: sample_count,
-                mask: !0,
-                alpha_to_coverage_enabled: false,
-            },
-            multiview: None,
-            cache: None,
-        });
+        let pipeline = create_pipeline(
+            gpu,
+            &pipeline_layout,
+            &shader.module(gpu),
+            config.format,
+            sample_count,
+        );
 
         Self {
+            shader,
+            pipeline_layout,
+            format: config.format,
+            sample_count,
             pipeline,
             bind_group_layout,
         }
     }
 }
 
+fn create_pipeline(
+    gpu: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    module: &wgpu::ShaderModule,
+    format: wgpu::TextureFormat,
+    sample_count: u32,
+) -> wgpu::RenderPipeline {
+    gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        label: Some("Crystal Pipeline"),
+        layout: Some(layout),
+        vertex: wgpu::VertexState {
+            module,
+            entry_point: Some("vs_main"),
+            buffers: &[GpuVertex::desc()],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(wgpu::FragmentState {
+            module,
+            entry_point: Some("fs_main"),
+            targets: &[Some(wgpu::ColorTargetState {
+                format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive: wgpu::PrimitiveState::default(),
+        depth_stencil: None,
+        multisample: wgpu::MultisampleState {
+            count: sample_count,
+            mask: !0,
+            alpha_to_coverage_enabled: false,
+        },
+        multiview: None,
+        cache: None,
+    })
+}
+
 impl DrawablePipeline<CrystalCommand> for CrystalPipeline {
+    fn begin_frame(
+        &mut self,
+        gpu: &wgpu::Device,
+        _gpu_queue: &wgpu::Queue,
+        _config: &wgpu::SurfaceConfiguration,
+    ) {
+        if let Some(pipeline) = self.shader.reload(gpu, |module| {
+            create_pipeline(
+                gpu,
+                &self.pipeline_layout,
+                module,
+                self.format,
+                self.sample_count,
+            )
+        }) {
+            self.pipeline = pipeline;
+        }
+    }
+
     fn draw(
         &mut self,
         gpu: &wgpu::Device,
@@ -275,8 +320,8 @@ pub fn crystal_shard(args: CrystalShardArgs) {
         input.metadata_mut().push_draw_command(command);
 
         Ok(ComputedData {
-            width: Px((RADIUS * 2.0) as i32),
-            height: Px((RADIUS * 2.0) as i32),
+            width: Px(RADIUS * 2.0),
+            height: Px(RADIUS * 2.0),
         })
     }));
 }
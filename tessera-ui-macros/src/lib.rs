@@ -17,26 +17,27 @@
 //! ```
 //!
 //! The `#[tessera]` macro automatically:
-//! - Registers the function as a component in the Tessera component tree
-//! - Injects `measure`, `state_handler` and `on_minimize` functions into the component scope
+//! - Registers the function as a component in the Tessera component tree, recording where
+//!   it was defined and called
+//! - Injects `measure`, `intrinsic`, `state_handler`, `semantics`, `test_tag` and window lifecycle functions (such as `on_minimize`) into the component scope
 //! - Handles component tree management (adding/removing nodes)
 //! - Provides error safety by wrapping the function body
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{ItemFn, parse_macro_input};
+use quote::{quote, quote_spanned};
+use syn::{ItemFn, LitStr, parse_macro_input};
 
 /// The `#[tessera]` attribute macro transforms a regular Rust function into a Tessera UI component.
 ///
 /// This macro performs several key transformations:
 /// 1. Registers the function as a node in the Tessera component tree
-/// 2. Injects `measure`, `state_handler` and `on_minimize` functions into the component scope
+/// 2. Injects `measure`, `intrinsic`, `state_handler`, `semantics`, `test_tag` and window lifecycle functions (such as `on_minimize`) into the component scope
 /// 3. Manages component tree lifecycle (push/pop operations)
 /// 4. Provides error safety by wrapping the original function body
 ///
 /// ## Parameters
 ///
-/// - `_attr`: Attribute arguments (currently unused)
+/// - `attr`: Optional `name = "..."` and `test_tag = "..."` arguments, see below
 /// - `item`: The function to be transformed into a component
 ///
 /// ## Generated Code
@@ -44,8 +45,9 @@ use syn::{ItemFn, parse_macro_input};
 /// The macro generates code that:
 ///
 /// - Accesses the Tessera runtime to manage the component tree
-/// - Creates a new component node with the function name
-/// - Provides closures for `measure` and `state_handler` functionality
+/// - Creates a new component node with the function name and its
+///   [`SourceLocation`](tessera_ui::SourceLocation)
+/// - Provides closures for `measure`, `intrinsic`, `state_handler`, `semantics` and `test_tag` functionality
 /// - Executes the original function body within a safe closure
 /// - Cleans up the component tree after execution
 ///
@@ -61,8 +63,8 @@ use syn::{ItemFn, parse_macro_input};
 ///         // Custom layout logic
 ///         use tessera_ui::{ComputedData, Px};
 ///         Ok(ComputedData {
-///             width: Px(100),
-///             height: Px(50),
+///             width: Px(100.0),
+///             height: Px(50.0),
 ///         })
 ///     }));
 ///     
@@ -77,6 +79,52 @@ use syn::{ItemFn, parse_macro_input};
 ///             println!("Window restored!");
 ///         }
 ///     }));
+///
+///     on_theme_changed(Box::new(|theme| {
+///         println!("Switched to the {theme:?} theme");
+///     }));
+///
+///     on_close_requested(Box::new(|| {
+///         // Keep the window open, e.g. to ask about unsaved changes
+///         tessera_ui::runtime::CloseDecision::Prevent
+///     }));
+/// }
+/// ```
+///
+/// The injected window lifecycle functions are `on_minimize`, `on_close`,
+/// `on_close_requested`, `on_window_focus_changed`, `on_resized`, `on_moved`, `on_occluded`
+/// and `on_theme_changed`. They register callbacks on the
+/// [`TesseraRuntime`](tessera_ui::TesseraRuntime) for the current frame.
+///
+/// ```
+/// # use tessera_ui_macros::tessera;
+/// #[tessera]
+/// fn window_watcher() {
+///     on_occluded(Box::new(|occluded| {
+///         println!("Window hidden: {occluded}");
+///     }));
+/// }
+/// ```
+///
+/// ## Names, Test Tags and Source Locations
+///
+/// Every node records the module, file and line of the component function, and where it was
+/// called from: the function is marked `#[track_caller]`. Measurement errors, warnings and
+/// the devtools inspector use this to tell nodes of the same component apart.
+///
+/// The node is named after the function unless `name` is given. A `test_tag` identifies the
+/// node in `TreeSnapshot::find_by_test_tag` of the `devtools` feature;
+/// it can also be set at runtime with the injected `test_tag` function, e.g. to tag each
+/// item of a list.
+///
+/// ```
+/// # use tessera_ui_macros::tessera;
+/// #[tessera(name = "settings_page", test_tag = "settings")]
+/// fn page() {}
+///
+/// #[tessera]
+/// fn list_item(index: usize) {
+///     test_tag(&format!("item-{index}"));
 /// }
 /// ```
 ///
@@ -87,7 +135,23 @@ use syn::{ItemFn, parse_macro_input};
 /// that the component tree is always properly cleaned up, even if the
 /// component function returns early.
 #[proc_macro_attribute]
-pub fn tessera(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn tessera(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Parse the optional `name` and `test_tag` arguments
+    let mut name: Option<LitStr> = None;
+    let mut tag: Option<LitStr> = None;
+    let attr_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("test_tag") {
+            tag = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `name` or `test_tag`"))
+        }
+    });
+    parse_macro_input!(attr with attr_parser);
+
     // Parse the input function that will be transformed into a component
     let input_fn = parse_macro_input!(item as ItemFn);
     let fn_name = &input_fn.sig.ident; // Function name for component identification
@@ -96,21 +160,45 @@ pub fn tessera(_attr: TokenStream, item: TokenStream) -> TokenStream {
     let fn_sig = &input_fn.sig; // Function signature (parameters, return type)
     let fn_block = &input_fn.block; // Original function body
 
+    let node_name = match &name {
+        Some(name) => quote! { #name },
+        None => quote! { stringify!(#fn_name) },
+    };
+    let test_tag = match &tag {
+        Some(tag) => quote! { Some(#tag.to_string()) },
+        None => quote! { None },
+    };
+    // Spanned so `line!()` reports the line of the function rather than of the attribute
+    let definition_line = quote_spanned! { fn_name.span()=> line!() };
+
     // Generate the transformed function with Tessera runtime integration
     let expanded = quote! {
         #(#fn_attrs)*
+        #[track_caller]
         #fn_vis #fn_sig {
             // Step 1: Register this function as a component node in the tree
             {
-                use tessera_ui::{TesseraRuntime, ComponentNode};
+                use tessera_ui::{TesseraRuntime, ComponentNode, SourceLocation};
 
                 TesseraRuntime::write()
                     .component_tree
                     .add_node(
                         ComponentNode {
-                            fn_name: stringify!(#fn_name).to_string(),
+                            fn_name: #node_name.to_string(),
+                            location: Some(SourceLocation {
+                                module_path: module_path!(),
+                                file: file!(),
+                                line: #definition_line,
+                                caller: std::panic::Location::caller(),
+                            }),
+                            test_tag: #test_tag,
                             measure_fn: None,
+                            intrinsic_fn: None,
                             state_handler_fn: None,
+                            semantics: None,
+                            context: Default::default(),
+                            key: 0,
+                            intrinsic_cache: Default::default(),
                         }
                     );
             }
@@ -128,6 +216,19 @@ pub fn tessera(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             };
 
+            // Step 2b: Inject the `intrinsic` function into the component scope
+            // This allows components to answer intrinsic size queries without a layout pass
+            let intrinsic = {
+                use tessera_ui::{IntrinsicFn, TesseraRuntime};
+                |fun: Box<IntrinsicFn>| {
+                    TesseraRuntime::write()
+                        .component_tree
+                        .current_node_mut()
+                        .unwrap()
+                        .intrinsic_fn = Some(fun);
+                }
+            };
+
             // Step 3: Inject the `state_handler` function into the component scope
             // This allows components to handle user interactions and events
             let state_handler = {
@@ -141,6 +242,37 @@ pub fn tessera(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             };
 
+            // Step 3b: Inject the `semantics` function into the component scope
+            // This allows components to describe themselves to assistive technology.
+            // The semantics are only built while assistive technology is listening
+            #[allow(dead_code)]
+            fn semantics(build: impl FnOnce() -> tessera_ui::accessibility::Semantics) {
+                use tessera_ui::{TesseraRuntime, accessibility};
+
+                if !accessibility::is_active() {
+                    return;
+                }
+                let semantics = build();
+                TesseraRuntime::write()
+                    .component_tree
+                    .current_node_mut()
+                    .unwrap()
+                    .semantics = Some(semantics);
+            }
+
+            // Step 3c: Inject the `test_tag` function into the component scope
+            // This allows components to tag their node for tests and inspection tools
+            let test_tag = {
+                use tessera_ui::TesseraRuntime;
+                |tag: &str| {
+                    TesseraRuntime::write()
+                        .component_tree
+                        .current_node_mut()
+                        .unwrap()
+                        .test_tag = Some(tag.to_string());
+                }
+            };
+
             // Step 4: Inject the `on_minimize` function into the component scope
             // This allows components to respond to window minimize events
             let on_minimize = {
@@ -159,6 +291,49 @@ pub fn tessera(_attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
             };
 
+            // Step 4c: Inject the `on_theme_changed` function into the component scope
+            // This allows components to respond to light/dark theme changes
+            let on_theme_changed = {
+                use tessera_ui::TesseraRuntime;
+                |fun: Box<dyn Fn(tessera_ui::winit::window::Theme) + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_theme_changed(fun);
+                }
+            };
+
+            // Step 4d: Inject the window lifecycle functions into the component scope
+            // This allows components to respond to focus, size, position and visibility
+            // changes, and to prevent the window from closing
+            let on_close_requested = {
+                use tessera_ui::{TesseraRuntime, runtime::CloseDecision};
+                |fun: Box<dyn Fn() -> CloseDecision + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_close_requested(fun);
+                }
+            };
+            let on_window_focus_changed = {
+                use tessera_ui::TesseraRuntime;
+                |fun: Box<dyn Fn(bool) + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_window_focus_changed(fun);
+                }
+            };
+            let on_resized = {
+                use tessera_ui::{PxSize, TesseraRuntime};
+                |fun: Box<dyn Fn(PxSize) + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_resized(fun);
+                }
+            };
+            let on_moved = {
+                use tessera_ui::{PxPosition, TesseraRuntime};
+                |fun: Box<dyn Fn(PxPosition) + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_moved(fun);
+                }
+            };
+            let on_occluded = {
+                use tessera_ui::TesseraRuntime;
+                |fun: Box<dyn Fn(bool) + Send + Sync + 'static>| {
+                    TesseraRuntime::write().on_occluded(fun);
+                }
+            };
+
             // Step 5: Execute the original function body within a closure
             // This prevents early returns from breaking the component tree structure
             let result = {
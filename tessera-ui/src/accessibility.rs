@@ -0,0 +1,579 @@
+//! # Accessibility
+//!
+//! This module exposes Tessera applications to screen readers and other assistive
+//! technologies through [AccessKit](https://accesskit.dev).
+//!
+//! ## Overview
+//!
+//! A [`ComponentNode`](crate::ComponentNode) only knows its function name, which tells
+//! assistive technology nothing. Components therefore describe themselves with
+//! [`Semantics`]: a role, a label, a value, toggled or expanded state, the actions they
+//! support and whether they can take focus. Inside a `#[tessera]` component, the macro
+//! injects a `semantics` function for this:
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//!
+//! use tessera_ui::accessibility::{Action, Role, Semantics};
+//! use tessera_ui_macros::tessera;
+//!
+//! #[tessera]
+//! fn ok_button(on_click: Arc<dyn Fn() + Send + Sync>) {
+//!     semantics(|| {
+//!         Semantics::new(Role::Button)
+//!             .with_label("OK")
+//!             .with_action(Action::Click)
+//!             .on_action(move |action, _| {
+//!                 if action == Action::Click {
+//!                     on_click();
+//!                 }
+//!             })
+//!     });
+//! }
+//! ```
+//!
+//! ## Tree Construction
+//!
+//! Every frame, after layout, the renderer turns the component tree into an AccessKit
+//! [`TreeUpdate`]. Each node with semantics becomes an AccessKit node whose bounds are
+//! the node's absolute rectangle in physical pixels. Nodes without semantics are
+//! transparent: their semantic descendants are attached to the nearest semantic ancestor,
+//! or to the window node at the root.
+//!
+//! Action requests from assistive technology are routed back to the
+//! [`Semantics::on_action`] handler of the targeted node, on the UI thread. AccessKit ids
+//! are derived from the [`key`](crate::ComponentNode::key) of each node, which stays the
+//! same across frames, so focus and actions keep landing on the same component.
+//!
+//! Semantics are only collected while assistive technology is listening, see
+//! [`is_active`]. The `semantics` function takes a closure, which is not called otherwise.
+//!
+//! ## Headless Testing
+//!
+//! [`ComponentTree::accessibility_tree_update`](crate::ComponentTree::accessibility_tree_update)
+//! returns the same [`TreeUpdate`] without a window, so tests can build a tree and assert
+//! on the semantics it publishes, after turning collection on with [`set_active`].
+
+use std::{
+    collections::HashMap,
+    fmt,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use accesskit::{Node, NodeId, Rect, Tree, TreeId, TreeUpdate};
+use winit::{event::WindowEvent, event_loop::ActiveEventLoop, window::Window};
+
+use crate::{
+    component_tree::{ComponentNodeMetaDatas, ComponentNodeTree},
+    ui_handle::UiHandle,
+};
+
+pub use accesskit::{Action, ActionData, ActionRequest, Role, Toggled};
+
+/// The AccessKit id of the window node that roots the tree.
+pub const WINDOW_NODE_ID: NodeId = NodeId(0);
+
+/// Whether components publish their semantics, see [`is_active`].
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Returns whether components publish their semantics.
+///
+/// This is the case once assistive technology asked for the accessibility tree, until it
+/// disconnects. Until then, the `semantics` function injected by `#[tessera]` does
+/// nothing, so an application without a screen reader does not pay for semantics.
+pub fn is_active() -> bool {
+    ACTIVE.load(Ordering::Acquire)
+}
+
+/// Turns semantics collection on or off.
+///
+/// The renderer calls this when AccessKit asks for the tree and when assistive technology
+/// disconnects. Headless tests call it to assert on the semantics of components.
+pub fn set_active(active: bool) {
+    ACTIVE.store(active, Ordering::Release);
+}
+
+/// Handles an accessibility action requested for a node.
+///
+/// Receives the requested action and its optional data, such as the new value of a
+/// [`Action::SetValue`] request.
+pub type SemanticsActionHandler = dyn Fn(Action, Option<ActionData>) + Send + Sync;
+
+/// A numeric value with its range, for sliders and progress indicators.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumericValue {
+    /// The current value.
+    pub value: f64,
+    /// The smallest possible value.
+    pub min: f64,
+    /// The largest possible value.
+    pub max: f64,
+    /// The amount one increment or decrement changes the value by, if any.
+    pub step: Option<f64>,
+}
+
+/// Describes a component to assistive technology.
+///
+/// Built with [`Semantics::new`] and the chainable `with_*` methods, then published with
+/// the `semantics` function injected by `#[tessera]`, which takes a closure returning them.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::accessibility::{Action, Role, Semantics};
+///
+/// let semantics = Semantics::new(Role::CheckBox)
+///     .with_label("Remember me")
+///     .with_toggled(true)
+///     .with_focusable(true)
+///     .with_action(Action::Click);
+/// assert_eq!(semantics.label.as_deref(), Some("Remember me"));
+/// ```
+#[derive(Clone)]
+pub struct Semantics {
+    /// What kind of element the component is.
+    pub role: Role,
+    /// The accessible name, such as a button's caption.
+    pub label: Option<String>,
+    /// The textual value, such as the content of a text field.
+    pub value: Option<String>,
+    /// The numeric value and range, for sliders and progress indicators.
+    pub numeric_value: Option<NumericValue>,
+    /// The checked state of checkboxes and switches.
+    pub toggled: Option<Toggled>,
+    /// Whether a collapsible element is expanded.
+    pub expanded: Option<bool>,
+    /// Whether the component can take keyboard focus.
+    pub focusable: bool,
+    /// Whether the component currently has keyboard focus.
+    pub focused: bool,
+    /// Whether the component is disabled.
+    pub disabled: bool,
+    /// Whether the component is modal, hiding everything outside of it.
+    pub modal: bool,
+    /// The actions the component supports.
+    pub actions: Vec<Action>,
+    /// Called when assistive technology requests one of the supported actions.
+    pub action_handler: Option<Arc<SemanticsActionHandler>>,
+}
+
+impl Semantics {
+    /// Creates semantics with the given role and nothing else.
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            label: None,
+            value: None,
+            numeric_value: None,
+            toggled: None,
+            expanded: None,
+            focusable: false,
+            focused: false,
+            disabled: false,
+            modal: false,
+            actions: Vec::new(),
+            action_handler: None,
+        }
+    }
+
+    /// Sets the accessible name.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the textual value.
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Sets the numeric value and its range.
+    pub fn with_numeric_value(mut self, value: f64, min: f64, max: f64) -> Self {
+        self.numeric_value = Some(NumericValue {
+            value,
+            min,
+            max,
+            step: None,
+        });
+        self
+    }
+
+    /// Sets the step of the numeric value. Has no effect without a numeric value.
+    pub fn with_numeric_step(mut self, step: f64) -> Self {
+        if let Some(numeric_value) = &mut self.numeric_value {
+            numeric_value.step = Some(step);
+        }
+        self
+    }
+
+    /// Sets the checked state.
+    pub fn with_toggled(mut self, toggled: impl Into<Toggled>) -> Self {
+        self.toggled = Some(toggled.into());
+        self
+    }
+
+    /// Sets whether a collapsible element is expanded.
+    pub fn with_expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Sets whether the component can take keyboard focus.
+    pub fn with_focusable(mut self, focusable: bool) -> Self {
+        self.focusable = focusable;
+        self
+    }
+
+    /// Sets whether the component currently has keyboard focus.
+    pub fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the component is disabled.
+    pub fn with_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets whether the component is modal.
+    pub fn with_modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// Adds a supported action.
+    pub fn with_action(mut self, action: Action) -> Self {
+        if !self.actions.contains(&action) {
+            self.actions.push(action);
+        }
+        self
+    }
+
+    /// Sets the handler for action requests from assistive technology.
+    pub fn on_action(
+        mut self,
+        handler: impl Fn(Action, Option<ActionData>) + Send + Sync + 'static,
+    ) -> Self {
+        self.action_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Converts the semantics into an AccessKit node without children.
+    fn to_node(&self) -> Node {
+        let mut node = Node::new(self.role);
+        if let Some(label) = &self.label {
+            node.set_label(label.as_str());
+        }
+        if let Some(value) = &self.value {
+            node.set_value(value.as_str());
+        }
+        if let Some(numeric_value) = self.numeric_value {
+            node.set_numeric_value(numeric_value.value);
+            node.set_min_numeric_value(numeric_value.min);
+            node.set_max_numeric_value(numeric_value.max);
+            if let Some(step) = numeric_value.step {
+                node.set_numeric_value_step(step);
+            }
+        }
+        if let Some(toggled) = self.toggled {
+            node.set_toggled(toggled);
+        }
+        if let Some(expanded) = self.expanded {
+            node.set_expanded(expanded);
+        }
+        if self.disabled {
+            node.set_disabled();
+        }
+        if self.modal {
+            node.set_modal();
+        }
+        if self.focusable {
+            node.add_action(Action::Focus);
+        }
+        for action in &self.actions {
+            node.add_action(*action);
+        }
+        node
+    }
+}
+
+impl fmt::Debug for Semantics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Semantics")
+            .field("role", &self.role)
+            .field("label", &self.label)
+            .field("value", &self.value)
+            .field("numeric_value", &self.numeric_value)
+            .field("toggled", &self.toggled)
+            .field("expanded", &self.expanded)
+            .field("focusable", &self.focusable)
+            .field("focused", &self.focused)
+            .field("disabled", &self.disabled)
+            .field("modal", &self.modal)
+            .field("actions", &self.actions)
+            .field("action_handler", &self.action_handler.is_some())
+            .finish()
+    }
+}
+
+/// Action handlers of the nodes in the last published tree, by AccessKit id.
+pub(crate) type ActionHandlers = HashMap<NodeId, Arc<SemanticsActionHandler>>;
+
+/// Returns the AccessKit id of a component node with the given
+/// [`key`](crate::ComponentNode::key).
+///
+/// Keys stay the same across frames, so assistive technology keeps focus on the same
+/// component while the tree is rebuilt. Keys are never 0, so component nodes never
+/// collide with [`WINDOW_NODE_ID`].
+pub fn node_id(key: u64) -> NodeId {
+    NodeId(key)
+}
+
+/// Builds a full AccessKit tree from a laid out component tree.
+pub(crate) fn build_tree_update(
+    tree: &ComponentNodeTree,
+    metadatas: &ComponentNodeMetaDatas,
+    root: Option<indextree::NodeId>,
+) -> (TreeUpdate, ActionHandlers) {
+    let mut builder = TreeBuilder {
+        tree,
+        metadatas,
+        nodes: Vec::new(),
+        handlers: ActionHandlers::new(),
+        focus: WINDOW_NODE_ID,
+    };
+    let mut window_children = Vec::new();
+    if let Some(root) = root {
+        builder.visit(root, &mut window_children);
+    }
+    let mut window = Node::new(Role::Window);
+    window.set_label("Tessera");
+    window.set_children(window_children);
+    builder.nodes.push((WINDOW_NODE_ID, window));
+
+    let mut tessera_tree = Tree::new(WINDOW_NODE_ID);
+    tessera_tree.toolkit_name = Some("Tessera".to_string());
+    tessera_tree.toolkit_version = Some(env!("CARGO_PKG_VERSION").to_string());
+    let update = TreeUpdate {
+        nodes: builder.nodes,
+        tree: Some(tessera_tree),
+        tree_id: TreeId::ROOT,
+        focus: builder.focus,
+    };
+    (update, builder.handlers)
+}
+
+/// Walks the component tree, collecting the nodes that publish semantics.
+struct TreeBuilder<'a> {
+    tree: &'a ComponentNodeTree,
+    metadatas: &'a ComponentNodeMetaDatas,
+    nodes: Vec<(NodeId, Node)>,
+    handlers: ActionHandlers,
+    focus: NodeId,
+}
+
+impl TreeBuilder<'_> {
+    /// Visits `node_id`, appending the ids of its topmost semantic nodes to `parent_children`.
+    fn visit(&mut self, node_id: indextree::NodeId, parent_children: &mut Vec<NodeId>) {
+        let Some((key, semantics)) = self.tree.get(node_id).and_then(|node| {
+            let node = node.get();
+            node.semantics
+                .as_ref()
+                .map(|semantics| (node.key, semantics))
+        }) else {
+            // Transparent node: its semantic descendants belong to our parent
+            for child in node_id.children(self.tree) {
+                self.visit(child, parent_children);
+            }
+            return;
+        };
+
+        let id = self::node_id(key);
+        let mut node = semantics.to_node();
+        if let Some(metadata) = self.metadatas.get(&node_id)
+            && let (Some(position), Some(size)) = (metadata.abs_position, metadata.computed_data)
+        {
+            node.set_bounds(Rect {
+                x0: position.x.0 as f64,
+                y0: position.y.0 as f64,
+                x1: (position.x.0 + size.width.0) as f64,
+                y1: (position.y.0 + size.height.0) as f64,
+            });
+        }
+        if semantics.focused {
+            self.focus = id;
+        }
+        if let Some(handler) = &semantics.action_handler {
+            self.handlers.insert(id, handler.clone());
+        }
+
+        let mut children = Vec::new();
+        for child in node_id.children(self.tree) {
+            self.visit(child, &mut children);
+        }
+        node.set_children(children);
+        self.nodes.push((id, node));
+        parent_children.push(id);
+    }
+}
+
+/// Connects the component tree to the platform accessibility API of a window.
+pub(crate) struct AccessibilityBridge {
+    adapter: accesskit_winit::Adapter,
+    handlers: ActionHandlers,
+}
+
+impl AccessibilityBridge {
+    /// Creates the AccessKit adapter. Must be called before the window is first shown.
+    pub(crate) fn new(event_loop: &ActiveEventLoop, window: &Window, ui_handle: &UiHandle) -> Self {
+        Self {
+            adapter: accesskit_winit::Adapter::with_event_loop_proxy(
+                event_loop,
+                window,
+                ui_handle.proxy(),
+            ),
+            handlers: ActionHandlers::new(),
+        }
+    }
+
+    /// Lets the adapter observe window events such as focus changes and resizes.
+    pub(crate) fn process_event(&mut self, window: &Window, event: &WindowEvent) {
+        self.adapter.process_event(window, event);
+    }
+
+    /// Publishes a new tree if assistive technology is listening.
+    ///
+    /// `build` is only called while the adapter is active, so the tree costs nothing
+    /// when no screen reader is running.
+    pub(crate) fn update(&mut self, build: impl FnOnce() -> (TreeUpdate, ActionHandlers)) {
+        let handlers = &mut self.handlers;
+        self.adapter.update_if_active(|| {
+            let (update, new_handlers) = build();
+            *handlers = new_handlers;
+            update
+        });
+    }
+
+    /// Routes an action request to the handler of its target node.
+    pub(crate) fn handle_action(&self, request: ActionRequest) {
+        match self.handlers.get(&request.target_node) {
+            Some(handler) => handler(request.action, request.data),
+            None => log::debug!(
+                "No accessibility action handler for node {:?}",
+                request.target_node
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ComponentNode, ComponentTree, ComputedData, Px, PxPosition};
+
+    fn node(fn_name: &str, semantics: Option<Semantics>) -> ComponentNode {
+        ComponentNode {
+            fn_name: fn_name.to_string(),
+            location: None,
+            test_tag: None,
+            measure_fn: None,
+            intrinsic_fn: None,
+            state_handler_fn: None,
+            semantics,
+            context: Default::default(),
+            key: 0,
+            intrinsic_cache: Default::default(),
+        }
+    }
+
+    fn find(update: &TreeUpdate, id: NodeId) -> &Node {
+        &update
+            .nodes
+            .iter()
+            .find(|(node_id, _)| *node_id == id)
+            .unwrap()
+            .1
+    }
+
+    #[test]
+    fn transparent_nodes_are_flattened() {
+        let mut tree = ComponentTree::new();
+        tree.add_node(node("column", None));
+        tree.add_node(node(
+            "button",
+            Some(Semantics::new(Role::Button).with_action(Action::Click)),
+        ));
+        tree.add_node(node("surface", None));
+        tree.add_node(node(
+            "text",
+            Some(Semantics::new(Role::Label).with_value("OK")),
+        ));
+        tree.pop_node();
+        tree.pop_node();
+        tree.pop_node();
+        tree.add_node(node("spacer", None));
+        tree.pop_node();
+        tree.pop_node();
+
+        let update = tree.accessibility_tree_update();
+        assert_eq!(update.tree.as_ref().unwrap().root, WINDOW_NODE_ID);
+        assert_eq!(update.focus, WINDOW_NODE_ID);
+        // The window, the button and the text
+        assert_eq!(update.nodes.len(), 3);
+
+        let window = find(&update, WINDOW_NODE_ID);
+        assert_eq!(window.role(), Role::Window);
+        assert_eq!(window.children().len(), 1);
+        let button = find(&update, window.children()[0]);
+        assert_eq!(button.role(), Role::Button);
+        assert!(button.supports_action(Action::Click));
+        assert_eq!(button.children().len(), 1);
+        let text = find(&update, button.children()[0]);
+        assert_eq!(text.value(), Some("OK"));
+    }
+
+    #[test]
+    fn bounds_focus_and_state_are_published() {
+        let mut tree = ComponentTree::new();
+        tree.add_node(node(
+            "switch",
+            Some(
+                Semantics::new(Role::Switch)
+                    .with_toggled(true)
+                    .with_focusable(true)
+                    .with_focused(true),
+            ),
+        ));
+        tree.pop_node();
+        let root = tree.root_node().unwrap();
+        {
+            let mut metadata = tree.metadatas().get_mut(&root).unwrap();
+            metadata.abs_position = Some(PxPosition::new(Px(10.0), Px(20.0)));
+            metadata.computed_data = Some(ComputedData {
+                width: Px(40.0),
+                height: Px(24.0),
+            });
+        }
+
+        let update = tree.accessibility_tree_update();
+        let id = node_id(tree.node_key(root).unwrap());
+        assert_eq!(update.focus, id);
+        let switch = find(&update, id);
+        assert_eq!(switch.toggled(), Some(Toggled::True));
+        assert!(switch.supports_action(Action::Focus));
+        assert_eq!(
+            switch.bounds(),
+            Some(Rect {
+                x0: 10.0,
+                y0: 20.0,
+                x1: 50.0,
+                y1: 44.0,
+            })
+        );
+    }
+}
@@ -0,0 +1,706 @@
+//! # Animation System
+//!
+//! This module provides declarative animations for Tessera components, driven by a single
+//! shared frame clock.
+//!
+//! ## Overview
+//!
+//! - [`Lerp`] describes how a type is interpolated between two values.
+//! - [`AnimationSpec`] describes how a value travels to a new target: a [`TweenSpec`]
+//!   (duration and [`Easing`] curve), a [`SpringSpec`] (damping ratio and stiffness), or an
+//!   instant [`AnimationSpec::Snap`].
+//! - [`Animatable`] holds a value that animates towards its target whenever the target
+//!   changes. It is usually stored in component state, for example in an `Arc<RwLock<_>>`.
+//! - [`InfiniteAnimation`] repeats a tween forever, restarting or reversing each iteration.
+//! - [`animate_float_as_state`], [`animate_color_as_state`], [`animate_dp_as_state`] and
+//!   [`animate_value_as_state`] keep the animation state for you, identified by a key in
+//!   the same way as [`launch`](crate::effect::launch). The state is dropped once a frame
+//!   is built without the key.
+//!
+//! ## Frame Clock
+//!
+//! All animations read time from [`now`], which the renderer samples once at the start of
+//! every frame. Reading a running animation requests another frame through
+//! [`TesseraRuntime::request_redraw`](crate::TesseraRuntime::request_redraw), so animations
+//! keep the frames coming until they settle. Install a [`TestClock`] with
+//! [`install_clock`] to make animations deterministic in tests.
+//!
+//! ## Usage
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use tessera_ui::animation::{
+//!     Animatable, AnimationSpec, Easing, TestClock, install_clock, reset_clock,
+//! };
+//!
+//! let clock = TestClock::new();
+//! install_clock(clock.clone());
+//!
+//! let mut offset = Animatable::new(0.0f32)
+//!     .with_spec(AnimationSpec::tween(Duration::from_millis(100), Easing::Linear));
+//! offset.animate_to(10.0);
+//!
+//! clock.advance(Duration::from_millis(50));
+//! assert_eq!(offset.value(), 5.0);
+//!
+//! clock.advance(Duration::from_millis(50));
+//! assert_eq!(offset.value(), 10.0);
+//! assert!(!offset.is_running());
+//!
+//! reset_clock();
+//! ```
+
+mod clock;
+mod easing;
+mod spec;
+
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::OnceLock,
+    time::Duration,
+};
+
+use parking_lot::{Mutex, MutexGuard};
+
+use crate::{
+    Color, Dp, Px, PxPosition, PxSize, effect::current_scoped_key, runtime::TesseraRuntime,
+};
+
+pub use clock::{Clock, TestClock, install_clock, now, reset_clock};
+pub use easing::Easing;
+pub use spec::{AnimationSpec, RepeatMode, SpringSpec, TweenSpec};
+
+pub(crate) use clock::{begin_frame, clock_now};
+
+/// Linear interpolation between two values of the same type.
+///
+/// `fraction` is usually in `[0.0, 1.0]`, but springs and some easing curves overshoot,
+/// so implementations should extrapolate for values outside of that range.
+pub trait Lerp {
+    /// Returns the value at `fraction` between `self` (`0.0`) and `target` (`1.0`).
+    fn lerp(&self, target: &Self, fraction: f32) -> Self;
+
+    /// Returns the numbers making up the value, for example `[r, g, b, a]` for a color.
+    ///
+    /// A retargeted [`Animatable`] uses them to keep moving at the speed it had. Types
+    /// returning `None`, the default, start every new animation at rest instead.
+    fn components(&self) -> Option<Vec<f32>> {
+        None
+    }
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        self + (target - self) * fraction
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![*self])
+    }
+}
+
+impl Lerp for f64 {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        self + (target - self) * fraction as f64
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![*self as f32])
+    }
+}
+
+impl<const N: usize> Lerp for [f32; N] {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        std::array::from_fn(|i| self[i].lerp(&target[i], fraction))
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(self.to_vec())
+    }
+}
+
+impl Lerp for Dp {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        Dp(self.0.lerp(&target.0, fraction))
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        self.0.components()
+    }
+}
+
+impl Lerp for Px {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        Px::saturating_from_f32(self.to_f32().lerp(&target.to_f32(), fraction))
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![self.to_f32()])
+    }
+}
+
+impl Lerp for PxPosition {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        PxPosition::new(
+            self.x.lerp(&target.x, fraction),
+            self.y.lerp(&target.y, fraction),
+        )
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![self.x.to_f32(), self.y.to_f32()])
+    }
+}
+
+impl Lerp for PxSize {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        PxSize::new(
+            self.width.lerp(&target.width, fraction),
+            self.height.lerp(&target.height, fraction),
+        )
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![self.width.to_f32(), self.height.to_f32()])
+    }
+}
+
+impl Lerp for Color {
+    fn lerp(&self, target: &Self, fraction: f32) -> Self {
+        Color::lerp(self, target, fraction)
+    }
+
+    fn components(&self) -> Option<Vec<f32>> {
+        Some(vec![self.r, self.g, self.b, self.a])
+    }
+}
+
+/// A value that animates towards a target.
+///
+/// Setting a new target with [`animate_to`](Self::animate_to) starts an animation from the
+/// current value, keeping the velocity of a running spring. Reading the value with [`value`](Self::value) samples the animation at
+/// the current frame time.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::{Color, animation::{Animatable, AnimationSpec, SpringSpec}};
+///
+/// let mut color = Animatable::new(Color::WHITE)
+///     .with_spec(AnimationSpec::Spring(SpringSpec::default()));
+/// color.animate_to(Color::BLACK);
+/// assert_eq!(color.target(), &Color::BLACK);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Animatable<T> {
+    /// The value the current animation started from.
+    start: T,
+    /// The value the current animation moves to.
+    target: T,
+    /// The spec used by [`animate_to`](Self::animate_to).
+    spec: AnimationSpec,
+    /// The spec of the current animation.
+    running_spec: AnimationSpec,
+    /// Frame clock time at which the current animation started.
+    start_time: Duration,
+    /// The velocity the current animation started with, in fractions per second.
+    initial_velocity: f32,
+}
+
+impl<T: Lerp + Clone + PartialEq> Animatable<T> {
+    /// Creates an animatable resting at `value`, using the default [`AnimationSpec`].
+    pub fn new(value: T) -> Self {
+        Self {
+            start: value.clone(),
+            target: value,
+            spec: AnimationSpec::default(),
+            running_spec: AnimationSpec::Snap,
+            start_time: Duration::ZERO,
+            initial_velocity: 0.0,
+        }
+    }
+
+    /// Returns the same animatable using `spec` for future animations.
+    pub fn with_spec(mut self, spec: AnimationSpec) -> Self {
+        self.spec = spec;
+        self
+    }
+
+    /// Returns the spec used for future animations.
+    pub fn spec(&self) -> AnimationSpec {
+        self.spec
+    }
+
+    /// Sets the spec used for future animations. A running animation is not affected.
+    pub fn set_spec(&mut self, spec: AnimationSpec) {
+        self.spec = spec;
+    }
+
+    /// Returns the value the animatable is moving to, or resting at.
+    pub fn target(&self) -> &T {
+        &self.target
+    }
+
+    /// Animates from the current value to `target` using the configured spec.
+    ///
+    /// Does nothing if `target` already is the target.
+    pub fn animate_to(&mut self, target: T) {
+        self.animate_to_with(target, self.spec);
+    }
+
+    /// Animates from the current value to `target` using `spec` for this animation only.
+    ///
+    /// Does nothing if `target` already is the target.
+    pub fn animate_to_with(&mut self, target: T, spec: AnimationSpec) {
+        self.retarget_at(target, spec, now());
+    }
+
+    fn retarget_at(&mut self, target: T, spec: AnimationSpec, time: Duration) {
+        if target == self.target {
+            return;
+        }
+        let (_, velocity, finished) = self.sample(time);
+        let start = self.value_at(time);
+        self.initial_velocity = if finished {
+            0.0
+        } else {
+            carry_velocity(velocity, [&self.start, &self.target], [&start, &target])
+        };
+        self.start = start;
+        self.target = target;
+        self.running_spec = spec;
+        self.start_time = time;
+    }
+
+    /// Jumps to `value` immediately, cancelling any running animation.
+    pub fn snap_to(&mut self, value: T) {
+        self.start = value.clone();
+        self.target = value;
+        self.running_spec = AnimationSpec::Snap;
+        self.initial_velocity = 0.0;
+    }
+
+    /// Returns the value at the current frame time.
+    ///
+    /// While the animation is running, this also requests another frame.
+    pub fn value(&self) -> T {
+        let now = now();
+        if self.is_running_at(now) {
+            TesseraRuntime::request_redraw();
+        }
+        self.value_at(now)
+    }
+
+    /// Returns the value at a given frame clock time.
+    pub fn value_at(&self, time: Duration) -> T {
+        let (fraction, _, finished) = self.sample(time);
+        if finished {
+            self.target.clone()
+        } else {
+            self.start.lerp(&self.target, fraction)
+        }
+    }
+
+    /// Returns the animation progress in fractions of the animated distance at the current
+    /// frame time: `0.0` at the start value and `1.0` at the target.
+    pub fn fraction(&self) -> f32 {
+        self.sample(now()).0
+    }
+
+    /// Returns whether an animation is running at the current frame time.
+    pub fn is_running(&self) -> bool {
+        self.is_running_at(now())
+    }
+
+    fn is_running_at(&self, time: Duration) -> bool {
+        !self.sample(time).2
+    }
+
+    fn sample(&self, time: Duration) -> (f32, f32, bool) {
+        if self.start == self.target {
+            return (1.0, 0.0, true);
+        }
+        let elapsed = time.saturating_sub(self.start_time);
+        self.running_spec.sample(elapsed, self.initial_velocity)
+    }
+}
+
+/// Converts a velocity in fractions per second of the distance `from[0]` to `from[1]` into
+/// fractions per second of the distance `to[0]` to `to[1]`.
+///
+/// Only the part of the velocity pointing towards the new target is kept.
+fn carry_velocity<T: Lerp>(velocity: f32, from: [&T; 2], to: [&T; 2]) -> f32 {
+    let difference = |[start, end]: [&T; 2]| {
+        let start = start.components()?;
+        let end = end.components()?;
+        Some(
+            end.iter()
+                .zip(start)
+                .map(|(end, start)| end - start)
+                .collect::<Vec<_>>(),
+        )
+    };
+    let (Some(from), Some(to)) = (difference(from), difference(to)) else {
+        return 0.0;
+    };
+    let length_squared: f32 = to.iter().map(|x| x * x).sum();
+    if length_squared == 0.0 {
+        return 0.0;
+    }
+    let dot: f32 = from.iter().zip(&to).map(|(a, b)| a * b).sum();
+    velocity * dot / length_squared
+}
+
+/// A tween that repeats forever between two values.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tessera_ui::animation::{Easing, InfiniteAnimation, RepeatMode, TweenSpec};
+///
+/// let pulse = InfiniteAnimation::new(
+///     0.5f32,
+///     1.0,
+///     TweenSpec::new(Duration::from_millis(800), Easing::EaseInOut),
+///     RepeatMode::Reverse,
+/// );
+/// let alpha = pulse.value();
+/// assert!((0.5..=1.0).contains(&alpha));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InfiniteAnimation<T> {
+    from: T,
+    to: T,
+    spec: TweenSpec,
+    repeat_mode: RepeatMode,
+    start_time: Duration,
+}
+
+impl<T: Lerp + Clone> InfiniteAnimation<T> {
+    /// Creates an infinite animation starting at the current frame time.
+    pub fn new(from: T, to: T, spec: TweenSpec, repeat_mode: RepeatMode) -> Self {
+        Self {
+            from,
+            to,
+            spec,
+            repeat_mode,
+            start_time: now(),
+        }
+    }
+
+    /// Returns the value at the current frame time and requests another frame.
+    pub fn value(&self) -> T {
+        TesseraRuntime::request_redraw();
+        self.value_at(now())
+    }
+
+    /// Returns the value at a given frame clock time.
+    pub fn value_at(&self, time: Duration) -> T {
+        let elapsed = time.saturating_sub(self.start_time);
+        let fraction = self.repeat_mode.fraction(&self.spec, elapsed);
+        self.from.lerp(&self.to, fraction)
+    }
+}
+
+/// Keyed animation states, kept alive while their keys are used every frame.
+#[derive(Default)]
+struct AnimationRegistry {
+    states: HashMap<u64, Box<dyn Any + Send + Sync>>,
+    alive: HashSet<u64>,
+}
+
+static ANIMATIONS: OnceLock<Mutex<AnimationRegistry>> = OnceLock::new();
+
+fn animations() -> MutexGuard<'static, AnimationRegistry> {
+    ANIMATIONS
+        .get_or_init(|| Mutex::new(AnimationRegistry::default()))
+        .lock()
+}
+
+impl AnimationRegistry {
+    /// Returns the state of type `S` under `key`, creating it with `init` if it is missing
+    /// or has a different type.
+    fn state<S: Send + Sync + 'static>(&mut self, key: u64, init: impl FnOnce() -> S) -> &mut S {
+        self.alive.insert(key);
+        let initialized = self.states.get(&key).is_some_and(|state| state.is::<S>());
+        if !initialized {
+            self.states.insert(key, Box::new(init()));
+        }
+        self.states.get_mut(&key).unwrap().downcast_mut().unwrap()
+    }
+
+    /// Drops the states whose key was not used since the last call.
+    fn drop_stale(&mut self) {
+        let Self { states, alive } = self;
+        states.retain(|key, _| alive.contains(key));
+        alive.clear();
+    }
+}
+
+/// Returns the state of type `S` under `key` in the calling component, creating it with
+/// `init` if it is missing or has a different type, then applies `f` to it.
+fn with_keyed_state<K, S, R>(key: K, init: impl FnOnce() -> S, f: impl FnOnce(&mut S) -> R) -> R
+where
+    K: Hash + 'static,
+    S: Send + Sync + 'static,
+{
+    let key = current_scoped_key(&key);
+    f(animations().state(key, init))
+}
+
+/// Drops keyed animation states whose key was not used while building the last frame.
+///
+/// Called by the renderer right after the entry point has built the component tree.
+pub(crate) fn drop_stale_animations() {
+    animations().drop_stale();
+}
+
+/// Animates a value towards `target`, keeping the animation state under `key`.
+///
+/// On the first call the value starts at `target`. Whenever a later frame passes a
+/// different target, the value animates to it from wherever it currently is, using `spec`.
+/// The state is dropped once a frame is built without `key`. Like
+/// [effect keys](crate::effect), keys are scoped to the calling component, so components
+/// using the same key each get their own animation.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::{Dp, animation::{AnimationSpec, animate_value_as_state}};
+///
+/// let expanded = true;
+/// let height = animate_value_as_state(
+///     "panel-height",
+///     if expanded { Dp(200.0) } else { Dp(48.0) },
+///     AnimationSpec::default(),
+/// );
+/// assert_eq!(height, Dp(200.0));
+/// ```
+pub fn animate_value_as_state<K, T>(key: K, target: T, spec: AnimationSpec) -> T
+where
+    K: Hash + 'static,
+    T: Lerp + Clone + PartialEq + Send + Sync + 'static,
+{
+    let initial = target.clone();
+    with_keyed_state(
+        key,
+        || Animatable::new(initial),
+        |animatable: &mut Animatable<T>| {
+            animatable.animate_to_with(target, spec);
+            animatable.value()
+        },
+    )
+}
+
+/// Animates an `f32` towards `target`. See [`animate_value_as_state`].
+pub fn animate_float_as_state<K: Hash + 'static>(key: K, target: f32, spec: AnimationSpec) -> f32 {
+    animate_value_as_state(key, target, spec)
+}
+
+/// Animates a [`Color`] towards `target`. See [`animate_value_as_state`].
+pub fn animate_color_as_state<K: Hash + 'static>(
+    key: K,
+    target: Color,
+    spec: AnimationSpec,
+) -> Color {
+    animate_value_as_state(key, target, spec)
+}
+
+/// Animates a [`Dp`] towards `target`. See [`animate_value_as_state`].
+pub fn animate_dp_as_state<K: Hash + 'static>(key: K, target: Dp, spec: AnimationSpec) -> Dp {
+    animate_value_as_state(key, target, spec)
+}
+
+/// Runs an infinite animation between `from` and `to`, keeping its state under `key`.
+///
+/// The animation starts the first time `key` is used and is dropped once a frame is
+/// built without it.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tessera_ui::animation::{Easing, RepeatMode, TweenSpec, animate_infinite_as_state};
+///
+/// let rotation = animate_infinite_as_state(
+///     "spinner",
+///     0.0f32,
+///     360.0,
+///     TweenSpec::new(Duration::from_secs(1), Easing::Linear),
+///     RepeatMode::Restart,
+/// );
+/// assert!((0.0..360.0).contains(&rotation));
+/// ```
+pub fn animate_infinite_as_state<K, T>(
+    key: K,
+    from: T,
+    to: T,
+    spec: TweenSpec,
+    repeat_mode: RepeatMode,
+) -> T
+where
+    K: Hash + 'static,
+    T: Lerp + Clone + Send + Sync + 'static,
+{
+    with_keyed_state(
+        key,
+        || InfiniteAnimation::new(from, to, spec, repeat_mode),
+        |animation: &mut InfiniteAnimation<T>| animation.value(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_tween_progress() {
+        let tween = AnimationSpec::tween(ms(100), Easing::Linear);
+        assert_eq!(tween.sample(ms(0), 0.0), (0.0, 0.0, false));
+        assert_eq!(tween.sample(ms(50), 0.0), (0.5, 0.0, false));
+        assert_eq!(tween.sample(ms(100), 0.0), (1.0, 0.0, true));
+        assert_eq!(tween.sample(ms(200), 0.0), (1.0, 0.0, true));
+    }
+
+    #[test]
+    fn test_tween_delay() {
+        let tween = TweenSpec::new(ms(100), Easing::Linear).with_delay(ms(50));
+        let spec = AnimationSpec::Tween(tween);
+        assert_eq!(spec.sample(ms(25), 0.0).0, 0.0);
+        assert_eq!(spec.sample(ms(100), 0.0).0, 0.5);
+        assert!(spec.sample(ms(150), 0.0).2);
+    }
+
+    #[test]
+    fn test_easing_end_points() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::FastOutSlowIn,
+        ] {
+            assert_eq!(easing.transform(0.0), 0.0);
+            assert_eq!(easing.transform(1.0), 1.0);
+        }
+        assert!((Easing::EaseInOut.transform(0.5) - 0.5).abs() < 1e-3);
+        assert!(Easing::EaseIn.transform(0.5) < 0.5);
+        assert!(Easing::EaseOut.transform(0.5) > 0.5);
+    }
+
+    #[test]
+    fn test_spring_settles() {
+        for damping_ratio in [0.2, 1.0, 2.0] {
+            let spring = AnimationSpec::spring(damping_ratio, SpringSpec::STIFFNESS_MEDIUM);
+            let (fraction, _, finished) = spring.sample(ms(0), 0.0);
+            assert_eq!(fraction, 0.0);
+            assert!(!finished);
+            let (fraction, velocity, finished) = spring.sample(Duration::from_secs(10), 0.0);
+            assert_eq!((fraction, velocity), (1.0, 0.0));
+            assert!(finished);
+        }
+    }
+
+    #[test]
+    fn test_bouncy_spring_overshoots() {
+        let spring = AnimationSpec::spring(
+            SpringSpec::DAMPING_RATIO_HIGH_BOUNCY,
+            SpringSpec::STIFFNESS_MEDIUM,
+        );
+        let overshoots = (0..100).any(|step| spring.sample(ms(step * 5), 0.0).0 > 1.0);
+        assert!(overshoots);
+    }
+
+    #[test]
+    fn test_repeat_modes() {
+        let tween = TweenSpec::new(ms(100), Easing::Linear);
+        assert_eq!(RepeatMode::Restart.fraction(&tween, ms(125)), 0.25);
+        assert_eq!(RepeatMode::Reverse.fraction(&tween, ms(125)), 0.75);
+        assert_eq!(RepeatMode::Reverse.fraction(&tween, ms(225)), 0.25);
+    }
+
+    #[test]
+    fn test_animatable_value_at() {
+        let mut animatable =
+            Animatable::new(0.0f32).with_spec(AnimationSpec::tween(ms(100), Easing::Linear));
+        animatable.start_time = ms(1000);
+        animatable.target = 10.0;
+        animatable.running_spec = animatable.spec;
+        assert_eq!(animatable.value_at(ms(1000)), 0.0);
+        assert_eq!(animatable.value_at(ms(1050)), 5.0);
+        assert_eq!(animatable.value_at(ms(1100)), 10.0);
+        assert!(!animatable.is_running_at(ms(1100)));
+        animatable.snap_to(3.0);
+        assert_eq!(animatable.value_at(ms(1050)), 3.0);
+    }
+
+    #[test]
+    fn test_retargeted_spring_keeps_velocity() {
+        let spec = AnimationSpec::spring(1.0, SpringSpec::STIFFNESS_MEDIUM);
+        let mut animatable = Animatable::new(0.0f32).with_spec(spec);
+        animatable.retarget_at(10.0, spec, ms(1000));
+        let before = animatable.value_at(ms(1099));
+        let at = animatable.value_at(ms(1100));
+        animatable.retarget_at(20.0, spec, ms(1100));
+        assert_eq!(animatable.value_at(ms(1100)), at);
+        // Pulled further away, the value speeds up instead of starting again from rest
+        let after = animatable.value_at(ms(1101));
+        let (speed_before, speed_after) = (at - before, after - at);
+        assert!(speed_before > 0.0);
+        assert!(speed_after > speed_before);
+    }
+
+    #[test]
+    fn test_carry_velocity() {
+        // Half the distance ahead: the same speed is twice the fraction per second
+        assert_eq!(carry_velocity(1.0, [&0.0f32, &10.0], [&5.0, &10.0]), 2.0);
+        // Turning around reverses the velocity
+        assert_eq!(carry_velocity(1.0, [&0.0f32, &10.0], [&5.0, &0.0]), -2.0);
+        // Moving sideways keeps none of it
+        assert_eq!(
+            carry_velocity(1.0, [&[0.0, 0.0], &[10.0, 0.0]], [&[5.0, 0.0], &[5.0, 5.0]]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_keyed_states_are_scoped_to_components() {
+        use crate::effect::scoped_key;
+
+        fn animatable(registry: &mut AnimationRegistry, key: u64) -> &mut Animatable<f32> {
+            registry.state(key, || Animatable::new(0.0))
+        }
+
+        let spec = AnimationSpec::tween(ms(100), Easing::Linear);
+        let mut registry = AnimationRegistry::default();
+        let first = scoped_key(Some(1), &"alpha");
+        let second = scoped_key(Some(2), &"alpha");
+        animatable(&mut registry, first).retarget_at(1.0, spec, ms(0));
+        animatable(&mut registry, second).retarget_at(-1.0, spec, ms(20));
+        assert_eq!(animatable(&mut registry, first).value_at(ms(50)), 0.5);
+        assert_eq!(animatable(&mut registry, second).value_at(ms(70)), -0.5);
+
+        // Only the states still in use survive
+        registry.drop_stale();
+        animatable(&mut registry, first);
+        registry.drop_stale();
+        assert_eq!(registry.states.len(), 1);
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(Px(0.0).lerp(&Px(10.0), 0.5), Px(5.0));
+        assert_eq!([0.0, 2.0].lerp(&[2.0, 4.0], 0.5), [1.0, 3.0]);
+        assert_eq!(Dp(10.0).lerp(&Dp(20.0), 0.5), Dp(15.0));
+    }
+}
@@ -0,0 +1,141 @@
+//! # Frame Clock
+//!
+//! All animations read time from a single monotonic frame clock. The renderer samples
+//! the clock once at the start of every frame, so every animation in a frame observes
+//! exactly the same timestamp, no matter how long building and measuring take.
+//!
+//! A different [`Clock`] can be installed with [`install_clock`], which is how tests make
+//! animations deterministic with a [`TestClock`].
+
+use std::{
+    sync::{Arc, OnceLock},
+    time::{Duration, Instant},
+};
+
+use parking_lot::{Mutex, RwLock};
+
+/// A monotonic time source for animations.
+///
+/// Times are durations since an arbitrary, fixed origin.
+pub trait Clock: Send + Sync {
+    /// Returns the current time.
+    fn now(&self) -> Duration;
+}
+
+/// The default clock, backed by [`Instant`].
+struct SystemClock {
+    origin: Instant,
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// A manually driven clock for deterministic animations in tests.
+///
+/// Clones share the same time, so a test can keep one handle and install another.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tessera_ui::animation::{Clock, TestClock};
+///
+/// let clock = TestClock::new();
+/// clock.advance(Duration::from_millis(16));
+/// assert_eq!(clock.now(), Duration::from_millis(16));
+/// ```
+#[derive(Clone, Default)]
+pub struct TestClock {
+    time: Arc<Mutex<Duration>>,
+}
+
+impl TestClock {
+    /// Creates a test clock starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the clock forward by `delta`.
+    pub fn advance(&self, delta: Duration) {
+        *self.time.lock() += delta;
+    }
+
+    /// Sets the clock to an absolute time.
+    ///
+    /// The clock should only move forward, since animations assume a monotonic clock.
+    pub fn set(&self, time: Duration) {
+        *self.time.lock() = time;
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Duration {
+        *self.time.lock()
+    }
+}
+
+/// The installed clock and the timestamp sampled for the current frame.
+struct FrameClock {
+    clock: Arc<dyn Clock>,
+    frame_time: Option<Duration>,
+}
+
+static FRAME_CLOCK: OnceLock<RwLock<FrameClock>> = OnceLock::new();
+
+fn frame_clock() -> &'static RwLock<FrameClock> {
+    FRAME_CLOCK.get_or_init(|| {
+        RwLock::new(FrameClock {
+            clock: Arc::new(SystemClock {
+                origin: Instant::now(),
+            }),
+            frame_time: None,
+        })
+    })
+}
+
+/// Returns the time of the current frame.
+///
+/// Outside of a running renderer, or right after a clock was installed, this reads the
+/// installed clock directly.
+pub fn now() -> Duration {
+    let frame_clock = frame_clock().read();
+    frame_clock
+        .frame_time
+        .unwrap_or_else(|| frame_clock.clock.now())
+}
+
+/// Reads the installed clock directly, ignoring the sampled frame time.
+///
+/// Used to schedule wake-ups between frames, where the frame time is already stale.
+pub(crate) fn clock_now() -> Duration {
+    frame_clock().read().clock.now()
+}
+
+/// Replaces the clock used by all animations.
+///
+/// Animations that are already running keep their start times, so installing a clock is
+/// best done before any animation starts.
+pub fn install_clock(clock: impl Clock + 'static) {
+    let mut frame_clock = frame_clock().write();
+    frame_clock.clock = Arc::new(clock);
+    frame_clock.frame_time = None;
+}
+
+/// Restores the default system clock.
+pub fn reset_clock() {
+    install_clock(SystemClock {
+        origin: Instant::now(),
+    });
+}
+
+/// Samples the clock for a new frame.
+///
+/// Called by the renderer before the component tree is built.
+pub(crate) fn begin_frame() {
+    let mut frame_clock = frame_clock().write();
+    frame_clock.frame_time = Some(frame_clock.clock.now());
+}
@@ -0,0 +1,95 @@
+//! Easing curves that map linear time progress to animation progress.
+
+/// An easing curve for tween animations.
+///
+/// Curves map a linear time fraction in `[0.0, 1.0]` to an animation fraction. The named
+/// curves are the usual cubic Bézier presets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+    /// Starts slowly and accelerates, `cubic-bezier(0.42, 0, 1, 1)`.
+    EaseIn,
+    /// Starts quickly and decelerates, `cubic-bezier(0, 0, 0.58, 1)`.
+    EaseOut,
+    /// Accelerates, then decelerates, `cubic-bezier(0.42, 0, 0.58, 1)`.
+    EaseInOut,
+    /// The Material standard curve, `cubic-bezier(0.4, 0, 0.2, 1)`.
+    FastOutSlowIn,
+    /// A custom cubic Bézier curve through `(0, 0)`, `(x1, y1)`, `(x2, y2)` and `(1, 1)`.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Applies the curve to a linear time fraction.
+    ///
+    /// The input is clamped to `[0.0, 1.0]`. The result may leave that range for custom
+    /// curves with control points outside of it.
+    ///
+    /// ```
+    /// use tessera_ui::animation::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.transform(0.25), 0.25);
+    /// assert!(Easing::EaseOut.transform(0.25) > 0.25);
+    /// assert_eq!(Easing::EaseInOut.transform(1.0), 1.0);
+    /// ```
+    pub fn transform(&self, fraction: f32) -> f32 {
+        let fraction = fraction.clamp(0.0, 1.0);
+        match *self {
+            Self::Linear => fraction,
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, fraction),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, fraction),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, fraction),
+            Self::FastOutSlowIn => cubic_bezier(0.4, 0.0, 0.2, 1.0, fraction),
+            Self::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1, y1, x2, y2, fraction),
+        }
+    }
+}
+
+/// Evaluates one coordinate of a cubic Bézier curve with end points 0 and 1.
+fn bezier_component(p1: f32, p2: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+    3.0 * u * u * t * p1 + 3.0 * u * t * t * p2 + t * t * t
+}
+
+/// Derivative of [`bezier_component`] with respect to `t`.
+fn bezier_derivative(p1: f32, p2: f32, t: f32) -> f32 {
+    let u = 1.0 - t;
+    3.0 * u * u * p1 + 6.0 * u * t * (p2 - p1) + 3.0 * t * t * (1.0 - p2)
+}
+
+/// Returns the `y` of the curve at horizontal position `x`.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    if x <= 0.0 || x >= 1.0 {
+        return x;
+    }
+    // Newton's method converges quickly for well-behaved curves
+    let mut t = x;
+    for _ in 0..8 {
+        let error = bezier_component(x1, x2, t) - x;
+        if error.abs() < 1e-6 {
+            return bezier_component(y1, y2, t);
+        }
+        let slope = bezier_derivative(x1, x2, t);
+        if slope.abs() < 1e-6 {
+            break;
+        }
+        t -= error / slope;
+    }
+    // Fall back to bisection, which always converges since x(t) is monotonic
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+    t = x;
+    for _ in 0..32 {
+        let value = bezier_component(x1, x2, t);
+        if (value - x).abs() < 1e-6 {
+            break;
+        }
+        if value < x {
+            low = t;
+        } else {
+            high = t;
+        }
+        t = (low + high) / 2.0;
+    }
+    bezier_component(y1, y2, t)
+}
@@ -0,0 +1,240 @@
+//! Animation specifications: tweens, springs and repeat modes.
+//!
+//! Every spec describes how a normalized fraction travels from `0.0` (the start value)
+//! to `1.0` (the target value) over time. Springs may overshoot past `1.0`.
+
+use std::time::Duration;
+
+use super::Easing;
+
+/// A duration-based animation following an [`Easing`] curve.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TweenSpec {
+    /// How long the animation runs, excluding the delay.
+    pub duration: Duration,
+    /// The easing curve applied to the time fraction.
+    pub easing: Easing,
+    /// How long to wait before the animation starts moving.
+    pub delay: Duration,
+}
+
+impl TweenSpec {
+    /// Creates a tween without delay.
+    pub const fn new(duration: Duration, easing: Easing) -> Self {
+        Self {
+            duration,
+            easing,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// Returns the same tween, delayed by `delay`.
+    pub const fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Returns the eased fraction after `elapsed`, and whether the tween has finished.
+    fn fraction(&self, elapsed: Duration) -> (f32, bool) {
+        let Some(running) = elapsed.checked_sub(self.delay) else {
+            return (0.0, false);
+        };
+        if self.duration.is_zero() || running >= self.duration {
+            return (1.0, true);
+        }
+        let linear = running.as_secs_f32() / self.duration.as_secs_f32();
+        (self.easing.transform(linear), false)
+    }
+}
+
+impl Default for TweenSpec {
+    /// A 300 ms tween using [`Easing::FastOutSlowIn`].
+    fn default() -> Self {
+        Self::new(Duration::from_millis(300), Easing::FastOutSlowIn)
+    }
+}
+
+/// A physics-based animation modelled as a damped spring with unit mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpringSpec {
+    /// `1.0` is critically damped (no bounce), lower values bounce, higher values are sluggish.
+    pub damping_ratio: f32,
+    /// Spring stiffness; higher values move faster.
+    pub stiffness: f32,
+    /// The spring is considered settled once both its distance to the target and its
+    /// velocity fall below this threshold, measured in fractions of the animated distance.
+    pub visibility_threshold: f32,
+}
+
+impl SpringSpec {
+    /// Damping ratio for a spring that does not bounce.
+    pub const DAMPING_RATIO_NO_BOUNCY: f32 = 1.0;
+    /// Damping ratio for a spring with a slight bounce.
+    pub const DAMPING_RATIO_LOW_BOUNCY: f32 = 0.75;
+    /// Damping ratio for a spring with a noticeable bounce.
+    pub const DAMPING_RATIO_MEDIUM_BOUNCY: f32 = 0.5;
+    /// Damping ratio for a very bouncy spring.
+    pub const DAMPING_RATIO_HIGH_BOUNCY: f32 = 0.2;
+    /// A very stiff, fast spring.
+    pub const STIFFNESS_HIGH: f32 = 10_000.0;
+    /// A moderately stiff spring.
+    pub const STIFFNESS_MEDIUM: f32 = 1_500.0;
+    /// A spring between medium and low stiffness.
+    pub const STIFFNESS_MEDIUM_LOW: f32 = 400.0;
+    /// A soft spring.
+    pub const STIFFNESS_LOW: f32 = 200.0;
+    /// A very soft, slow spring.
+    pub const STIFFNESS_VERY_LOW: f32 = 50.0;
+
+    /// Creates a spring with the default visibility threshold.
+    pub const fn new(damping_ratio: f32, stiffness: f32) -> Self {
+        Self {
+            damping_ratio,
+            stiffness,
+            visibility_threshold: 0.001,
+        }
+    }
+
+    /// Returns the displacement from the target and the velocity after `elapsed` seconds,
+    /// starting at displacement `-1.0` with `initial_velocity`.
+    fn solve(&self, elapsed: f32, initial_velocity: f32) -> (f32, f32) {
+        let omega = self.stiffness.max(f32::EPSILON).sqrt();
+        let zeta = self.damping_ratio.max(0.0);
+        let d0 = -1.0;
+        let v0 = initial_velocity;
+        let t = elapsed;
+        if zeta < 1.0 {
+            // Under-damped
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let a = d0;
+            let b = (v0 + zeta * omega * d0) / omega_d;
+            let decay = (-zeta * omega * t).exp();
+            let (sin, cos) = (omega_d * t).sin_cos();
+            let displacement = decay * (a * cos + b * sin);
+            let velocity = decay
+                * ((b * omega_d - zeta * omega * a) * cos - (a * omega_d + zeta * omega * b) * sin);
+            (displacement, velocity)
+        } else if zeta == 1.0 {
+            // Critically damped
+            let a = d0;
+            let b = v0 + omega * d0;
+            let decay = (-omega * t).exp();
+            let displacement = (a + b * t) * decay;
+            let velocity = (b - omega * (a + b * t)) * decay;
+            (displacement, velocity)
+        } else {
+            // Over-damped
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+            let c2 = (v0 - r1 * d0) / (r2 - r1);
+            let c1 = d0 - c2;
+            let (e1, e2) = ((r1 * t).exp(), (r2 * t).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+
+    /// Returns the fraction and velocity after `elapsed`, and whether the spring settled.
+    fn fraction(&self, elapsed: Duration, initial_velocity: f32) -> (f32, f32, bool) {
+        let (displacement, velocity) = self.solve(elapsed.as_secs_f32(), initial_velocity);
+        if displacement.abs() < self.visibility_threshold
+            && velocity.abs() < self.visibility_threshold
+        {
+            return (1.0, 0.0, true);
+        }
+        (1.0 + displacement, velocity, false)
+    }
+}
+
+impl Default for SpringSpec {
+    /// A spring without bounce and medium stiffness.
+    fn default() -> Self {
+        Self::new(Self::DAMPING_RATIO_NO_BOUNCY, Self::STIFFNESS_MEDIUM)
+    }
+}
+
+/// Describes how an animated value travels to a new target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnimationSpec {
+    /// Jump to the target immediately.
+    Snap,
+    /// A duration-based animation.
+    Tween(TweenSpec),
+    /// A physics-based spring animation.
+    Spring(SpringSpec),
+}
+
+impl AnimationSpec {
+    /// Shorthand for a tween of `duration` following `easing`.
+    pub const fn tween(duration: Duration, easing: Easing) -> Self {
+        Self::Tween(TweenSpec::new(duration, easing))
+    }
+
+    /// Shorthand for a spring with the given damping ratio and stiffness.
+    pub const fn spring(damping_ratio: f32, stiffness: f32) -> Self {
+        Self::Spring(SpringSpec::new(damping_ratio, stiffness))
+    }
+
+    /// Returns the fraction and velocity (in fractions per second) after `elapsed`,
+    /// and whether the animation has finished.
+    pub(crate) fn sample(&self, elapsed: Duration, initial_velocity: f32) -> (f32, f32, bool) {
+        match self {
+            Self::Snap => (1.0, 0.0, true),
+            Self::Tween(tween) => {
+                let (fraction, finished) = tween.fraction(elapsed);
+                (fraction, 0.0, finished)
+            }
+            Self::Spring(spring) => spring.fraction(elapsed, initial_velocity),
+        }
+    }
+}
+
+impl Default for AnimationSpec {
+    /// The default [`TweenSpec`].
+    fn default() -> Self {
+        Self::Tween(TweenSpec::default())
+    }
+}
+
+impl From<TweenSpec> for AnimationSpec {
+    fn from(spec: TweenSpec) -> Self {
+        Self::Tween(spec)
+    }
+}
+
+impl From<SpringSpec> for AnimationSpec {
+    fn from(spec: SpringSpec) -> Self {
+        Self::Spring(spec)
+    }
+}
+
+/// How an infinite animation continues after each iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepeatMode {
+    /// Jump back to the start value and play again.
+    #[default]
+    Restart,
+    /// Play backwards to the start value, then forwards again.
+    Reverse,
+}
+
+impl RepeatMode {
+    /// Returns the eased fraction of a repeating tween after `elapsed`.
+    pub(crate) fn fraction(&self, spec: &TweenSpec, elapsed: Duration) -> f32 {
+        let Some(running) = elapsed.checked_sub(spec.delay) else {
+            return 0.0;
+        };
+        if spec.duration.is_zero() {
+            return 1.0;
+        }
+        let iterations = running.as_secs_f64() / spec.duration.as_secs_f64();
+        let iteration = iterations.floor();
+        let linear = (iterations - iteration) as f32;
+        let linear = match self {
+            Self::Restart => linear,
+            Self::Reverse if iteration as u64 % 2 == 1 => 1.0 - linear,
+            Self::Reverse => linear,
+        };
+        spec.easing.transform(linear)
+    }
+}
@@ -1,14 +1,34 @@
 //! Color utilities for the Tessera UI framework.
 //!
 //! This module provides the [`Color`] struct and related utilities for working with colors
-//! in the linear sRGB color space. The color representation is optimized for GPU rendering
+//! in the sRGB color space. The color representation is optimized for GPU rendering
 //! and shader compatibility.
 //!
 //! # Color Space
 //!
-//! All colors are represented in the linear sRGB color space, which is the standard for
-//! modern graphics rendering. This ensures consistent color reproduction across different
-//! devices and platforms.
+//! All colors are **gamma-encoded sRGB** values, the same values used by CSS, image files
+//! and color pickers: `Color::from_rgb_u8(128, 128, 128)` is the mid gray of `#808080`.
+//!
+//! Pipelines write these values to their render targets unchanged. The renderer always
+//! renders into a non-sRGB texture format, so a color looks the same on every machine,
+//! whatever surface formats the GPU offers. As a consequence, alpha blending happens on
+//! sRGB values, like in web browsers.
+//!
+//! Use [`Color::to_linear`] and [`Color::from_linear`] when doing lighting or other
+//! physically based math, and [`Color::lerp_oklab`] for perceptually even gradients and
+//! transitions.
+//!
+//! # Other Color Models
+//!
+//! - HSL and HSV: [`Color::from_hsl`], [`Color::to_hsl`], [`Color::from_hsv`] and
+//!   [`Color::to_hsv`]
+//! - OKLCH: [`Color::from_oklch`] and [`Color::to_oklch`]
+//! - Hex strings: [`Color::from_hex`] and [`Color::to_hex`]
+//!
+//! # Accessibility
+//!
+//! [`Color::contrast_ratio`] computes the WCAG 2 contrast ratio between two colors, and
+//! [`Color::contrasting_text_color`] picks black or white text for a background.
 //!
 //! # Usage
 //!
@@ -30,11 +50,20 @@
 //! // Convert from arrays
 //! let from_array: Color = [0.1, 0.2, 0.3, 0.4].into();
 //! let to_array: [f32; 4] = custom_color.into();
+//!
+//! // Parse and format hex strings
+//! let accent = Color::from_hex("#6750A4").unwrap();
+//! assert_eq!(accent.to_hex(), "#6750A4FF");
 //! ```
 
+use std::{fmt, str::FromStr};
+
 use bytemuck::{Pod, Zeroable};
 
-/// A color in the linear sRGB color space with an alpha component.
+/// A color in the sRGB color space with an alpha component.
+///
+/// The components are gamma-encoded sRGB values, see the [module documentation](self).
+/// The alpha component is linear coverage and is not premultiplied.
 ///
 /// This struct represents a color using four floating-point components: red, green, blue,
 /// and alpha (transparency). Values are typically in the range `[0.0, 1.0]`, where:
@@ -262,6 +291,9 @@ impl Color {
     }
     /// Linearly interpolates between two colors.
     ///
+    /// The sRGB components are mixed directly, which is cheap and matches how the GPU
+    /// blends. Use [`Color::lerp_oklab`] for perceptually even transitions.
+    ///
     /// # Arguments
     ///
     /// * `other` - The target color to interpolate towards.
@@ -292,6 +324,474 @@ impl Color {
             a: self.a + (other.a - self.a) * t,
         }
     }
+
+    /// Converts the color to 8-bit components `[r, g, b, a]`, rounding to the nearest value.
+    ///
+    /// Components outside of `[0.0, 1.0]` are clamped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let color = Color::from_rgba_u8(255, 128, 64, 32);
+    /// assert_eq!(color.to_rgba_u8(), [255, 128, 64, 32]);
+    /// ```
+    #[inline]
+    pub fn to_rgba_u8(self) -> [u8; 4] {
+        self.to_array()
+            .map(|component| (component.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+
+    /// Converts the sRGB color to linear light, keeping the alpha component.
+    ///
+    /// Linear components are proportional to the emitted light, which is what lighting and
+    /// physically correct blending expect. The result is still a `Color`, but it must be
+    /// converted back with [`Color::from_linear`] before being used as a regular color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let gray = Color::from_rgb(0.5, 0.5, 0.5).to_linear();
+    /// assert!((gray.r - 0.214).abs() < 0.001);
+    /// assert_eq!(Color::WHITE.to_linear(), Color::WHITE);
+    /// ```
+    #[inline]
+    pub fn to_linear(self) -> Self {
+        Self::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+            self.a,
+        )
+    }
+
+    /// Converts linear light components to an sRGB color, keeping the alpha component.
+    ///
+    /// This is the inverse of [`Color::to_linear`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let color = Color::new(0.2, 0.4, 0.6, 1.0);
+    /// let round_trip = Color::from_linear(color.to_linear());
+    /// assert!((round_trip.g - color.g).abs() < 1e-5);
+    /// ```
+    #[inline]
+    pub fn from_linear(linear: Self) -> Self {
+        Self::new(
+            linear_to_srgb(linear.r),
+            linear_to_srgb(linear.g),
+            linear_to_srgb(linear.b),
+            linear.a,
+        )
+    }
+
+    /// Creates an opaque color from hue, saturation and lightness.
+    ///
+    /// # Parameters
+    ///
+    /// * `h` - Hue in degrees, wrapped into `[0.0, 360.0)`
+    /// * `s` - Saturation in range [0.0, 1.0]
+    /// * `l` - Lightness in range [0.0, 1.0]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::RED);
+    /// assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::BLUE);
+    /// ```
+    pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        Self::from_hue_chroma(h, chroma, l - chroma / 2.0)
+    }
+
+    /// Returns the hue (in degrees), saturation and lightness of the color.
+    ///
+    /// The alpha component is ignored. Gray colors have a hue of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let (h, s, l) = Color::from_rgb(0.0, 0.5, 0.0).to_hsl();
+    /// assert_eq!((h, s, l), (120.0, 1.0, 0.25));
+    /// ```
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        (hue, s, l)
+    }
+
+    /// Creates an opaque color from hue, saturation and value.
+    ///
+    /// # Parameters
+    ///
+    /// * `h` - Hue in degrees, wrapped into `[0.0, 360.0)`
+    /// * `s` - Saturation in range [0.0, 1.0]
+    /// * `v` - Value (brightness) in range [0.0, 1.0]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(120.0, 1.0, 1.0), Color::GREEN);
+    /// assert_eq!(Color::from_hsv(0.0, 0.0, 1.0), Color::WHITE);
+    /// ```
+    pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+        let v = v.clamp(0.0, 1.0);
+        let chroma = v * s.clamp(0.0, 1.0);
+        Self::from_hue_chroma(h, chroma, v - chroma)
+    }
+
+    /// Returns the hue (in degrees), saturation and value of the color.
+    ///
+    /// The alpha component is ignored. Gray colors have a hue of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let (h, s, v) = Color::from_rgb(1.0, 1.0, 0.0).to_hsv();
+    /// assert_eq!((h, s, v), (60.0, 1.0, 1.0));
+    /// ```
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (hue, s, max)
+    }
+
+    /// Creates an opaque color from OKLCH lightness, chroma and hue.
+    ///
+    /// OKLCH is a perceptual color space: colors with the same lightness look equally
+    /// bright, whatever their hue, which makes it well suited for generating palettes.
+    /// Colors outside of the sRGB gamut are clamped to it.
+    ///
+    /// # Parameters
+    ///
+    /// * `l` - Perceptual lightness in range [0.0, 1.0]
+    /// * `c` - Chroma, `0.0` for grays and up to about `0.37` for the most vivid sRGB colors
+    /// * `h` - Hue in degrees
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let white = Color::from_oklch(1.0, 0.0, 0.0);
+    /// assert_eq!(white.to_rgba_u8(), [255, 255, 255, 255]);
+    ///
+    /// // A pink and a teal that look equally bright
+    /// let pink = Color::from_oklch(0.7, 0.15, 0.0);
+    /// let teal = Color::from_oklch(0.7, 0.15, 180.0);
+    /// ```
+    pub fn from_oklch(l: f32, c: f32, h: f32) -> Self {
+        let (sin, cos) = h.to_radians().sin_cos();
+        Self::from_oklab(l, c * cos, c * sin, 1.0)
+    }
+
+    /// Returns the OKLCH lightness, chroma and hue (in degrees) of the color.
+    ///
+    /// The alpha component is ignored. Gray colors have a hue of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let (l, c, _) = Color::BLACK.to_oklch();
+    /// assert!(l.abs() < 1e-4 && c.abs() < 1e-4);
+    /// ```
+    pub fn to_oklch(self) -> (f32, f32, f32) {
+        let [l, a, b] = self.to_oklab();
+        let c = a.hypot(b);
+        let h = if c < 1e-4 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        };
+        (l, c, h)
+    }
+
+    /// Interpolates between two colors in the OKLab color space.
+    ///
+    /// Unlike [`Color::lerp`], which mixes sRGB components, this produces perceptually even
+    /// steps and avoids the dark or grayish middle of gradients between complementary
+    /// colors. The alpha component is interpolated linearly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// let start = Color::from_hex("#1E88E5").unwrap();
+    /// let end = Color::from_hex("#FDD835").unwrap();
+    /// let middle = start.lerp_oklab(&end, 0.5);
+    ///
+    /// assert_eq!(start.lerp_oklab(&end, 0.0).to_hex(), "#1E88E5FF");
+    /// assert_eq!(start.lerp_oklab(&end, 1.0).to_hex(), "#FDD835FF");
+    /// ```
+    pub fn lerp_oklab(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let from = self.to_oklab();
+        let to = other.to_oklab();
+        let [l, a, b] = std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t);
+        Self::from_oklab(l, a, b, self.a + (other.a - self.a) * t)
+    }
+
+    /// Parses a hex color string.
+    ///
+    /// Accepted forms are `RGB`, `RGBA`, `RRGGBB` and `RRGGBBAA`, with an optional leading
+    /// `#`. Digits are case-insensitive. Forms without alpha are opaque.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseColorError`] if the string has another length or contains a
+    /// character that is not a hex digit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#FF0000").unwrap(), Color::RED);
+    /// assert_eq!(Color::from_hex("00f").unwrap(), Color::BLUE);
+    /// assert_eq!(Color::from_hex("#00000000").unwrap(), Color::TRANSPARENT);
+    /// assert!(Color::from_hex("#12345").is_err());
+    ///
+    /// // `Color` also implements `FromStr`
+    /// let white: Color = "#fff".parse().unwrap();
+    /// assert_eq!(white, Color::WHITE);
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let values = digits
+            .chars()
+            .map(|c| c.to_digit(16).map(|digit| digit as u8))
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(ParseColorError::InvalidDigit)?;
+        let bytes: Vec<u8> = match values.len() {
+            3 | 4 => values.iter().map(|digit| digit * 17).collect(),
+            6 | 8 => values
+                .chunks(2)
+                .map(|pair| pair[0] * 16 + pair[1])
+                .collect(),
+            _ => return Err(ParseColorError::InvalidLength),
+        };
+        Ok(Self::from_rgba_u8(
+            bytes[0],
+            bytes[1],
+            bytes[2],
+            bytes.get(3).copied().unwrap_or(255),
+        ))
+    }
+
+    /// Formats the color as an uppercase `#RRGGBBAA` hex string.
+    ///
+    /// Components are rounded to 8 bits, see [`Color::to_rgba_u8`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::RED.to_hex(), "#FF0000FF");
+    /// assert_eq!(Color::BLACK.with_alpha(0.5).to_hex(), "#00000080");
+    /// ```
+    pub fn to_hex(self) -> String {
+        let [r, g, b, a] = self.to_rgba_u8();
+        format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+
+    /// Returns the relative luminance of the color, as defined by WCAG 2.
+    ///
+    /// The result ranges from `0.0` for black to `1.0` for white. The alpha component is
+    /// ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::BLACK.relative_luminance(), 0.0);
+    /// assert_eq!(Color::WHITE.relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(self) -> f32 {
+        let linear = self.to_linear();
+        0.2126 * linear.r + 0.7152 * linear.g + 0.0722 * linear.b
+    }
+
+    /// Returns the WCAG 2 contrast ratio between two colors, from `1.0` to `21.0`.
+    ///
+    /// The order of the colors does not matter, and alpha is ignored. WCAG AA asks for at
+    /// least `4.5` for body text and `3.0` for large text, AAA for `7.0` and `4.5`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::BLACK.contrast_ratio(&Color::WHITE).round(), 21.0);
+    ///
+    /// let gray = Color::from_hex("#767676").unwrap();
+    /// assert!(gray.contrast_ratio(&Color::WHITE) >= 4.5);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Self) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns black or white, whichever has the higher contrast against this color.
+    ///
+    /// Use it to pick a readable text color for a background color.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#FDD835").unwrap().contrasting_text_color(), Color::BLACK);
+    /// assert_eq!(Color::from_hex("#1A237E").unwrap().contrasting_text_color(), Color::WHITE);
+    /// ```
+    pub fn contrasting_text_color(self) -> Self {
+        if self.contrast_ratio(&Self::BLACK) >= self.contrast_ratio(&Self::WHITE) {
+            Self::BLACK
+        } else {
+            Self::WHITE
+        }
+    }
+
+    /// Builds an opaque color from a hue, a chroma and the amount added to every channel.
+    fn from_hue_chroma(h: f32, chroma: f32, offset: f32) -> Self {
+        let sector = h.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        Self::from_rgb(r + offset, g + offset, b + offset)
+    }
+
+    /// Returns the hue in degrees and the largest and smallest component, shared by HSL
+    /// and HSV.
+    fn hue_max_min(self) -> (f32, f32, f32) {
+        let max = self.r.max(self.g).max(self.b);
+        let min = self.r.min(self.g).min(self.b);
+        let delta = max - min;
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == self.r {
+            60.0 * ((self.g - self.b) / delta).rem_euclid(6.0)
+        } else if max == self.g {
+            60.0 * ((self.b - self.r) / delta + 2.0)
+        } else {
+            60.0 * ((self.r - self.g) / delta + 4.0)
+        };
+        (hue, max, min)
+    }
+
+    /// Converts the color to OKLab `[l, a, b]`, ignoring alpha.
+    fn to_oklab(self) -> [f32; 3] {
+        let Self { r, g, b, .. } = self.to_linear();
+        let l = (0.41222147 * r + 0.53633254 * g + 0.05144599 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171884 * g + 0.6299787 * b).cbrt();
+        [
+            0.21045426 * l + 0.7936178 * m - 0.00407205 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.02590404 * l + 0.78277177 * m - 0.80867577 * s,
+        ]
+    }
+
+    /// Converts OKLab coordinates to a color, clamping it to the sRGB gamut.
+    fn from_oklab(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        let l_ = (l + 0.39633778 * a + 0.21580376 * b).powi(3);
+        let m_ = (l - 0.10556135 * a - 0.06385417 * b).powi(3);
+        let s_ = (l - 0.08948418 * a - 1.2914855 * b).powi(3);
+        let linear = Self::new(
+            4.0767417 * l_ - 3.3077116 * m_ + 0.23096993 * s_,
+            -1.268438 * l_ + 2.6097574 * m_ - 0.3413194 * s_,
+            -0.00419609 * l_ - 0.7034186 * m_ + 1.7076147 * s_,
+            alpha,
+        );
+        let srgb = Self::from_linear(linear);
+        Self::new(
+            srgb.r.clamp(0.0, 1.0),
+            srgb.g.clamp(0.0, 1.0),
+            srgb.b.clamp(0.0, 1.0),
+            alpha,
+        )
+    }
+}
+
+/// Decodes one gamma-encoded sRGB component to linear light.
+fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encodes one linear light component to gamma-encoded sRGB.
+fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// The error returned when parsing a hex color string fails.
+///
+/// See [`Color::from_hex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string does not have 3, 4, 6 or 8 hex digits.
+    InvalidLength,
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "hex color must have 3, 4, 6 or 8 digits"),
+            Self::InvalidDigit => write!(f, "hex color contains a non-hex digit"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Parses a hex color string, see [`Color::from_hex`].
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex(s)
+    }
 }
 
 /// The default color is fully transparent.
@@ -409,3 +909,90 @@ impl From<[u8; 3]> for Color {
         Self::from_rgb_u8(r, g, b)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Color, b: Color) {
+        let (a, b) = (a.to_array(), b.to_array());
+        for i in 0..4 {
+            assert!((a[i] - b[i]).abs() < 1e-3, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_linear_round_trip() {
+        for value in [0.0, 0.01, 0.04045, 0.2, 0.5, 0.8, 1.0] {
+            let color = Color::new(value, value, value, 0.5);
+            assert_close(Color::from_linear(color.to_linear()), color);
+        }
+    }
+
+    #[test]
+    fn test_hsl_hsv_round_trip() {
+        let colors = [
+            Color::from_rgb(0.2, 0.4, 0.6),
+            Color::from_rgb(0.9, 0.1, 0.3),
+            Color::from_rgb(0.5, 0.5, 0.5),
+            Color::from_rgb(0.0, 0.7, 0.1),
+        ];
+        for color in colors {
+            let (h, s, l) = color.to_hsl();
+            assert_close(Color::from_hsl(h, s, l), color);
+            let (h, s, v) = color.to_hsv();
+            assert_close(Color::from_hsv(h, s, v), color);
+        }
+    }
+
+    #[test]
+    fn test_oklch_round_trip() {
+        let colors = [
+            Color::from_rgb(0.2, 0.4, 0.6),
+            Color::from_rgb(0.9, 0.1, 0.3),
+            Color::WHITE,
+            Color::BLACK,
+        ];
+        for color in colors {
+            let (l, c, h) = color.to_oklch();
+            assert_close(Color::from_oklch(l, c, h), color);
+        }
+        // Reference value for sRGB red
+        let (l, c, h) = Color::RED.to_oklch();
+        assert!((l - 0.628).abs() < 1e-3);
+        assert!((c - 0.258).abs() < 1e-3);
+        assert!((h - 29.23).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_lerp_oklab() {
+        let middle = Color::BLACK.lerp_oklab(&Color::WHITE, 0.5);
+        let (l, c, _) = middle.to_oklch();
+        assert!((l - 0.5).abs() < 1e-3);
+        assert!(c < 1e-3);
+        assert_close(Color::RED.lerp_oklab(&Color::BLUE, 1.0), Color::BLUE);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Color::from_hex("#3366CC80").unwrap().to_hex(), "#3366CC80");
+        assert_eq!(Color::from_hex("#36c8").unwrap().to_hex(), "#3366CC88");
+        assert_eq!(Color::from_hex(""), Err(ParseColorError::InvalidLength));
+        assert_eq!(
+            Color::from_hex("#GG0000"),
+            Err(ParseColorError::InvalidDigit)
+        );
+        assert_eq!(
+            Color::from_hex("#ff00é"),
+            Err(ParseColorError::InvalidDigit)
+        );
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let gray = Color::from_rgb_u8(0x77, 0x77, 0x77);
+        let ratio = gray.contrast_ratio(&Color::WHITE);
+        assert!((ratio - 4.48).abs() < 0.01);
+        assert_eq!(ratio, Color::WHITE.contrast_ratio(&gray));
+    }
+}
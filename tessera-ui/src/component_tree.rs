@@ -1,24 +1,34 @@
 mod constraint;
 mod node;
-
-use std::{num::NonZero, sync::Arc, time::Instant};
+#[cfg(feature = "devtools")]
+mod snapshot;
+
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    num::NonZero,
+    sync::Arc,
+    time::Instant,
+};
 
 use log::debug;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 
 use crate::{
-    Clipboard, ComputeResourceManager,
+    Clipboard, ComputeResourceManager, TesseraRuntime, accessibility, context,
     cursor::CursorEvent,
-    px::{PxPosition, PxSize},
-    renderer::Command,
+    profiling::profile_span,
+    px::{Px, PxPosition, PxSize},
+    renderer::{Command, DebugOverlayCommand},
 };
 
 pub use constraint::{Constraint, DimensionValue};
 pub use node::{
-    ComponentNode, ComponentNodeMetaData, ComponentNodeMetaDatas, ComponentNodeTree, ComputedData,
-    ImeRequest, MeasureFn, MeasurementError, StateHandlerFn, StateHandlerInput, WindowRequests,
-    measure_node, measure_nodes, place_node,
+    AlignmentLines, ComponentNode, ComponentNodeMetaData, ComponentNodeMetaDatas,
+    ComponentNodeTree, ComputedData, ImeRequest, IntrinsicCache, IntrinsicFn, IntrinsicInput,
+    IntrinsicQuery, IntrinsicSize, MeasureFn, MeasurementError, SourceLocation, StateHandlerFn,
+    StateHandlerInput, WindowRequests, intrinsic_size, measure_node, measure_nodes, place_node,
 };
 
 /// Respents a component tree
@@ -29,6 +39,12 @@ pub struct ComponentTree {
     metadatas: ComponentNodeMetaDatas,
     /// Used to remember the current node
     node_queue: Vec<indextree::NodeId>,
+    /// How many children each parent has added so far from each call site, used to key nodes
+    call_site_counts: HashMap<(Option<indextree::NodeId>, u64), u32>,
+    /// Whether `compute` records the command types of the nodes for snapshots, set while
+    /// an inspection tool is connected
+    #[cfg(feature = "devtools")]
+    pub(crate) record_command_types: bool,
 }
 
 impl Default for ComponentTree {
@@ -47,6 +63,9 @@ impl ComponentTree {
             tree,
             node_queue,
             metadatas,
+            call_site_counts: HashMap::new(),
+            #[cfg(feature = "devtools")]
+            record_command_types: false,
         }
     }
 
@@ -55,6 +74,7 @@ impl ComponentTree {
         self.tree.clear();
         self.metadatas.clear();
         self.node_queue.clear();
+        self.call_site_counts.clear();
     }
 
     /// Get node by NodeId
@@ -83,7 +103,30 @@ impl ComponentTree {
     /// Add a new node to the tree
     /// Nodes now store their intrinsic constraints in their metadata.
     /// The `node_component` itself primarily holds the measure_fn.
-    pub fn add_node(&mut self, node_component: ComponentNode) {
+    ///
+    /// The node captures the [composition locals](crate::context) in scope on the calling
+    /// thread, overwriting its `context` field.
+    ///
+    /// The node is also given a `key` that stays the same across frames, unlike its
+    /// [`NodeId`](indextree::NodeId) which only reflects the build order. The key hashes
+    /// the key of the parent, the call site of the component (or its name, for nodes
+    /// without a [`SourceLocation`]) and how many earlier siblings were added from the
+    /// same call site. Adding or removing a sibling called from elsewhere therefore does
+    /// not change the key of a node.
+    pub fn add_node(&mut self, mut node_component: ComponentNode) {
+        node_component.context = context::capture();
+        let parent = self.node_queue.last().copied();
+        let parent_key = parent
+            .and_then(|parent| self.get(parent))
+            .map_or(0, |parent| parent.key);
+        let call_site = call_site_hash(&node_component);
+        let count = self
+            .call_site_counts
+            .entry((parent, call_site))
+            .or_default();
+        node_component.key = node_key(parent_key, call_site, *count);
+        *count += 1;
+
         let new_node_id = self.tree.new_node(node_component);
         if let Some(current_node_id) = self.node_queue.last_mut() {
             current_node_id.append(new_node_id, &mut self.tree);
@@ -93,11 +136,98 @@ impl ComponentTree {
         self.node_queue.push(new_node_id);
     }
 
+    /// Get the key identifying a node across frames, see [`add_node`](Self::add_node)
+    pub fn node_key(&self, node_id: indextree::NodeId) -> Option<u64> {
+        self.get(node_id).map(|node| node.key)
+    }
+
+    /// Get the key of the current node, see [`add_node`](Self::add_node)
+    pub fn current_node_key(&self) -> Option<u64> {
+        self.current_node().map(|node| node.key)
+    }
+
+    /// Find the node with the given key in the current tree
+    pub fn find_by_key(&self, key: u64) -> Option<indextree::NodeId> {
+        let root = self.root_node()?;
+        root.descendants(&self.tree)
+            .find(|node_id| self.node_key(*node_id) == Some(key))
+    }
+
     /// Pop the last node from the queue
     pub fn pop_node(&mut self) {
         self.node_queue.pop();
     }
 
+    /// Describe a node for diagnostics, with its name, test tag and source location
+    fn describe_node(&self, node_id: indextree::NodeId) -> String {
+        match self.get(node_id) {
+            Some(node) => format!("{node} ({node_id:?})"),
+            None => format!("{node_id:?}"),
+        }
+    }
+
+    /// Get the root node, which is always the first node added
+    pub(crate) fn root_node(&self) -> Option<indextree::NodeId> {
+        self.tree.get_node_id_at(NonZero::new(1).unwrap())
+    }
+
+    /// Get the metadatas of all nodes
+    #[cfg(test)]
+    pub(crate) fn metadatas(&self) -> &ComponentNodeMetaDatas {
+        &self.metadatas
+    }
+
+    /// Build the AccessKit tree for the current frame
+    ///
+    /// Every node that published [`Semantics`](crate::accessibility::Semantics) becomes an
+    /// AccessKit node below a window node, with bounds taken from the layout. Call this after
+    /// [`compute`](Self::compute) and before [`clear`](Self::clear) to get bounds; before
+    /// layout, nodes are published without bounds.
+    ///
+    /// This is what the renderer sends to the platform accessibility API, which makes it
+    /// useful for asserting on semantics in headless tests.
+    pub fn accessibility_tree_update(&self) -> accesskit::TreeUpdate {
+        self.accessibility().0
+    }
+
+    /// Build the AccessKit tree together with the action handlers of its nodes
+    pub(crate) fn accessibility(&self) -> (accesskit::TreeUpdate, accessibility::ActionHandlers) {
+        accessibility::build_tree_update(&self.tree, &self.metadatas, self.root_node())
+    }
+
+    /// Take a serializable snapshot of the tree, with the layout of every node
+    ///
+    /// Sizes, positions and emitted commands are only known once the tree was computed,
+    /// and the tree is cleared after each frame, so this is meant for the renderer and
+    /// for tests. Running applications can be inspected with the
+    /// [devtools server](crate::devtools) instead.
+    #[cfg(feature = "devtools")]
+    pub fn snapshot(&self) -> crate::TreeSnapshot {
+        crate::TreeSnapshot {
+            root: self
+                .root_node()
+                .map(|root| snapshot::snapshot_node(root, &self.tree, &self.metadatas)),
+        }
+    }
+
+    /// Build the layout debug overlay of the laid out tree, see
+    /// [`TesseraRuntime::set_debug_overlay`] and [`TesseraRuntime::set_highlighted_node`]
+    pub(crate) fn debug_overlay(
+        &self,
+        scale_factor: f64,
+        outline_all: bool,
+        highlight: Option<u64>,
+    ) -> DebugOverlayCommand {
+        DebugOverlayCommand::build(
+            &self.tree,
+            &self.metadatas,
+            self.root_node(),
+            scale_factor,
+            outline_all,
+            highlight,
+        )
+    }
+
     /// Compute the ComponentTree into a list of rendering commands
     ///
     /// This method processes the component tree through three main phases:
@@ -107,6 +237,7 @@ impl ComponentTree {
     ///
     /// Returns a tuple of (commands, window_requests) where commands contain
     /// the rendering instructions with their associated sizes and positions.
+    #[allow(clippy::too_many_arguments)]
     pub fn compute(
         &mut self,
         screen_size: PxSize,
@@ -119,7 +250,7 @@ impl ComponentTree {
         gpu: &wgpu::Device,
         clipboard: &mut Clipboard,
     ) -> (Vec<(Command, PxSize, PxPosition)>, WindowRequests) {
-        let Some(root_node) = self.tree.get_node_id_at(NonZero::new(1).unwrap()) else {
+        let Some(root_node) = self.root_node() else {
             return (vec![], WindowRequests::default());
         };
         let screen_constraint = Constraint::new(
@@ -144,23 +275,41 @@ impl ComponentTree {
                 debug!("Component tree measured in {:?}", measure_timer.elapsed());
             }
             Err(e) => {
+                let cause = match &e {
+                    MeasurementError::ChildMeasurementFailed(child) => {
+                        format!(" (child {} failed)", self.describe_node(*child))
+                    }
+                    _ => String::new(),
+                };
                 panic!(
-                    "Root node ({root_node:?}) measurement failed: {e:?}. Aborting draw command computation."
+                    "Root node {} measurement failed: {e:?}{cause}. Aborting draw command computation.",
+                    self.describe_node(root_node)
                 );
             }
         }
 
+        // Collecting the draw commands empties the metadata, keep what snapshots show of them
+        #[cfg(feature = "devtools")]
+        if self.record_command_types {
+            for mut metadata in self.metadatas.iter_mut() {
+                metadata.command_types = metadata.commands.iter().map(Command::type_name).collect();
+            }
+        }
+
         let compute_draw_timer = Instant::now();
         debug!("Start computing draw commands...");
         // compute_draw_commands_parallel expects &ComponentNodeTree and &ComponentNodeMetaDatas
         // It also uses get_mut on metadatas internally, which is fine for DashMap with &self.
-        let commands = compute_draw_commands_parallel(
-            root_node,
-            &self.tree,
-            &self.metadatas,
-            screen_size.width.0,
-            screen_size.height.0,
-        );
+        let commands = {
+            let _span = profile_span!("draw_commands");
+            compute_draw_commands_parallel(
+                root_node,
+                &self.tree,
+                &self.metadatas,
+                screen_size.width,
+                screen_size.height,
+            )
+        };
         debug!(
             "Draw commands computed in {:?}, total commands: {}",
             compute_draw_timer.elapsed(),
@@ -169,7 +318,10 @@ impl ComponentTree {
 
         let state_handler_timer = Instant::now();
         let mut window_requests = WindowRequests::default();
+        let scale_factor = TesseraRuntime::scale_factor();
         debug!("Start executing state handlers...");
+        // The phase runs until the end of the function
+        let _phase_span = profile_span!("state_handlers");
         for node_id in root_node
             .reverse_traverse(&self.tree)
             .filter_map(|edge| match edge {
@@ -177,13 +329,17 @@ impl ComponentTree {
                 indextree::NodeEdge::End(_) => None,
             })
         {
-            let Some(state_handler) = self
-                .tree
-                .get(node_id)
-                .and_then(|n| n.get().state_handler_fn.as_ref())
+            let Some((state_handler, node_context, fn_name)) =
+                self.tree.get(node_id).and_then(|n| {
+                    let node = n.get();
+                    node.state_handler_fn
+                        .as_ref()
+                        .map(|f| (f, &node.context, &node.fn_name))
+                })
             else {
                 continue;
             };
+            let _span = profile_span!("state_handler", fn_name = fn_name);
 
             // Compute the relative cursor position for the current node
             let current_cursor_position = cursor_position.map(|pos| {
@@ -210,8 +366,9 @@ impl ComponentTree {
                     key_modifiers: modifiers,
                     requests: &mut window_requests,
                     clipboard,
+                    scale_factor,
                 };
-                state_handler(input);
+                context::with_context(node_context, || state_handler(input));
                 // if state_handler set ime request, it's position must be None, and we set it here
                 if let Some(ref mut ime_request) = window_requests.ime_request
                     && ime_request.position.is_none()
@@ -225,7 +382,8 @@ impl ComponentTree {
                 }
             } else {
                 log::warn!(
-                    "Computed data not found for node {node_id:?} during state handler execution."
+                    "Computed data not found for node {} during state handler execution.",
+                    self.describe_node(node_id)
                 );
             }
         }
@@ -237,13 +395,37 @@ impl ComponentTree {
     }
 }
 
+/// Hashes where a node was created, to tell it apart from its siblings
+fn call_site_hash(node: &ComponentNode) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    match &node.location {
+        Some(location) => {
+            location.caller.file().hash(&mut hasher);
+            location.caller.line().hash(&mut hasher);
+            location.caller.column().hash(&mut hasher);
+        }
+        None => node.fn_name.hash(&mut hasher),
+    }
+    hasher.finish()
+}
+
+/// Combines the key of the parent, the call site and the sibling index into a node key
+///
+/// Keys are never 0, which is the [window node](crate::accessibility::WINDOW_NODE_ID) of
+/// the accessibility tree.
+fn node_key(parent_key: u64, call_site: u64, index: u32) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    (parent_key, call_site, index).hash(&mut hasher);
+    hasher.finish().max(1)
+}
+
 // Helper struct for rectangle and intersection check
 #[derive(Debug, Clone, Copy)]
 struct Rect {
-    x: i32,
-    y: i32,
-    width: i32,
-    height: i32,
+    x: Px,
+    y: Px,
+    width: Px,
+    height: Px,
 }
 
 impl Rect {
@@ -268,8 +450,8 @@ fn compute_draw_commands_parallel(
     tree: &ComponentNodeTree,
     metadatas: &ComponentNodeMetaDatas,
     // New params: screen width and height
-    screen_width: i32,
-    screen_height: i32,
+    screen_width: Px,
+    screen_height: Px,
 ) -> Vec<(Command, PxSize, PxPosition)> {
     compute_draw_commands_inner_parallel(
         PxPosition::ZERO,
@@ -288,8 +470,8 @@ fn compute_draw_commands_inner_parallel(
     node_id: indextree::NodeId,
     tree: &ComponentNodeTree,
     metadatas: &ComponentNodeMetaDatas,
-    screen_width: i32,
-    screen_height: i32,
+    screen_width: Px,
+    screen_height: Px,
 ) -> Vec<(Command, PxSize, PxPosition)> {
     let mut local_commands = Vec::new();
 
@@ -313,20 +495,20 @@ fn compute_draw_commands_inner_parallel(
 
     // Viewport culling check
     let screen_rect = Rect {
-        x: 0,
-        y: 0,
+        x: Px::ZERO,
+        y: Px::ZERO,
         width: screen_width,
         height: screen_height,
     };
     let node_rect = Rect {
-        x: self_pos.x.0,
-        y: self_pos.y.0,
-        width: size.width.0,
-        height: size.height.0,
+        x: self_pos.x,
+        y: self_pos.y,
+        width: size.width,
+        height: size.height,
     };
 
     // Only drain commands if the node is visible.
-    if size.width.0 > 0 && size.height.0 > 0 && node_rect.intersects(&screen_rect) {
+    if size.width > Px::ZERO && size.height > Px::ZERO && node_rect.intersects(&screen_rect) {
         for cmd in metadata.commands.drain(..) {
             local_commands.push((cmd, size, self_pos));
         }
@@ -359,3 +541,102 @@ fn compute_draw_commands_inner_parallel(
 
     local_commands
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn node(fn_name: &str) -> ComponentNode {
+        ComponentNode {
+            fn_name: fn_name.to_string(),
+            location: None,
+            test_tag: None,
+            measure_fn: None,
+            intrinsic_fn: None,
+            state_handler_fn: None,
+            semantics: None,
+            context: Default::default(),
+            key: 0,
+            intrinsic_cache: Default::default(),
+        }
+    }
+
+    /// Builds a column of `children`, returning the keys of the children
+    fn build(tree: &mut ComponentTree, children: &[&str]) -> Vec<u64> {
+        tree.clear();
+        tree.add_node(node("column"));
+        let keys = children
+            .iter()
+            .map(|name| {
+                tree.add_node(node(name));
+                let key = tree.current_node_key().unwrap();
+                tree.pop_node();
+                key
+            })
+            .collect();
+        tree.pop_node();
+        keys
+    }
+
+    #[test]
+    fn test_node_keys_are_stable_across_frames() {
+        let mut tree = ComponentTree::new();
+        let first = build(&mut tree, &["text", "button", "button"]);
+        let second = build(&mut tree, &["text", "button", "button"]);
+        assert_eq!(first, second);
+        // Siblings from the same call site are told apart by their index
+        assert_ne!(first[1], first[2]);
+        assert!(first.iter().all(|key| *key != 0));
+
+        // Removing a sibling from another call site keeps the keys of the others
+        let third = build(&mut tree, &["button", "button"]);
+        assert_eq!(third, first[1..]);
+        let button = tree.find_by_key(first[2]).unwrap();
+        assert_eq!(tree.get(button).unwrap().fn_name, "button");
+        assert!(tree.find_by_key(first[0]).is_none());
+    }
+
+    #[test]
+    fn test_intrinsic_queries_are_cached() {
+        static MEASURES: AtomicUsize = AtomicUsize::new(0);
+        let mut tree = ComponentTree::new();
+        tree.clear();
+        // Every layout asks its child twice, which is exponential in the depth unless the
+        // answers are kept
+        for _ in 0..16 {
+            let mut layout = node("layout");
+            layout.intrinsic_fn = Some(Box::new(|input| {
+                let child = input.children_ids[0];
+                let first = input.child_intrinsic_size(child, input.query, None)?;
+                let second = input.child_intrinsic_size(child, input.query, None)?;
+                Ok(first.max(second))
+            }));
+            tree.add_node(layout);
+        }
+        let mut leaf = node("leaf");
+        leaf.measure_fn = Some(Box::new(|_| {
+            MEASURES.fetch_add(1, Ordering::Relaxed);
+            Ok(ComputedData {
+                width: Px(10.0),
+                height: Px(20.0),
+            })
+        }));
+        tree.add_node(leaf);
+
+        let gpu = wgpu::Device::noop(&Default::default()).0;
+        let resources = Arc::new(RwLock::new(ComputeResourceManager::new()));
+        let root = tree.root_node().unwrap();
+        let width = intrinsic_size(
+            root,
+            IntrinsicQuery::MinWidth,
+            None,
+            &tree.tree,
+            resources,
+            &gpu,
+        );
+        assert_eq!(width.unwrap(), Px(10.0));
+        assert_eq!(MEASURES.load(Ordering::Relaxed), 1);
+    }
+}
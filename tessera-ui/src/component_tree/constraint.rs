@@ -20,7 +20,7 @@
 //! ```
 //! # use tessera_ui::Px;
 //! # use tessera_ui::DimensionValue;
-//! let fixed_width = DimensionValue::Fixed(Px(100));
+//! let fixed_width = DimensionValue::Fixed(Px(100.0));
 //! ```
 //!
 //! ### Wrap
@@ -32,15 +32,15 @@
 //! let wrap_content = DimensionValue::Wrap { min: None, max: None };
 //!
 //! // Wrap content but ensure at least 50px wide
-//! let wrap_with_min = DimensionValue::Wrap { min: Some(Px(50)), max: None };
+//! let wrap_with_min = DimensionValue::Wrap { min: Some(Px(50.0)), max: None };
 //!
 //! // Wrap content but never exceed 200px
-//! let wrap_with_max = DimensionValue::Wrap { min: None, max: Some(Px(200)) };
+//! let wrap_with_max = DimensionValue::Wrap { min: None, max: Some(Px(200.0)) };
 //!
 //! // Wrap content within bounds
 //! let wrap_bounded = DimensionValue::Wrap {
-//!     min: Some(Px(50)),
-//!     max: Some(Px(200))
+//!     min: Some(Px(50.0)),
+//!     max: Some(Px(200.0))
 //! };
 //! ```
 //!
@@ -53,10 +53,35 @@
 //! let fill_all = DimensionValue::Fill { min: None, max: None };
 //!
 //! // Fill space but ensure at least 100px
-//! let fill_with_min = DimensionValue::Fill { min: Some(Px(100)), max: None };
+//! let fill_with_min = DimensionValue::Fill { min: Some(Px(100.0)), max: None };
 //!
 //! // Fill space but never exceed 300px
-//! let fill_with_max = DimensionValue::Fill { min: None, max: Some(Px(300)) };
+//! let fill_with_max = DimensionValue::Fill { min: None, max: Some(Px(300.0)) };
+//! ```
+//!
+//! ### Fraction
+//! The component takes a fraction of the space available in its parent:
+//! ```
+//! # use tessera_ui::DimensionValue;
+//! // Half of the parent's width
+//! let half_width = DimensionValue::Fraction(0.5);
+//! ```
+//!
+//! ## Aspect Ratio
+//!
+//! A [`Constraint`] can also keep the width and height of a component in proportion, see
+//! [`Constraint::with_aspect_ratio`]. The dimension that is known after merging decides
+//! the size, and the other dimension follows:
+//! ```
+//! # use tessera_ui::Px;
+//! # use tessera_ui::{Constraint, DimensionValue};
+//! // A 16:9 card that fills the width of a 320px wide parent
+//! let card = Constraint::new(DimensionValue::FILLED, DimensionValue::WRAP)
+//!     .with_aspect_ratio(16.0 / 9.0);
+//! let parent = Constraint::new(DimensionValue::Fixed(Px(320.0)), DimensionValue::WRAP);
+//! let merged = card.merge(&parent);
+//! assert_eq!(merged.width, DimensionValue::Fixed(Px(320.0)));
+//! assert_eq!(merged.height, DimensionValue::Fixed(Px(180.0)));
 //! ```
 //!
 //! ## Constraint Merging
@@ -68,6 +93,8 @@
 //! - **Fixed always wins**: A fixed constraint cannot be overridden by its parent
 //! - **Wrap preserves content sizing**: Wrap constraints maintain their intrinsic sizing behavior
 //! - **Fill adapts to available space**: Fill constraints expand within parent bounds
+//! - **Fraction resolves to a fixed size**: A fraction of the parent's maximum size, or wrap
+//!   content if the parent is unbounded
 //!
 //! ### Merge Examples
 //!
@@ -76,21 +103,21 @@
 //! # use tessera_ui::{Constraint, DimensionValue};
 //! // Parent provides 200px of space
 //! let parent = Constraint::new(
-//!     DimensionValue::Fixed(Px(200)),
-//!     DimensionValue::Fixed(Px(200))
+//!     DimensionValue::Fixed(Px(200.0)),
+//!     DimensionValue::Fixed(Px(200.0))
 //! );
 //!
 //! // Child wants to fill with minimum 50px
 //! let child = Constraint::new(
-//!     DimensionValue::Fill { min: Some(Px(50)), max: None },
-//!     DimensionValue::Fill { min: Some(Px(50)), max: None }
+//!     DimensionValue::Fill { min: Some(Px(50.0)), max: None },
+//!     DimensionValue::Fill { min: Some(Px(50.0)), max: None }
 //! );
 //!
 //! // Result: Child fills parent's 200px space, respecting its 50px minimum
 //! let merged = child.merge(&parent);
 //! assert_eq!(merged.width, DimensionValue::Fill {
-//!     min: Some(Px(50)),
-//!     max: Some(Px(200))
+//!     min: Some(Px(50.0)),
+//!     max: Some(Px(200.0))
 //! });
 //! ```
 //!
@@ -104,20 +131,23 @@
 //!     measure(|constraints| {
 //!         // This component wants to be exactly 100x50 pixels
 //!         let my_constraint = Constraint::new(
-//!             DimensionValue::Fixed(Px(100)),
-//!             DimensionValue::Fixed(Px(50))
+//!             DimensionValue::Fixed(Px(100.0)),
+//!             DimensionValue::Fixed(Px(50.0))
 //!         );
 //!         
 //!         // Measure children with merged constraints
 //!         let child_constraint = my_constraint.merge(&constraints);
 //!         // ... measure children ...
 //!         
-//!         ComputedData::new(Size::new(Px(100), Px(50)))
+//!         ComputedData::new(Size::new(Px(100.0), Px(50.0)))
 //!     });
 //! }
 //! ```
 
-use std::ops::Sub;
+use std::{
+    hash::{Hash, Hasher},
+    ops::Sub,
+};
 
 use crate::{Dp, Px};
 
@@ -126,7 +156,7 @@ use crate::{Dp, Px};
 /// This enum represents the three fundamental sizing strategies available
 /// in Tessera's layout system. Each variant provides different behavior
 /// for how a component determines its size in a given dimension.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub enum DimensionValue {
     /// The dimension is a fixed value in logical pixels.
     ///
@@ -138,7 +168,7 @@ pub enum DimensionValue {
     /// ```
     /// # use tessera_ui::Px;
     /// # use tessera_ui::DimensionValue;
-    /// let button_width = DimensionValue::Fixed(Px(120));
+    /// let button_width = DimensionValue::Fixed(Px(120.0));
     /// ```
     Fixed(Px),
 
@@ -160,10 +190,10 @@ pub enum DimensionValue {
     /// let text_width = DimensionValue::Wrap { min: None, max: None };
     ///
     /// // Text with minimum width to prevent being too narrow
-    /// let min_text_width = DimensionValue::Wrap { min: Some(Px(100)), max: None };
+    /// let min_text_width = DimensionValue::Wrap { min: Some(Px(100.0)), max: None };
     ///
     /// // Text that wraps but never exceeds container width
-    /// let bounded_text = DimensionValue::Wrap { min: Some(Px(50)), max: Some(Px(300)) };
+    /// let bounded_text = DimensionValue::Wrap { min: Some(Px(50.0)), max: Some(Px(300.0)) };
     /// ```
     Wrap { min: Option<Px>, max: Option<Px> },
 
@@ -185,17 +215,75 @@ pub enum DimensionValue {
     /// let flexible_width = DimensionValue::Fill { min: None, max: None };
     ///
     /// // Fill space but ensure minimum usability
-    /// let min_fill_width = DimensionValue::Fill { min: Some(Px(200)), max: None };
+    /// let min_fill_width = DimensionValue::Fill { min: Some(Px(200.0)), max: None };
     ///
     /// // Fill space but cap maximum size for readability
-    /// let capped_fill = DimensionValue::Fill { min: Some(Px(100)), max: Some(Px(800)) };
+    /// let capped_fill = DimensionValue::Fill { min: Some(Px(100.0)), max: Some(Px(800.0)) };
     /// ```
     Fill { min: Option<Px>, max: Option<Px> },
+
+    /// The dimension is a fraction of the space available in the parent.
+    ///
+    /// The fraction applies to the parent's maximum size in this dimension, so `1.0`
+    /// fills the parent and `0.5` takes half of it. Merging with the parent constraint
+    /// resolves it to a `Fixed` size, or to an unbounded `Wrap` if the parent has no
+    /// maximum size, so measure functions only see it in their own arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use tessera_ui::Px;
+    /// # use tessera_ui::{Constraint, DimensionValue};
+    /// let third = Constraint::new(DimensionValue::Fraction(1.0 / 3.0), DimensionValue::WRAP);
+    /// let parent = Constraint::new(DimensionValue::Fixed(Px(300.0)), DimensionValue::WRAP);
+    /// assert_eq!(third.merge(&parent).width, DimensionValue::Fixed(Px(100.0)));
+    /// ```
+    Fraction(f32),
+}
+
+impl PartialEq for DimensionValue {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (DimensionValue::Fixed(a), DimensionValue::Fixed(b)) => a == b,
+            (
+                DimensionValue::Wrap { min, max },
+                DimensionValue::Wrap {
+                    min: other_min,
+                    max: other_max,
+                },
+            )
+            | (
+                DimensionValue::Fill { min, max },
+                DimensionValue::Fill {
+                    min: other_min,
+                    max: other_max,
+                },
+            ) => min == other_min && max == other_max,
+            // Compared like `Px`, so that equality is reflexive and agrees with `Hash`
+            (DimensionValue::Fraction(a), DimensionValue::Fraction(b)) => Px(*a) == Px(*b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for DimensionValue {}
+
+impl Hash for DimensionValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            DimensionValue::Fixed(value) => value.hash(state),
+            DimensionValue::Wrap { min, max } | DimensionValue::Fill { min, max } => {
+                min.hash(state);
+                max.hash(state);
+            }
+            DimensionValue::Fraction(fraction) => Px(*fraction).hash(state),
+        }
+    }
 }
 
 impl DimensionValue {
-    /// Zero-sized dimension, equivalent to `Fixed(Px(0))`.
-    pub const ZERO: Self = DimensionValue::Fixed(Px(0));
+    /// Zero-sized dimension, equivalent to `Fixed(Px(0.0))`.
+    pub const ZERO: Self = DimensionValue::Fixed(Px(0.0));
 
     /// Fill with no constraints.
     pub const FILLED: Self = DimensionValue::Fill {
@@ -236,25 +324,27 @@ impl DimensionValue {
     /// # Returns
     /// - For `Fixed`: Returns the fixed value
     /// - For `Wrap` and `Fill`: Returns the `max` value if specified, otherwise the `default`
+    /// - For `Fraction`: Returns the `default`, as the size depends on the parent
     ///
     /// # Example
     /// ```
     /// # use tessera_ui::Px;
     /// # use tessera_ui::DimensionValue;
-    /// let fixed = DimensionValue::Fixed(Px(100));
-    /// assert_eq!(fixed.to_max_px(Px(200)), Px(100));
+    /// let fixed = DimensionValue::Fixed(Px(100.0));
+    /// assert_eq!(fixed.to_max_px(Px(200.0)), Px(100.0));
     ///
     /// let wrap_unbounded = DimensionValue::Wrap { min: None, max: None };
-    /// assert_eq!(wrap_unbounded.to_max_px(Px(200)), Px(200));
+    /// assert_eq!(wrap_unbounded.to_max_px(Px(200.0)), Px(200.0));
     ///
-    /// let wrap_bounded = DimensionValue::Wrap { min: None, max: Some(Px(150)) };
-    /// assert_eq!(wrap_bounded.to_max_px(Px(200)), Px(150));
+    /// let wrap_bounded = DimensionValue::Wrap { min: None, max: Some(Px(150.0)) };
+    /// assert_eq!(wrap_bounded.to_max_px(Px(200.0)), Px(150.0));
     /// ```
     pub fn to_max_px(&self, default: Px) -> Px {
         match self {
             DimensionValue::Fixed(value) => *value,
             DimensionValue::Wrap { max, .. } => max.unwrap_or(default),
             DimensionValue::Fill { max, .. } => max.unwrap_or(default),
+            DimensionValue::Fraction(_) => default,
         }
     }
 
@@ -266,16 +356,17 @@ impl DimensionValue {
     /// # Returns
     /// - For `Fixed`: Returns `Some(fixed_value)` since fixed dimensions have an implicit maximum
     /// - For `Wrap` and `Fill`: Returns the `max` value if specified, otherwise `None`
+    /// - For `Fraction`: Returns `None`
     ///
     /// # Example
     /// ```
     /// # use tessera_ui::Px;
     /// # use tessera_ui::DimensionValue;
-    /// let fixed = DimensionValue::Fixed(Px(100));
-    /// assert_eq!(fixed.get_max(), Some(Px(100)));
+    /// let fixed = DimensionValue::Fixed(Px(100.0));
+    /// assert_eq!(fixed.get_max(), Some(Px(100.0)));
     ///
-    /// let wrap_bounded = DimensionValue::Wrap { min: Some(Px(50)), max: Some(Px(200)) };
-    /// assert_eq!(wrap_bounded.get_max(), Some(Px(200)));
+    /// let wrap_bounded = DimensionValue::Wrap { min: Some(Px(50.0)), max: Some(Px(200.0)) };
+    /// assert_eq!(wrap_bounded.get_max(), Some(Px(200.0)));
     ///
     /// let wrap_unbounded = DimensionValue::Wrap { min: None, max: None };
     /// assert_eq!(wrap_unbounded.get_max(), None);
@@ -285,6 +376,7 @@ impl DimensionValue {
             DimensionValue::Fixed(value) => Some(*value),
             DimensionValue::Wrap { max, .. } => *max,
             DimensionValue::Fill { max, .. } => *max,
+            DimensionValue::Fraction(_) => None,
         }
     }
 
@@ -297,16 +389,17 @@ impl DimensionValue {
     /// # Returns
     /// - For `Fixed`: Returns `Some(fixed_value)` since fixed dimensions have an implicit minimum
     /// - For `Wrap` and `Fill`: Returns the `min` value if specified, otherwise `None`
+    /// - For `Fraction`: Returns `None`
     ///
     /// # Example
     /// ```
     /// # use tessera_ui::Px;
     /// # use tessera_ui::DimensionValue;
-    /// let fixed = DimensionValue::Fixed(Px(100));
-    /// assert_eq!(fixed.get_min(), Some(Px(100)));
+    /// let fixed = DimensionValue::Fixed(Px(100.0));
+    /// assert_eq!(fixed.get_min(), Some(Px(100.0)));
     ///
-    /// let fill_bounded = DimensionValue::Fill { min: Some(Px(50)), max: Some(Px(200)) };
-    /// assert_eq!(fill_bounded.get_min(), Some(Px(50)));
+    /// let fill_bounded = DimensionValue::Fill { min: Some(Px(50.0)), max: Some(Px(200.0)) };
+    /// assert_eq!(fill_bounded.get_min(), Some(Px(50.0)));
     ///
     /// let fill_unbounded = DimensionValue::Fill { min: None, max: None };
     /// assert_eq!(fill_unbounded.get_min(), None);
@@ -316,6 +409,37 @@ impl DimensionValue {
             DimensionValue::Fixed(value) => Some(*value),
             DimensionValue::Wrap { min, .. } => *min,
             DimensionValue::Fill { min, .. } => *min,
+            DimensionValue::Fraction(_) => None,
+        }
+    }
+
+    /// Clamps a size to the bounds of this dimension.
+    ///
+    /// # Returns
+    /// - For `Fixed`: Returns the fixed value, whatever the given size
+    /// - For `Wrap` and `Fill`: Returns the size, raised to `min` and lowered to `max` if
+    ///   they are specified
+    /// - For `Fraction`: Returns the size unchanged
+    ///
+    /// # Example
+    /// ```
+    /// # use tessera_ui::Px;
+    /// # use tessera_ui::DimensionValue;
+    /// let fixed = DimensionValue::Fixed(Px(100.0));
+    /// assert_eq!(fixed.clamp(Px(40.0)), Px(100.0));
+    ///
+    /// let wrap_bounded = DimensionValue::Wrap { min: Some(Px(50.0)), max: Some(Px(200.0)) };
+    /// assert_eq!(wrap_bounded.clamp(Px(40.0)), Px(50.0));
+    /// assert_eq!(wrap_bounded.clamp(Px(300.0)), Px(200.0));
+    /// ```
+    pub fn clamp(&self, size: Px) -> Px {
+        match self {
+            DimensionValue::Fixed(value) => *value,
+            DimensionValue::Wrap { min, max } | DimensionValue::Fill { min, max } => {
+                let size = min.map_or(size, |min| size.max(min));
+                max.map_or(size, |max| size.min(max))
+            }
+            DimensionValue::Fraction(_) => size,
         }
     }
 }
@@ -348,6 +472,7 @@ impl Sub<Px> for DimensionValue {
                 min,
                 max: max.map(|m| m - rhs),
             },
+            DimensionValue::Fraction(fraction) => DimensionValue::Fraction(fraction),
         }
     }
 }
@@ -366,6 +491,7 @@ impl std::ops::Add<Px> for DimensionValue {
                 min,
                 max: max.map(|m| m + rhs),
             },
+            DimensionValue::Fraction(fraction) => DimensionValue::Fraction(fraction),
         }
     }
 }
@@ -384,6 +510,7 @@ impl std::ops::AddAssign<Px> for DimensionValue {
                     *m = *m + rhs;
                 }
             }
+            DimensionValue::Fraction(_) => {}
         }
     }
 }
@@ -402,6 +529,7 @@ impl std::ops::SubAssign<Px> for DimensionValue {
                     *m = *m - rhs;
                 }
             }
+            DimensionValue::Fraction(_) => {}
         }
     }
 }
@@ -420,28 +548,48 @@ impl std::ops::SubAssign<Px> for DimensionValue {
 /// # use tessera_ui::{Constraint, DimensionValue};
 /// // A button with fixed size
 /// let button_constraint = Constraint::new(
-///     DimensionValue::Fixed(Px(120)),
-///     DimensionValue::Fixed(Px(40))
+///     DimensionValue::Fixed(Px(120.0)),
+///     DimensionValue::Fixed(Px(40.0))
 /// );
 ///
 /// // A flexible container that fills width but wraps height
 /// let container_constraint = Constraint::new(
-///     DimensionValue::Fill { min: Some(Px(200)), max: None },
+///     DimensionValue::Fill { min: Some(Px(200.0)), max: None },
 ///     DimensionValue::Wrap { min: None, max: None }
 /// );
 ///
 /// // A text component with bounded wrapping
 /// let text_constraint = Constraint::new(
-///     DimensionValue::Wrap { min: Some(Px(100)), max: Some(Px(400)) },
+///     DimensionValue::Wrap { min: Some(Px(100.0)), max: Some(Px(400.0)) },
 ///     DimensionValue::Wrap { min: None, max: None }
 /// );
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy)]
 pub struct Constraint {
     /// The width dimension constraint
     pub width: DimensionValue,
     /// The height dimension constraint
     pub height: DimensionValue,
+    /// The ratio of width to height to keep, if any. See [`Constraint::with_aspect_ratio`].
+    pub aspect_ratio: Option<f32>,
+}
+
+impl PartialEq for Constraint {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.aspect_ratio.map(Px) == other.aspect_ratio.map(Px)
+    }
+}
+
+impl Eq for Constraint {}
+
+impl Hash for Constraint {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.aspect_ratio.map(Px).hash(state);
+    }
 }
 
 impl Constraint {
@@ -467,6 +615,7 @@ impl Constraint {
             min: None,
             max: None,
         },
+        aspect_ratio: None,
     };
 
     /// Creates a new constraint with the specified width and height dimensions.
@@ -482,12 +631,80 @@ impl Constraint {
     /// # use tessera_ui::Px;
     /// # use tessera_ui::{Constraint, DimensionValue};
     /// let constraint = Constraint::new(
-    ///     DimensionValue::Fixed(Px(100)),
-    ///     DimensionValue::Fill { min: Some(Px(50)), max: None }
+    ///     DimensionValue::Fixed(Px(100.0)),
+    ///     DimensionValue::Fill { min: Some(Px(50.0)), max: None }
     /// );
     /// ```
     pub fn new(width: DimensionValue, height: DimensionValue) -> Self {
-        Self { width, height }
+        Self {
+            width,
+            height,
+            aspect_ratio: None,
+        }
+    }
+
+    /// Keeps the width and height in the given ratio of width to height.
+    ///
+    /// The ratio is applied when the constraint is merged with its parent constraint:
+    ///
+    /// - If one dimension is `Fixed`, the other one follows it, within its own bounds.
+    /// - Otherwise, the component takes the largest size its `Fill` dimensions allow
+    ///   without exceeding the maximum size of the other dimension.
+    /// - If neither dimension is `Fixed` or a bounded `Fill`, the component wraps its
+    ///   content and the ratio is not applied.
+    ///
+    /// Ratios that are not finite and positive are ignored.
+    ///
+    /// # Example
+    /// ```
+    /// # use tessera_ui::Px;
+    /// # use tessera_ui::{Constraint, DimensionValue};
+    /// // A square avatar that fills the available height
+    /// let avatar = Constraint::new(DimensionValue::WRAP, DimensionValue::FILLED)
+    ///     .with_aspect_ratio(1.0);
+    /// let parent = Constraint::new(
+    ///     DimensionValue::Fixed(Px(300.0)),
+    ///     DimensionValue::Fixed(Px(48.0)),
+    /// );
+    /// let merged = avatar.merge(&parent);
+    /// assert_eq!(merged.width, DimensionValue::Fixed(Px(48.0)));
+    /// assert_eq!(merged.height, DimensionValue::Fixed(Px(48.0)));
+    /// ```
+    pub fn with_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.aspect_ratio = Some(aspect_ratio);
+        self
+    }
+
+    /// Resolves the aspect ratio into fixed dimensions, see [`Constraint::with_aspect_ratio`].
+    fn apply_aspect_ratio(mut self) -> Self {
+        let Some(ratio) = self
+            .aspect_ratio
+            .filter(|ratio| ratio.is_finite() && *ratio > 0.0)
+        else {
+            return self;
+        };
+        let bounded_fill = |dimension: DimensionValue| match dimension {
+            DimensionValue::Fill { max, .. } => max,
+            _ => None,
+        };
+        let width = match (self.width, self.height) {
+            (DimensionValue::Fixed(_), DimensionValue::Fixed(_)) => return self,
+            (DimensionValue::Fixed(width), _) => width,
+            (_, DimensionValue::Fixed(height)) => height * ratio,
+            (width, height) => match (bounded_fill(width), bounded_fill(height)) {
+                (Some(width), _) => height
+                    .get_max()
+                    .map_or(width, |max_height| width.min(max_height * ratio)),
+                (None, Some(height)) => width
+                    .get_max()
+                    .map_or(height * ratio, |max_width| (height * ratio).min(max_width)),
+                (None, None) => return self,
+            },
+        };
+        let width = self.width.clamp(width);
+        self.width = DimensionValue::Fixed(width);
+        self.height = DimensionValue::Fixed(self.height.clamp(width / ratio));
+        self
     }
 
     /// Merges this constraint with a parent constraint to resolve layout conflicts.
@@ -517,6 +734,14 @@ impl Constraint {
     /// - When parent is Wrap: Child fills available space within parent's wrap bounds
     /// - When parent is Fill: Child combines fill constraints with parent
     ///
+    /// ## Fraction Constraints (Relative to Parent)
+    /// - **Resolves to a fixed size**: A fraction of the parent's maximum size
+    /// - When parent has no maximum size: Child wraps its content
+    ///
+    /// ## Aspect Ratio
+    /// - The child's aspect ratio, if any, is applied to the merged dimensions, see
+    ///   [`Constraint::with_aspect_ratio`]
+    ///
     /// # Parameters
     /// - `parent_constraint`: The constraint from the parent component
     ///
@@ -530,31 +755,36 @@ impl Constraint {
     /// # use tessera_ui::{Constraint, DimensionValue};
     /// // Fixed child in fixed parent - child wins
     /// let parent = Constraint::new(
-    ///     DimensionValue::Fixed(Px(200)),
-    ///     DimensionValue::Fixed(Px(200))
+    ///     DimensionValue::Fixed(Px(200.0)),
+    ///     DimensionValue::Fixed(Px(200.0))
     /// );
     /// let child = Constraint::new(
-    ///     DimensionValue::Fixed(Px(100)),
-    ///     DimensionValue::Fixed(Px(100))
+    ///     DimensionValue::Fixed(Px(100.0)),
+    ///     DimensionValue::Fixed(Px(100.0))
     /// );
     /// let merged = child.merge(&parent);
-    /// assert_eq!(merged.width, DimensionValue::Fixed(Px(100)));
+    /// assert_eq!(merged.width, DimensionValue::Fixed(Px(100.0)));
     ///
     /// // Fill child in fixed parent - child fills parent's space
     /// let child_fill = Constraint::new(
-    ///     DimensionValue::Fill { min: Some(Px(50)), max: None },
-    ///     DimensionValue::Fill { min: Some(Px(50)), max: None }
+    ///     DimensionValue::Fill { min: Some(Px(50.0)), max: None },
+    ///     DimensionValue::Fill { min: Some(Px(50.0)), max: None }
     /// );
     /// let merged_fill = child_fill.merge(&parent);
     /// assert_eq!(merged_fill.width, DimensionValue::Fill {
-    ///     min: Some(Px(50)),
-    ///     max: Some(Px(200))
+    ///     min: Some(Px(50.0)),
+    ///     max: Some(Px(200.0))
     /// });
     /// ```
     pub fn merge(&self, parent_constraint: &Constraint) -> Self {
         let new_width = Self::merge_dimension(self.width, parent_constraint.width);
         let new_height = Self::merge_dimension(self.height, parent_constraint.height);
-        Constraint::new(new_width, new_height)
+        Constraint {
+            width: new_width,
+            height: new_height,
+            aspect_ratio: self.aspect_ratio,
+        }
+        .apply_aspect_ratio()
     }
 
     /// Internal helper method that merges two dimension values according to the constraint rules.
@@ -571,6 +801,10 @@ impl Constraint {
     fn merge_dimension(child_dim: DimensionValue, parent_dim: DimensionValue) -> DimensionValue {
         match child_dim {
             DimensionValue::Fixed(cv) => DimensionValue::Fixed(cv), // Child's Fixed overrides
+            DimensionValue::Fraction(fraction) => match parent_dim.get_max() {
+                Some(available) => DimensionValue::Fixed((available * fraction).max(Px(0.0))),
+                None => DimensionValue::WRAP,
+            },
             DimensionValue::Wrap {
                 min: c_min,
                 max: c_max,
@@ -609,6 +843,11 @@ impl Constraint {
                         (None, None) => None,
                     },
                 },
+                // A parent that was not merged itself offers no known space
+                DimensionValue::Fraction(_) => DimensionValue::Wrap {
+                    min: c_min,
+                    max: c_max,
+                },
             },
             DimensionValue::Fill {
                 min: c_fill_min,
@@ -667,6 +906,10 @@ impl Constraint {
                         max: final_max,
                     }
                 }
+                DimensionValue::Fraction(_) => DimensionValue::Fill {
+                    min: c_fill_min,
+                    max: c_fill_max,
+                },
             },
         }
     }
@@ -683,31 +926,31 @@ mod tests {
 
         // Parent component with fixed 100x100 size
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         // Child component that wraps content with bounds 20-80
         let child = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(20)),
-                max: Some(Px(80)),
+                min: Some(Px(20.0)),
+                max: Some(Px(80.0)),
             },
             DimensionValue::Wrap {
-                min: Some(Px(20)),
-                max: Some(Px(80)),
+                min: Some(Px(20.0)),
+                max: Some(Px(80.0)),
             },
         );
 
         // Grandchild component that wraps content with bounds 10-50
         let grandchild = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(10)),
-                max: Some(Px(50)),
+                min: Some(Px(10.0)),
+                max: Some(Px(50.0)),
             },
             DimensionValue::Wrap {
-                min: Some(Px(10)),
-                max: Some(Px(50)),
+                min: Some(Px(10.0)),
+                max: Some(Px(50.0)),
             },
         );
 
@@ -719,15 +962,15 @@ mod tests {
         assert_eq!(
             merged_child.width,
             DimensionValue::Wrap {
-                min: Some(Px(20)),
-                max: Some(Px(80))
+                min: Some(Px(20.0)),
+                max: Some(Px(80.0))
             }
         );
         assert_eq!(
             merged_child.height,
             DimensionValue::Wrap {
-                min: Some(Px(20)),
-                max: Some(Px(80))
+                min: Some(Px(20.0)),
+                max: Some(Px(80.0))
             }
         );
 
@@ -739,15 +982,15 @@ mod tests {
         assert_eq!(
             final_result.width,
             DimensionValue::Wrap {
-                min: Some(Px(10)),
-                max: Some(Px(50))
+                min: Some(Px(10.0)),
+                max: Some(Px(50.0))
             }
         );
         assert_eq!(
             final_result.height,
             DimensionValue::Wrap {
-                min: Some(Px(10)),
-                max: Some(Px(50))
+                min: Some(Px(10.0)),
+                max: Some(Px(50.0))
             }
         );
     }
@@ -759,23 +1002,23 @@ mod tests {
 
         let parent = Constraint::new(
             DimensionValue::Fill {
-                min: Some(Px(50)),
-                max: Some(Px(200)),
+                min: Some(Px(50.0)),
+                max: Some(Px(200.0)),
             },
             DimensionValue::Fill {
-                min: Some(Px(50)),
-                max: Some(Px(200)),
+                min: Some(Px(50.0)),
+                max: Some(Px(200.0)),
             },
         );
 
         let child = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150)),
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0)),
             },
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150)),
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0)),
             },
         );
 
@@ -786,15 +1029,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150))
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150))
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0))
             }
         );
     }
@@ -806,23 +1049,23 @@ mod tests {
 
         let parent = Constraint::new(
             DimensionValue::Fill {
-                min: Some(Px(50)),
-                max: Some(Px(200)),
+                min: Some(Px(50.0)),
+                max: Some(Px(200.0)),
             },
             DimensionValue::Fill {
-                min: Some(Px(50)),
-                max: Some(Px(200)),
+                min: Some(Px(50.0)),
+                max: Some(Px(200.0)),
             },
         );
 
         let child = Constraint::new(
             DimensionValue::Wrap {
                 min: None,
-                max: Some(Px(150)),
+                max: Some(Px(150.0)),
             },
             DimensionValue::Wrap {
                 min: None,
-                max: Some(Px(150)),
+                max: Some(Px(150.0)),
             },
         );
 
@@ -834,14 +1077,14 @@ mod tests {
             result.width,
             DimensionValue::Wrap {
                 min: None,
-                max: Some(Px(150))
+                max: Some(Px(150.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Wrap {
                 min: None,
-                max: Some(Px(150))
+                max: Some(Px(150.0))
             }
         );
     }
@@ -853,23 +1096,23 @@ mod tests {
 
         let parent = Constraint::new(
             DimensionValue::Fill {
-                min: Some(Px(50)),
+                min: Some(Px(50.0)),
                 max: None,
             },
             DimensionValue::Fill {
-                min: Some(Px(50)),
+                min: Some(Px(50.0)),
                 max: None,
             },
         );
 
         let child = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150)),
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0)),
             },
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150)),
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0)),
             },
         );
 
@@ -879,15 +1122,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150))
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(150))
+                min: Some(Px(30.0)),
+                max: Some(Px(150.0))
             }
         );
     }
@@ -898,18 +1141,18 @@ mod tests {
         // Child's max should be capped by parent's fixed size
 
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         let child = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(120)),
+                min: Some(Px(30.0)),
+                max: Some(Px(120.0)),
             },
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(120)),
+                min: Some(Px(30.0)),
+                max: Some(Px(120.0)),
             },
         );
 
@@ -921,15 +1164,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
     }
@@ -940,17 +1183,17 @@ mod tests {
         // Parent's fixed size should become the child's maximum
 
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         let child = Constraint::new(
             DimensionValue::Wrap {
-                min: Some(Px(30)),
+                min: Some(Px(30.0)),
                 max: None,
             },
             DimensionValue::Wrap {
-                min: Some(Px(30)),
+                min: Some(Px(30.0)),
                 max: None,
             },
         );
@@ -962,15 +1205,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Wrap {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
     }
@@ -981,18 +1224,18 @@ mod tests {
         // Child should fill parent's space but be capped by parent's fixed size
 
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         let child = Constraint::new(
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(120)),
+                min: Some(Px(30.0)),
+                max: Some(Px(120.0)),
             },
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(120)),
+                min: Some(Px(30.0)),
+                max: Some(Px(120.0)),
             },
         );
 
@@ -1004,15 +1247,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
     }
@@ -1023,17 +1266,17 @@ mod tests {
         // Parent's fixed size should become the child's maximum
 
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         let child = Constraint::new(
             DimensionValue::Fill {
-                min: Some(Px(30)),
+                min: Some(Px(30.0)),
                 max: None,
             },
             DimensionValue::Fill {
-                min: Some(Px(30)),
+                min: Some(Px(30.0)),
                 max: None,
             },
         );
@@ -1045,15 +1288,15 @@ mod tests {
         assert_eq!(
             result.width,
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Fill {
-                min: Some(Px(30)),
-                max: Some(Px(100))
+                min: Some(Px(30.0)),
+                max: Some(Px(100.0))
             }
         );
     }
@@ -1064,18 +1307,18 @@ mod tests {
         // Child should fill parent's space with no minimum constraint
 
         let parent = Constraint::new(
-            DimensionValue::Fixed(Px(100)),
-            DimensionValue::Fixed(Px(100)),
+            DimensionValue::Fixed(Px(100.0)),
+            DimensionValue::Fixed(Px(100.0)),
         );
 
         let child = Constraint::new(
             DimensionValue::Fill {
                 min: None,
-                max: Some(Px(120)),
+                max: Some(Px(120.0)),
             },
             DimensionValue::Fill {
                 min: None,
-                max: Some(Px(120)),
+                max: Some(Px(120.0)),
             },
         );
 
@@ -1087,15 +1330,77 @@ mod tests {
             result.width,
             DimensionValue::Fill {
                 min: None,
-                max: Some(Px(100))
+                max: Some(Px(100.0))
             }
         );
         assert_eq!(
             result.height,
             DimensionValue::Fill {
                 min: None,
-                max: Some(Px(100))
+                max: Some(Px(100.0))
             }
         );
     }
+
+    #[test]
+    fn test_fraction_child() {
+        let child = Constraint::new(
+            DimensionValue::Fraction(0.25),
+            DimensionValue::Fraction(0.5),
+        );
+
+        // Bounded parent: a fraction of its maximum size
+        let parent = Constraint::new(
+            DimensionValue::Fixed(Px(200.0)),
+            DimensionValue::Fill {
+                min: None,
+                max: Some(Px(100.0)),
+            },
+        );
+        let merged = child.merge(&parent);
+        assert_eq!(merged.width, DimensionValue::Fixed(Px(50.0)));
+        assert_eq!(merged.height, DimensionValue::Fixed(Px(50.0)));
+
+        // Unbounded parent: wrap content
+        let merged = child.merge(&Constraint::NONE);
+        assert_eq!(merged.width, DimensionValue::WRAP);
+        assert_eq!(merged.height, DimensionValue::WRAP);
+    }
+
+    #[test]
+    fn test_aspect_ratio_limited_by_other_dimension() {
+        // A 2:1 child filling a 300x100 parent is limited by the height
+        let child =
+            Constraint::new(DimensionValue::FILLED, DimensionValue::FILLED).with_aspect_ratio(2.0);
+        let parent = Constraint::new(
+            DimensionValue::Fixed(Px(300.0)),
+            DimensionValue::Fixed(Px(100.0)),
+        );
+        let merged = child.merge(&parent);
+        assert_eq!(merged.width, DimensionValue::Fixed(Px(200.0)));
+        assert_eq!(merged.height, DimensionValue::Fixed(Px(100.0)));
+    }
+
+    #[test]
+    fn test_aspect_ratio_follows_fixed_dimension() {
+        let child = Constraint::new(
+            DimensionValue::Fixed(Px(90.0)),
+            DimensionValue::Wrap {
+                min: None,
+                max: Some(Px(40.0)),
+            },
+        )
+        .with_aspect_ratio(1.5);
+        let merged = child.merge(&Constraint::NONE);
+        assert_eq!(merged.width, DimensionValue::Fixed(Px(90.0)));
+        // 60px would keep the ratio, but the height may not exceed 40px
+        assert_eq!(merged.height, DimensionValue::Fixed(Px(40.0)));
+
+        // Without any known size, the ratio is not applied
+        let wrapping = Constraint::NONE.with_aspect_ratio(1.5);
+        assert_eq!(
+            wrapping.merge(&Constraint::NONE).width,
+            DimensionValue::WRAP
+        );
+    }
 }
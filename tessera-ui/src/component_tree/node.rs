@@ -1,20 +1,25 @@
 use std::{
     collections::HashMap,
+    fmt,
     ops::{Add, AddAssign},
+    panic::Location,
     sync::Arc,
     time::Instant,
 };
 
 use dashmap::DashMap;
 use indextree::NodeId;
-use log::debug;
-use parking_lot::RwLock;
+use log::{debug, trace, warn};
+use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
 use winit::window::CursorIcon;
 
 use crate::{
-    Clipboard, ComputeCommand, ComputeResourceManager, DrawCommand, Px,
+    Clipboard, ComputeCommand, ComputeResourceManager, DrawCommand, Px, TesseraRuntime,
+    accessibility::Semantics,
+    context::{self, Context},
     cursor::CursorEvent,
+    profiling::profile_span,
     px::{PxPosition, PxSize},
     renderer::Command,
 };
@@ -25,14 +30,83 @@ use super::constraint::{Constraint, DimensionValue};
 /// It represents all information about a component.
 pub struct ComponentNode {
     /// Component function's name, for debugging purposes.
+    ///
+    /// This is the `name` given to `#[tessera(name = "...")]` if any.
     pub fn_name: String,
+    /// Where the component was defined and called, for debugging purposes.
+    /// None for nodes not created by the `#[tessera]` macro.
+    pub location: Option<SourceLocation>,
+    /// A tag identifying the node in tests and inspection tools, see
+    /// `TreeSnapshot::find_by_test_tag` with the `devtools` feature.
+    ///
+    /// Set with `#[tessera(test_tag = "...")]`, or by the injected `test_tag` function.
+    pub test_tag: Option<String>,
     /// Describes the component in layout.
     /// None means using default measure policy which places children at the top-left corner
     /// of the parent node, with no offset.
     pub measure_fn: Option<Box<MeasureFn>>,
+    /// Answers intrinsic size queries about the component, see [`IntrinsicFn`].
+    /// None means measuring the component without bounds to find its natural size.
+    pub intrinsic_fn: Option<Box<IntrinsicFn>>,
     /// Describes the state handler for the component.
     /// This is used to handle state changes.
     pub state_handler_fn: Option<Box<StateHandlerFn>>,
+    /// Describes the component to assistive technology.
+    /// None means the node is transparent in the accessibility tree.
+    pub semantics: Option<Semantics>,
+    /// The composition locals in scope when the node was added to the tree.
+    ///
+    /// This is filled in by [`ComponentTree::add_node`](crate::ComponentTree::add_node),
+    /// and put back in scope while the node's measure function and state handler run.
+    pub context: Context,
+    /// Identifies the node across frames, see [`ComponentTree::add_node`](crate::ComponentTree::add_node).
+    ///
+    /// This is filled in by `add_node`, overwriting the field.
+    pub key: u64,
+    /// The intrinsic sizes of the node answered this frame, start with an empty cache.
+    pub intrinsic_cache: IntrinsicCache,
+}
+
+impl fmt::Display for ComponentNode {
+    /// Formats the node for diagnostics, like `button [submit] at src/app.rs:12:5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.fn_name)?;
+        if let Some(tag) = &self.test_tag {
+            write!(f, " [{tag}]")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location.caller)?;
+        }
+        Ok(())
+    }
+}
+
+/// Where a component was defined and where it was called.
+///
+/// Recorded by the `#[tessera]` macro for every node it adds, so nodes created by the same
+/// component can be told apart in logs, errors and inspection tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The module path of the component function, from `module_path!()`.
+    pub module_path: &'static str,
+    /// The file defining the component function, from `file!()`.
+    pub file: &'static str,
+    /// The line defining the component function, from `line!()`.
+    pub line: u32,
+    /// Where the component function was called, from `#[track_caller]`.
+    pub caller: &'static Location<'static>,
+}
+
+impl fmt::Display for SourceLocation {
+    /// Formats the definition and the call site, like
+    /// `my_app::button (src/button.rs:8), called at src/app.rs:12:5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}:{}), called at {}",
+            self.module_path, self.file, self.line, self.caller
+        )
+    }
 }
 
 /// Contains metadata of the component node.
@@ -48,6 +122,21 @@ pub struct ComponentNodeMetaData {
     /// This will be computed during drawing command's generation.
     /// None if the node is not drawn yet.
     pub abs_position: Option<PxPosition>,
+    /// The node's alignment lines, relative to its top edge.
+    ///
+    /// Set by the node's measure function with [`MeasureInput::set_alignment_lines`].
+    /// Otherwise, the lines of the placed children are taken over once the node is
+    /// measured, see [`AlignmentLines`].
+    pub alignment_lines: Option<AlignmentLines>,
+    /// The constraint the node was measured with, as offered by its parent.
+    /// None if the node is not measured yet.
+    pub constraint: Option<Constraint>,
+    /// The type names of the commands the node emitted, see [`Command::type_name`].
+    ///
+    /// Recorded while an inspection tool is connected, before the commands are collected
+    /// for rendering, after which `commands` is empty.
+    #[cfg(feature = "devtools")]
+    pub command_types: Vec<&'static str>,
     /// Commands associated with this node.
     ///
     /// This stores both draw and compute commands in a unified vector using the
@@ -64,6 +153,10 @@ impl ComponentNodeMetaData {
             computed_data: None,
             rel_position: None,
             abs_position: None,
+            alignment_lines: None,
+            constraint: None,
+            #[cfg(feature = "devtools")]
+            command_types: Vec::new(),
             commands: Vec::new(),
         }
     }
@@ -151,6 +244,10 @@ pub struct MeasureInput<'a> {
     pub compute_resource_manager: Arc<RwLock<ComputeResourceManager>>,
     /// Gpu device
     pub gpu: &'a wgpu::Device,
+    /// The effective scale factor of the window, see [`TesseraRuntime::scale_factor`].
+    ///
+    /// [`TesseraRuntime::scale_factor`]: crate::TesseraRuntime::scale_factor
+    pub scale_factor: f64,
 }
 
 impl<'a> MeasureInput<'a> {
@@ -215,6 +312,284 @@ impl<'a> MeasureInput<'a> {
     pub fn place_child(&self, child_id: NodeId, position: PxPosition) {
         place_node(child_id, position, self.metadatas);
     }
+
+    /// Reports the alignment lines of the current node, relative to its top edge.
+    ///
+    /// Nodes that do not report any take over the lines of their placed children, so
+    /// only components that lay out content themselves, such as text, need to call this.
+    pub fn set_alignment_lines(&self, lines: AlignmentLines) {
+        self.metadata_mut().alignment_lines = Some(lines);
+    }
+
+    /// Returns the alignment lines of a measured child, relative to the child's top edge.
+    pub fn child_alignment_lines(&self, child_id: NodeId) -> AlignmentLines {
+        self.metadatas
+            .get(&child_id)
+            .and_then(|metadata| metadata.alignment_lines)
+            .unwrap_or_default()
+    }
+}
+
+impl MeasureInput<'_> {
+    /// Returns the smallest width a child can take without its content overflowing, for
+    /// example the width of the widest part of a text that cannot be wrapped. See
+    /// [`intrinsic_size`].
+    pub fn min_intrinsic_width(
+        &self,
+        child_id: NodeId,
+        height: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        self.intrinsic_size(child_id, IntrinsicQuery::MinWidth, height)
+    }
+
+    /// Returns the width a child takes when it is not constrained, for example the width
+    /// of a text on a single line. See [`intrinsic_size`].
+    pub fn max_intrinsic_width(
+        &self,
+        child_id: NodeId,
+        height: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        self.intrinsic_size(child_id, IntrinsicQuery::MaxWidth, height)
+    }
+
+    /// Returns the smallest height a child can take at the given width without its content
+    /// overflowing. See [`intrinsic_size`].
+    pub fn min_intrinsic_height(
+        &self,
+        child_id: NodeId,
+        width: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        self.intrinsic_size(child_id, IntrinsicQuery::MinHeight, width)
+    }
+
+    /// Returns the height a child takes at the given width when it is not constrained.
+    /// See [`intrinsic_size`].
+    pub fn max_intrinsic_height(
+        &self,
+        child_id: NodeId,
+        width: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        self.intrinsic_size(child_id, IntrinsicQuery::MaxHeight, width)
+    }
+
+    /// Answers an intrinsic size query about a child, see [`intrinsic_size`].
+    pub fn intrinsic_size(
+        &self,
+        child_id: NodeId,
+        query: IntrinsicQuery,
+        cross_size: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        intrinsic_size(
+            child_id,
+            query,
+            cross_size,
+            self.tree,
+            self.compute_resource_manager.clone(),
+            self.gpu,
+        )
+    }
+}
+
+/// Which intrinsic size of a component is queried.
+///
+/// Intrinsic sizes describe a component's content independently of the constraint it will
+/// be measured with. Layouts use them to size children relative to each other, for
+/// example to give all children of a column the width of the widest one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntrinsicQuery {
+    /// The smallest width at which the content does not overflow.
+    MinWidth,
+    /// The width the content takes when it is not constrained.
+    MaxWidth,
+    /// The smallest height at which the content does not overflow.
+    MinHeight,
+    /// The height the content takes when it is not constrained.
+    MaxHeight,
+}
+
+/// Whether a layout should use the minimum or the maximum intrinsic size of its content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntrinsicSize {
+    /// The smallest size at which the content does not overflow.
+    Min,
+    /// The size the content takes when it is not constrained.
+    Max,
+}
+
+impl IntrinsicQuery {
+    /// Returns the query for the given intrinsic width.
+    pub fn width(size: IntrinsicSize) -> Self {
+        match size {
+            IntrinsicSize::Min => Self::MinWidth,
+            IntrinsicSize::Max => Self::MaxWidth,
+        }
+    }
+
+    /// Returns the query for the given intrinsic height.
+    pub fn height(size: IntrinsicSize) -> Self {
+        match size {
+            IntrinsicSize::Min => Self::MinHeight,
+            IntrinsicSize::Max => Self::MaxHeight,
+        }
+    }
+
+    /// Returns whether this query is about a width, rather than a height.
+    pub fn is_width(self) -> bool {
+        matches!(self, Self::MinWidth | Self::MaxWidth)
+    }
+
+    /// Returns whether this query asks for a minimum, rather than a maximum, size.
+    pub fn is_min(self) -> bool {
+        matches!(self, Self::MinWidth | Self::MinHeight)
+    }
+}
+
+/// An `IntrinsicFn` answers intrinsic size queries about a component without laying it
+/// out, see [`IntrinsicQuery`].
+///
+/// It is optional: components without one are measured without bounds on the queried
+/// axis, which gives their natural size for both the minimum and the maximum query.
+/// Layouts should provide one that combines the intrinsic sizes of their children.
+pub type IntrinsicFn = dyn Fn(&IntrinsicInput<'_>) -> Result<Px, MeasurementError> + Send + Sync;
+
+/// Input for the intrinsic function (`IntrinsicFn`).
+pub struct IntrinsicInput<'a> {
+    /// The intrinsic size to compute.
+    pub query: IntrinsicQuery,
+    /// The size available on the other axis: the height for width queries and the width
+    /// for height queries. `None` means unbounded.
+    pub cross_size: Option<Px>,
+    /// The `NodeId` of the current node being queried.
+    pub current_node_id: indextree::NodeId,
+    /// The component tree containing all nodes.
+    pub tree: &'a ComponentNodeTree,
+    /// The children nodes of the current node.
+    pub children_ids: &'a [indextree::NodeId],
+    /// Compute resources manager
+    pub compute_resource_manager: Arc<RwLock<ComputeResourceManager>>,
+    /// Gpu device
+    pub gpu: &'a wgpu::Device,
+    /// The effective scale factor of the window, see [`TesseraRuntime::scale_factor`].
+    ///
+    /// [`TesseraRuntime::scale_factor`]: crate::TesseraRuntime::scale_factor
+    pub scale_factor: f64,
+}
+
+impl IntrinsicInput<'_> {
+    /// Answers an intrinsic size query about a child, see [`intrinsic_size`].
+    pub fn child_intrinsic_size(
+        &self,
+        child_id: NodeId,
+        query: IntrinsicQuery,
+        cross_size: Option<Px>,
+    ) -> Result<Px, MeasurementError> {
+        intrinsic_size(
+            child_id,
+            query,
+            cross_size,
+            self.tree,
+            self.compute_resource_manager.clone(),
+            self.gpu,
+        )
+    }
+}
+
+/// The intrinsic sizes of a node answered during the current frame.
+///
+/// Layouts often query the same child several times, and nested layouts query the nodes
+/// below them again for each of their own queries. Since the tree does not change until it
+/// is rebuilt, [`intrinsic_size`] answers each query about a node once per frame.
+#[derive(Default)]
+pub struct IntrinsicCache {
+    sizes: Mutex<HashMap<(IntrinsicQuery, Option<Px>), Px>>,
+}
+
+/// Answers an intrinsic size query about a node.
+///
+/// Nodes with an [`IntrinsicFn`] answer it themselves. Other nodes are measured without
+/// bounds on the queried axis, and with `cross_size` fixed on the other axis if given.
+/// This measurement is done on scratch metadata, so it does not affect the layout or
+/// the draw commands of the current frame.
+///
+/// Answers are kept in the [`IntrinsicCache`] of the node for the rest of the frame.
+pub fn intrinsic_size(
+    node_id: NodeId,
+    query: IntrinsicQuery,
+    cross_size: Option<Px>,
+    tree: &ComponentNodeTree,
+    compute_resource_manager: Arc<RwLock<ComputeResourceManager>>,
+    gpu: &wgpu::Device,
+) -> Result<Px, MeasurementError> {
+    let node_data = tree
+        .get(node_id)
+        .ok_or(MeasurementError::NodeNotFoundInTree)?
+        .get();
+    let key = (query, cross_size);
+    if let Some(&size) = node_data.intrinsic_cache.sizes.lock().get(&key) {
+        return Ok(size);
+    }
+    let size = compute_intrinsic_size(
+        node_id,
+        node_data,
+        query,
+        cross_size,
+        tree,
+        compute_resource_manager,
+        gpu,
+    )?;
+    node_data.intrinsic_cache.sizes.lock().insert(key, size);
+    Ok(size)
+}
+
+fn compute_intrinsic_size(
+    node_id: NodeId,
+    node_data: &ComponentNode,
+    query: IntrinsicQuery,
+    cross_size: Option<Px>,
+    tree: &ComponentNodeTree,
+    compute_resource_manager: Arc<RwLock<ComputeResourceManager>>,
+    gpu: &wgpu::Device,
+) -> Result<Px, MeasurementError> {
+    if let Some(intrinsic_fn) = &node_data.intrinsic_fn {
+        let children: Vec<_> = node_id.children(tree).collect();
+        return context::with_context(&node_data.context, || {
+            intrinsic_fn(&IntrinsicInput {
+                query,
+                cross_size,
+                current_node_id: node_id,
+                tree,
+                children_ids: &children,
+                compute_resource_manager,
+                gpu,
+                scale_factor: TesseraRuntime::scale_factor(),
+            })
+        });
+    }
+
+    let unbounded = DimensionValue::Wrap {
+        min: None,
+        max: None,
+    };
+    let cross = cross_size.map_or(unbounded, DimensionValue::Fixed);
+    let constraint = if query.is_width() {
+        Constraint::new(unbounded, cross)
+    } else {
+        Constraint::new(cross, unbounded)
+    };
+    let scratch = ComponentNodeMetaDatas::new();
+    let size = measure_node(
+        node_id,
+        &constraint,
+        tree,
+        &scratch,
+        compute_resource_manager,
+        gpu,
+    )?;
+    Ok(if query.is_width() {
+        size.width
+    } else {
+        size.height
+    })
 }
 
 /// A `StateHandlerFn` is a function that handles state changes for a component.
@@ -251,6 +626,10 @@ pub struct StateHandlerInput<'a> {
     pub requests: &'a mut WindowRequests,
     /// Clipboard
     pub clipboard: &'a mut Clipboard,
+    /// The effective scale factor of the window, see [`TesseraRuntime::scale_factor`].
+    ///
+    /// [`TesseraRuntime::scale_factor`]: crate::TesseraRuntime::scale_factor
+    pub scale_factor: f64,
 }
 
 /// A collection of requests that components can make to the windowing system for the current frame.
@@ -300,58 +679,93 @@ pub fn measure_node(
     gpu: &wgpu::Device,
 ) -> Result<ComputedData, MeasurementError> {
     // Make sure metadata and default value exists for the node.
-    component_node_metadatas.insert(node_id, Default::default());
+    component_node_metadatas.insert(
+        node_id,
+        ComponentNodeMetaData {
+            constraint: Some(*parent_constraint),
+            ..Default::default()
+        },
+    );
 
     let node_data_ref = tree
         .get(node_id)
         .ok_or(MeasurementError::NodeNotFoundInTree)?;
     let node_data = node_data_ref.get();
+    let _span = profile_span!("measure", fn_name = node_data.fn_name);
 
     let children: Vec<_> = node_id.children(tree).collect(); // No .as_ref() needed for &Arena
+    let scale_factor = TesseraRuntime::scale_factor();
     let timer = Instant::now();
 
-    debug!(
+    trace!(
         "Measuring node {} with {} children, parent constraint: {:?}",
-        node_data.fn_name,
+        node_data,
         children.len(),
         parent_constraint
     );
 
-    let size = if let Some(measure_fn) = &node_data.measure_fn {
-        measure_fn(&MeasureInput {
-            current_node_id: node_id,
-            tree,
-            parent_constraint,
-            children_ids: &children,
-            metadatas: component_node_metadatas,
-            compute_resource_manager,
-            gpu,
-        })
-    } else {
-        DEFAULT_LAYOUT_DESC(&MeasureInput {
-            current_node_id: node_id,
-            tree,
-            parent_constraint,
-            children_ids: &children,
-            metadatas: component_node_metadatas,
-            compute_resource_manager,
-            gpu,
-        })
-    }?;
+    let size = context::with_context(&node_data.context, || {
+        if let Some(measure_fn) = &node_data.measure_fn {
+            measure_fn(&MeasureInput {
+                current_node_id: node_id,
+                tree,
+                parent_constraint,
+                children_ids: &children,
+                metadatas: component_node_metadatas,
+                compute_resource_manager,
+                gpu,
+                scale_factor,
+            })
+        } else {
+            DEFAULT_LAYOUT_DESC(&MeasureInput {
+                current_node_id: node_id,
+                tree,
+                parent_constraint,
+                children_ids: &children,
+                metadatas: component_node_metadatas,
+                compute_resource_manager,
+                gpu,
+                scale_factor,
+            })
+        }
+    })
+    .inspect_err(|e| {
+        // Ancestors of a failing node fail too, only report where the failure started
+        if !matches!(e, MeasurementError::ChildMeasurementFailed(_)) {
+            warn!("Measuring node {node_data} failed: {e:?}");
+        }
+    })?;
 
-    debug!(
+    trace!(
         "Measured node {} in {:?} with size {:?}",
-        node_data.fn_name,
+        node_data,
         timer.elapsed(),
         size
     );
 
+    let children_lines = children_alignment_lines(&children, component_node_metadatas);
     let mut metadata = component_node_metadatas.entry(node_id).or_default();
     metadata.computed_data = Some(size);
+    metadata.alignment_lines.get_or_insert(children_lines);
 
     Ok(size)
 }
 
+/// Combines the alignment lines of the placed children, moved by their positions.
+fn children_alignment_lines(
+    children: &[NodeId],
+    component_node_metadatas: &ComponentNodeMetaDatas,
+) -> AlignmentLines {
+    children
+        .iter()
+        .filter_map(|child_id| {
+            let metadata = component_node_metadatas.get(child_id)?;
+            let position = metadata.rel_position?;
+            Some(metadata.alignment_lines?.offset(position.y))
+        })
+        .fold(AlignmentLines::NONE, AlignmentLines::merge)
+}
+
 /// Places a node at the specified relative position within its parent.
 pub fn place_node(
     node: indextree::NodeId,
@@ -479,6 +893,8 @@ pub const DEFAULT_LAYOUT_DESC: &MeasureFn = &|input| {
             // The actual "filling" happens because children might be Fill.
             // If children are not Fill, this node wraps them.
         }
+        // Only offered by parents that did not merge it, and then there is no known space
+        DimensionValue::Fraction(_) => {}
     }
     match input.parent_constraint.height {
         DimensionValue::Fixed(h) => final_height = h,
@@ -498,6 +914,7 @@ pub const DEFAULT_LAYOUT_DESC: &MeasureFn = &|input| {
                 final_height = final_height.min(max_h);
             }
         }
+        DimensionValue::Fraction(_) => {}
     }
     Ok(ComputedData {
         width: final_width,
@@ -536,6 +953,76 @@ pub fn measure_nodes(
         .collect::<HashMap<NodeId, Result<ComputedData, MeasurementError>>>()
 }
 
+/// Horizontal lines of a measured node that its parent can align it on, such as the
+/// baselines of its text. Positions are relative to the top edge of the node.
+///
+/// A node that does not report its own lines takes over the lines of its placed
+/// children: the topmost first baseline and the bottommost last baseline. This carries
+/// the baselines of a text through the containers around it, including their padding.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::{AlignmentLines, Px};
+///
+/// let label = AlignmentLines::baseline(Px(12.0));
+/// let paragraph = AlignmentLines {
+///     first_baseline: Some(Px(14.0)),
+///     last_baseline: Some(Px(50.0)),
+/// };
+///
+/// // A paragraph placed 20px below the label
+/// let merged = label.merge(paragraph.offset(Px(20.0)));
+/// assert_eq!(merged.first_baseline, Some(Px(12.0)));
+/// assert_eq!(merged.last_baseline, Some(Px(70.0)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AlignmentLines {
+    /// The baseline of the first line of text.
+    pub first_baseline: Option<Px>,
+    /// The baseline of the last line of text.
+    pub last_baseline: Option<Px>,
+}
+
+impl AlignmentLines {
+    /// No alignment lines.
+    pub const NONE: Self = Self {
+        first_baseline: None,
+        last_baseline: None,
+    };
+
+    /// Alignment lines of a single line of text with the given baseline.
+    pub fn baseline(baseline: Px) -> Self {
+        Self {
+            first_baseline: Some(baseline),
+            last_baseline: Some(baseline),
+        }
+    }
+
+    /// Moves the lines down by `dy`, e.g. from a child's coordinates to its parent's.
+    pub fn offset(self, dy: Px) -> Self {
+        Self {
+            first_baseline: self.first_baseline.map(|line| line + dy),
+            last_baseline: self.last_baseline.map(|line| line + dy),
+        }
+    }
+
+    /// Combines two sets of lines in the same coordinates, keeping the topmost first
+    /// baseline and the bottommost last baseline.
+    pub fn merge(self, other: Self) -> Self {
+        fn pick(a: Option<Px>, b: Option<Px>, f: fn(Px, Px) -> Px) -> Option<Px> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(f(a, b)),
+                (a, b) => a.or(b),
+            }
+        }
+        Self {
+            first_baseline: pick(self.first_baseline, other.first_baseline, Px::min),
+            last_baseline: pick(self.last_baseline, other.last_baseline, Px::max),
+        }
+    }
+}
+
 /// Layout information computed at the measure stage, representing the size of a node.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ComputedData {
@@ -561,22 +1048,25 @@ impl AddAssign for ComputedData {
 
 impl ComputedData {
     pub const ZERO: Self = Self {
-        width: Px(0),
-        height: Px(0),
+        width: Px(0.0),
+        height: Px(0.0),
     };
 
     /// Calculates a "minimum" size based on a constraint.
     /// For Fixed, it's the fixed value. For Wrap/Fill, it's their 'min' if Some, else 0.
+    /// For Fraction, which depends on the parent, it's 0.
     pub fn min_from_constraint(constraint: &Constraint) -> Self {
         let width = match constraint.width {
             DimensionValue::Fixed(w) => w,
-            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0)),
-            DimensionValue::Fill { min, .. } => min.unwrap_or(Px(0)),
+            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fill { min, .. } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fraction(_) => Px(0.0),
         };
         let height = match constraint.height {
             DimensionValue::Fixed(h) => h,
-            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0)),
-            DimensionValue::Fill { min, .. } => min.unwrap_or(Px(0)),
+            DimensionValue::Wrap { min, .. } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fill { min, .. } => min.unwrap_or(Px(0.0)),
+            DimensionValue::Fraction(_) => Px(0.0),
         };
         Self { width, height }
     }
@@ -0,0 +1,186 @@
+//! Snapshots of a laid out component tree, for inspection tools.
+//!
+//! The snapshot types are defined by the devtools protocol, this module fills them in from
+//! the tree.
+
+use indextree::NodeId;
+use tessera_ui_devtools_protocol::{
+    ConstraintSnapshot, DimensionSnapshot, LocationSnapshot, NodeSnapshot, PositionSnapshot,
+    SizeSnapshot,
+};
+
+use crate::px::{Px, PxPosition};
+
+use super::{
+    ComponentNodeMetaDatas, ComponentNodeTree, ComputedData, Constraint, DimensionValue,
+    SourceLocation,
+};
+
+fn location_snapshot(location: &SourceLocation) -> LocationSnapshot {
+    LocationSnapshot {
+        module_path: location.module_path.to_string(),
+        file: location.file.to_string(),
+        line: location.line,
+        caller_file: location.caller.file().to_string(),
+        caller_line: location.caller.line(),
+        caller_column: location.caller.column(),
+    }
+}
+
+fn size_snapshot(size: ComputedData) -> SizeSnapshot {
+    SizeSnapshot {
+        width: size.width.to_f32(),
+        height: size.height.to_f32(),
+    }
+}
+
+fn position_snapshot(position: PxPosition) -> PositionSnapshot {
+    PositionSnapshot {
+        x: position.x.to_f32(),
+        y: position.y.to_f32(),
+    }
+}
+
+/// Converts a bound, with [`Px::MAX`] meaning unbounded
+fn bound_snapshot(bound: Option<Px>) -> Option<f32> {
+    bound.filter(|bound| *bound < Px::MAX).map(Px::to_f32)
+}
+
+fn dimension_snapshot(dimension: DimensionValue) -> DimensionSnapshot {
+    match dimension {
+        DimensionValue::Fixed(value) => DimensionSnapshot::Fixed(value.to_f32()),
+        DimensionValue::Wrap { min, max } => DimensionSnapshot::Wrap {
+            min: bound_snapshot(min),
+            max: bound_snapshot(max),
+        },
+        DimensionValue::Fill { min, max } => DimensionSnapshot::Fill {
+            min: bound_snapshot(min),
+            max: bound_snapshot(max),
+        },
+        DimensionValue::Fraction(fraction) => DimensionSnapshot::Fraction(fraction),
+    }
+}
+
+fn constraint_snapshot(constraint: Constraint) -> ConstraintSnapshot {
+    ConstraintSnapshot {
+        width: dimension_snapshot(constraint.width),
+        height: dimension_snapshot(constraint.height),
+        aspect_ratio: constraint.aspect_ratio,
+    }
+}
+
+/// Builds the snapshot of the subtree starting at `node_id`.
+pub(super) fn snapshot_node(
+    node_id: NodeId,
+    tree: &ComponentNodeTree,
+    metadatas: &ComponentNodeMetaDatas,
+) -> NodeSnapshot {
+    let node = tree.get(node_id).map(|node| node.get());
+    let mut snapshot = NodeSnapshot {
+        id: node.map_or(0, |node| node.key),
+        fn_name: node.map(|node| node.fn_name.clone()).unwrap_or_default(),
+        test_tag: node.and_then(|node| node.test_tag.clone()),
+        location: node
+            .and_then(|node| node.location.as_ref())
+            .map(location_snapshot),
+        size: None,
+        rel_position: None,
+        abs_position: None,
+        constraint: None,
+        commands: Vec::new(),
+        children: Vec::new(),
+    };
+    if let Some(metadata) = metadatas.get(&node_id) {
+        snapshot.size = metadata.computed_data.map(size_snapshot);
+        snapshot.rel_position = metadata.rel_position.map(position_snapshot);
+        snapshot.abs_position = metadata.abs_position.map(position_snapshot);
+        snapshot.constraint = metadata.constraint.map(constraint_snapshot);
+        snapshot.commands = metadata
+            .command_types
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+    }
+    snapshot.children = node_id
+        .children(tree)
+        .map(|child| snapshot_node(child, tree, metadatas))
+        .collect();
+    snapshot
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ComponentNode, ComponentTree};
+
+    use super::*;
+
+    fn node(name: &str) -> ComponentNode {
+        ComponentNode {
+            fn_name: name.to_string(),
+            location: None,
+            test_tag: None,
+            measure_fn: None,
+            intrinsic_fn: None,
+            state_handler_fn: None,
+            semantics: None,
+            context: Default::default(),
+            key: 0,
+            intrinsic_cache: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_snapshot_mirrors_tree() {
+        let mut tree = ComponentTree::new();
+        tree.add_node(node("column"));
+        tree.add_node(node("text"));
+        tree.pop_node();
+        tree.add_node(ComponentNode {
+            test_tag: Some("submit".to_string()),
+            ..node("button")
+        });
+        tree.pop_node();
+        tree.pop_node();
+        let root = tree.root_node().unwrap();
+
+        {
+            let mut metadata = tree.metadatas().get_mut(&root).unwrap();
+            metadata.computed_data = Some(ComputedData {
+                width: Px(100.0),
+                height: Px(40.0),
+            });
+            metadata.constraint = Some(Constraint::new(
+                DimensionValue::Fixed(Px(100.0)),
+                DimensionValue::WRAP,
+            ));
+            metadata.command_types = vec!["ShapeCommand"];
+        }
+
+        let snapshot = tree.snapshot();
+        assert_eq!(snapshot.node_count(), 3);
+        let root = snapshot.root.as_ref().unwrap();
+        assert_eq!(root.fn_name, "column");
+        assert_eq!(root.size.unwrap().width, 100.0);
+        assert_eq!(
+            root.constraint.unwrap().height,
+            DimensionSnapshot::Wrap {
+                min: None,
+                max: None
+            }
+        );
+        assert_eq!(root.commands, ["ShapeCommand"]);
+        let names: Vec<_> = root.children.iter().map(|c| c.fn_name.as_str()).collect();
+        assert_eq!(names, ["text", "button"]);
+        let button = &root.children[1];
+        assert_eq!(snapshot.find(button.id), Some(button));
+        assert!(button.size.is_none());
+        assert_eq!(snapshot.find_by_test_tag("submit"), Some(button));
+        assert!(snapshot.find_by_test_tag("cancel").is_none());
+
+        let json = snapshot.to_json();
+        assert_eq!(
+            tessera_ui_devtools_protocol::TreeSnapshot::from_json(&json).unwrap(),
+            snapshot
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! # Composition Locals
+//!
+//! This module lets a component hand values down to every component below it without
+//! threading them through each function signature. It is the mechanism behind things like
+//! the theme, the content color of a surface, or application-wide services.
+//!
+//! ## Overview
+//!
+//! - [`provide`] makes a value available to everything built inside its closure.
+//! - [`current`] returns the innermost value of a type, or `None` if nothing provided one.
+//!
+//! Values are identified by their type, so wrap plain values in a newtype to give them a
+//! meaning of their own:
+//!
+//! ```
+//! use tessera_ui::{Color, context::{current, provide}};
+//!
+//! struct ContentColor(Color);
+//!
+//! provide(ContentColor(Color::WHITE), || {
+//!     assert_eq!(current::<ContentColor>().unwrap().0, Color::WHITE);
+//!
+//!     // Inner values shadow outer ones of the same type
+//!     provide(ContentColor(Color::BLACK), || {
+//!         assert_eq!(current::<ContentColor>().unwrap().0, Color::BLACK);
+//!     });
+//! });
+//!
+//! assert!(current::<ContentColor>().is_none());
+//! ```
+//!
+//! ## Scoping
+//!
+//! Every node added to the [`ComponentTree`](crate::ComponentTree) remembers the values in
+//! scope when it was added. While that node's measure function and state handler run, the
+//! same values are in scope again, so [`current`] returns the same result during build,
+//! measure and state handling. Values provided inside a component are only seen by the
+//! nodes added within the `provide` closure, not by the component's own node.
+//!
+//! ## Thread Safety
+//!
+//! Measure functions run in parallel, so provided values must be `Send + Sync`. The values
+//! in scope are tracked per thread, and each measure function or state handler runs with
+//! the values of its own node, whichever thread it runs on.
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    sync::Arc,
+};
+
+thread_local! {
+    /// The values in scope on this thread.
+    static CURRENT: RefCell<Context> = RefCell::new(Context::default());
+}
+
+/// A set of provided values, innermost first.
+///
+/// Contexts are immutable and cheap to clone: providing a value creates a new context
+/// that shares all outer values with its parent.
+#[derive(Clone, Default)]
+pub struct Context {
+    head: Option<Arc<Entry>>,
+}
+
+/// A single provided value.
+struct Entry {
+    type_id: TypeId,
+    value: Arc<dyn Any + Send + Sync>,
+    parent: Option<Arc<Entry>>,
+}
+
+impl Context {
+    /// Returns the innermost value of type `T` in this context.
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        let type_id = TypeId::of::<T>();
+        let mut entry = self.head.as_ref();
+        while let Some(current) = entry {
+            if current.type_id == type_id {
+                return current.value.clone().downcast().ok();
+            }
+            entry = current.parent.as_ref();
+        }
+        None
+    }
+
+    /// Returns a new context with `value` shadowing any outer value of the same type.
+    fn with<T: Send + Sync + 'static>(&self, value: T) -> Self {
+        Self {
+            head: Some(Arc::new(Entry {
+                type_id: TypeId::of::<T>(),
+                value: Arc::new(value),
+                parent: self.head.clone(),
+            })),
+        }
+    }
+}
+
+/// Restores the previous context, even if the scoped closure panicked.
+struct Scope {
+    previous: Context,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        CURRENT.set(std::mem::take(&mut self.previous));
+    }
+}
+
+/// Runs `content` with `context` as the values in scope on this thread.
+pub(crate) fn with_context<R>(context: &Context, content: impl FnOnce() -> R) -> R {
+    let _scope = Scope {
+        previous: CURRENT.replace(context.clone()),
+    };
+    content()
+}
+
+/// Returns the values in scope on this thread.
+pub(crate) fn capture() -> Context {
+    CURRENT.with_borrow(Context::clone)
+}
+
+/// Provides `value` to every component built by `content`.
+///
+/// The value shadows any value of the same type provided by an enclosing call, for the
+/// duration of `content` only. Components added inside `content` keep seeing the value
+/// in their measure functions and state handlers.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::context::{current, provide};
+///
+/// struct Locale(&'static str);
+///
+/// let greeting = provide(Locale("fr"), || match current::<Locale>().unwrap().0 {
+///     "fr" => "Bonjour",
+///     _ => "Hello",
+/// });
+/// assert_eq!(greeting, "Bonjour");
+/// ```
+pub fn provide<T: Send + Sync + 'static, R>(value: T, content: impl FnOnce() -> R) -> R {
+    let context = capture().with(value);
+    with_context(&context, content)
+}
+
+/// Returns the innermost provided value of type `T`, or `None` if there is none.
+///
+/// During build this is the value provided by the enclosing [`provide`] calls. Inside a
+/// measure function or a state handler, it is the value that was in scope when the
+/// component's node was added to the tree.
+pub fn current<T: Send + Sync + 'static>() -> Option<Arc<T>> {
+    CURRENT.with_borrow(|context| context.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shadowing_and_restoring() {
+        provide(1u32, || {
+            provide("outer", || {
+                provide(2u32, || {
+                    assert_eq!(*current::<u32>().unwrap(), 2);
+                    assert_eq!(*current::<&str>().unwrap(), "outer");
+                });
+                assert_eq!(*current::<u32>().unwrap(), 1);
+            });
+            assert!(current::<&str>().is_none());
+        });
+        assert!(current::<u32>().is_none());
+    }
+
+    #[test]
+    fn test_captured_context_is_restored_later() {
+        let captured = provide(7u8, capture);
+        assert!(current::<u8>().is_none());
+        let value = with_context(&captured, || *current::<u8>().unwrap());
+        assert_eq!(value, 7);
+        assert!(current::<u8>().is_none());
+    }
+
+    #[test]
+    fn test_nodes_capture_context() {
+        let mut tree = crate::ComponentTree::new();
+        let node = || crate::ComponentNode {
+            fn_name: "node".to_string(),
+            location: None,
+            test_tag: None,
+            measure_fn: None,
+            intrinsic_fn: None,
+            state_handler_fn: None,
+            semantics: None,
+            context: Context::default(),
+            key: 0,
+            intrinsic_cache: Default::default(),
+        };
+        provide(3u16, || tree.add_node(node()));
+        tree.pop_node();
+        tree.add_node(node());
+        let root = tree.current_node().unwrap();
+        assert!(root.context.get::<u16>().is_none());
+        let first = tree.get(tree.root_node().unwrap()).unwrap();
+        assert_eq!(*first.context.get::<u16>().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_scope_survives_panic() {
+        let result = std::panic::catch_unwind(|| provide(1i64, || panic!("content failed")));
+        assert!(result.is_err());
+        assert!(current::<i64>().is_none());
+    }
+}
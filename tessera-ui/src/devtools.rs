@@ -0,0 +1,434 @@
+//! # Devtools
+//!
+//! This module lets external tools inspect a running application, with the `devtools`
+//! feature. When [`TesseraConfig::devtools`](crate::renderer::TesseraConfig::devtools) is
+//! set, the renderer listens on a local TCP port or Unix socket, and streams a
+//! [`TreeSnapshot`](crate::TreeSnapshot) and [`FrameStats`] of every rendered frame to the
+//! connected clients.
+//!
+//! ```rust,no_run
+//! use tessera_ui::{Renderer, devtools::DevtoolsAddress, renderer::TesseraConfig};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let config = TesseraConfig {
+//!     devtools: Some(DevtoolsAddress::localhost(DevtoolsAddress::DEFAULT_PORT)),
+//!     ..Default::default()
+//! };
+//! Renderer::run_with_config(|| {}, |_app| {}, config)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The `tessera-ui-devtools` crate of the workspace is a terminal client for it. The
+//! messages are defined by the `tessera-ui-devtools-protocol` crate, re-exported here,
+//! which clients can use without depending on the renderer.
+//!
+//! Snapshots are only taken while a client is connected, so an idle server costs nothing
+//! per frame. Only the latest frame waits to be sent: frames rendered while the clients are
+//! still busy with an earlier one are skipped, and a client that stops reading is
+//! disconnected once a write to it times out.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::Duration,
+};
+
+#[cfg(unix)]
+use std::os::unix::{
+    fs::FileTypeExt,
+    net::{UnixListener, UnixStream},
+};
+
+use log::{debug, info, warn};
+use parking_lot::{Condvar, Mutex};
+
+use crate::TesseraRuntime;
+
+pub use tessera_ui_devtools_protocol::{
+    DevtoolsAddress, DevtoolsCommand, DevtoolsMessage, FrameStats,
+};
+
+/// How long a write to a client may block before the client is disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Applies a command of a client to the running application.
+fn apply(command: DevtoolsCommand) {
+    match command {
+        DevtoolsCommand::Highlight { node } => TesseraRuntime::set_highlighted_node(node),
+        DevtoolsCommand::SetDebugOverlay { enabled } => TesseraRuntime::set_debug_overlay(enabled),
+        DevtoolsCommand::ToggleDebugOverlay => {
+            TesseraRuntime::set_debug_overlay(!TesseraRuntime::debug_overlay())
+        }
+        DevtoolsCommand::RequestFrame => TesseraRuntime::request_redraw(),
+    }
+}
+
+/// A connection to a client, over TCP or a Unix socket.
+enum Stream {
+    Tcp(TcpStream),
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
+
+impl Stream {
+    fn try_clone(&self) -> io::Result<Self> {
+        match self {
+            Stream::Tcp(stream) => stream.try_clone().map(Stream::Tcp),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.try_clone().map(Stream::Unix),
+        }
+    }
+
+    fn set_write_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.set_write_timeout(timeout),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.set_write_timeout(timeout),
+        }
+    }
+}
+
+impl io::Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Stream::Tcp(stream) => stream.read(buf),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Stream::Tcp(stream) => stream.write(buf),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Stream::Tcp(stream) => stream.flush(),
+            #[cfg(unix)]
+            Stream::Unix(stream) => stream.flush(),
+        }
+    }
+}
+
+/// The clients currently connected to a server.
+#[derive(Default)]
+struct Clients {
+    streams: Mutex<Vec<Stream>>,
+    /// The number of connected clients, readable without locking from the UI thread
+    count: AtomicUsize,
+}
+
+impl Clients {
+    fn add(&self, stream: Stream) {
+        self.streams.lock().push(stream);
+        self.count.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Writes `line` to every client, dropping the ones that disconnected or fell behind.
+    ///
+    /// The clients are written to without holding the lock, so a slow client never keeps
+    /// new ones from connecting.
+    fn broadcast(&self, line: &[u8]) {
+        let mut streams = std::mem::take(&mut *self.streams.lock());
+        streams.retain_mut(|stream| match stream.write_all(line) {
+            Ok(()) => true,
+            Err(e) => {
+                debug!("Dropping devtools client: {e}");
+                false
+            }
+        });
+        let mut current = self.streams.lock();
+        // Clients that connected in the meantime go after the existing ones
+        streams.append(&mut current);
+        *current = streams;
+        self.count.store(current.len(), Ordering::Release);
+    }
+}
+
+/// The latest message waiting to be written to the clients.
+#[derive(Default)]
+struct Outbox {
+    state: Mutex<OutboxState>,
+    ready: Condvar,
+}
+
+#[derive(Default)]
+struct OutboxState {
+    /// The serialized message, replaced when a newer one is published before it was sent
+    line: Option<Vec<u8>>,
+    /// Set when the server stops, to end the writer thread
+    closed: bool,
+}
+
+impl Outbox {
+    /// Replaces the waiting message with `line`.
+    fn put(&self, line: Vec<u8>) {
+        self.state.lock().line = Some(line);
+        self.ready.notify_one();
+    }
+
+    /// Waits for the next message, `None` once the server stopped.
+    fn take(&self) -> Option<Vec<u8>> {
+        let mut state = self.state.lock();
+        loop {
+            if state.closed {
+                return None;
+            }
+            if let Some(line) = state.line.take() {
+                return Some(line);
+            }
+            self.ready.wait(&mut state);
+        }
+    }
+
+    fn close(&self) {
+        self.state.lock().closed = true;
+        self.ready.notify_one();
+    }
+}
+
+/// The devtools server started by the renderer.
+pub(crate) struct DevtoolsServer {
+    clients: Arc<Clients>,
+    /// Messages are written to the clients by a background thread so a slow client never
+    /// stalls a frame
+    outbox: Arc<Outbox>,
+}
+
+impl DevtoolsServer {
+    /// Starts listening on `address`, serving clients on background threads.
+    pub(crate) fn start(address: &DevtoolsAddress) -> io::Result<Self> {
+        let clients = Arc::new(Clients::default());
+        match address {
+            DevtoolsAddress::Tcp(address) => {
+                let listener = TcpListener::bind(address)?;
+                info!("Devtools listening on {}", listener.local_addr()?);
+                spawn_listener(clients.clone(), move || {
+                    listener.accept().map(|(stream, _)| Stream::Tcp(stream))
+                })?;
+            }
+            #[cfg(unix)]
+            DevtoolsAddress::Unix(path) => {
+                // Only replace a socket left behind by an earlier run, never another file
+                match std::fs::symlink_metadata(path) {
+                    Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)?,
+                    Ok(_) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::AlreadyExists,
+                            format!("{} exists and is not a socket", path.display()),
+                        ));
+                    }
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                    Err(e) => return Err(e),
+                }
+                let listener = UnixListener::bind(path)?;
+                info!("Devtools listening on {}", path.display());
+                spawn_listener(clients.clone(), move || {
+                    listener.accept().map(|(stream, _)| Stream::Unix(stream))
+                })?;
+            }
+        }
+
+        let outbox = Arc::new(Outbox::default());
+        let writer_outbox = outbox.clone();
+        let writer_clients = clients.clone();
+        thread::Builder::new()
+            .name("Tessera Devtools Writer".to_string())
+            .spawn(move || {
+                while let Some(line) = writer_outbox.take() {
+                    writer_clients.broadcast(&line);
+                }
+            })?;
+
+        Ok(Self { clients, outbox })
+    }
+
+    /// Returns whether any client is connected, i.e. whether snapshots are worth taking.
+    pub(crate) fn has_clients(&self) -> bool {
+        self.clients.count.load(Ordering::Acquire) > 0
+    }
+
+    /// Sends `message` to every connected client, replacing any message not sent yet.
+    pub(crate) fn publish(&self, message: &DevtoolsMessage) {
+        match serde_json::to_vec(message) {
+            Ok(mut line) => {
+                line.push(b'\n');
+                self.outbox.put(line);
+            }
+            Err(e) => warn!("Failed to serialize devtools message: {e}"),
+        }
+    }
+}
+
+impl Drop for DevtoolsServer {
+    fn drop(&mut self) {
+        self.outbox.close();
+    }
+}
+
+/// Spawns the thread accepting clients with `accept`.
+fn spawn_listener(
+    clients: Arc<Clients>,
+    accept: impl Fn() -> io::Result<Stream> + Send + 'static,
+) -> io::Result<()> {
+    thread::Builder::new()
+        .name("Tessera Devtools".to_string())
+        .spawn(move || {
+            loop {
+                let stream = match accept() {
+                    Ok(stream) => stream,
+                    Err(e) => {
+                        warn!("Failed to accept devtools client: {e}");
+                        continue;
+                    }
+                };
+                let reader = match stream.try_clone() {
+                    Ok(reader) => reader,
+                    Err(e) => {
+                        warn!("Failed to set up devtools client: {e}");
+                        continue;
+                    }
+                };
+                if let Err(e) = stream.set_write_timeout(Some(WRITE_TIMEOUT)) {
+                    warn!("Failed to set up devtools client: {e}");
+                    continue;
+                }
+                debug!("Devtools client connected");
+                clients.add(stream);
+                if let Err(e) = thread::Builder::new()
+                    .name("Tessera Devtools Client".to_string())
+                    .spawn(move || read_commands(reader))
+                {
+                    warn!("Failed to spawn devtools client thread: {e}");
+                }
+                // Send the new client a frame right away
+                TesseraRuntime::request_redraw();
+            }
+        })?;
+    Ok(())
+}
+
+/// Reads and applies the commands of a client until it disconnects.
+fn read_commands(stream: Stream) {
+    for line in BufReader::new(stream).lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<DevtoolsCommand>(&line) {
+            Ok(command) => apply(command),
+            Err(e) => warn!("Invalid devtools command {line:?}: {e}"),
+        }
+    }
+    debug!("Devtools client disconnected");
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, time::Instant};
+
+    use super::*;
+    use crate::TreeSnapshot;
+
+    /// Starts a server on a free port and connects a client to it
+    fn connect() -> (DevtoolsServer, TcpStream) {
+        // Bind a listener to learn a free port, then serve on it
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let server = DevtoolsServer::start(&DevtoolsAddress::Tcp(address)).unwrap();
+        assert!(!server.has_clients());
+        let client = TcpStream::connect(address).unwrap();
+        while !server.has_clients() {
+            thread::yield_now();
+        }
+        (server, client)
+    }
+
+    #[test]
+    fn test_server_streams_to_clients() {
+        let (server, mut client) = connect();
+
+        let message = DevtoolsMessage::Frame {
+            snapshot: TreeSnapshot::default(),
+            stats: FrameStats::default(),
+        };
+        server.publish(&message);
+        let mut line = Vec::new();
+        let mut byte = [0];
+        while byte[0] != b'\n' {
+            client.read_exact(&mut byte).unwrap();
+            line.push(byte[0]);
+        }
+        assert_eq!(
+            serde_json::from_slice::<DevtoolsMessage>(&line).unwrap(),
+            message
+        );
+    }
+
+    #[test]
+    fn test_stalled_client_is_dropped() {
+        let (server, _client) = connect();
+        // Frames much larger than the socket buffers, which the client never reads
+        let snapshot = TreeSnapshot {
+            root: Some(crate::NodeSnapshot {
+                id: 1,
+                fn_name: "x".repeat(4 << 20),
+                test_tag: None,
+                location: None,
+                size: None,
+                rel_position: None,
+                abs_position: None,
+                constraint: None,
+                commands: Vec::new(),
+                children: Vec::new(),
+            }),
+        };
+        let message = DevtoolsMessage::Frame {
+            snapshot,
+            stats: FrameStats::default(),
+        };
+        let deadline = Instant::now() + WRITE_TIMEOUT * 10;
+        while server.has_clients() {
+            assert!(Instant::now() < deadline, "the stalled client was kept");
+            // Publishing never waits for the client
+            let start = Instant::now();
+            server.publish(&message);
+            assert!(start.elapsed() < WRITE_TIMEOUT);
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_address_keeps_other_files() {
+        let path = std::env::temp_dir().join(format!("tessera-devtools-{}", std::process::id()));
+        std::fs::write(&path, "not a socket").unwrap();
+        let error = DevtoolsServer::start(&DevtoolsAddress::Unix(path.clone()))
+            .err()
+            .unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "not a socket");
+        std::fs::remove_file(&path).unwrap();
+
+        // A socket left behind by an earlier server is replaced
+        drop(UnixListener::bind(&path).unwrap());
+        let server = DevtoolsServer::start(&DevtoolsAddress::Unix(path.clone()));
+        assert!(server.is_ok());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -12,9 +12,13 @@
 //!
 //! ## Scale Factor
 //!
-//! The conversion between dp and physical pixels is controlled by a global scale factor
-//! stored in [`SCALE_FACTOR`]. This factor is typically set based on the device's DPI
-//! (dots per inch) and user preferences.
+//! The conversion between dp and physical pixels is controlled by the runtime scale factor
+//! returned by [`TesseraRuntime::scale_factor`]. It combines the DPI scale of the window's
+//! current monitor with the application zoom, and it may change while the application
+//! runs (for example when the window moves to another monitor).
+//!
+//! Because of that, prefer storing lengths as `Dp` and converting them when they are used,
+//! typically in a measure function, instead of caching the converted [`Px`] value.
 //!
 //! ## Usage
 //!
@@ -37,29 +41,7 @@
 //! convert between them using the provided methods, with the conversion automatically
 //! applying the current scale factor.
 
-use std::sync::OnceLock;
-
-use parking_lot::RwLock;
-
-use crate::Px;
-
-/// Global scale factor for converting between density-independent pixels and physical pixels.
-///
-/// This static variable holds the current scale factor used for dp-to-pixel conversions.
-/// It's typically initialized once during application startup based on the device's
-/// screen density and user scaling preferences.
-///
-/// The scale factor represents how many physical pixels correspond to one dp unit.
-/// For example:
-/// - Scale factor of 1.0: 1 dp = 1 pixel (standard density)
-/// - Scale factor of 2.0: 1 dp = 2 pixels (high density)
-/// - Scale factor of 0.75: 1 dp = 0.75 pixels (low density)
-///
-/// # Thread Safety
-///
-/// This variable uses `OnceLock<RwLock<f64>>` to ensure thread-safe access while
-/// allowing the scale factor to be updated during runtime if needed.
-pub static SCALE_FACTOR: OnceLock<RwLock<f64>> = OnceLock::new();
+use crate::{Px, runtime::TesseraRuntime};
 
 /// Density-independent pixels (dp) for UI scaling.
 ///
@@ -77,7 +59,7 @@ pub static SCALE_FACTOR: OnceLock<RwLock<f64>> = OnceLock::new();
 /// ## Internal Representation
 ///
 /// The `Dp` struct wraps a single `f64` value representing the dp measurement.
-/// This value is converted to physical pixels using the global [`SCALE_FACTOR`]
+/// This value is converted to physical pixels using [`TesseraRuntime::scale_factor`]
 /// when rendering operations require pixel-precise measurements.
 ///
 /// ## Examples
@@ -147,12 +129,12 @@ impl Dp {
     /// Converts this dp value to physical pixels as an `f64`.
     ///
     /// This method applies the current global scale factor to convert density-independent
-    /// pixels to physical pixels. The scale factor is read from [`SCALE_FACTOR`].
+    /// pixels to physical pixels. The scale factor is read from [`TesseraRuntime::scale_factor`].
     ///
     /// # Returns
     ///
     /// The equivalent value in physical pixels as a 64-bit floating-point number.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -164,8 +146,7 @@ impl Dp {
     /// // Result depends on the current scale factor
     /// ```
     pub fn to_pixels_f64(&self) -> f64 {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
-        self.0 * scale_factor
+        TesseraRuntime::window_scale().dp_to_pixels(*self)
     }
 
     /// Creates a `Dp` value from physical pixels specified as an `f64`.
@@ -181,7 +162,7 @@ impl Dp {
     /// # Returns
     ///
     /// A new `Dp` instance representing the equivalent dp value.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -192,8 +173,7 @@ impl Dp {
     /// let dp_value = Dp::from_pixels_f64(96.0);
     /// ```
     pub fn from_pixels_f64(value: f64) -> Self {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
-        Dp(value / scale_factor)
+        TesseraRuntime::window_scale().pixels_to_dp(value)
     }
 
     /// Converts this dp value to physical pixels as a `u32`.
@@ -206,7 +186,7 @@ impl Dp {
     ///
     /// The equivalent value in physical pixels as an unsigned 32-bit integer.
     /// The result is truncated (not rounded) from the floating-point calculation.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -224,7 +204,7 @@ impl Dp {
     /// behavior, use [`to_pixels_f64`](Self::to_pixels_f64) and apply your preferred
     /// rounding method.
     pub fn to_pixels_u32(&self) -> u32 {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
+        let scale_factor = TesseraRuntime::scale_factor();
         (self.0 * scale_factor) as u32
     }
 
@@ -241,7 +221,7 @@ impl Dp {
     /// # Returns
     ///
     /// A new `Dp` instance representing the equivalent dp value.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -252,7 +232,7 @@ impl Dp {
     /// let dp_value = Dp::from_pixels_u32(96);
     /// ```
     pub fn from_pixels_u32(value: u32) -> Self {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
+        let scale_factor = TesseraRuntime::scale_factor();
         Dp((value as f64) / scale_factor)
     }
 
@@ -265,7 +245,7 @@ impl Dp {
     /// # Returns
     ///
     /// The equivalent value in physical pixels as a 32-bit floating-point number.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -282,7 +262,7 @@ impl Dp {
     /// Converting from `f64` to `f32` may result in precision loss for very large
     /// or very precise values. For maximum precision, use [`to_pixels_f64`](Self::to_pixels_f64).
     pub fn to_pixels_f32(&self) -> f32 {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
+        let scale_factor = TesseraRuntime::scale_factor();
         (self.0 * scale_factor) as f32
     }
 
@@ -299,7 +279,7 @@ impl Dp {
     /// # Returns
     ///
     /// A new `Dp` instance representing the equivalent dp value.
-    /// If the scale factor hasn't been initialized, defaults to 1.0 (no scaling).
+    /// Before the window is created, the scale factor is 1.0 (no scaling).
     ///
     /// # Examples
     ///
@@ -310,7 +290,7 @@ impl Dp {
     /// let dp_value = Dp::from_pixels_f32(36.0);
     /// ```
     pub fn from_pixels_f32(value: f32) -> Self {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
+        let scale_factor = TesseraRuntime::scale_factor();
         Dp((value as f64) / scale_factor)
     }
 
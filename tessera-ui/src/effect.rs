@@ -0,0 +1,396 @@
+//! # Component-scoped Async Effects
+//!
+//! This module connects the shared [`tokio_runtime`](crate::tokio_runtime) to the UI.
+//! It lets components start asynchronous work whose lifetime follows the component
+//! that launched it.
+//!
+//! ## Overview
+//!
+//! Because Tessera rebuilds the component tree every frame, a component cannot hold on
+//! to a task handle by itself. Instead, every effect is identified by a **key**, scoped to
+//! the component calling it. A component calls [`launch`] or [`produce_state`] with the
+//! same key on every frame it wants the effect to stay alive:
+//!
+//! - The first call with a key spawns the task on the tokio runtime.
+//! - Later calls with the same key only keep the task alive; the task is not restarted.
+//! - When a frame finishes building without any call using that key (the launching
+//!   component disappeared, or stopped asking for the effect), the task is aborted.
+//!
+//! Changing the key therefore restarts the effect, which is useful for work that depends
+//! on an input such as a URL or a file path.
+//!
+//! Keys only need to be unique within a component: two components using the same key
+//! each get their own effect. The scope is the [`key`](crate::ComponentNode::key) of the
+//! component node, which follows the component from frame to frame. Effects launched
+//! outside of any component, such as in tests, share a single global scope.
+//!
+//! ## Delivering Results
+//!
+//! Results are delivered back to UI state through [`produce_state`], which returns a
+//! shared `Arc<RwLock<T>>` and hands a [`StateProducer`] to the async producer. Every
+//! update through the producer requests a redraw via [`TesseraRuntime::request_redraw`].
+//!
+//! ## Usage
+//!
+//! ```
+//! use tessera_ui::effect::{launch, produce_state};
+//!
+//! // Fire-and-forget work that is cancelled when this call stops happening.
+//! launch("log-on-start", async {
+//!     println!("component appeared");
+//! });
+//!
+//! // Async state with an initial value.
+//! let greeting = produce_state("greeting", String::from("Loading..."), |producer| async move {
+//!     producer.set(String::from("Hello from a background task!"));
+//! });
+//! let _current = greeting.read().clone();
+//! ```
+//!
+//! ## Thread Safety
+//!
+//! The effect registry is a global singleton protected by a mutex. [`launch`] and
+//! [`produce_state`] should be called while building the component tree, never from
+//! inside a measure function or a state handler.
+
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet},
+    future::Future,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::{Arc, OnceLock},
+};
+
+use parking_lot::{Mutex, MutexGuard, RwLock};
+use tokio::task::JoinHandle;
+
+use crate::{runtime::TesseraRuntime, tokio_runtime};
+
+/// Global effect registry.
+///
+/// Effects outlive a single frame, so they are stored outside of the component tree,
+/// which is cleared after every frame.
+static EFFECTS: OnceLock<Mutex<EffectRegistry>> = OnceLock::new();
+
+/// Bookkeeping for all running effects.
+#[derive(Default)]
+struct EffectRegistry {
+    /// Running (or already finished) effects, indexed by their hashed key.
+    entries: HashMap<u64, EffectEntry>,
+    /// Keys that were used during the current frame.
+    alive: HashSet<u64>,
+}
+
+/// A single effect owned by a key.
+struct EffectEntry {
+    /// Handle of the spawned task, used to abort it.
+    handle: JoinHandle<()>,
+    /// The state shared with the task, if it was created by [`produce_state`].
+    state: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+fn effects() -> MutexGuard<'static, EffectRegistry> {
+    EFFECTS
+        .get_or_init(|| Mutex::new(EffectRegistry::default()))
+        .lock()
+}
+
+/// Hashes a user key together with its type, so `1u32` and `1u64` are different keys.
+pub(crate) fn hash_key<K: Hash + 'static>(key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    TypeId::of::<K>().hash(&mut hasher);
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes a user key within the scope of the component node with the key `node`, if any.
+pub(crate) fn scoped_key<K: Hash + 'static>(node: Option<u64>, key: &K) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    node.hash(&mut hasher);
+    hash_key(key).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes a user key within the scope of the component being built.
+pub(crate) fn current_scoped_key<K: Hash + 'static>(key: &K) -> u64 {
+    let node = TesseraRuntime::read().component_tree.current_node_key();
+    scoped_key(node, key)
+}
+
+impl EffectRegistry {
+    /// Keeps the effect of `key` alive, spawning `future` if there is none yet.
+    fn launch(&mut self, key: u64, future: impl Future<Output = ()> + Send + 'static) {
+        self.alive.insert(key);
+        if self.entries.contains_key(&key) {
+            return;
+        }
+        let handle = tokio_runtime::get().spawn(future);
+        self.entries.insert(
+            key,
+            EffectEntry {
+                handle,
+                state: None,
+            },
+        );
+    }
+
+    /// Keeps the effect of `key` alive and returns its state, spawning the producer if
+    /// there is no effect yet or its state has another type.
+    fn produce_state<T, F, Fut>(&mut self, key: u64, initial: T, producer: F) -> Arc<RwLock<T>>
+    where
+        T: Send + Sync + 'static,
+        F: FnOnce(StateProducer<T>) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.alive.insert(key);
+        if let Some(entry) = self.entries.get(&key) {
+            if let Some(state) = entry
+                .state
+                .clone()
+                .and_then(|state| state.downcast::<RwLock<T>>().ok())
+            {
+                return state;
+            }
+            entry.handle.abort();
+        }
+        let state = Arc::new(RwLock::new(initial));
+        let handle = tokio_runtime::get().spawn(producer(StateProducer {
+            state: state.clone(),
+        }));
+        self.entries.insert(
+            key,
+            EffectEntry {
+                handle,
+                state: Some(state.clone()),
+            },
+        );
+        state
+    }
+
+    /// Aborts every effect whose key was not used since the last call.
+    fn drop_stale(&mut self) {
+        let Self { entries, alive } = self;
+        entries.retain(|key, entry| {
+            let keep = alive.contains(key);
+            if !keep {
+                entry.handle.abort();
+            }
+            keep
+        });
+        alive.clear();
+    }
+}
+
+/// Launches an async task tied to the lifetime of the calling component.
+///
+/// The future is spawned on the shared tokio runtime the first time the calling component
+/// uses `key`. As long as the component keeps calling `launch` with the same key each
+/// frame, the task keeps running and the future passed on later frames is dropped without
+/// being polled. Once a frame is built without this key, the task is aborted.
+///
+/// A finished task is not restarted while its key stays alive. Use a new key to run the
+/// effect again.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::{Arc, atomic::{AtomicBool, Ordering}};
+///
+/// use tessera_ui::{TesseraRuntime, effect::launch};
+///
+/// let loaded = Arc::new(AtomicBool::new(false));
+/// let loaded_clone = loaded.clone();
+/// launch(("load-data", 42), async move {
+///     // ... await some IO here ...
+///     loaded_clone.store(true, Ordering::Release);
+///     TesseraRuntime::request_redraw();
+/// });
+/// ```
+pub fn launch<K, Fut>(key: K, future: Fut)
+where
+    K: Hash + 'static,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let key = current_scoped_key(&key);
+    effects().launch(key, future);
+}
+
+/// Produces UI state from an async producer.
+///
+/// On the first call with `key` in the calling component, a new `Arc<RwLock<T>>` holding
+/// `initial` is created and `producer` is spawned with a [`StateProducer`] that writes into
+/// it. Later calls with the same key return the same state and ignore `initial` and
+/// `producer`. The task is aborted when the key is no longer used, and the state is
+/// dropped with it.
+///
+/// If the key was previously used with a different state type, the old effect is aborted
+/// and a new one is started.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::effect::produce_state;
+///
+/// let progress = produce_state("download", 0.0f32, |producer| async move {
+///     for step in 1..=10 {
+///         producer.set(step as f32 / 10.0);
+///     }
+/// });
+/// assert!(*progress.read() >= 0.0);
+/// ```
+pub fn produce_state<K, T, F, Fut>(key: K, initial: T, producer: F) -> Arc<RwLock<T>>
+where
+    K: Hash + 'static,
+    T: Send + Sync + 'static,
+    F: FnOnce(StateProducer<T>) -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let key = current_scoped_key(&key);
+    effects().produce_state(key, initial, producer)
+}
+
+/// Aborts every effect whose key was not used during the frame that just finished building.
+///
+/// Called by the renderer right after the entry point has built the component tree.
+pub(crate) fn drop_stale_effects() {
+    effects().drop_stale();
+}
+
+/// Write access to the state created by [`produce_state`], handed to the async producer.
+///
+/// Every update requests a redraw so the new value shows up on screen.
+pub struct StateProducer<T> {
+    state: Arc<RwLock<T>>,
+}
+
+impl<T> Clone for StateProducer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> StateProducer<T> {
+    /// Replaces the current value and requests a redraw.
+    pub fn set(&self, value: T) {
+        *self.state.write() = value;
+        TesseraRuntime::request_redraw();
+    }
+
+    /// Updates the current value in place and requests a redraw.
+    pub fn update(&self, f: impl FnOnce(&mut T)) {
+        f(&mut self.state.write());
+        TesseraRuntime::request_redraw();
+    }
+
+    /// Returns the shared state, for reading the current value inside the producer.
+    pub fn state(&self) -> &Arc<RwLock<T>> {
+        &self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+        thread,
+        time::{Duration, Instant},
+    };
+
+    use super::*;
+
+    /// Sets a flag when dropped, i.e. when the task owning it is aborted
+    struct DropFlag(Arc<AtomicBool>);
+
+    impl Drop for DropFlag {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Release);
+        }
+    }
+
+    /// Launches a task that never finishes, returning a flag set once it was aborted
+    fn launch_pending(registry: &mut EffectRegistry, key: u64) -> Arc<AtomicBool> {
+        let aborted = Arc::new(AtomicBool::new(false));
+        let flag = DropFlag(aborted.clone());
+        registry.launch(key, async move {
+            let _flag = flag;
+            std::future::pending::<()>().await;
+        });
+        aborted
+    }
+
+    fn wait_for(flag: &AtomicBool) -> bool {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !flag.load(Ordering::Acquire) {
+            if Instant::now() > deadline {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(1));
+        }
+        true
+    }
+
+    #[test]
+    fn test_effect_restarts_when_key_changes() {
+        let mut registry = EffectRegistry::default();
+        let spawned = Arc::new(AtomicUsize::new(0));
+        let launch_counted = |registry: &mut EffectRegistry, key: u64| {
+            let spawned = spawned.clone();
+            registry.launch(key, async move {
+                spawned.fetch_add(1, Ordering::AcqRel);
+            });
+        };
+
+        let first = launch_pending(&mut registry, hash_key(&"url-a"));
+        launch_counted(&mut registry, 1);
+        registry.drop_stale();
+        // The same keys on the next frame keep the effects without restarting them
+        let again = launch_pending(&mut registry, hash_key(&"url-a"));
+        launch_counted(&mut registry, 1);
+        registry.drop_stale();
+        assert!(wait_for(&again), "the duplicate future is dropped unpolled");
+        assert!(!first.load(Ordering::Acquire));
+
+        // A new key starts a new effect and cancels the old one
+        let second = launch_pending(&mut registry, hash_key(&"url-b"));
+        launch_counted(&mut registry, 1);
+        registry.drop_stale();
+        assert!(wait_for(&first));
+        assert!(!second.load(Ordering::Acquire));
+        assert_eq!(registry.entries.len(), 2);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while spawned.load(Ordering::Acquire) == 0 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        thread::sleep(Duration::from_millis(20));
+        assert_eq!(spawned.load(Ordering::Acquire), 1);
+    }
+
+    #[test]
+    fn test_effect_is_cancelled_without_its_key() {
+        let mut registry = EffectRegistry::default();
+        let aborted = launch_pending(&mut registry, 7);
+        let state = registry.produce_state(8, 0, |_| std::future::pending());
+        registry.drop_stale();
+        assert!(!aborted.load(Ordering::Acquire));
+
+        registry.drop_stale();
+        assert!(wait_for(&aborted));
+        assert!(registry.entries.is_empty());
+        // Only the caller still holds the state of the cancelled producer
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while Arc::strong_count(&state) > 1 && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(Arc::strong_count(&state), 1);
+    }
+
+    #[test]
+    fn test_keys_are_scoped_to_components() {
+        assert_eq!(scoped_key(Some(1), &"load"), scoped_key(Some(1), &"load"));
+        assert_ne!(scoped_key(Some(1), &"load"), scoped_key(Some(2), &"load"));
+        assert_ne!(scoped_key(None, &"load"), scoped_key(Some(1), &"load"));
+        assert_ne!(scoped_key(Some(1), &1u32), scoped_key(Some(1), &1u64));
+    }
+}
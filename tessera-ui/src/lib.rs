@@ -125,7 +125,7 @@
 //! - [`Renderer`] - Main application renderer and lifecycle manager
 //! - [`measure_node`], [`place_node`] - Core layout functions
 //! - [`Constraint`], [`DimensionValue`] - Layout constraint system
-//! - [`Dp`], [`Px`] - Measurement units (device-independent and pixel units)
+//! - [`Dp`], [`Px`], [`Sp`] - Measurement units (device-independent, pixel and font units)
 //! - [`Color`] - Color representation and utilities
 //!
 //! ### Component System
@@ -133,6 +133,11 @@
 //! - [`ComponentNode`] - Individual component node representation
 //! - [`ComputedData`] - Layout computation results
 //! - [`StateHandlerFn`] - State management and event handling
+//! - [`effect`] - Async tasks tied to the lifetime of a component
+//! - [`animation`] - Tweens, springs and the shared frame clock
+//! - [`timer`] - Timeouts and intervals that run on the frame loop
+//! - [`accessibility`] - Semantics for screen readers, published through AccessKit
+//! - [`context`] - Values provided implicitly to a subtree of components
 //!
 //! ### Event Handling
 //! - [`CursorEvent`] - Mouse and touch input events
@@ -142,6 +147,7 @@
 //! ### Rendering System
 //! - [`renderer::drawer`] - Drawing pipeline system
 //! - [`renderer::compute`] - Compute pipeline system
+//! - [`renderer::shader`] - Shader sources, reloaded from disk with the `shader-hot-reload` feature
 //! - [`DrawCommand`], [`ComputeCommand`] - Rendering commands
 //!
 //! ## Examples
@@ -159,21 +165,38 @@
 //! - Efficient GPU utilization through custom shaders
 //! - Minimal allocations in hot paths
 //! - Optimized component tree traversal
+//!
+//! Enable the `tracing` feature to record a span for every phase of a frame, down to single
+//! components and compute dispatches, see [`profiling`].
+//!
+//! Enable the `devtools` feature to take snapshots of the component tree and serve them to
+//! inspection tools, see `devtools`.
 
+pub mod accessibility;
+pub mod animation;
 pub mod clipboard;
 pub mod color;
 mod component_tree;
+pub mod context;
 mod cursor;
+#[cfg(feature = "devtools")]
+pub mod devtools;
 pub mod dp;
+pub mod effect;
 pub mod focus_state;
 mod ime_state;
 mod keyboard_state;
+pub mod profiling;
 pub mod px;
 pub mod renderer;
 pub mod runtime;
+pub mod sp;
 mod thread_utils;
+pub mod timer;
 pub mod tokio_runtime;
+pub mod ui_handle;
 
+pub use accesskit;
 pub use indextree::{Arena, NodeId};
 pub use wgpu;
 pub use winit;
@@ -182,13 +205,15 @@ pub use crate::{
     clipboard::Clipboard,
     color::Color,
     component_tree::{
-        ComponentNode, ComponentNodeMetaData, ComponentNodeMetaDatas, ComponentNodeTree,
-        ComponentTree, ComputedData, Constraint, DimensionValue, ImeRequest, MeasureFn,
-        MeasurementError, StateHandlerFn, StateHandlerInput, measure_node, measure_nodes,
-        place_node,
+        AlignmentLines, ComponentNode, ComponentNodeMetaData, ComponentNodeMetaDatas,
+        ComponentNodeTree, ComponentTree, ComputedData, Constraint, DimensionValue, ImeRequest,
+        IntrinsicCache, IntrinsicFn, IntrinsicInput, IntrinsicQuery, IntrinsicSize, MeasureFn,
+        MeasurementError, SourceLocation, StateHandlerFn, StateHandlerInput, intrinsic_size,
+        measure_node, measure_nodes, place_node,
     },
     cursor::{CursorEvent, CursorEventContent, PressKeyEventType, ScrollEventConent},
     dp::Dp,
+    effect::{StateProducer, launch, produce_state},
     focus_state::Focus,
     px::{Px, PxPosition, PxSize},
     renderer::{
@@ -202,6 +227,14 @@ pub use crate::{
         },
     },
     runtime::TesseraRuntime,
+    sp::Sp,
+    ui_handle::UiHandle,
+};
+
+#[cfg(feature = "devtools")]
+pub use tessera_ui_devtools_protocol::{
+    ConstraintSnapshot, DimensionSnapshot, LocationSnapshot, NodeSnapshot, PositionSnapshot,
+    SizeSnapshot, TreeSnapshot,
 };
 
 use ime_state::ImeState;
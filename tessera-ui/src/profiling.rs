@@ -0,0 +1,48 @@
+//! # Profiling Spans
+//!
+//! With the `tracing` feature enabled, Tessera emits [`tracing`](https://docs.rs/tracing)
+//! spans for every phase of a frame:
+//!
+//! - `frame`: a whole frame, containing the spans below
+//! - `build`: running the entry point to build the component tree
+//! - `measure`: measuring one node, with the `fn_name` of its component
+//! - `draw_commands`: collecting the draw and compute commands of the tree
+//! - `state_handlers`: running the state handlers, each in a `state_handler` span with the
+//!   `fn_name` of its component
+//! - `render`: encoding and submitting the frame to the GPU, containing `render_pass` for each
+//!   render pass, `barrier_copy` for each copy of the scene made for a barrier and
+//!   `compute_dispatch` for each compute command, with the `command` type name
+//!
+//! Any `tracing` subscriber can record them, e.g. `tracing-chrome` to export a trace for
+//! `chrome://tracing` or Perfetto, or `tracing-tracy` for a live Tracy capture. Without the
+//! feature the spans compile to nothing.
+//!
+//! Measurement runs in parallel, so `measure` spans of sibling nodes show up on the worker
+//! threads of Rayon rather than nested in their parent.
+
+/// Enters a span until the returned guard is dropped, when the `tracing` feature is enabled.
+///
+/// Fields are recorded with their `Display` implementation. Without the feature, the field
+/// values are only borrowed, so the call costs nothing and raises no unused warnings.
+///
+/// ```rust,ignore
+/// let _span = profile_span!("measure", fn_name = node.fn_name);
+/// ```
+macro_rules! profile_span {
+    ($name:literal $(, $field:ident = $value:expr)* $(,)?) => {{
+        #[cfg(feature = "tracing")]
+        let guard = tracing::info_span!($name $(, $field = %$value)*).entered();
+        #[cfg(not(feature = "tracing"))]
+        let guard = {
+            $(let _ = &$value;)*
+            $crate::profiling::SpanGuard
+        };
+        guard
+    }};
+}
+
+pub(crate) use profile_span;
+
+/// What [`profile_span!`] returns without the `tracing` feature.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct SpanGuard;
@@ -24,6 +24,33 @@
 //! - Use [`Px::from_dp`] to convert from Dp to Px
 //! - Use [`Px::to_dp`] to convert from Px to Dp
 //!
+//! # Subpixel Layout
+//!
+//! A [`Px`] holds an `f32`, so layout runs in fractional pixels: sizes and positions are
+//! not rounded while they are measured and placed, and they reach the rendering pipelines
+//! as exact `f32` values. This keeps smooth scrolling and animations free of jitter, and
+//! lets rows and columns on fractional-DPI screens line up without accumulated rounding
+//! gaps.
+//!
+//! Snapping to whole pixels happens only where it is needed, at render time: for example
+//! text is drawn at a whole-pixel origin to keep glyphs crisp, and GPU resources such as
+//! textures and scissor rectangles use [`Px::floor`] / [`Px::ceil`] to cover every
+//! touched pixel.
+//!
+//! ## Migrating from integer pixels
+//!
+//! `Px` used to wrap an `i32`. Most integer APIs are kept, so existing code keeps
+//! compiling with minimal changes:
+//!
+//! - [`Px::new`], `From<i32>` and `From<u32>` still take integers, and `Px * i32` and
+//!   `Px / i32` still work.
+//! - Tuple literals such as `Px(10)` must become `Px(10.0)` or `Px::new(10)`.
+//! - Code that read the raw integer through `px.0` or [`Px::raw`] should use
+//!   [`Px::to_f32`], or [`Px::round`], [`Px::floor`] or [`Px::ceil`] followed by
+//!   [`Px::to_i32`] where a whole number is really needed.
+//! - [`Px::from_f32`] no longer truncates. Call [`Px::round`] (or `floor`/`ceil`) on the
+//!   result to keep the old whole-pixel behaviour.
+//!
 //! # Example
 //!
 //! ```
@@ -48,9 +75,13 @@
 //! let px_value = Px::from_dp(dp_value);
 //! ```
 
-use std::ops::{AddAssign, Neg};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    ops::{AddAssign, Neg, SubAssign},
+};
 
-use crate::dp::{Dp, SCALE_FACTOR};
+use crate::{dp::Dp, runtime::TesseraRuntime};
 
 /// A physical pixel coordinate value.
 ///
@@ -59,13 +90,19 @@ use crate::dp::{Dp, SCALE_FACTOR};
 /// Unlike density-independent pixels ([`Dp`]), physical pixels are not scaled based on
 /// screen density.
 ///
+/// The value is fractional, see [Subpixel Layout](self#subpixel-layout). `Px` still
+/// implements [`Eq`], [`Ord`] and [`Hash`]: `NaN` compares equal to itself and sorts after
+/// every other value, and `-0.0` equals `0.0`.
+///
 /// # Features
 ///
 /// - Supports negative values for scrolling and off-screen positioning
+/// - Supports fractional values for subpixel layout
 /// - Provides arithmetic operations (addition, subtraction, multiplication, division)
-/// - Includes saturating arithmetic to prevent overflow
+/// - Includes saturating arithmetic that clamps to [`Px::MIN`]..=[`Px::MAX`]
 /// - Converts to/from density-independent pixels ([`Dp`])
-/// - Converts to/from floating-point values with overflow protection
+/// - Snaps to whole pixels with [`round`](Px::round), [`floor`](Px::floor) and
+///   [`ceil`](Px::ceil)
 ///
 /// # Examples
 ///
@@ -80,26 +117,30 @@ use crate::dp::{Dp, SCALE_FACTOR};
 /// let sum = px1 + px2; // Px(50)
 /// let doubled = px1 * 2; // Px(200)
 ///
-/// // Saturating arithmetic prevents overflow
-/// let max_px = Px::new(i32::MAX);
-/// let safe_add = max_px.saturating_add(Px::new(1)); // Still Px(i32::MAX)
+/// // Saturating arithmetic clamps to the representable range
+/// let safe_add = Px::MAX.saturating_add(Px::new(1)); // Still Px::MAX
 ///
-/// // Convert to absolute value for rendering
-/// let abs_value = Px::new(-10).abs(); // 0 (negative becomes 0)
+/// // Fractional values are kept until they are snapped
+/// let half = Px(10.5);
+/// assert_eq!(half.round(), Px(11.0));
 /// ```
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
-pub struct Px(pub i32);
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Px(pub f32);
 
 impl Px {
     /// A constant representing zero pixels.
-    pub const ZERO: Self = Self(0);
+    pub const ZERO: Self = Self(0.0);
 
     /// A constant representing the maximum possible pixel value.
-    pub const MAX: Self = Self(i32::MAX);
-
-    /// Returns the raw i32 value.
     ///
-    /// This provides direct access to the underlying integer value.
+    /// It is used as "unbounded" by layout constraints. It matches the largest value of
+    /// the former integer representation, so it still converts to `i32::MAX`.
+    pub const MAX: Self = Self(i32::MAX as f32);
+
+    /// A constant representing the minimum possible pixel value.
+    pub const MIN: Self = Self(i32::MIN as f32);
+
+    /// Returns the value rounded to the nearest whole pixel, as an `i32`.
     ///
     /// # Examples
     ///
@@ -107,17 +148,81 @@ impl Px {
     /// use tessera_ui::px::Px;
     ///
     /// let px = Px::new(42);
-    /// assert_eq!(px.raw(), 42);
+    /// #[allow(deprecated)]
+    /// let raw = px.raw();
+    /// assert_eq!(raw, 42);
     /// ```
+    #[deprecated(
+        note = "`Px` is fractional now; use `to_f32`, or `round().to_i32()` to snap explicitly"
+    )]
     pub fn raw(self) -> i32 {
-        self.0
+        self.round().to_i32()
+    }
+
+    /// Returns the value truncated to an `i32`, saturating at the `i32` bounds.
+    ///
+    /// Call [`round`](Self::round), [`floor`](Self::floor) or [`ceil`](Self::ceil) first to
+    /// pick how fractional values are snapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::px::Px;
+    ///
+    /// assert_eq!(Px(42.7).to_i32(), 42);
+    /// assert_eq!(Px(42.7).round().to_i32(), 43);
+    /// ```
+    pub fn to_i32(self) -> i32 {
+        self.0 as i32
+    }
+
+    /// Rounds to the nearest whole pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::px::Px;
+    ///
+    /// assert_eq!(Px(1.4).round(), Px(1.0));
+    /// assert_eq!(Px(1.5).round(), Px(2.0));
+    /// ```
+    pub fn round(self) -> Self {
+        Px(self.0.round())
+    }
+
+    /// Rounds down to a whole pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::px::Px;
+    ///
+    /// assert_eq!(Px(1.9).floor(), Px(1.0));
+    /// assert_eq!(Px(-1.1).floor(), Px(-2.0));
+    /// ```
+    pub fn floor(self) -> Self {
+        Px(self.0.floor())
+    }
+
+    /// Rounds up to a whole pixel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::px::Px;
+    ///
+    /// assert_eq!(Px(1.1).ceil(), Px(2.0));
+    /// ```
+    pub fn ceil(self) -> Self {
+        Px(self.0.ceil())
     }
 
     /// Creates a new `Px` instance from an i32 value.
     ///
     /// # Arguments
     ///
-    /// * `value` - The pixel value as an i32. Negative values are allowed.
+    /// * `value` - The pixel value as an i32. Negative values are allowed. Use
+    ///   [`from_f32`](Self::from_f32) for fractional values.
     ///
     /// # Examples
     ///
@@ -129,7 +234,7 @@ impl Px {
     /// let zero = Px::new(0);
     /// ```
     pub const fn new(value: i32) -> Self {
-        Px(value)
+        Px(value as f32)
     }
 
     /// Converts from density-independent pixels ([`Dp`]) to physical pixels.
@@ -152,7 +257,7 @@ impl Px {
     /// let px_value = Px::from_dp(dp_value);
     /// ```
     pub fn from_dp(dp: Dp) -> Self {
-        Px(dp.to_pixels_f64() as i32)
+        Px(dp.to_pixels_f32())
     }
 
     /// Converts from physical pixels to density-independent pixels ([`Dp`]).
@@ -169,11 +274,11 @@ impl Px {
     /// let dp_value = px_value.to_dp();
     /// ```
     pub fn to_dp(self) -> Dp {
-        let scale_factor = SCALE_FACTOR.get().map(|lock| *lock.read()).unwrap_or(1.0);
+        let scale_factor = TesseraRuntime::scale_factor();
         Dp((self.0 as f64) / scale_factor)
     }
 
-    /// Returns the absolute value as a u32
+    /// Returns the absolute value rounded to a whole pixel, as a u32
     ///
     /// This method is primarily used for coordinate conversion during rendering,
     /// where negative coordinates need to be handled appropriately.
@@ -188,10 +293,10 @@ impl Px {
     /// assert_eq!(Px::new(0).abs(), 0);
     /// ```
     pub fn abs(self) -> u32 {
-        self.0.unsigned_abs()
+        self.0.abs().round() as u32
     }
 
-    /// Returns only the positive value, or zero if negative.
+    /// Returns only the positive value rounded to a whole pixel, or zero if negative.
     ///
     /// This is useful for ensuring that pixel values are always non-negative,
     /// especially when dealing with rendering or layout calculations.
@@ -206,10 +311,10 @@ impl Px {
     /// assert_eq!(Px::new(0).positive(), 0);
     /// ```
     pub fn positive(self) -> u32 {
-        if self.0 < 0 { 0 } else { self.0 as u32 }
+        self.0.max(0.0).round() as u32
     }
 
-    /// Returns the negative value, or zero if positive.
+    /// Returns the negative value rounded to a whole pixel, or zero if positive.
     ///
     /// This is useful for ensuring that pixel values are always non-positive,
     /// especially when dealing with rendering or layout calculations.
@@ -223,7 +328,7 @@ impl Px {
     /// assert_eq!(Px::new(-5).negative(), -5);
     /// assert_eq!(Px::new(0).negative(), 0);
     pub fn negative(self) -> i32 {
-        if self.0 > 0 { 0 } else { self.0 }
+        self.0.min(0.0).round() as i32
     }
 
     /// Converts the pixel value to f32.
@@ -237,15 +342,12 @@ impl Px {
     /// assert_eq!(px.to_f32(), 42.0);
     /// ```
     pub fn to_f32(self) -> f32 {
-        self.0 as f32
+        self.0
     }
 
     /// Creates a `Px` from an f32 value.
     ///
-    /// # Panics
-    ///
-    /// This function may panic on overflow in debug builds when the f32 value
-    /// cannot be represented as an i32.
+    /// The value is kept as is, including its fractional part.
     ///
     /// # Examples
     ///
@@ -253,16 +355,15 @@ impl Px {
     /// use tessera_ui::px::Px;
     ///
     /// let px = Px::from_f32(42.7);
-    /// assert_eq!(px.raw(), 42);
+    /// assert_eq!(px.to_f32(), 42.7);
     /// ```
     pub fn from_f32(value: f32) -> Self {
-        Px(value as i32)
+        Px(value)
     }
 
-    /// Creates a `Px` from an f32 value, saturating at the numeric bounds instead of overflowing.
+    /// Creates a `Px` from an f32 value, clamping it to [`Px::MIN`]..=[`Px::MAX`].
     ///
-    /// This is the safe alternative to [`from_f32`](Self::from_f32) that handles overflow
-    /// by clamping the value to the valid i32 range.
+    /// `NaN` becomes zero.
     ///
     /// # Examples
     ///
@@ -270,23 +371,25 @@ impl Px {
     /// use tessera_ui::px::Px;
     ///
     /// let normal = Px::saturating_from_f32(42.7);
-    /// assert_eq!(normal.raw(), 42);
+    /// assert_eq!(normal.to_f32(), 42.7);
     ///
     /// let max_val = Px::saturating_from_f32(f32::MAX);
-    /// assert_eq!(max_val.raw(), i32::MAX);
+    /// assert_eq!(max_val, Px::MAX);
     ///
     /// let min_val = Px::saturating_from_f32(f32::MIN);
-    /// assert_eq!(min_val.raw(), i32::MIN);
+    /// assert_eq!(min_val, Px::MIN);
     /// ```
     pub fn saturating_from_f32(value: f32) -> Self {
-        let clamped_value = value.clamp(i32::MIN as f32, i32::MAX as f32);
-        Px(clamped_value as i32)
+        if value.is_nan() {
+            return Px::ZERO;
+        }
+        Px(value.clamp(Px::MIN.0, Px::MAX.0))
     }
 
-    /// Saturating integer addition.
+    /// Saturating addition.
     ///
     /// Computes `self + rhs`, saturating at the numeric bounds instead of overflowing.
-    /// This prevents integer overflow by clamping the result to the valid i32 range.
+    /// The result is clamped to [`Px::MIN`]..=[`Px::MAX`].
     ///
     /// # Arguments
     ///
@@ -302,17 +405,16 @@ impl Px {
     /// assert_eq!(a.saturating_add(b), Px::new(15));
     ///
     /// // Prevents overflow
-    /// let max = Px::new(i32::MAX);
-    /// assert_eq!(max.saturating_add(Px::new(1)), max);
+    /// assert_eq!(Px::MAX.saturating_add(Px::MAX), Px::MAX);
     /// ```
     pub fn saturating_add(self, rhs: Self) -> Self {
-        Px(self.0.saturating_add(rhs.0))
+        Px::saturating_from_f32(self.0 + rhs.0)
     }
 
-    /// Saturating integer subtraction.
+    /// Saturating subtraction.
     ///
     /// Computes `self - rhs`, saturating at the numeric bounds instead of overflowing.
-    /// This prevents integer underflow by clamping the result to the valid i32 range.
+    /// The result is clamped to [`Px::MIN`]..=[`Px::MAX`].
     ///
     /// # Arguments
     ///
@@ -328,11 +430,10 @@ impl Px {
     /// assert_eq!(a.saturating_sub(b), Px::new(5));
     ///
     /// // Prevents underflow
-    /// let min = Px::new(i32::MIN);
-    /// assert_eq!(min.saturating_sub(Px::new(1)), min);
+    /// assert_eq!(Px::MIN.saturating_sub(Px::MAX), Px::MIN);
     /// ```
     pub fn saturating_sub(self, rhs: Self) -> Self {
-        Px(self.0.saturating_sub(rhs.0))
+        Px::saturating_from_f32(self.0 - rhs.0)
     }
 
     /// Multiplies the pixel value by a scalar f32.
@@ -355,7 +456,7 @@ impl Px {
     /// assert_eq!(result, Px::new(20));
     /// ```
     pub fn mul_f32(self, rhs: f32) -> Self {
-        Px((self.0 as f32 * rhs) as i32)
+        Px(self.0 * rhs)
     }
 
     /// Divides the pixel value by a scalar f32.
@@ -382,7 +483,43 @@ impl Px {
     /// assert_eq!(result, Px::new(10));
     /// ```
     pub fn div_f32(self, rhs: f32) -> Self {
-        Px::from_f32(self.to_f32() / rhs)
+        Px(self.0 / rhs)
+    }
+}
+
+impl PartialEq for Px {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Px {}
+
+impl PartialOrd for Px {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Px {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `total_cmp` orders `-0.0` before `0.0`, but they are the same length
+        if self.0 == other.0 {
+            Ordering::Equal
+        } else {
+            self.0.total_cmp(&other.0)
+        }
+    }
+}
+
+impl Hash for Px {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equal values must hash equally, so `-0.0` is hashed as `0.0`
+        if self.0 == 0.0 {
+            0.0f32.to_bits().hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
     }
 }
 
@@ -428,7 +565,10 @@ pub struct PxPosition {
 
 impl PxPosition {
     /// A constant representing the zero position (0, 0).
-    pub const ZERO: Self = Self { x: Px(0), y: Px(0) };
+    pub const ZERO: Self = Self {
+        x: Px::ZERO,
+        y: Px::ZERO,
+    };
 
     /// Creates a new position from x and y coordinates.
     ///
@@ -496,7 +636,7 @@ impl PxPosition {
     /// assert_eq!(offset_position, PxPosition::new(Px::new(15), Px::new(17)));
     ///
     /// // Prevents overflow
-    /// let max_position = PxPosition::new(Px::new(i32::MAX), Px::new(i32::MAX));
+    /// let max_position = PxPosition::new(Px::MAX, Px::MAX);
     /// let safe_offset = max_position.saturating_offset(Px::new(1), Px::new(1));
     /// assert_eq!(safe_offset, max_position);
     /// ```
@@ -527,8 +667,8 @@ impl PxPosition {
     /// assert_eq!(pos1.distance_to(pos2), 5.0);
     /// ```
     pub fn distance_to(self, other: Self) -> f32 {
-        let dx = (self.x.0 - other.x.0) as f32;
-        let dy = (self.y.0 - other.y.0) as f32;
+        let dx = self.x.0 - other.x.0;
+        let dy = self.y.0 - other.y.0;
         (dx * dx + dy * dy).sqrt()
     }
 
@@ -547,7 +687,7 @@ impl PxPosition {
     /// assert_eq!(position.to_f32_arr2(), [10.0, 20.0]);
     /// ```
     pub fn to_f32_arr2(self) -> [f32; 2] {
-        [self.x.0 as f32, self.y.0 as f32]
+        [self.x.0, self.y.0]
     }
 
     /// Converts the position to a 3D f32 array with z=0.
@@ -565,27 +705,27 @@ impl PxPosition {
     /// assert_eq!(position.to_f32_arr3(), [10.0, 20.0, 0.0]);
     /// ```
     pub fn to_f32_arr3(self) -> [f32; 3] {
-        [self.x.0 as f32, self.y.0 as f32, 0.0]
+        [self.x.0, self.y.0, 0.0]
     }
 
     /// Creates a position from a 2D f32 array.
     ///
     /// # Arguments
     ///
-    /// * `arr` - An array `[x, y]` where both values will be converted to i32
+    /// * `arr` - An array `[x, y]`, fractional values are kept
     ///
     /// # Examples
     ///
     /// ```
     /// use tessera_ui::px::{Px, PxPosition};
     ///
-    /// let position = PxPosition::from_f32_arr2([10.5, 20.7]);
-    /// assert_eq!(position, PxPosition::new(Px::new(10), Px::new(20)));
+    /// let position = PxPosition::from_f32_arr2([10.5, 20.75]);
+    /// assert_eq!(position, PxPosition::new(Px(10.5), Px(20.75)));
     /// ```
     pub fn from_f32_arr2(arr: [f32; 2]) -> Self {
         Self {
-            x: Px::new(arr[0] as i32),
-            y: Px::new(arr[1] as i32),
+            x: Px(arr[0]),
+            y: Px(arr[1]),
         }
     }
 
@@ -600,13 +740,13 @@ impl PxPosition {
     /// ```
     /// use tessera_ui::px::{Px, PxPosition};
     ///
-    /// let position = PxPosition::from_f32_arr3([10.5, 20.7, 30.9]);
-    /// assert_eq!(position, PxPosition::new(Px::new(10), Px::new(20)));
+    /// let position = PxPosition::from_f32_arr3([10.5, 20.75, 30.9]);
+    /// assert_eq!(position, PxPosition::new(Px(10.5), Px(20.75)));
     /// ```
     pub fn from_f32_arr3(arr: [f32; 3]) -> Self {
         Self {
-            x: Px::new(arr[0] as i32),
-            y: Px::new(arr[1] as i32),
+            x: Px(arr[0]),
+            y: Px(arr[1]),
         }
     }
 
@@ -650,20 +790,20 @@ impl PxPosition {
     ///
     /// # Arguments
     ///
-    /// * `arr` - An array `[x, y]` where both values will be converted to i32
+    /// * `arr` - An array `[x, y]`, converted to `f32`
     ///
     /// # Examples
     ///
     /// ```
     /// use tessera_ui::px::{Px, PxPosition};
     ///
-    /// let position = PxPosition::from_f64_arr2([10.5, 20.7]);
-    /// assert_eq!(position, PxPosition::new(Px::new(10), Px::new(20)));
+    /// let position = PxPosition::from_f64_arr2([10.5, 20.75]);
+    /// assert_eq!(position, PxPosition::new(Px(10.5), Px(20.75)));
     /// ```
     pub fn from_f64_arr2(arr: [f64; 2]) -> Self {
         Self {
-            x: Px::new(arr[0] as i32),
-            y: Px::new(arr[1] as i32),
+            x: Px(arr[0] as f32),
+            y: Px(arr[1] as f32),
         }
     }
 
@@ -678,13 +818,13 @@ impl PxPosition {
     /// ```
     /// use tessera_ui::px::{Px, PxPosition};
     ///
-    /// let position = PxPosition::from_f64_arr3([10.5, 20.7, 30.9]);
-    /// assert_eq!(position, PxPosition::new(Px::new(10), Px::new(20)));
+    /// let position = PxPosition::from_f64_arr3([10.5, 20.75, 30.9]);
+    /// assert_eq!(position, PxPosition::new(Px(10.5), Px(20.75)));
     /// ```
     pub fn from_f64_arr3(arr: [f64; 3]) -> Self {
         Self {
-            x: Px::new(arr[0] as i32),
-            y: Px::new(arr[1] as i32),
+            x: Px(arr[0] as f32),
+            y: Px(arr[1] as f32),
         }
     }
 }
@@ -721,8 +861,8 @@ pub struct PxSize {
 impl PxSize {
     /// A constant representing zero size (0×0).
     pub const ZERO: Self = Self {
-        width: Px(0),
-        height: Px(0),
+        width: Px::ZERO,
+        height: Px::ZERO,
     };
 
     /// Creates a new size from width and height.
@@ -763,7 +903,7 @@ impl PxSize {
     /// assert_eq!(size.to_f32_arr2(), [300.0, 200.0]);
     /// ```
     pub fn to_f32_arr2(self) -> [f32; 2] {
-        [self.width.0 as f32, self.height.0 as f32]
+        [self.width.0, self.height.0]
     }
 }
 
@@ -779,8 +919,8 @@ impl From<[Px; 2]> for PxSize {
 impl From<PxSize> for winit::dpi::PhysicalSize<i32> {
     fn from(size: PxSize) -> Self {
         winit::dpi::PhysicalSize {
-            width: size.width.raw(),
-            height: size.height.raw(),
+            width: size.width.round().to_i32(),
+            height: size.height.round().to_i32(),
         }
     }
 }
@@ -788,8 +928,8 @@ impl From<PxSize> for winit::dpi::PhysicalSize<i32> {
 impl From<winit::dpi::PhysicalSize<u32>> for PxSize {
     fn from(size: winit::dpi::PhysicalSize<u32>) -> Self {
         Self {
-            width: Px(size.width as i32),
-            height: Px(size.height as i32),
+            width: Px(size.width as f32),
+            height: Px(size.height as f32),
         }
     }
 }
@@ -821,7 +961,7 @@ impl Neg for Px {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        Px::new(-self.0)
+        Px(-self.0)
     }
 }
 
@@ -853,7 +993,7 @@ impl std::ops::Mul<i32> for Px {
     type Output = Self;
 
     fn mul(self, rhs: i32) -> Self::Output {
-        Px(self.0 * rhs)
+        Px(self.0 * rhs as f32)
     }
 }
 
@@ -861,19 +1001,41 @@ impl std::ops::Div<i32> for Px {
     type Output = Self;
 
     fn div(self, rhs: i32) -> Self::Output {
+        Px(self.0 / rhs as f32)
+    }
+}
+
+impl std::ops::Mul<f32> for Px {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Px(self.0 * rhs)
+    }
+}
+
+impl std::ops::Div<f32> for Px {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
         Px(self.0 / rhs)
     }
 }
 
 impl From<i32> for Px {
     fn from(value: i32) -> Self {
-        Px(value)
+        Px::new(value)
     }
 }
 
 impl From<u32> for Px {
     fn from(value: u32) -> Self {
-        Px(value as i32)
+        Px(value as f32)
+    }
+}
+
+impl From<f32> for Px {
+    fn from(value: f32) -> Self {
+        Px(value)
     }
 }
 
@@ -886,8 +1048,8 @@ impl From<Dp> for Px {
 impl From<PxPosition> for winit::dpi::PhysicalPosition<i32> {
     fn from(pos: PxPosition) -> Self {
         winit::dpi::PhysicalPosition {
-            x: pos.x.0,
-            y: pos.y.0,
+            x: pos.x.round().to_i32(),
+            y: pos.y.round().to_i32(),
         }
     }
 }
@@ -904,6 +1066,18 @@ impl AddAssign for Px {
     }
 }
 
+impl SubAssign for Px {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl std::iter::Sum for Px {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Px::ZERO, |acc, px| acc + px)
+    }
+}
+
 // Arithmetic operations support - PxPosition
 impl std::ops::Add for PxPosition {
     type Output = Self;
@@ -931,23 +1105,23 @@ impl std::ops::Sub for PxPosition {
 impl From<[i32; 2]> for PxPosition {
     fn from(pos: [i32; 2]) -> Self {
         PxPosition {
-            x: Px(pos[0]),
-            y: Px(pos[1]),
+            x: Px::new(pos[0]),
+            y: Px::new(pos[1]),
         }
     }
 }
 
 impl From<PxPosition> for [i32; 2] {
     fn from(pos: PxPosition) -> Self {
-        [pos.x.0, pos.y.0]
+        [pos.x.round().to_i32(), pos.y.round().to_i32()]
     }
 }
 
 impl From<[u32; 2]> for PxPosition {
     fn from(pos: [u32; 2]) -> Self {
         PxPosition {
-            x: Px(pos[0] as i32),
-            y: Px(pos[1] as i32),
+            x: Px(pos[0] as f32),
+            y: Px(pos[1] as f32),
         }
     }
 }
@@ -980,68 +1154,68 @@ mod tests {
     #[test]
     fn test_px_creation() {
         let px = Px::new(42);
-        assert_eq!(px.0, 42);
+        assert_eq!(px.0, 42.0);
 
         let px_neg = Px::new(-10);
-        assert_eq!(px_neg.0, -10);
+        assert_eq!(px_neg.0, -10.0);
     }
 
     #[test]
     fn test_px_arithmetic() {
-        let a = Px(10);
-        let b = Px(5);
-
-        assert_eq!(a + b, Px(15));
-        assert_eq!(a - b, Px(5));
-        assert_eq!(a * 2, Px(20));
-        assert_eq!(a / 2, Px(5));
-        assert_eq!(a * b, Px(50));
-        assert_eq!(a / b, Px(2));
+        let a = Px(10.0);
+        let b = Px(5.0);
+
+        assert_eq!(a + b, Px(15.0));
+        assert_eq!(a - b, Px(5.0));
+        assert_eq!(a * 2, Px(20.0));
+        assert_eq!(a / 2, Px(5.0));
+        assert_eq!(a * b, Px(50.0));
+        assert_eq!(a / b, Px(2.0));
+        assert_eq!(Px(10.5) / 2, Px(5.25));
     }
 
     #[test]
     fn test_px_saturating_arithmetic() {
-        let max = Px(i32::MAX);
-        let min = Px(i32::MIN);
-        assert_eq!(max.saturating_add(Px(1)), max);
-        assert_eq!(min.saturating_sub(Px(1)), min);
+        assert_eq!(Px::MAX.saturating_add(Px::MAX), Px::MAX);
+        assert_eq!(Px::MIN.saturating_sub(Px::MAX), Px::MIN);
     }
 
     #[test]
     fn test_saturating_from_f32() {
-        assert_eq!(Px::saturating_from_f32(f32::MAX), Px(i32::MAX));
-        assert_eq!(Px::saturating_from_f32(f32::MIN), Px(i32::MIN));
-        assert_eq!(Px::saturating_from_f32(100.5), Px(100));
-        assert_eq!(Px::saturating_from_f32(-100.5), Px(-100));
+        assert_eq!(Px::saturating_from_f32(f32::MAX), Px::MAX);
+        assert_eq!(Px::saturating_from_f32(f32::MIN), Px::MIN);
+        assert_eq!(Px::saturating_from_f32(100.5), Px(100.5));
+        assert_eq!(Px::saturating_from_f32(-100.5), Px(-100.5));
+        assert_eq!(Px::saturating_from_f32(f32::NAN), Px::ZERO);
     }
 
     #[test]
     fn test_px_abs() {
-        assert_eq!(Px(10).abs(), 10);
-        assert_eq!(Px(-5).abs(), 5);
-        assert_eq!(Px(0).abs(), 0);
+        assert_eq!(Px(10.0).abs(), 10);
+        assert_eq!(Px(-5.0).abs(), 5);
+        assert_eq!(Px(0.0).abs(), 0);
     }
 
     #[test]
     fn test_px_position() {
-        let pos = PxPosition::new(Px(10), Px(-5));
-        assert_eq!(pos.x, Px(10));
-        assert_eq!(pos.y, Px(-5));
+        let pos = PxPosition::new(Px(10.0), Px(-5.0));
+        assert_eq!(pos.x, Px(10.0));
+        assert_eq!(pos.y, Px(-5.0));
 
-        let offset_pos = pos.offset(Px(2), Px(3));
-        assert_eq!(offset_pos, PxPosition::new(Px(12), Px(-2)));
+        let offset_pos = pos.offset(Px(2.0), Px(3.0));
+        assert_eq!(offset_pos, PxPosition::new(Px(12.0), Px(-2.0)));
     }
 
     #[test]
     fn test_px_position_arithmetic() {
-        let pos1 = PxPosition::new(Px(10), Px(20));
-        let pos2 = PxPosition::new(Px(5), Px(15));
+        let pos1 = PxPosition::new(Px(10.0), Px(20.0));
+        let pos2 = PxPosition::new(Px(5.0), Px(15.0));
 
         let sum = pos1 + pos2;
-        assert_eq!(sum, PxPosition::new(Px(15), Px(35)));
+        assert_eq!(sum, PxPosition::new(Px(15.0), Px(35.0)));
 
         let diff = pos1 - pos2;
-        assert_eq!(diff, PxPosition::new(Px(5), Px(5)));
+        assert_eq!(diff, PxPosition::new(Px(5.0), Px(5.0)));
     }
 
     #[test]
@@ -1059,8 +1233,31 @@ mod tests {
 
     #[test]
     fn test_distance() {
-        let pos1 = PxPosition::new(Px(0), Px(0));
-        let pos2 = PxPosition::new(Px(3), Px(4));
+        let pos1 = PxPosition::new(Px(0.0), Px(0.0));
+        let pos2 = PxPosition::new(Px(3.0), Px(4.0));
         assert_eq!(pos1.distance_to(pos2), 5.0);
     }
+
+    #[test]
+    fn test_snapping() {
+        assert_eq!(Px(2.5).floor(), Px(2.0));
+        assert_eq!(Px(2.5).ceil(), Px(3.0));
+        assert_eq!(Px(2.5).round(), Px(3.0));
+        assert_eq!(Px(2.5).positive(), 3);
+        assert_eq!(Px(-2.4).negative(), -2);
+        assert_eq!(Px::MAX.to_i32(), i32::MAX);
+    }
+
+    #[test]
+    fn test_eq_ord_hash_are_consistent() {
+        use std::hash::{BuildHasher, RandomState};
+
+        let hasher = RandomState::new();
+        assert_eq!(Px(0.0), Px(-0.0));
+        assert_eq!(hasher.hash_one(Px(0.0)), hasher.hash_one(Px(-0.0)));
+        assert_eq!(Px(f32::NAN), Px(f32::NAN));
+        assert!(Px(-1.5) < Px(-1.0));
+        assert!(Px(1.0) < Px(1.5));
+        assert_eq!(Px(1.0).max(Px(1.5)), Px(1.5));
+    }
 }
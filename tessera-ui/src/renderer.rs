@@ -21,6 +21,8 @@
 //! - **[`command`]**: Rendering command abstraction
 //! - **[`compute`]**: Compute shader pipeline management
 //! - **[`drawer`]**: Drawing pipeline management and execution
+//! - **`debug_overlay`**: Built-in pipeline drawing the layout debug overlay, see
+//!   [`TesseraRuntime::set_debug_overlay`]
 //!
 //! ## Basic Usage
 //!
@@ -54,6 +56,7 @@
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let config = TesseraConfig {
 //!     sample_count: 8,  // 8x MSAA
+//!     ..Default::default()
 //! };
 //!
 //! Renderer::run_with_config(
@@ -187,7 +190,9 @@
 pub mod app;
 pub mod command;
 pub mod compute;
+mod debug_overlay;
 pub mod drawer;
+pub mod shader;
 
 use std::{sync::Arc, time::Instant};
 
@@ -195,25 +200,35 @@ use log::{debug, warn};
 use winit::{
     application::ApplicationHandler,
     error::EventLoopError,
-    event::WindowEvent,
-    event_loop::{ActiveEventLoop, EventLoop},
+    event::{ElementState, KeyEvent, StartCause, WindowEvent},
+    event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
+    keyboard::{Key, ModifiersState, NamedKey},
     window::{Window, WindowId},
 };
 
+#[cfg(feature = "devtools")]
+use crate::devtools::{DevtoolsAddress, DevtoolsMessage, DevtoolsServer, FrameStats};
 use crate::{
     Clipboard, ImeState, PxPosition,
+    accessibility::{self, AccessibilityBridge},
+    animation,
     cursor::{CursorEvent, CursorEventContent, CursorState},
-    dp::SCALE_FACTOR,
+    effect,
     keyboard_state::KeyboardState,
+    profiling::profile_span,
     px::PxSize,
-    runtime::TesseraRuntime,
-    thread_utils, tokio_runtime,
+    runtime::{CloseDecision, TesseraRuntime, ThemePreference},
+    thread_utils, timer, tokio_runtime,
+    ui_handle::{self, MessageHandlers, TesseraEvent, UiHandle},
 };
 
 pub use app::WgpuApp;
 pub use command::Command;
 pub use compute::{ComputablePipeline, ComputePipelineRegistry};
 pub use drawer::{BarrierRequirement, DrawCommand, DrawablePipeline, PipelineRegistry};
+pub use shader::ShaderSource;
+
+pub(crate) use debug_overlay::{DebugOverlayCommand, DebugOverlayPipeline};
 
 #[cfg(target_os = "android")]
 use winit::platform::android::{
@@ -236,11 +251,13 @@ use winit::platform::android::{
 /// // Custom configuration with 8x MSAA
 /// let config = TesseraConfig {
 ///     sample_count: 8,
+///     ..Default::default()
 /// };
 ///
 /// // Disable MSAA for better performance
 /// let config = TesseraConfig {
 ///     sample_count: 1,
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Clone)]
@@ -262,12 +279,98 @@ pub struct TesseraConfig {
     /// - Mobile devices may have limited support for higher sample counts
     /// - Consider using lower values on resource-constrained devices
     pub sample_count: u32,
+
+    /// Handlers for typed application messages sent through [`UiHandle::send_message`].
+    ///
+    /// Handlers run on the UI thread as soon as the message arrives. See
+    /// [`TesseraConfig::with_message_handler`] for a convenient way to register them.
+    pub message_handlers: MessageHandlers,
+
+    /// Whether `Ctrl +`, `Ctrl -` and `Ctrl 0` (`Cmd` on macOS) change the application
+    /// zoom, see [`TesseraRuntime::set_zoom`].
+    ///
+    /// When enabled, these key presses are consumed by the renderer and are not delivered to
+    /// components, so they are off by default to leave the keys to the application.
+    /// Defaults to `false`.
+    pub zoom_shortcuts: bool,
+
+    /// The initial font scale, applied to text sized in [`Sp`](crate::Sp).
+    ///
+    /// It can be changed later with [`TesseraRuntime::set_font_scale`]. Defaults to `1.0`.
+    pub font_scale: f64,
+
+    /// Whether the application follows the light or dark theme of the operating system,
+    /// or always uses one of them.
+    ///
+    /// It can be changed later with [`TesseraRuntime::set_theme_preference`]. Defaults to
+    /// [`ThemePreference::FollowSystem`].
+    pub theme_preference: ThemePreference,
+
+    /// Whether the layout debug overlay is shown from the start.
+    ///
+    /// It can be changed later with [`TesseraRuntime::set_debug_overlay`]. Defaults to
+    /// `false`.
+    pub debug_overlay: bool,
+
+    /// Whether `F12` toggles the layout debug overlay.
+    ///
+    /// When enabled, the key press is consumed by the renderer and is not delivered to
+    /// components. Defaults to `false`.
+    pub debug_overlay_shortcut: bool,
+
+    /// Where to serve the [devtools](crate::devtools) protocol, if anywhere.
+    ///
+    /// Defaults to `None`, so running applications cannot be inspected unless asked for.
+    /// Only available with the `devtools` feature.
+    #[cfg(feature = "devtools")]
+    pub devtools: Option<DevtoolsAddress>,
 }
 
 impl Default for TesseraConfig {
-    /// Creates a default configuration with no MSAA enabled.
+    /// Creates a default configuration with no MSAA enabled, zoom shortcuts enabled, a
+    /// font scale of `1.0`, the theme following the system, the debug overlay hidden and no
+    /// devtools server.
     fn default() -> Self {
-        Self { sample_count: 1 }
+        Self {
+            sample_count: 1,
+            message_handlers: MessageHandlers::default(),
+            zoom_shortcuts: false,
+            font_scale: 1.0,
+            theme_preference: ThemePreference::FollowSystem,
+            debug_overlay: false,
+            debug_overlay_shortcut: false,
+            #[cfg(feature = "devtools")]
+            devtools: None,
+        }
+    }
+}
+
+impl TesseraConfig {
+    /// Registers the handler for application messages of type `M`.
+    ///
+    /// Messages are sent from any thread with [`UiHandle::send_message`] and handled on
+    /// the UI thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handler is already registered for `M`.
+    ///
+    /// ```
+    /// use tessera_ui::renderer::TesseraConfig;
+    ///
+    /// struct Refresh;
+    ///
+    /// let config = TesseraConfig::default().with_message_handler(|_: Refresh| {
+    ///     println!("refresh requested");
+    /// });
+    /// assert!(config.message_handlers.contains::<Refresh>());
+    /// ```
+    pub fn with_message_handler<M: Send + 'static>(
+        mut self,
+        handler: impl Fn(M) + Send + Sync + 'static,
+    ) -> Self {
+        self.message_handlers.register(handler);
+        self
     }
 }
 
@@ -319,6 +422,13 @@ pub struct Renderer<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> {
     config: TesseraConfig,
     /// Clipboard manager
     clipboard: Clipboard,
+    /// Closures posted through [`UiHandle::run_on_ui_thread`], run before the next build
+    ui_tasks: Vec<Box<dyn FnOnce() + Send>>,
+    /// Publishes the accessibility tree, created together with the window
+    accessibility: Option<AccessibilityBridge>,
+    /// Streams frames to inspection tools, see [`TesseraConfig::devtools`]
+    #[cfg(feature = "devtools")]
+    devtools: Option<DevtoolsServer>,
     #[cfg(target_os = "android")]
     /// Android-specific state tracking whether the soft keyboard is currently open
     android_ime_opened: bool,
@@ -392,6 +502,7 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = TesseraConfig {
     ///     sample_count: 8,  // 8x MSAA for higher quality
+    ///     ..Default::default()
     /// };
     ///
     /// Renderer::run_with_config(
@@ -407,7 +518,15 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
         register_pipelines_fn: R,
         config: TesseraConfig,
     ) -> Result<(), EventLoopError> {
-        let event_loop = EventLoop::new().unwrap();
+        let event_loop = EventLoop::<TesseraEvent>::with_user_event()
+            .build()
+            .unwrap();
+        ui_handle::install(UiHandle::new(event_loop.create_proxy()));
+        TesseraRuntime::set_font_scale(config.font_scale);
+        TesseraRuntime::set_theme_preference(config.theme_preference);
+        TesseraRuntime::set_debug_overlay(config.debug_overlay);
+        #[cfg(feature = "devtools")]
+        let devtools = start_devtools(&config);
         let app = None;
         let cursor_state = CursorState::default();
         let keyboard_state = KeyboardState::default();
@@ -422,6 +541,10 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
             ime_state,
             config,
             clipboard,
+            ui_tasks: Vec::new(),
+            accessibility: None,
+            #[cfg(feature = "devtools")]
+            devtools,
         };
         thread_utils::set_thread_name("Tessera Renderer");
         event_loop.run_app(&mut renderer)
@@ -505,6 +628,7 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
     /// fn android_main(android_app: AndroidApp) {
     ///     let config = TesseraConfig {
     ///         sample_count: 2,  // Lower MSAA for mobile performance
+    ///         ..Default::default()
     ///     };
     ///     
     ///     Renderer::run_with_config(
@@ -521,10 +645,16 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
         android_app: AndroidApp,
         config: TesseraConfig,
     ) -> Result<(), EventLoopError> {
-        let event_loop = EventLoop::builder()
+        let event_loop = EventLoop::<TesseraEvent>::with_user_event()
             .with_android_app(android_app.clone())
             .build()
             .unwrap();
+        ui_handle::install(UiHandle::new(event_loop.create_proxy()));
+        TesseraRuntime::set_font_scale(config.font_scale);
+        TesseraRuntime::set_theme_preference(config.theme_preference);
+        TesseraRuntime::set_debug_overlay(config.debug_overlay);
+        #[cfg(feature = "devtools")]
+        let devtools = start_devtools(&config);
         let app = None;
         let cursor_state = CursorState::default();
         let keyboard_state = KeyboardState::default();
@@ -540,6 +670,10 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
             android_ime_opened: false,
             config,
             clipboard,
+            ui_tasks: Vec::new(),
+            accessibility: None,
+            #[cfg(feature = "devtools")]
+            devtools,
         };
         thread_utils::set_thread_name("Tessera Renderer");
         event_loop.run_app(&mut renderer)
@@ -581,6 +715,7 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
     ///
     /// This method runs on the main thread but coordinates with other threads for
     /// component tree processing and resource management.
+    #[allow(clippy::too_many_arguments)]
     fn execute_render_frame(
         entry_point: &F,
         cursor_state: &mut CursorState,
@@ -590,20 +725,38 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
         app: &mut WgpuApp,
         #[cfg(target_os = "android")] event_loop: &ActiveEventLoop,
         clipboard: &mut Clipboard,
+        accessibility: Option<&mut AccessibilityBridge>,
+        #[cfg(feature = "devtools")] devtools: Option<&DevtoolsServer>,
     ) {
+        let _frame_span = profile_span!("frame");
         // notify the windowing system before rendering
         // this will help winit to properly schedule and make assumptions about its internal state
         app.window.pre_present_notify();
         // and tell runtime the new size
         TesseraRuntime::write().window_size = app.size().into();
+        // Let the last frame's components know if the theme changed, before they are rebuilt
+        if let Some(theme) = TesseraRuntime::take_theme_change() {
+            TesseraRuntime::call_theme_changed_callbacks(theme);
+        }
         // render the surface
         // Clear any registered callbacks
         TesseraRuntime::write().clear_frame_callbacks();
+        // Sample the frame clock so every animation sees the same frame time
+        animation::begin_frame();
+        // Run due timers before building, so their effects show up in this frame
+        timer::fire_due_timers();
         // timer for performance measurement
         let tree_timer = Instant::now();
         // build the component tree
         debug!("Building component tree...");
-        entry_point();
+        {
+            let _span = profile_span!("build");
+            entry_point();
+            // Cancel async effects whose launching components are gone
+            effect::drop_stale_effects();
+            animation::drop_stale_animations();
+            timer::drop_stale_timers();
+        }
         let build_tree_cost = tree_timer.elapsed();
         debug!("Component tree built in {build_tree_cost:?}");
         // timer for performance measurement
@@ -617,8 +770,14 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
         let screen_size: PxSize = app.size().into();
         // Clear any existing compute resources
         app.resource_manager.write().clear();
+        // Record what snapshots need only while an inspection tool is listening
+        #[cfg(feature = "devtools")]
+        {
+            TesseraRuntime::write().component_tree.record_command_types =
+                devtools.is_some_and(|devtools| devtools.has_clients());
+        }
         // Compute the draw commands
-        let (commands, window_requests) = TesseraRuntime::write().component_tree.compute(
+        let (mut commands, window_requests) = TesseraRuntime::write().component_tree.compute(
             screen_size,
             cursor_position,
             cursor_events,
@@ -631,6 +790,29 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
         );
         let draw_cost = draw_timer.elapsed();
         debug!("Draw commands computed in {draw_cost:?}");
+        // Publish the accessibility tree while the laid out tree is still around
+        if let Some(accessibility) = accessibility {
+            accessibility.update(|| TesseraRuntime::read().component_tree.accessibility());
+        }
+        // Snapshot the laid out tree for inspection tools, if any is listening
+        #[cfg(feature = "devtools")]
+        let snapshot = devtools
+            .filter(|devtools| devtools.has_clients())
+            .map(|_| TesseraRuntime::read().component_tree.snapshot());
+        // Draw the layout debug overlay and the highlighted node on top of everything else
+        let highlighted_node = TesseraRuntime::highlighted_node();
+        if TesseraRuntime::debug_overlay() || highlighted_node.is_some() {
+            let overlay = TesseraRuntime::read().component_tree.debug_overlay(
+                TesseraRuntime::scale_factor(),
+                TesseraRuntime::debug_overlay(),
+                highlighted_node,
+            );
+            commands.push((
+                Command::Draw(Box::new(overlay)),
+                screen_size,
+                PxPosition::ZERO,
+            ));
+        }
         TesseraRuntime::write().component_tree.clear();
         // Handle the window requests
         // After compute, check for cursor change requests
@@ -690,13 +872,34 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
             app.window.request_redraw();
             return;
         }
+        // skip rendering and stop the frame loop while the window is hidden,
+        // a new frame is requested when it becomes visible again
+        if TesseraRuntime::read().window_occluded {
+            return;
+        }
         // Render the commands
         debug!("Rendering draw commands...");
         // Render the commands to the surface
-        app.render(commands).unwrap();
+        let command_count = commands.len();
+        {
+            let _span = profile_span!("render", commands = command_count);
+            app.render(commands).unwrap();
+        }
         let render_cost = render_timer.elapsed();
         debug!("Rendered to surface in {render_cost:?}");
 
+        #[cfg(feature = "devtools")]
+        if let (Some(devtools), Some(snapshot)) = (devtools, snapshot) {
+            let stats = FrameStats {
+                build_ms: build_tree_cost.as_secs_f64() * 1000.0,
+                layout_ms: draw_cost.as_secs_f64() * 1000.0,
+                render_ms: render_cost.as_secs_f64() * 1000.0,
+                node_count: snapshot.node_count(),
+                command_count,
+            };
+            devtools.publish(&DevtoolsMessage::Frame { snapshot, stats });
+        }
+
         // print frame statistics
         let fps = 1.0 / (build_tree_cost + draw_cost + render_cost).as_secs_f32();
         if fps < 60.0 {
@@ -726,7 +929,9 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> Renderer<F, R> {
 /// This implementation handles the application lifecycle events from winit, including
 /// window creation, suspension/resumption, and various window events. It bridges the
 /// gap between winit's event system and Tessera's component-based UI framework.
-impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Renderer<F, R> {
+impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler<TesseraEvent>
+    for Renderer<F, R>
+{
     /// Called when the application is resumed or started.
     ///
     /// This method is responsible for:
@@ -758,10 +963,19 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
         }
 
         // Create a new window
+        // The window starts hidden, since the accessibility adapter must exist before it is shown
         let window_attributes = Window::default_attributes()
             .with_title("Tessera")
-            .with_transparent(true);
+            .with_transparent(true)
+            .with_visible(false);
         let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
+        if let Some(ui_handle) = ui_handle::installed() {
+            self.accessibility = Some(AccessibilityBridge::new(event_loop, &window, &ui_handle));
+        }
+        window.set_visible(true);
+        TesseraRuntime::set_system_theme(window.theme());
+        // `Focused` only arrives on a change, so seed the initial state from the window
+        TesseraRuntime::write().window_focused = window.has_focus();
         let register_pipelines_fn = self.register_pipelines_fn.clone();
 
         let mut wgpu_app =
@@ -795,6 +1009,79 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
         todo!("Handle suspend event");
     }
 
+    /// Handles events sent from other threads through a [`UiHandle`].
+    ///
+    /// - Redraw requests wake the event loop and schedule a new frame.
+    /// - Posted closures are queued and run right before the next build.
+    /// - Application messages are dispatched to the handlers in [`TesseraConfig`].
+    /// - Accessibility action requests are routed to the semantics of their target node.
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, event: TesseraEvent) {
+        match event {
+            TesseraEvent::RedrawRequested => {}
+            TesseraEvent::RunOnUiThread(task) => self.ui_tasks.push(task),
+            TesseraEvent::AppMessage(message) => self.config.message_handlers.dispatch(message),
+            TesseraEvent::Accessibility(event) => match event.window_event {
+                // Components publish semantics from the next frame on, which sends the tree
+                accesskit_winit::WindowEvent::InitialTreeRequested => {
+                    accessibility::set_active(true)
+                }
+                accesskit_winit::WindowEvent::ActionRequested(request) => {
+                    if let Some(accessibility) = self.accessibility.as_ref() {
+                        accessibility.handle_action(request);
+                    }
+                }
+                accesskit_winit::WindowEvent::AccessibilityDeactivated => {
+                    accessibility::set_active(false)
+                }
+            },
+        }
+        if let Some(app) = self.app.as_ref() {
+            app.window.request_redraw();
+        }
+    }
+
+    /// Called when the event loop wakes up, before any other event of the iteration.
+    ///
+    /// Schedules a frame when the deadline of a [`timer`] was reached.
+    fn new_events(&mut self, _event_loop: &ActiveEventLoop, cause: StartCause) {
+        if let StartCause::ResumeTimeReached { .. } = cause
+            && let Some(app) = self.app.as_ref()
+        {
+            app.window.request_redraw();
+        }
+    }
+
+    /// Called when the event loop is about to wait for new events.
+    ///
+    /// Picks up redraw requests made through [`TesseraRuntime::request_redraw`], for example
+    /// by async effects that delivered new state from another thread, and sets up a wake-up
+    /// for the next due [`timer`].
+    fn about_to_wait(&mut self, event_loop: &ActiveEventLoop) {
+        let Some(app) = self.app.as_ref() else {
+            return;
+        };
+        if TesseraRuntime::exit_requested() {
+            TesseraRuntime::call_close_callbacks();
+            event_loop.exit();
+            return;
+        }
+        if TesseraRuntime::take_redraw_request() {
+            app.window.request_redraw();
+        }
+        match timer::next_deadline() {
+            Some(deadline) => {
+                let remaining = deadline.saturating_sub(animation::clock_now());
+                if remaining.is_zero() {
+                    app.window.request_redraw();
+                    event_loop.set_control_flow(ControlFlow::Wait);
+                } else {
+                    event_loop.set_control_flow(ControlFlow::WaitUntil(Instant::now() + remaining));
+                }
+            }
+            None => event_loop.set_control_flow(ControlFlow::Wait),
+        }
+    }
+
     /// Handles window-specific events from the windowing system.
     ///
     /// This method processes all window events including user input, window state changes,
@@ -806,7 +1093,8 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
     /// ### Window Management
     /// - `CloseRequested`: User requested to close the window
     /// - `Resized`: Window size changed
-    /// - `ScaleFactorChanged`: Display scaling changed (high-DPI support)
+    /// - `ScaleFactorChanged`: Display scaling changed (high-DPI support), which relayouts
+    ///   the whole UI in the next frame
     ///
     /// ### Input Events
     /// - `CursorMoved`: Mouse cursor position changed
@@ -814,7 +1102,7 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
     /// - `MouseInput`: Mouse button press/release
     /// - `MouseWheel`: Mouse wheel scrolling
     /// - `Touch`: Touch screen interactions (mobile)
-    /// - `KeyboardInput`: Keyboard key press/release
+    /// - `KeyboardInput`: Keyboard key press/release, except for the zoom shortcuts
     /// - `Ime`: Input Method Editor events (international text input)
     ///
     /// ### Rendering
@@ -832,6 +1120,7 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
     /// - Touch events (mobile platforms)
     /// - IME events (different implementations per platform)
     /// - Scale factor changes (high-DPI displays)
+    /// - Light/dark theme changes of the operating system
     fn window_event(
         &mut self,
         event_loop: &ActiveEventLoop,
@@ -842,27 +1131,50 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
             Some(app) => app,
             None => return,
         };
+        if let Some(accessibility) = self.accessibility.as_mut() {
+            accessibility.process_event(&app.window, &event);
+        }
 
         // Handle window events
         match event {
             WindowEvent::CloseRequested => {
-                TesseraRuntime::read().trigger_close_callbacks();
+                let decision = TesseraRuntime::call_close_requested_callbacks();
+                if decision == CloseDecision::Prevent {
+                    debug!("Window close was prevented by the application");
+                    return;
+                }
+                TesseraRuntime::call_close_callbacks();
                 event_loop.exit();
             }
+            WindowEvent::Focused(focused) => {
+                TesseraRuntime::write().window_focused = focused;
+                TesseraRuntime::call_focus_changed_callbacks(focused);
+            }
+            WindowEvent::Moved(position) => {
+                TesseraRuntime::call_moved_callbacks(PxPosition::from([position.x, position.y]));
+            }
+            WindowEvent::Occluded(occluded) => {
+                TesseraRuntime::write().window_occluded = occluded;
+                TesseraRuntime::call_occluded_callbacks(occluded);
+                if !occluded {
+                    app.window.request_redraw();
+                }
+            }
             WindowEvent::Resized(size) => {
                 if size.width == 0 || size.height == 0 {
                     // Window minimize handling & callback API
                     if !TesseraRuntime::write().window_minimized {
                         TesseraRuntime::write().window_minimized = true;
-                        TesseraRuntime::read().trigger_minimize_callbacks(true);
+                        TesseraRuntime::call_minimize_callbacks(true);
                     }
                 } else {
                     // Window (un)minimize handling & callback API
                     if TesseraRuntime::write().window_minimized {
                         TesseraRuntime::write().window_minimized = false;
-                        TesseraRuntime::read().trigger_minimize_callbacks(false);
+                        TesseraRuntime::call_minimize_callbacks(false);
                     }
                     app.resize(size);
+                    TesseraRuntime::call_resized_callbacks(size.into());
                 }
             }
             WindowEvent::CursorMoved {
@@ -936,11 +1248,26 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
                     }
                 }
             }
+            WindowEvent::ThemeChanged(theme) => {
+                debug!("System theme changed: {theme:?}");
+                TesseraRuntime::set_system_theme(Some(theme));
+            }
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                *SCALE_FACTOR.get().unwrap().write() = scale_factor;
+                debug!("Scale factor changed: {scale_factor}");
+                TesseraRuntime::set_window_scale_factor(scale_factor);
             }
             WindowEvent::KeyboardInput { event, .. } => {
                 debug!("Keyboard input: {event:?}");
+                if self.config.zoom_shortcuts
+                    && let Some(zoom) = zoom_shortcut(&event, self.keyboard_state.modifiers())
+                {
+                    TesseraRuntime::set_zoom(zoom);
+                    return;
+                }
+                if self.config.debug_overlay_shortcut && is_debug_overlay_shortcut(&event) {
+                    TesseraRuntime::set_debug_overlay(!TesseraRuntime::debug_overlay());
+                    return;
+                }
                 self.keyboard_state.push_event(event);
             }
             WindowEvent::ModifiersChanged(modifiers) => {
@@ -952,6 +1279,10 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
                 self.ime_state.push_event(ime_event);
             }
             WindowEvent::RedrawRequested => {
+                // Run closures posted from other threads before building
+                for task in self.ui_tasks.drain(..) {
+                    task();
+                }
                 app.resize_if_needed();
                 Self::execute_render_frame(
                     &self.entry_point,
@@ -964,6 +1295,9 @@ impl<F: Fn(), R: Fn(&mut WgpuApp) + Clone + 'static> ApplicationHandler for Rend
                     #[cfg(target_os = "android")]
                     event_loop,
                     &mut self.clipboard,
+                    self.accessibility.as_mut(),
+                    #[cfg(feature = "devtools")]
+                    self.devtools.as_ref(),
                 );
             }
             _ => (),
@@ -1208,3 +1542,65 @@ pub fn hide_soft_input(android_app: &AndroidApp) {
         let _ = env.exception_clear();
     }
 }
+
+#[cfg(feature = "devtools")]
+/// Starts the devtools server if the configuration asks for one.
+///
+/// Failing to start it is not fatal: the application runs, it just cannot be inspected.
+fn start_devtools(config: &TesseraConfig) -> Option<DevtoolsServer> {
+    let address = config.devtools.as_ref()?;
+    DevtoolsServer::start(address)
+        .inspect_err(|e| warn!("Failed to start devtools server on {address:?}: {e}"))
+        .ok()
+}
+
+/// Returns whether `event` is a press of `F12`, which toggles the layout debug overlay.
+fn is_debug_overlay_shortcut(event: &KeyEvent) -> bool {
+    event.state == ElementState::Pressed
+        && !event.repeat
+        && event.logical_key == Key::Named(NamedKey::F12)
+}
+
+/// Returns the new application zoom if `event` is a zoom shortcut.
+///
+/// `Ctrl +` (or `Ctrl =`, which shares the key on most layouts) zooms in, `Ctrl -` zooms
+/// out and `Ctrl 0` resets the zoom. `Cmd` is used instead of `Ctrl` on macOS.
+fn zoom_shortcut(event: &KeyEvent, modifiers: ModifiersState) -> Option<f64> {
+    let modifier = if cfg!(target_os = "macos") {
+        modifiers.super_key()
+    } else {
+        modifiers.control_key()
+    };
+    if !modifier || event.state != ElementState::Pressed {
+        return None;
+    }
+    let Key::Character(key) = &event.logical_key else {
+        return None;
+    };
+    zoom_for_key(key, TesseraRuntime::zoom())
+}
+
+/// Returns the zoom after the zoom shortcut for `key` was pressed at `zoom`.
+fn zoom_for_key(key: &str, zoom: f64) -> Option<f64> {
+    match key {
+        "+" | "=" => Some(zoom + TesseraRuntime::ZOOM_STEP),
+        "-" => Some(zoom - TesseraRuntime::ZOOM_STEP),
+        "0" => Some(1.0),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zoom_for_key() {
+        let step = TesseraRuntime::ZOOM_STEP;
+        assert_eq!(zoom_for_key("+", 1.0), Some(1.0 + step));
+        assert_eq!(zoom_for_key("=", 1.5), Some(1.5 + step));
+        assert_eq!(zoom_for_key("-", 1.5), Some(1.5 - step));
+        assert_eq!(zoom_for_key("0", 2.0), Some(1.0));
+        assert_eq!(zoom_for_key("a", 2.0), None);
+    }
+}
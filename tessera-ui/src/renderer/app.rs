@@ -1,4 +1,4 @@
-use std::{mem, sync::Arc};
+use std::{iter::Peekable, mem, sync::Arc};
 
 use log::{error, info, warn};
 use parking_lot::RwLock;
@@ -6,11 +6,20 @@ use wgpu::TextureFormat;
 use winit::window::Window;
 
 use crate::{
-    ComputeCommand, PxPosition, compute::resource::ComputeResourceManager, dp::SCALE_FACTOR,
-    px::PxSize, renderer::command::Command,
+    ComputeCommand, PxPosition, TesseraRuntime,
+    compute::resource::ComputeResourceManager,
+    profiling::profile_span,
+    px::PxSize,
+    renderer::{
+        command::Command,
+        drawer::{DrawCommand, command::AsAny},
+    },
 };
 
-use super::{compute::ComputePipelineRegistry, drawer::Drawer};
+use super::{DebugOverlayPipeline, compute::ComputePipelineRegistry, drawer::Drawer};
+
+/// A draw command with the size and position of its component.
+type DrawItem = (Box<dyn DrawCommand>, PxSize, PxPosition);
 
 // Render pass resources for ping-pong operation
 struct PassTarget {
@@ -29,7 +38,12 @@ pub struct WgpuApp {
     /// WGPU queue
     pub queue: wgpu::Queue,
     /// WGPU surface configuration
+    ///
+    /// Its format is never an sRGB format, see [`Self::surface_format`].
     pub config: wgpu::SurfaceConfiguration,
+    /// The format the surface itself is configured with, which may be the sRGB variant
+    /// of `config.format` on platforms that only offer sRGB surfaces
+    surface_format: TextureFormat,
     /// size of the window
     size: winit::dpi::PhysicalSize<u32>,
     /// if size is changed
@@ -42,6 +56,8 @@ pub struct WgpuApp {
     // --- New ping-pong rendering resources ---
     pass_a: PassTarget,
     pass_b: PassTarget,
+    /// The draw commands of the render pass being recorded, kept to reuse the allocation
+    pass_commands: Vec<DrawItem>,
 
     // --- MSAA resources ---
     pub sample_count: u32,
@@ -120,9 +136,20 @@ impl WgpuApp {
             wgpu::PresentMode::Immediate
         };
         info!("Using present mode: {present_mode:?}");
+        // Colors are sRGB values that pipelines write to their targets unchanged, so the
+        // targets must not be sRGB formats, or the GPU would encode them a second time.
+        // The surface may still be an sRGB format: the final copy to it works either way,
+        // as formats differing only by the sRGB suffix are copy compatible.
+        let surface_format = caps
+            .formats
+            .iter()
+            .copied()
+            .find(|format| !format.is_srgb())
+            .unwrap_or(caps.formats[0]);
+        info!("Using surface format: {surface_format:?}");
         let config = wgpu::SurfaceConfiguration {
             usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_DST,
-            format: caps.formats[0],
+            format: surface_format.remove_srgb_suffix(),
             width: size.width,
             height: size.height,
             present_mode,
@@ -130,7 +157,13 @@ impl WgpuApp {
             view_formats: vec![],
             desired_maximum_frame_latency: 2,
         };
-        surface.configure(&gpu, &config);
+        surface.configure(
+            &gpu,
+            &wgpu::SurfaceConfiguration {
+                format: surface_format,
+                ..config.clone()
+            },
+        );
 
         // --- Create MSAA Target ---
         let (msaa_texture, msaa_view) = if sample_count > 1 {
@@ -163,14 +196,16 @@ impl WgpuApp {
         let compute_target_b =
             Self::create_compute_pass_target(&gpu, &config, TextureFormat::Rgba8Unorm, "Compute B");
 
-        let drawer = Drawer::new();
+        // The debug overlay is built in, so it works whatever pipelines the app registers
+        let mut drawer = Drawer::new();
+        drawer
+            .pipeline_registry
+            .register(DebugOverlayPipeline::new(&gpu, &config, sample_count));
 
         // Set scale factor for dp conversion
         let scale_factor = window.scale_factor();
         info!("Window scale factor: {scale_factor}");
-        SCALE_FACTOR
-            .set(RwLock::new(scale_factor))
-            .expect("Failed to set scale factor");
+        TesseraRuntime::set_window_scale_factor(scale_factor);
 
         Self {
             window,
@@ -178,11 +213,13 @@ impl WgpuApp {
             surface,
             queue,
             config,
+            surface_format,
             size,
             size_changed: false,
             drawer,
             pass_a,
             pass_b,
+            pass_commands: Vec::new(),
             compute_pipeline_registry: ComputePipelineRegistry::new(),
             sample_count,
             msaa_texture,
@@ -326,7 +363,13 @@ impl WgpuApp {
             self.config.width = self.size.width;
             self.config.height = self.size.height;
             self.resize_pass_targets_if_needed();
-            self.surface.configure(&self.gpu, &self.config);
+            self.surface.configure(
+                &self.gpu,
+                &wgpu::SurfaceConfiguration {
+                    format: self.surface_format,
+                    ..self.config.clone()
+                },
+            );
             self.size_changed = false;
         }
     }
@@ -410,11 +453,14 @@ impl WgpuApp {
             if command.barrier().is_some() {
                 // Perform a ping-pong operation
                 std::mem::swap(&mut read_target, &mut write_target);
-                encoder.copy_texture_to_texture(
-                    read_target.texture.as_image_copy(),
-                    write_target.texture.as_image_copy(),
-                    texture_size,
-                );
+                {
+                    let _span = profile_span!("barrier_copy");
+                    encoder.copy_texture_to_texture(
+                        read_target.texture.as_image_copy(),
+                        write_target.texture.as_image_copy(),
+                        texture_size,
+                    );
+                }
                 // --- Apply compute effect ---
                 let final_view_after_compute = if !self.compute_commands.is_empty() {
                     let compute_commands = mem::take(&mut self.compute_commands);
@@ -439,6 +485,7 @@ impl WgpuApp {
             match command {
                 // Process draw commands using the graphics pipeline
                 Command::Draw(command) => {
+                    let _span = profile_span!("render_pass");
                     let (view, resolve_target) = if let Some(msaa_view) = &self.msaa_view {
                         (msaa_view, Some(&write_target.view))
                     } else {
@@ -460,38 +507,24 @@ impl WgpuApp {
                     self.drawer
                         .begin_pass(&self.gpu, &self.queue, &self.config, &mut rpass);
 
-                    // Submit the first command
-                    self.drawer.submit(
-                        &self.gpu,
-                        &self.queue,
-                        &self.config,
-                        &mut rpass,
-                        &*command,
-                        size,
-                        start_pos,
-                        scene_texture_view,
-                    );
-
-                    // Batch subsequent draw commands that don't require barriers
-                    while let Some((Command::Draw(command), _, _)) = commands_iter.peek() {
-                        if command.barrier().is_some() {
-                            break; // Break if a barrier is required
-                        }
-                        if let Some((Command::Draw(command), size, start_pos)) =
-                            commands_iter.next()
-                        {
-                            self.drawer.submit(
-                                &self.gpu,
-                                &self.queue,
-                                &self.config,
-                                &mut rpass,
-                                &*command,
-                                size,
-                                start_pos,
-                                scene_texture_view,
-                            );
-                        }
+                    // Batch subsequent draw commands that don't require barriers, submitting
+                    // each run of commands of the same type at once
+                    let mut pass = mem::take(&mut self.pass_commands);
+                    pass.push((command, size, start_pos));
+                    take_pass_commands(&mut commands_iter, &mut pass);
+                    for run in draw_runs(&pass) {
+                        self.drawer.submit_batch(
+                            &self.gpu,
+                            &self.queue,
+                            &self.config,
+                            &mut rpass,
+                            run,
+                            scene_texture_view,
+                        );
                     }
+                    pass.clear();
+                    self.pass_commands = pass;
+
                     self.drawer
                         .end_pass(&self.gpu, &self.queue, &self.config, &mut rpass);
                 }
@@ -526,6 +559,7 @@ impl WgpuApp {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn do_compute<'a>(
         encoder: &mut wgpu::CommandEncoder,
         commands: Vec<Box<dyn ComputeCommand>>,
@@ -548,6 +582,13 @@ impl WgpuApp {
         let (mut write_target, mut read_target) = (target_a, target_b);
 
         for command in commands {
+            if !compute_pipeline_registry.handles(&*command) {
+                continue;
+            }
+            let _span = profile_span!(
+                "compute_dispatch",
+                command = <dyn ComputeCommand as AsAny>::type_name(command.as_ref())
+            );
             // Ensure the write target is cleared before use
             let rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("Compute Target Clear"),
@@ -595,3 +636,97 @@ impl WgpuApp {
         read_view
     }
 }
+
+/// Moves the draw commands at the front of `commands` to `pass`, up to the first one that
+/// needs a barrier or is not a draw command.
+fn take_pass_commands(
+    commands: &mut Peekable<impl Iterator<Item = (Command, PxSize, PxPosition)>>,
+    pass: &mut Vec<DrawItem>,
+) {
+    while let Some((Command::Draw(command), _, _)) = commands.peek() {
+        if command.barrier().is_some() {
+            break;
+        }
+        let Some((Command::Draw(command), size, start_pos)) = commands.next() else {
+            unreachable!("the next command was just peeked");
+        };
+        pass.push((command, size, start_pos));
+    }
+}
+
+/// Splits the commands of a render pass into runs of consecutive commands of the same type.
+fn draw_runs(pass: &[DrawItem]) -> impl Iterator<Item = &[DrawItem]> {
+    // `as_ref` so the ids are those of the commands rather than of their boxes
+    pass.chunk_by(|(a, _, _), (b, _, _)| {
+        a.as_ref().as_any().type_id() == b.as_ref().as_any().type_id()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BarrierRequirement;
+
+    use super::*;
+
+    struct DotCommand;
+
+    impl DrawCommand for DotCommand {}
+
+    struct LineCommand;
+
+    impl DrawCommand for LineCommand {}
+
+    struct BlurCommand;
+
+    impl DrawCommand for BlurCommand {
+        fn barrier(&self) -> Option<BarrierRequirement> {
+            Some(BarrierRequirement::SampleBackground)
+        }
+    }
+
+    fn draw(command: impl DrawCommand + 'static) -> (Command, PxSize, PxPosition) {
+        (
+            Command::Draw(Box::new(command)),
+            PxSize::default(),
+            PxPosition::ZERO,
+        )
+    }
+
+    fn run_lengths(pass: &[DrawItem]) -> Vec<usize> {
+        draw_runs(pass).map(<[DrawItem]>::len).collect()
+    }
+
+    #[test]
+    fn test_runs_split_on_type_change() {
+        let mut commands = [
+            draw(DotCommand),
+            draw(DotCommand),
+            draw(LineCommand),
+            draw(DotCommand),
+        ]
+        .into_iter()
+        .peekable();
+        let mut pass = Vec::new();
+        take_pass_commands(&mut commands, &mut pass);
+        assert!(commands.next().is_none());
+        assert_eq!(run_lengths(&pass), [2, 1, 1]);
+    }
+
+    #[test]
+    fn test_pass_ends_at_barrier() {
+        let mut commands = [
+            draw(DotCommand),
+            draw(LineCommand),
+            draw(BlurCommand),
+            draw(DotCommand),
+        ]
+        .into_iter()
+        .peekable();
+        let mut pass = Vec::new();
+        take_pass_commands(&mut commands, &mut pass);
+        assert_eq!(run_lengths(&pass), [1, 1]);
+        // The command needing a barrier starts the next pass
+        let (next, _, _) = commands.next().unwrap();
+        assert!(next.barrier().is_some());
+    }
+}
@@ -4,7 +4,7 @@
 //! into a single type, enabling seamless integration of graphics and compute pipelines
 //! in the rendering workflow.
 
-use crate::{BarrierRequirement, ComputeCommand, DrawCommand};
+use crate::{BarrierRequirement, ComputeCommand, DrawCommand, renderer::drawer::command::AsAny};
 
 /// Unified command enum that can represent either a draw or compute operation.
 ///
@@ -41,6 +41,15 @@ impl Command {
             Command::Compute(_) => Some(BarrierRequirement::SampleBackground),
         }
     }
+
+    /// Returns the name of the concrete command type, like
+    /// `tessera_ui_basic_components::pipelines::ShapeCommand`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Command::Draw(command) => <dyn DrawCommand as AsAny>::type_name(command.as_ref()),
+            Command::Compute(command) => <dyn ComputeCommand as AsAny>::type_name(command.as_ref()),
+        }
+    }
 }
 
 /// Automatic conversion from boxed draw commands to unified commands
@@ -173,6 +173,11 @@
 //!
 //! The framework automatically handles format conversions when necessary.
 
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+};
+
 use crate::compute::resource::ComputeResourceManager;
 
 use super::command::ComputeCommand;
@@ -280,6 +285,7 @@ pub trait ComputablePipeline<C: ComputeCommand>: Send + Sync + 'static {
     /// - Validate command parameters before use
     /// - Ensure texture dimensions are compatible
     /// - Handle resource creation failures appropriately
+    #[allow(clippy::too_many_arguments)]
     fn dispatch(
         &mut self,
         device: &wgpu::Device,
@@ -308,6 +314,7 @@ pub trait ComputablePipeline<C: ComputeCommand>: Send + Sync + 'static {
 /// [`ComputablePipeline<C>`] through the [`ComputablePipelineImpl`] wrapper.
 pub(crate) trait ErasedComputablePipeline: Send + Sync {
     /// Dispatches a type-erased compute command.
+    #[allow(clippy::too_many_arguments)]
     fn dispatch_erased(
         &mut self,
         device: &wgpu::Device,
@@ -365,14 +372,17 @@ impl<C: ComputeCommand + 'static, P: ComputablePipeline<C>> ErasedComputablePipe
 /// # Architecture
 ///
 /// The registry uses type erasure to store pipelines of different types in a single
-/// collection. When a compute command needs to be processed, the registry attempts
-/// to dispatch it to all registered pipelines until one handles it successfully.
+/// map, keyed by the [`TypeId`] of the command type they process. When a compute command
+/// needs to be processed, the registry looks up its pipeline directly. Commands without
+/// a pipeline are skipped, leaving the scene unchanged, and their type is reported in the
+/// log the first time.
 ///
 /// # Usage Pattern
 ///
 /// 1. Create a new registry
 /// 2. Register all required compute pipelines during application initialization
-/// 3. The renderer uses the registry to dispatch commands during frame rendering
+/// 3. Optionally check that the pipelines the application needs are present
+/// 4. The renderer uses the registry to dispatch commands during frame rendering
 ///
 /// # Example
 ///
@@ -386,21 +396,23 @@ impl<C: ComputeCommand + 'static, P: ComputablePipeline<C>> ErasedComputablePipe
 /// registry.register(brightness_pipeline);
 ///
 /// // Registry is now ready for use by the renderer
+/// assert!(registry.contains::<BlurCommand>());
 /// ```
 ///
-/// # Performance Considerations
-///
-/// - All registered pipelines are called for each command until one handles it
-/// - Register more commonly used pipelines first for better average performance
-/// - Consider the frequency of different compute operations when ordering registrations
-///
 /// # Thread Safety
 ///
 /// The registry and all registered pipelines must be `Send + Sync` to support
 /// parallel execution in the rendering system.
 #[derive(Default)]
 pub struct ComputePipelineRegistry {
-    pipelines: Vec<Box<dyn ErasedComputablePipeline>>,
+    pipelines: HashMap<TypeId, RegisteredPipeline>,
+    /// Command types already reported as having no pipeline
+    unhandled: HashSet<TypeId>,
+}
+
+struct RegisteredPipeline {
+    command_type: &'static str,
+    pipeline: Box<dyn ErasedComputablePipeline>,
 }
 
 impl ComputePipelineRegistry {
@@ -430,6 +442,11 @@ impl ComputePipelineRegistry {
     ///
     /// * `pipeline` - The pipeline instance to register
     ///
+    /// # Panics
+    ///
+    /// Panics if a pipeline is already registered for `C`, since only one of them could
+    /// ever process its commands.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -449,12 +466,6 @@ impl ComputePipelineRegistry {
     /// registry.register(ColorGradingPipeline::new(&device));
     /// ```
     ///
-    /// # Registration Order
-    ///
-    /// Unlike drawable pipelines, compute pipelines are dispatched to all registered
-    /// pipelines until one handles the command. Consider registering more frequently
-    /// used pipelines first for better performance.
-    ///
     /// # Thread Safety
     ///
     /// The pipeline must implement `Send + Sync` to be compatible with Tessera's
@@ -463,14 +474,57 @@ impl ComputePipelineRegistry {
         &mut self,
         pipeline: impl ComputablePipeline<C> + 'static,
     ) {
-        let erased_pipeline = Box::new(ComputablePipelineImpl {
-            pipeline,
-            _command: std::marker::PhantomData,
-        });
-        self.pipelines.push(erased_pipeline);
+        let command_type = std::any::type_name::<C>();
+        if self.pipelines.contains_key(&TypeId::of::<C>()) {
+            panic!("A compute pipeline for {command_type} is already registered");
+        }
+        self.pipelines.insert(
+            TypeId::of::<C>(),
+            RegisteredPipeline {
+                command_type,
+                pipeline: Box::new(ComputablePipelineImpl {
+                    pipeline,
+                    _command: std::marker::PhantomData,
+                }),
+            },
+        );
+    }
+
+    /// Returns whether a pipeline is registered for the command type `C`.
+    ///
+    /// Applications can use it to check at startup that the pipelines they need are
+    /// present, instead of finding effects missing from the screen.
+    pub fn contains<C: ComputeCommand + 'static>(&self) -> bool {
+        self.pipelines.contains_key(&TypeId::of::<C>())
+    }
+
+    /// Returns the type names of the commands with a registered pipeline, in no particular
+    /// order.
+    pub fn command_types(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.pipelines.values().map(|entry| entry.command_type)
+    }
+
+    /// Returns whether a pipeline is registered for the command, reporting its type in
+    /// the log the first time it is not.
+    pub(crate) fn handles(&mut self, command: &dyn ComputeCommand) -> bool {
+        let type_id = command.as_any().type_id();
+        if self.pipelines.contains_key(&type_id) {
+            return true;
+        }
+        if self.unhandled.insert(type_id) {
+            log::error!(
+                "No compute pipeline registered for {}, its commands are skipped",
+                command.type_name()
+            );
+        }
+        false
     }
 
     /// Dispatches a command to its corresponding registered pipeline.
+    ///
+    /// Does nothing if no pipeline is registered for the command, see
+    /// [`handles`](Self::handles).
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn dispatch_erased(
         &mut self,
         device: &wgpu::Device,
@@ -482,8 +536,8 @@ impl ComputePipelineRegistry {
         input_view: &wgpu::TextureView,
         output_view: &wgpu::TextureView,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            pipeline.dispatch_erased(
+        if let Some(entry) = self.pipelines.get_mut(&command.as_any().type_id()) {
+            entry.pipeline.dispatch_erased(
                 device,
                 queue,
                 config,
@@ -0,0 +1,703 @@
+//! Built-in layout debug overlay.
+//!
+//! When [`TesseraRuntime::debug_overlay`](crate::TesseraRuntime::debug_overlay) is enabled,
+//! the renderer draws a [`DebugOverlayCommand`] after all other commands. It outlines every
+//! laid out node and labels it with its function name, its size and the constraint it was
+//! measured with:
+//!
+//! - nodes larger than that constraint are outlined in red,
+//! - nodes reaching outside of their parent are outlined in orange,
+//! - all other nodes are outlined in blue.
+//!
+//! The same command also fills the node highlighted by inspection tools in magenta, even
+//! while the rest of the overlay is hidden.
+//!
+//! The overlay must work without any component library, so its pipeline only draws solid
+//! rectangles, and the labels use a tiny built-in bitmap font made of such rectangles.
+
+use bytemuck::{Pod, Zeroable};
+use indextree::NodeId;
+
+use crate::{
+    ComponentNodeMetaDatas, ComponentNodeTree, Constraint, DimensionValue, Px, PxPosition, PxSize,
+    renderer::{DrawCommand, DrawablePipeline},
+};
+
+/// Outline of nodes without layout issues.
+const OUTLINE_COLOR: [f32; 4] = [0.2, 0.6, 1.0, 0.8];
+/// Outline of nodes larger than their constraint.
+const VIOLATION_COLOR: [f32; 4] = [1.0, 0.1, 0.1, 1.0];
+/// Outline of nodes reaching outside of their parent.
+const OVERFLOW_COLOR: [f32; 4] = [1.0, 0.6, 0.0, 1.0];
+/// Outline and fill of the node highlighted by inspection tools.
+const HIGHLIGHT_COLOR: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+const HIGHLIGHT_FILL_COLOR: [f32; 4] = [1.0, 0.0, 1.0, 0.25];
+const LABEL_BACKGROUND_COLOR: [f32; 4] = [0.0, 0.0, 0.0, 0.7];
+const LABEL_TEXT_COLOR: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// Sizes closer than this to a bound, in physical pixels, are not reported as issues,
+/// as they come from rounding rather than from the layout.
+const TOLERANCE: f32 = 0.5;
+
+/// Glyphs are `GLYPH_WIDTH` by `GLYPH_HEIGHT` font pixels.
+const GLYPH_WIDTH: usize = 3;
+const GLYPH_HEIGHT: usize = 5;
+
+/// A solid rectangle of the overlay, in the layout of the pipeline's instance buffer.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Pod, Zeroable)]
+struct OverlayRect {
+    /// x, y, width and height in physical pixels
+    rect: [f32; 4],
+    color: [f32; 4],
+}
+
+impl OverlayRect {
+    const ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+        wgpu::vertex_attr_array![0 => Float32x4, 1 => Float32x4];
+
+    fn new(x: f32, y: f32, width: f32, height: f32, color: [f32; 4]) -> Self {
+        Self {
+            rect: [x, y, width, height],
+            color,
+        }
+    }
+
+    fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<OverlayRect>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBUTES,
+        }
+    }
+}
+
+/// A layout issue of a node, see the module documentation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Issue {
+    /// The node is larger than the constraint it was measured with.
+    ConstraintViolation,
+    /// The node reaches outside of its parent.
+    Overflow,
+}
+
+impl Issue {
+    fn color(issue: Option<Self>) -> [f32; 4] {
+        match issue {
+            Some(Issue::ConstraintViolation) => VIOLATION_COLOR,
+            Some(Issue::Overflow) => OVERFLOW_COLOR,
+            None => OUTLINE_COLOR,
+        }
+    }
+}
+
+/// The absolute rectangle of a laid out node.
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    position: PxPosition,
+    size: PxSize,
+}
+
+impl Bounds {
+    fn contains(&self, other: &Bounds) -> bool {
+        other.position.x.0 >= self.position.x.0 - TOLERANCE
+            && other.position.y.0 >= self.position.y.0 - TOLERANCE
+            && other.position.x.0 + other.size.width.0
+                <= self.position.x.0 + self.size.width.0 + TOLERANCE
+            && other.position.y.0 + other.size.height.0
+                <= self.position.y.0 + self.size.height.0 + TOLERANCE
+    }
+}
+
+/// Returns the most severe layout issue of a node, if any.
+fn node_issue(
+    bounds: &Bounds,
+    constraint: Option<&Constraint>,
+    parent: Option<&Bounds>,
+) -> Option<Issue> {
+    let exceeds = |size: Px, dimension: &DimensionValue| {
+        dimension
+            .get_max()
+            .is_some_and(|max| size.0 > max.0 + TOLERANCE)
+    };
+    if constraint.is_some_and(|constraint| {
+        exceeds(bounds.size.width, &constraint.width)
+            || exceeds(bounds.size.height, &constraint.height)
+    }) {
+        return Some(Issue::ConstraintViolation);
+    }
+    if parent.is_some_and(|parent| !parent.contains(bounds)) {
+        return Some(Issue::Overflow);
+    }
+    None
+}
+
+/// Draws the layout debug overlay, built from a laid out component tree.
+pub(crate) struct DebugOverlayCommand {
+    rects: Vec<OverlayRect>,
+}
+
+impl DrawCommand for DebugOverlayCommand {}
+
+impl DebugOverlayCommand {
+    /// Builds the overlay of the nodes below `root`.
+    ///
+    /// Every node is outlined if `outline_all` is set. The node whose key is `highlight`
+    /// is filled and labelled either way, see
+    /// [`TesseraRuntime::set_highlighted_node`](crate::TesseraRuntime::set_highlighted_node).
+    ///
+    /// Must be called after the draw commands were computed, as it relies on the absolute
+    /// positions of the nodes. `scale_factor` sets the size of the outlines and labels.
+    pub(crate) fn build(
+        tree: &ComponentNodeTree,
+        metadatas: &ComponentNodeMetaDatas,
+        root: Option<NodeId>,
+        scale_factor: f64,
+        outline_all: bool,
+        highlight: Option<u64>,
+    ) -> Self {
+        let unit = scale_factor.round().max(1.0) as f32;
+        let mut outlines = Vec::new();
+        let mut issue_outlines = Vec::new();
+        let mut labels = Vec::new();
+        let mut highlights = Vec::new();
+
+        let mut stack: Vec<(NodeId, Option<Bounds>)> =
+            root.map(|root| (root, None)).into_iter().collect();
+        while let Some((node_id, parent)) = stack.pop() {
+            let Some(metadata) = metadatas.get(&node_id) else {
+                continue;
+            };
+            // Nodes that were not placed have no position, and neither do their children
+            let (Some(position), Some(computed_data)) =
+                (metadata.abs_position, metadata.computed_data)
+            else {
+                continue;
+            };
+            let bounds = Bounds {
+                position,
+                size: PxSize::new(computed_data.width, computed_data.height),
+            };
+            let constraint = metadata.constraint;
+            drop(metadata);
+
+            let highlighted =
+                highlight.is_some() && highlight == tree.get(node_id).map(|node| node.get().key);
+            if outline_all || highlighted {
+                let issue = node_issue(&bounds, constraint.as_ref(), parent.as_ref());
+                let name = tree.get(node_id).map_or_else(
+                    || "?".to_string(),
+                    |node| match &node.get().test_tag {
+                        Some(tag) => format!("{} [{tag}]", node.get().fn_name),
+                        None => node.get().fn_name.clone(),
+                    },
+                );
+                let lines = label_lines(&name, &bounds, constraint.as_ref());
+                if highlighted {
+                    highlights.push(OverlayRect::new(
+                        bounds.position.x.0,
+                        bounds.position.y.0,
+                        bounds.size.width.0,
+                        bounds.size.height.0,
+                        HIGHLIGHT_FILL_COLOR,
+                    ));
+                    push_outline(&mut highlights, &bounds, unit, HIGHLIGHT_COLOR);
+                    push_label(&mut highlights, &lines, bounds.position, unit);
+                } else {
+                    let target = if issue.is_some() {
+                        &mut issue_outlines
+                    } else {
+                        &mut outlines
+                    };
+                    push_outline(target, &bounds, unit, Issue::color(issue));
+                    // Only label nodes the label fits in, unless they have an issue
+                    if issue.is_some() || bounds.size.height.0 >= label_height(lines.len(), unit) {
+                        push_label(&mut labels, &lines, bounds.position, unit);
+                    }
+                }
+            }
+
+            // Children are pushed in reverse so they are visited, and drawn, in order
+            let children: Vec<_> = node_id.children(tree).collect();
+            stack.extend(
+                children
+                    .into_iter()
+                    .rev()
+                    .map(|child| (child, Some(bounds))),
+            );
+        }
+
+        outlines.extend(issue_outlines);
+        outlines.extend(labels);
+        outlines.extend(highlights);
+        Self { rects: outlines }
+    }
+}
+
+/// Returns the label of a node: its name and size, then its constraint.
+fn label_lines(name: &str, bounds: &Bounds, constraint: Option<&Constraint>) -> [String; 2] {
+    [
+        format!(
+            "{name} {}x{}",
+            format_px(bounds.size.width),
+            format_px(bounds.size.height)
+        ),
+        constraint.map_or_else(
+            || "unmeasured".to_string(),
+            |constraint| {
+                format!(
+                    "w {} h {}",
+                    format_dimension(&constraint.width),
+                    format_dimension(&constraint.height)
+                )
+            },
+        ),
+    ]
+}
+
+/// Formats a length in whole pixels.
+fn format_px(px: Px) -> String {
+    format!("{}", px.0.round() as i64)
+}
+
+/// Formats a bound of a dimension, leaving out unbounded ones.
+fn format_bound(bound: Option<Px>) -> String {
+    match bound {
+        Some(px) if px.0 < Px::MAX.0 => format_px(px),
+        _ => String::new(),
+    }
+}
+
+/// Formats a dimension of a constraint, like `fixed(100)` or `wrap(0..200)`.
+fn format_dimension(dimension: &DimensionValue) -> String {
+    match dimension {
+        DimensionValue::Fixed(px) => format!("fixed({})", format_px(*px)),
+        DimensionValue::Wrap { min, max } => {
+            format!("wrap({}..{})", format_bound(*min), format_bound(*max))
+        }
+        DimensionValue::Fill { min, max } => {
+            format!("fill({}..{})", format_bound(*min), format_bound(*max))
+        }
+        DimensionValue::Fraction(fraction) => format!("fraction({fraction})"),
+    }
+}
+
+/// Pushes the four sides of the outline of `bounds`, `unit` pixels thick.
+fn push_outline(rects: &mut Vec<OverlayRect>, bounds: &Bounds, unit: f32, color: [f32; 4]) {
+    let [x, y] = bounds.position.to_f32_arr2();
+    let (width, height) = (bounds.size.width.0, bounds.size.height.0);
+    let thickness = unit.min(width).min(height);
+    if thickness <= 0.0 {
+        return;
+    }
+    rects.push(OverlayRect::new(x, y, width, thickness, color));
+    rects.push(OverlayRect::new(
+        x,
+        y + height - thickness,
+        width,
+        thickness,
+        color,
+    ));
+    rects.push(OverlayRect::new(x, y, thickness, height, color));
+    rects.push(OverlayRect::new(
+        x + width - thickness,
+        y,
+        thickness,
+        height,
+        color,
+    ));
+}
+
+/// Returns the height of a label of `lines` lines, in physical pixels.
+fn label_height(lines: usize, unit: f32) -> f32 {
+    (lines * (GLYPH_HEIGHT + 2) + 1) as f32 * unit
+}
+
+/// Pushes a label with a background at `position`, one text line below the other.
+fn push_label(rects: &mut Vec<OverlayRect>, lines: &[String], position: PxPosition, unit: f32) {
+    let [x, y] = position.to_f32_arr2();
+    let columns = lines
+        .iter()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let width = (columns * (GLYPH_WIDTH + 1) + 1) as f32 * unit;
+    rects.push(OverlayRect::new(
+        x,
+        y,
+        width,
+        label_height(lines.len(), unit),
+        LABEL_BACKGROUND_COLOR,
+    ));
+    for (row, line) in lines.iter().enumerate() {
+        let line_y = y + (row * (GLYPH_HEIGHT + 2) + 1) as f32 * unit;
+        push_text(rects, line, [x + unit, line_y], unit);
+    }
+}
+
+/// Pushes the rectangles drawing `text` with the built-in font, `unit` pixels per font
+/// pixel. Lit pixels next to each other in a glyph row are merged into one rectangle.
+fn push_text(rects: &mut Vec<OverlayRect>, text: &str, origin: [f32; 2], unit: f32) {
+    for (column, c) in text.chars().enumerate() {
+        let bits = glyph(c);
+        let glyph_x = origin[0] + (column * (GLYPH_WIDTH + 1)) as f32 * unit;
+        for row in 0..GLYPH_HEIGHT {
+            let row_bits = (bits >> ((GLYPH_HEIGHT - 1 - row) * GLYPH_WIDTH)) & 0b111;
+            let mut start = None;
+            for pixel in 0..=GLYPH_WIDTH {
+                let lit = pixel < GLYPH_WIDTH && row_bits & (0b100 >> pixel) != 0;
+                match (lit, start) {
+                    (true, None) => start = Some(pixel),
+                    (false, Some(first)) => {
+                        rects.push(OverlayRect::new(
+                            glyph_x + first as f32 * unit,
+                            origin[1] + row as f32 * unit,
+                            (pixel - first) as f32 * unit,
+                            unit,
+                            LABEL_TEXT_COLOR,
+                        ));
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Returns the 3x5 bitmap of `c`, one row of three bits per line, top row first.
+///
+/// Lowercase letters are drawn as uppercase ones, unknown characters as `?`.
+fn glyph(c: char) -> u16 {
+    match c.to_ascii_uppercase() {
+        'A' => 0b010_101_111_101_101,
+        'B' => 0b110_101_110_101_110,
+        'C' => 0b011_100_100_100_011,
+        'D' => 0b110_101_101_101_110,
+        'E' => 0b111_100_110_100_111,
+        'F' => 0b111_100_110_100_100,
+        'G' => 0b011_100_101_101_011,
+        'H' => 0b101_101_111_101_101,
+        'I' => 0b111_010_010_010_111,
+        'J' => 0b001_001_001_101_010,
+        'K' => 0b101_101_110_101_101,
+        'L' => 0b100_100_100_100_111,
+        'M' => 0b101_111_111_101_101,
+        'N' => 0b110_101_101_101_101,
+        'O' => 0b010_101_101_101_010,
+        'P' => 0b110_101_110_100_100,
+        'Q' => 0b010_101_101_110_011,
+        'R' => 0b110_101_110_101_101,
+        'S' => 0b011_100_010_001_110,
+        'T' => 0b111_010_010_010_010,
+        'U' => 0b101_101_101_101_111,
+        'V' => 0b101_101_101_101_010,
+        'W' => 0b101_101_111_111_101,
+        'X' => 0b101_101_010_101_101,
+        'Y' => 0b101_101_010_010_010,
+        'Z' => 0b111_001_010_100_111,
+        '0' => 0b111_101_101_101_111,
+        '1' => 0b010_110_010_010_111,
+        '2' => 0b110_001_010_100_111,
+        '3' => 0b110_001_010_001_110,
+        '4' => 0b101_101_111_001_001,
+        '5' => 0b111_100_110_001_110,
+        '6' => 0b011_100_111_101_111,
+        '7' => 0b111_001_010_010_010,
+        '8' => 0b111_101_111_101_111,
+        '9' => 0b111_101_111_001_110,
+        ' ' => 0,
+        '_' => 0b000_000_000_000_111,
+        '.' => 0b000_000_000_000_010,
+        ',' => 0b000_000_000_010_100,
+        ':' => 0b000_010_000_010_000,
+        '-' => 0b000_000_111_000_000,
+        '(' => 0b010_100_100_100_010,
+        ')' => 0b010_001_001_001_010,
+        '[' => 0b110_100_100_100_110,
+        ']' => 0b011_001_001_001_011,
+        '/' => 0b001_001_010_100_100,
+        '<' => 0b001_010_100_010_001,
+        '>' => 0b100_010_001_010_100,
+        _ => 0b110_001_010_000_010,
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct OverlayUniforms {
+    screen_size: [f32; 2],
+    _padding: [f32; 2],
+}
+
+/// Draws [`DebugOverlayCommand`]s as instanced solid rectangles.
+pub(crate) struct DebugOverlayPipeline {
+    pipeline: wgpu::RenderPipeline,
+    uniform_buffer: wgpu::Buffer,
+    bind_group: wgpu::BindGroup,
+    instance_buffer: wgpu::Buffer,
+    /// Number of rectangles the instance buffer can hold
+    instance_capacity: usize,
+}
+
+impl DebugOverlayPipeline {
+    pub(crate) fn new(
+        gpu: &wgpu::Device,
+        config: &wgpu::SurfaceConfiguration,
+        sample_count: u32,
+    ) -> Self {
+        let shader = gpu.create_shader_module(wgpu::include_wgsl!("debug_overlay.wgsl"));
+
+        let uniform_buffer = gpu.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Overlay Uniform Buffer"),
+            size: std::mem::size_of::<OverlayUniforms>() as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group_layout = gpu.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Debug Overlay Bind Group Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::VERTEX,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+
+        let bind_group = gpu.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Debug Overlay Bind Group"),
+            layout: &bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: uniform_buffer.as_entire_binding(),
+            }],
+        });
+
+        let pipeline_layout = gpu.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Debug Overlay Pipeline Layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let pipeline = gpu.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Debug Overlay Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[OverlayRect::desc()],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: config.format,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            multiview: None,
+            cache: None,
+        });
+
+        let instance_capacity = 1024;
+        let instance_buffer = Self::create_instance_buffer(gpu, instance_capacity);
+
+        Self {
+            pipeline,
+            uniform_buffer,
+            bind_group,
+            instance_buffer,
+            instance_capacity,
+        }
+    }
+
+    fn create_instance_buffer(gpu: &wgpu::Device, capacity: usize) -> wgpu::Buffer {
+        gpu.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Debug Overlay Instance Buffer"),
+            size: (capacity * std::mem::size_of::<OverlayRect>()) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+}
+
+impl DrawablePipeline<DebugOverlayCommand> for DebugOverlayPipeline {
+    fn draw(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        command: &DebugOverlayCommand,
+        _size: PxSize,
+        _start_pos: PxPosition,
+        _scene_texture_view: &wgpu::TextureView,
+    ) {
+        if command.rects.is_empty() {
+            return;
+        }
+        let uniforms = OverlayUniforms {
+            screen_size: [config.width as f32, config.height as f32],
+            _padding: [0.0; 2],
+        };
+        gpu_queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+        // Grow the instance buffer if this frame has more rectangles than ever before
+        if command.rects.len() > self.instance_capacity {
+            self.instance_capacity = command.rects.len().next_power_of_two();
+            self.instance_buffer = Self::create_instance_buffer(gpu, self.instance_capacity);
+        }
+        gpu_queue.write_buffer(
+            &self.instance_buffer,
+            0,
+            bytemuck::cast_slice(&command.rects),
+        );
+
+        render_pass.set_pipeline(&self.pipeline);
+        render_pass.set_bind_group(0, &self.bind_group, &[]);
+        render_pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+        render_pass.draw(0..6, 0..command.rects.len() as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounds(x: f32, y: f32, width: f32, height: f32) -> Bounds {
+        Bounds {
+            position: PxPosition::new(Px(x), Px(y)),
+            size: PxSize::new(Px(width), Px(height)),
+        }
+    }
+
+    #[test]
+    fn test_node_issues() {
+        let parent = bounds(0.0, 0.0, 100.0, 100.0);
+        let constraint = Constraint::new(
+            DimensionValue::Wrap {
+                min: None,
+                max: Some(Px(100.0)),
+            },
+            DimensionValue::Fixed(Px(20.0)),
+        );
+
+        let fits = bounds(10.0, 10.0, 90.0, 20.0);
+        assert_eq!(node_issue(&fits, Some(&constraint), Some(&parent)), None);
+
+        // Rounding does not count as an issue
+        let rounded = bounds(10.0, 10.0, 90.2, 20.4);
+        assert_eq!(node_issue(&rounded, Some(&constraint), Some(&parent)), None);
+
+        let too_wide = bounds(0.0, 0.0, 120.0, 20.0);
+        assert_eq!(
+            node_issue(&too_wide, Some(&constraint), Some(&parent)),
+            Some(Issue::ConstraintViolation)
+        );
+
+        let outside = bounds(50.0, 90.0, 60.0, 20.0);
+        assert_eq!(
+            node_issue(&outside, Some(&constraint), Some(&parent)),
+            Some(Issue::Overflow)
+        );
+
+        // The root has neither a parent nor, before measuring, a constraint
+        assert_eq!(node_issue(&outside, None, None), None);
+    }
+
+    #[test]
+    fn test_text_merges_pixel_runs() {
+        let mut rects = Vec::new();
+        push_text(&mut rects, "T", [10.0, 20.0], 2.0);
+        // The bar of the T is one rectangle, the stem one per remaining row
+        assert_eq!(rects.len(), 5);
+        assert_eq!(rects[0].rect, [10.0, 20.0, 6.0, 2.0]);
+        assert_eq!(rects[1].rect, [12.0, 22.0, 2.0, 2.0]);
+
+        rects.clear();
+        push_text(&mut rects, " ", [0.0, 0.0], 1.0);
+        assert!(rects.is_empty());
+    }
+
+    #[test]
+    fn test_dimension_labels() {
+        assert_eq!(
+            format_dimension(&DimensionValue::Fixed(Px(99.6))),
+            "fixed(100)"
+        );
+        assert_eq!(format_dimension(&DimensionValue::WRAP), "wrap(..)");
+        assert_eq!(
+            format_dimension(&DimensionValue::Fill {
+                min: Some(Px(10.0)),
+                max: Some(Px::MAX),
+            }),
+            "fill(10..)"
+        );
+    }
+
+    #[test]
+    fn test_overlay_marks_oversized_child() {
+        let mut tree = crate::ComponentTree::new();
+        let node = |name: &str| crate::ComponentNode {
+            fn_name: name.to_string(),
+            location: None,
+            test_tag: None,
+            measure_fn: None,
+            intrinsic_fn: None,
+            state_handler_fn: None,
+            semantics: None,
+            context: Default::default(),
+            key: 0,
+            intrinsic_cache: Default::default(),
+        };
+        tree.add_node(node("root"));
+        tree.add_node(node("child"));
+        tree.pop_node();
+        tree.pop_node();
+        let root = tree.root_node().unwrap();
+
+        for mut entry in tree.metadatas().iter_mut() {
+            let (size, max) = if *entry.key() == root {
+                (100.0, Px(100.0))
+            } else {
+                (150.0, Px(100.0))
+            };
+            let dimension = DimensionValue::Wrap {
+                min: None,
+                max: Some(max),
+            };
+            let metadata = entry.value_mut();
+            metadata.computed_data = Some(crate::ComputedData {
+                width: Px(size),
+                height: Px(size),
+            });
+            metadata.abs_position = Some(PxPosition::ZERO);
+            metadata.constraint = Some(Constraint::new(dimension, dimension));
+        }
+
+        let overlay = tree.debug_overlay(1.0, true, None);
+        let colors: Vec<_> = overlay.rects.iter().map(|rect| rect.color).collect();
+        assert_eq!(colors[..4], [OUTLINE_COLOR; 4]);
+        assert_eq!(colors[4..8], [VIOLATION_COLOR; 4]);
+        assert!(colors[8..].contains(&LABEL_TEXT_COLOR));
+    }
+}
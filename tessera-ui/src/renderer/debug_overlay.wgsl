@@ -0,0 +1,42 @@
+struct OverlayUniforms {
+    screen_size: vec2f,    // width, height of the surface in physical pixels
+    _padding: vec2f,
+};
+
+@group(0) @binding(0)
+var<uniform> uniforms: OverlayUniforms;
+
+struct InstanceInput {
+    @location(0) rect: vec4f,   // x, y, width, height in physical pixels
+    @location(1) color: vec4f,
+};
+
+struct VertexOutput {
+    @builtin(position) clip_position: vec4f,
+    @location(0) color: vec4f,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, instance: InstanceInput) -> VertexOutput {
+    // Two triangles covering the unit square
+    var corners = array<vec2f, 6>(
+        vec2f(0.0, 0.0),
+        vec2f(0.0, 1.0),
+        vec2f(1.0, 1.0),
+        vec2f(0.0, 0.0),
+        vec2f(1.0, 1.0),
+        vec2f(1.0, 0.0),
+    );
+    let pixel = instance.rect.xy + corners[vertex_index] * instance.rect.zw;
+    let ndc = pixel / uniforms.screen_size * 2.0 - 1.0;
+
+    var out: VertexOutput;
+    out.clip_position = vec4f(ndc.x, -ndc.y, 0.0, 1.0);
+    out.color = instance.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4f {
+    return in.color;
+}
@@ -77,6 +77,7 @@ impl Drawer {
     /// * `start_pos` - Position where drawing should begin
     /// * `scene_texture_view` - Optional background texture for sampling
     /// * `compute_texture_view` - Compute pipeline output texture
+    #[allow(clippy::too_many_arguments)]
     pub fn submit(
         &mut self,
         gpu: &wgpu::Device,
@@ -99,4 +100,32 @@ impl Drawer {
             scene_texture_view,
         );
     }
+
+    /// Submit a run of draw commands of the same type to their pipeline.
+    ///
+    /// The pipeline receives them in one [`DrawablePipeline::draw_batch`] call, which
+    /// lets it draw them together.
+    ///
+    /// # Arguments
+    /// * `commands` - The draw commands in draw order, all of the same type, each with the
+    ///   size and position of its component
+    /// * `scene_texture_view` - Background texture for sampling
+    pub fn submit_batch(
+        &mut self,
+        gpu: &wgpu::Device,
+        queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(Box<dyn DrawCommand>, PxSize, PxPosition)],
+        scene_texture_view: &wgpu::TextureView,
+    ) {
+        self.pipeline_registry.dispatch_batch(
+            gpu,
+            queue,
+            config,
+            render_pass,
+            commands,
+            scene_texture_view,
+        );
+    }
 }
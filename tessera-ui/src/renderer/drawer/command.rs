@@ -25,6 +25,9 @@ pub enum BarrierRequirement {
 pub trait AsAny {
     /// Returns a reference to the concrete type as `&dyn Any`.
     fn as_any(&self) -> &dyn Any;
+
+    /// Returns the name of the concrete type, for debugging and inspection.
+    fn type_name(&self) -> &'static str;
 }
 
 /// Blanket implementation of `AsAny` for all types that implement `Any`.
@@ -32,6 +35,10 @@ impl<T: Any> AsAny for T {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
 
 /// Trait for graphics rendering commands that can be processed by draw pipelines.
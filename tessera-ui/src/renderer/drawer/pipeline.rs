@@ -143,6 +143,11 @@
 //! The `scene_texture_view` parameter provides access to the current scene texture,
 //! enabling effects that sample from the background or perform post-processing.
 
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+};
+
 use crate::{PxPosition, px::PxSize, renderer::DrawCommand};
 
 /// Core trait for implementing custom graphics rendering pipelines.
@@ -161,7 +166,8 @@ use crate::{PxPosition, px::PxSize, renderer::DrawCommand};
 ///
 /// 1. [`begin_frame()`](Self::begin_frame): Called once at the start of a new frame, before any render passes.
 /// 2. [`begin_pass()`](Self::begin_pass): Called at the start of each render pass that involves this pipeline.
-/// 3. [`draw()`](Self::draw): Called for each command of type `T` within a render pass.
+/// 3. [`draw_batch()`](Self::draw_batch): Called for each run of consecutive commands of type
+///    `T` within a render pass, which by default calls [`draw()`](Self::draw) for each of them.
 /// 4. [`end_pass()`](Self::end_pass): Called at the end of each render pass that involved this pipeline.
 /// 5. [`end_frame()`](Self::end_frame): Called once at the end of the frame, after all render passes are complete.
 ///
@@ -173,7 +179,8 @@ use crate::{PxPosition, px::PxSize, renderer::DrawCommand};
 /// - Only the [`draw()`](Self::draw) method is required; others have default empty implementations.
 /// - Pipelines should be stateless between frames when possible
 /// - Resource management should prefer reuse over recreation
-/// - Consider batching multiple commands for better performance
+/// - Override [`draw_batch()`](Self::draw_batch) to draw runs of commands at once, e.g. with
+///   instancing, when a pipeline draws many small commands
 ///
 /// # Example
 ///
@@ -295,6 +302,7 @@ pub trait DrawablePipeline<T: DrawCommand> {
     ///     render_pass.draw(0..6, 0..1);
     /// }
     /// ```
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         gpu: &wgpu::Device,
@@ -307,6 +315,66 @@ pub trait DrawablePipeline<T: DrawCommand> {
         scene_texture_view: &wgpu::TextureView,
     );
 
+    /// Draws a run of consecutive commands of this pipeline's type.
+    ///
+    /// The renderer collects commands of the same type that follow each other in draw
+    /// order and that do not require a barrier, and hands them over in one call, so a
+    /// pipeline can upload their data together and draw them with a single instanced
+    /// draw call instead of one per command. The commands must be drawn in order, as
+    /// later ones are painted over earlier ones.
+    ///
+    /// # Parameters
+    ///
+    /// * `commands` - The commands in draw order, each with the size and position of its
+    ///   component
+    ///
+    /// The other parameters are the same as for [`draw()`](Self::draw).
+    ///
+    /// # Default Implementation
+    ///
+    /// The default implementation calls [`draw()`](Self::draw) for each command.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// fn draw_batch(&mut self, gpu: &wgpu::Device, gpu_queue: &wgpu::Queue,
+    ///               config: &wgpu::SurfaceConfiguration, render_pass: &mut wgpu::RenderPass<'_>,
+    ///               commands: &[(&MyCommand, PxSize, PxPosition)],
+    ///               scene_texture_view: &wgpu::TextureView) {
+    ///     let instances: Vec<MyInstance> = commands
+    ///         .iter()
+    ///         .map(|(command, size, start_pos)| MyInstance::new(command, *size, *start_pos))
+    ///         .collect();
+    ///     gpu_queue.write_buffer(&self.instance_buffer, 0, bytemuck::cast_slice(&instances));
+    ///
+    ///     render_pass.set_pipeline(&self.render_pipeline);
+    ///     render_pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+    ///     render_pass.draw(0..6, 0..instances.len() as u32);
+    /// }
+    /// ```
+    fn draw_batch(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(&T, PxSize, PxPosition)],
+        scene_texture_view: &wgpu::TextureView,
+    ) {
+        for &(command, size, start_pos) in commands {
+            self.draw(
+                gpu,
+                gpu_queue,
+                config,
+                render_pass,
+                command,
+                size,
+                start_pos,
+                scene_texture_view,
+            );
+        }
+    }
+
     /// Called once at the end of the render pass.
     ///
     /// Use this method to perform cleanup operations or finalize rendering
@@ -407,6 +475,7 @@ pub trait ErasedDrawablePipeline {
         render_pass: &mut wgpu::RenderPass<'_>,
     );
 
+    #[allow(clippy::too_many_arguments)]
     fn draw_erased(
         &mut self,
         gpu: &wgpu::Device,
@@ -418,9 +487,19 @@ pub trait ErasedDrawablePipeline {
         start_pos: PxPosition,
         scene_texture_view: &wgpu::TextureView,
     ) -> bool;
+
+    fn draw_batch_erased(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(Box<dyn DrawCommand>, PxSize, PxPosition)],
+        scene_texture_view: &wgpu::TextureView,
+    );
 }
 
-struct DrawablePipelineImpl<T: DrawCommand, P: DrawablePipeline<T>> {
+struct DrawablePipelineImpl<T: DrawCommand + 'static, P: DrawablePipeline<T>> {
     pipeline: P,
     _marker: std::marker::PhantomData<T>,
 }
@@ -494,6 +573,37 @@ impl<T: DrawCommand + 'static, P: DrawablePipeline<T> + 'static> ErasedDrawableP
             false
         }
     }
+
+    fn draw_batch_erased(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(Box<dyn DrawCommand>, PxSize, PxPosition)],
+        scene_texture_view: &wgpu::TextureView,
+    ) {
+        let mut batch = Vec::with_capacity(commands.len());
+        for (command, size, start_pos) in commands {
+            // `as_ref` so the type is that of the command rather than of its box
+            match command.as_ref().as_any().downcast_ref::<T>() {
+                Some(command) => batch.push((command, *size, *start_pos)),
+                None => log::error!(
+                    "{} was batched with commands of {}, it is skipped",
+                    command.type_name(),
+                    std::any::type_name::<T>()
+                ),
+            }
+        }
+        self.pipeline.draw_batch(
+            gpu,
+            gpu_queue,
+            config,
+            render_pass,
+            &batch,
+            scene_texture_view,
+        );
+    }
 }
 
 /// Registry for managing and dispatching drawable pipelines.
@@ -505,14 +615,16 @@ impl<T: DrawCommand + 'static, P: DrawablePipeline<T> + 'static> ErasedDrawableP
 /// # Architecture
 ///
 /// The registry uses type erasure to store pipelines of different types in a single
-/// collection. When a draw command needs to be rendered, the registry iterates through
-/// all registered pipelines until it finds one that can handle the command type.
+/// collection, indexed by the [`TypeId`] of the command type they draw. When a draw
+/// command needs to be rendered, the registry looks up its pipeline directly. Commands
+/// without a pipeline are skipped, and their type is reported in the log the first time.
 ///
 /// # Usage Pattern
 ///
 /// 1. Create a new registry
 /// 2. Register all required pipelines during application initialization
-/// 3. The renderer uses the registry to dispatch commands during frame rendering
+/// 3. Optionally check that the pipelines the application needs are present
+/// 4. The renderer uses the registry to dispatch commands during frame rendering
 ///
 /// # Example
 ///
@@ -526,15 +638,19 @@ impl<T: DrawCommand + 'static, P: DrawablePipeline<T> + 'static> ErasedDrawableP
 /// registry.register(my_image_pipeline);
 ///
 /// // Registry is now ready for use by the renderer
+/// assert!(registry.contains::<ShapeCommand>());
 /// ```
-///
-/// # Performance Considerations
-///
-/// - Pipeline lookup is O(n) where n is the number of registered pipelines
-/// - Register frequently used pipelines first for better average performance
-/// - Consider the order of registration based on command frequency
 pub struct PipelineRegistry {
-    pub(crate) pipelines: Vec<Box<dyn ErasedDrawablePipeline>>,
+    pipelines: Vec<RegisteredPipeline>,
+    /// Index in `pipelines` of the pipeline for each command type
+    indices: HashMap<TypeId, usize>,
+    /// Command types already reported as having no pipeline
+    unhandled: HashSet<TypeId>,
+}
+
+struct RegisteredPipeline {
+    command_type: &'static str,
+    pipeline: Box<dyn ErasedDrawablePipeline>,
 }
 
 impl Default for PipelineRegistry {
@@ -556,6 +672,8 @@ impl PipelineRegistry {
     pub fn new() -> Self {
         Self {
             pipelines: Vec::new(),
+            indices: HashMap::new(),
+            unhandled: HashSet::new(),
         }
     }
 
@@ -575,8 +693,8 @@ impl PipelineRegistry {
     ///
     /// # Panics
     ///
-    /// This method does not panic, but the registry will panic during dispatch
-    /// if no pipeline is found for a given command type.
+    /// Panics if a pipeline is already registered for `T`, since only one of them could
+    /// ever draw its commands.
     ///
     /// # Example
     ///
@@ -596,17 +714,38 @@ impl PipelineRegistry {
     ///
     /// # Registration Order
     ///
-    /// The order of registration can affect performance since pipeline lookup
-    /// is performed linearly. Consider registering more frequently used pipelines first.
+    /// Pipelines are looked up by command type, so the order of registration does not
+    /// affect dispatch. It is the order in which the frame and pass hooks are called.
     pub fn register<T: DrawCommand + 'static, P: DrawablePipeline<T> + 'static>(
         &mut self,
         pipeline: P,
     ) {
-        let erased = Box::new(DrawablePipelineImpl::<T, P> {
-            pipeline,
-            _marker: std::marker::PhantomData,
+        let command_type = std::any::type_name::<T>();
+        if self.indices.contains_key(&TypeId::of::<T>()) {
+            panic!("A drawable pipeline for {command_type} is already registered");
+        }
+        self.indices.insert(TypeId::of::<T>(), self.pipelines.len());
+        self.pipelines.push(RegisteredPipeline {
+            command_type,
+            pipeline: Box::new(DrawablePipelineImpl::<T, P> {
+                pipeline,
+                _marker: std::marker::PhantomData,
+            }),
         });
-        self.pipelines.push(erased);
+    }
+
+    /// Returns whether a pipeline is registered for the command type `T`.
+    ///
+    /// Applications can use it to check at startup that the pipelines they need are
+    /// present, instead of finding commands missing from the screen.
+    pub fn contains<T: DrawCommand + 'static>(&self) -> bool {
+        self.indices.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the type names of the commands with a registered pipeline, in registration
+    /// order.
+    pub fn command_types(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.pipelines.iter().map(|entry| entry.command_type)
     }
 
     pub(crate) fn begin_all_passes(
@@ -616,8 +755,10 @@ impl PipelineRegistry {
         config: &wgpu::SurfaceConfiguration,
         render_pass: &mut wgpu::RenderPass<'_>,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            pipeline.begin_pass(gpu, gpu_queue, config, render_pass);
+        for entry in self.pipelines.iter_mut() {
+            entry
+                .pipeline
+                .begin_pass(gpu, gpu_queue, config, render_pass);
         }
     }
 
@@ -628,8 +769,8 @@ impl PipelineRegistry {
         config: &wgpu::SurfaceConfiguration,
         render_pass: &mut wgpu::RenderPass<'_>,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            pipeline.end_pass(gpu, gpu_queue, config, render_pass);
+        for entry in self.pipelines.iter_mut() {
+            entry.pipeline.end_pass(gpu, gpu_queue, config, render_pass);
         }
     }
 
@@ -639,8 +780,8 @@ impl PipelineRegistry {
         gpu_queue: &wgpu::Queue,
         config: &wgpu::SurfaceConfiguration,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            pipeline.begin_frame(gpu, gpu_queue, config);
+        for entry in self.pipelines.iter_mut() {
+            entry.pipeline.begin_frame(gpu, gpu_queue, config);
         }
     }
 
@@ -650,11 +791,12 @@ impl PipelineRegistry {
         gpu_queue: &wgpu::Queue,
         config: &wgpu::SurfaceConfiguration,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            pipeline.end_frame(gpu, gpu_queue, config);
+        for entry in self.pipelines.iter_mut() {
+            entry.pipeline.end_frame(gpu, gpu_queue, config);
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn dispatch(
         &mut self,
         gpu: &wgpu::Device,
@@ -666,24 +808,112 @@ impl PipelineRegistry {
         start_pos: PxPosition,
         scene_texture_view: &wgpu::TextureView,
     ) {
-        for pipeline in self.pipelines.iter_mut() {
-            if pipeline.draw_erased(
-                gpu,
-                gpu_queue,
-                config,
-                render_pass,
-                cmd,
-                size,
-                start_pos,
-                scene_texture_view,
-            ) {
-                return;
-            }
-        }
+        let Some(index) = self.index_of(cmd) else {
+            return;
+        };
+        self.pipelines[index].pipeline.draw_erased(
+            gpu,
+            gpu_queue,
+            config,
+            render_pass,
+            cmd,
+            size,
+            start_pos,
+            scene_texture_view,
+        );
+    }
 
-        panic!(
-            "No pipeline found for command {:?}",
-            std::any::type_name_of_val(cmd)
+    /// Draws a run of commands that all have the same type.
+    pub(crate) fn dispatch_batch(
+        &mut self,
+        gpu: &wgpu::Device,
+        gpu_queue: &wgpu::Queue,
+        config: &wgpu::SurfaceConfiguration,
+        render_pass: &mut wgpu::RenderPass<'_>,
+        commands: &[(Box<dyn DrawCommand>, PxSize, PxPosition)],
+        scene_texture_view: &wgpu::TextureView,
+    ) {
+        let Some((first, _, _)) = commands.first() else {
+            return;
+        };
+        let Some(index) = self.index_of(first.as_ref()) else {
+            return;
+        };
+        self.pipelines[index].pipeline.draw_batch_erased(
+            gpu,
+            gpu_queue,
+            config,
+            render_pass,
+            commands,
+            scene_texture_view,
         );
     }
+
+    /// Returns the index of the pipeline for the command, reporting its type in the log
+    /// the first time it has none.
+    fn index_of(&mut self, cmd: &dyn DrawCommand) -> Option<usize> {
+        let type_id = cmd.as_any().type_id();
+        let index = self.indices.get(&type_id).copied();
+        if index.is_none() && self.unhandled.insert(type_id) {
+            log::error!(
+                "No drawable pipeline registered for {}, its commands are skipped",
+                cmd.type_name()
+            );
+        }
+        index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct DotCommand;
+
+    impl DrawCommand for DotCommand {}
+
+    struct DotPipeline;
+
+    impl DrawablePipeline<DotCommand> for DotPipeline {
+        fn draw(
+            &mut self,
+            _gpu: &wgpu::Device,
+            _gpu_queue: &wgpu::Queue,
+            _config: &wgpu::SurfaceConfiguration,
+            _render_pass: &mut wgpu::RenderPass<'_>,
+            _command: &DotCommand,
+            _size: PxSize,
+            _start_pos: PxPosition,
+            _scene_texture_view: &wgpu::TextureView,
+        ) {
+        }
+    }
+
+    struct LineCommand;
+
+    impl DrawCommand for LineCommand {}
+
+    #[test]
+    fn test_registry_is_queryable() {
+        let mut registry = PipelineRegistry::new();
+        assert!(!registry.contains::<DotCommand>());
+
+        registry.register(DotPipeline);
+        assert!(registry.contains::<DotCommand>());
+        assert!(!registry.contains::<LineCommand>());
+        let command_types: Vec<_> = registry.command_types().collect();
+        assert_eq!(command_types, [std::any::type_name::<DotCommand>()]);
+
+        let dot: Box<dyn DrawCommand> = Box::new(DotCommand);
+        assert_eq!(registry.index_of(dot.as_ref()), Some(0));
+        assert_eq!(registry.index_of(&LineCommand), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered")]
+    fn test_registry_rejects_duplicates() {
+        let mut registry = PipelineRegistry::new();
+        registry.register(DotPipeline);
+        registry.register(DotPipeline);
+    }
 }
@@ -0,0 +1,537 @@
+//! WGSL shader sources that can be reloaded from disk during development.
+//!
+//! Pipelines usually embed their shader with `include_str!`, so every tweak of a shader
+//! needs a rebuild. A [`ShaderSource`] embeds the shader the same way but also remembers
+//! the path of the file. With the `shader-hot-reload` feature, the file is watched, and
+//! [`ShaderSource::reload`] hands the new source to the pipeline so it can recreate its
+//! `wgpu::RenderPipeline` or `wgpu::ComputePipeline`. Shaders that fail to compile are
+//! reported in the log and the pipeline keeps the last good version.
+//!
+//! Without the feature, the embedded source is always used and `reload` never does
+//! anything, so release builds pay nothing for it.
+//!
+//! # Imports
+//!
+//! Shaders can share code through `#import` directives, resolved by [`compose`] before
+//! compiling. A directive takes a line of its own, `#import` followed by whitespace and the
+//! name of the module. Each module is included once, where it is first imported, which
+//! shifts the lines after it: errors of the compiled shader refer to lines of the composed
+//! source. Reload errors list where each module was inserted. Tessera ships:
+//!
+//! - `tessera::sdf`: `sdf_g2_rounded_box` and `sdf_ellipse`, the signed distance functions
+//!   of the built-in shapes, and their gradients `grad_sd_g2_rounded_box` and
+//!   `grad_sd_ellipse`
+//! - `tessera::color`: `to_linear_srgb`, `from_linear_srgb`, `luminance` and
+//!   `saturate_color`
+//! - `tessera::noise`: `rand` and `value_noise`
+//!
+//! ```wgsl
+//! #import tessera::sdf
+//!
+//! @fragment
+//! fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+//!     let sd = sdf_g2_rounded_box(in.centered, in.half_size, 8.0, 3.0);
+//!     // ...
+//! }
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use tessera_ui::{renderer::ShaderSource, shader_source, wgpu};
+//!
+//! struct MyPipeline {
+//!     shader: ShaderSource,
+//!     pipeline: wgpu::RenderPipeline,
+//! }
+//!
+//! impl MyPipeline {
+//!     fn new(gpu: &wgpu::Device) -> Self {
+//!         let shader = shader_source!("src/my_shader.wgsl");
+//!         let pipeline = create_pipeline(gpu, &shader.module(gpu));
+//!         Self { shader, pipeline }
+//!     }
+//! }
+//!
+//! impl DrawablePipeline<MyCommand> for MyPipeline {
+//!     fn begin_frame(&mut self, gpu: &wgpu::Device, _: &wgpu::Queue, _: &wgpu::SurfaceConfiguration) {
+//!         if let Some(pipeline) = self.shader.reload(gpu, |module| create_pipeline(gpu, module)) {
+//!             self.pipeline = pipeline;
+//!         }
+//!     }
+//!     // ...
+//! }
+//! ```
+
+use std::fmt;
+#[cfg(feature = "shader-hot-reload")]
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+/// The modules that shaders can `#import`.
+const MODULES: &[(&str, &str)] = &[
+    ("tessera::sdf", include_str!("shader/sdf.wgsl")),
+    ("tessera::color", include_str!("shader/color.wgsl")),
+    ("tessera::noise", include_str!("shader/noise.wgsl")),
+];
+
+/// The error returned when the `#import` directives of a shader cannot be resolved.
+///
+/// See [`compose`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaderImportError {
+    /// The imported module does not exist.
+    UnknownModule {
+        /// The name of the module.
+        module: String,
+        /// The line of the directive, starting at 1.
+        line: usize,
+    },
+    /// The directive does not name a module.
+    MissingModule {
+        /// The line of the directive, starting at 1.
+        line: usize,
+    },
+}
+
+impl fmt::Display for ShaderImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownModule { module, line } => {
+                write!(f, "line {line}: unknown shader module `{module}`")
+            }
+            Self::MissingModule { line } => write!(f, "line {line}: `#import` without a module"),
+        }
+    }
+}
+
+impl std::error::Error for ShaderImportError {}
+
+/// Resolves the `#import` directives of a WGSL source, see the [module documentation](self).
+///
+/// ```
+/// use tessera_ui::renderer::shader::compose;
+///
+/// let source = compose("#import tessera::color\n#import tessera::color\nfn main() {}").unwrap();
+/// assert_eq!(source.matches("fn luminance").count(), 1);
+/// assert!(compose("#import my::module").is_err());
+/// ```
+pub fn compose(source: &str) -> Result<String, ShaderImportError> {
+    let mut composition = Composition::default();
+    composition.add(source)?;
+    Ok(composition.source)
+}
+
+/// A shader source being composed, remembering where the imported modules went.
+#[derive(Default)]
+struct Composition {
+    source: String,
+    /// The number of lines in `source`
+    lines: usize,
+    /// The imported modules, with the first line and the number of lines they take
+    insertions: Vec<(&'static str, usize, usize)>,
+}
+
+impl Composition {
+    /// Appends `source`, resolving its imports.
+    fn add(&mut self, source: &str) -> Result<(), ShaderImportError> {
+        for (index, line) in source.lines().enumerate() {
+            let Some(module) = import_directive(line) else {
+                self.source.push_str(line);
+                self.source.push('\n');
+                self.lines += 1;
+                continue;
+            };
+            let line = index + 1;
+            if module.is_empty() {
+                return Err(ShaderImportError::MissingModule { line });
+            }
+            let Some(&(name, module_source)) = MODULES.iter().find(|(name, _)| *name == module)
+            else {
+                return Err(ShaderImportError::UnknownModule {
+                    module: module.to_string(),
+                    line,
+                });
+            };
+            if !self
+                .insertions
+                .iter()
+                .any(|(imported, ..)| *imported == name)
+            {
+                let index = self.insertions.len();
+                self.insertions.push((name, self.lines + 1, 0));
+                self.add(module_source)?;
+                self.insertions[index].2 = self.lines + 1 - self.insertions[index].1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Describes where the imported modules were inserted, to make sense of the line
+    /// numbers in errors about the composed source.
+    fn describe_insertions(&self) -> String {
+        let insertions: Vec<_> = self
+            .insertions
+            .iter()
+            .map(|(name, first, count)| format!("{first}-{} `{name}`", first + count - 1))
+            .collect();
+        if insertions.is_empty() {
+            return String::new();
+        }
+        format!(
+            " (lines are those of the composed shader, imported modules take lines {})",
+            insertions.join(", ")
+        )
+    }
+}
+
+/// Returns the module named by `line` if it is an `#import` directive.
+fn import_directive(line: &str) -> Option<&str> {
+    let directive = line.trim_start().strip_prefix("#import")?;
+    if !directive.is_empty() && !directive.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some(directive.trim().trim_end_matches(';'))
+}
+
+/// Embeds a WGSL file in the binary as a [`ShaderSource`](crate::renderer::ShaderSource).
+///
+/// The path is relative to the root of the calling crate, the directory of its
+/// `Cargo.toml`, so the file can be found again at runtime for hot reloading.
+///
+/// ```rust,ignore
+/// let shader = tessera_ui::shader_source!("src/pipelines/shape/shape.wgsl");
+/// ```
+#[macro_export]
+macro_rules! shader_source {
+    ($path:literal) => {
+        $crate::renderer::ShaderSource::new(
+            $path,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/", $path),
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)),
+        )
+    };
+}
+
+/// A WGSL shader embedded in the binary, reloaded from disk with the `shader-hot-reload`
+/// feature.
+///
+/// Usually created with the [`shader_source!`](crate::shader_source) macro, see the
+/// [module documentation](self).
+pub struct ShaderSource {
+    label: &'static str,
+    #[cfg_attr(not(feature = "shader-hot-reload"), allow(unused))]
+    path: &'static str,
+    embedded: &'static str,
+    /// WGSL provided by the pipeline, appended to the source
+    appended: &'static str,
+    /// Set by the file watcher when the file changes
+    #[cfg(feature = "shader-hot-reload")]
+    changed: Option<Arc<AtomicBool>>,
+}
+
+impl ShaderSource {
+    /// Creates a shader source from its embedded source and the path it was embedded from.
+    ///
+    /// With the `shader-hot-reload` feature this starts watching `path`.
+    pub fn new(label: &'static str, path: &'static str, embedded: &'static str) -> Self {
+        Self {
+            label,
+            path,
+            embedded,
+            appended: "",
+            #[cfg(feature = "shader-hot-reload")]
+            changed: watch::watch(std::path::Path::new(path)),
+        }
+    }
+
+    /// Appends WGSL provided by the pipeline to the source, e.g. the bindings and vertex
+    /// stage shared by all the shaders of a pipeline.
+    ///
+    /// The code is appended rather than prepended so errors in `#import` directives still
+    /// report the lines of the file. It can `#import` modules too.
+    pub fn with_appended(mut self, wgsl: &'static str) -> Self {
+        self.appended = wgsl;
+        self
+    }
+
+    /// The label of the shader, used for its modules and in the log.
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// The source embedded in the binary.
+    pub fn embedded(&self) -> &'static str {
+        self.embedded
+    }
+
+    /// Creates a shader module from the embedded source, with its imports resolved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an import of the embedded source cannot be resolved.
+    pub fn module(&self, gpu: &wgpu::Device) -> wgpu::ShaderModule {
+        let composition = self
+            .compose(self.embedded)
+            .unwrap_or_else(|e| panic!("Invalid imports in shader {}: {e}", self.label));
+        let insertions = composition.describe_insertions();
+        if !insertions.is_empty() {
+            log::debug!("Composed shader {}{insertions}", self.label);
+        }
+        gpu.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some(self.label),
+            source: wgpu::ShaderSource::Wgsl(composition.source.into()),
+        })
+    }
+
+    /// Recreates whatever depends on the shader if its file changed since the last call.
+    ///
+    /// `build` receives the module compiled from the new source, and usually creates the
+    /// pipeline from it. Returns `None` if the file did not change, or if the shader or
+    /// what `build` created failed validation, in which case the error is logged and the
+    /// caller keeps its last good pipeline.
+    ///
+    /// Always returns `None` without the `shader-hot-reload` feature.
+    #[cfg_attr(not(feature = "shader-hot-reload"), allow(unused_variables))]
+    pub fn reload<T>(
+        &mut self,
+        gpu: &wgpu::Device,
+        build: impl FnOnce(&wgpu::ShaderModule) -> T,
+    ) -> Option<T> {
+        #[cfg(feature = "shader-hot-reload")]
+        {
+            if !self.changed.as_ref()?.swap(false, Ordering::AcqRel) {
+                return None;
+            }
+            let source = match std::fs::read_to_string(self.path) {
+                Ok(source) => source,
+                Err(e) => {
+                    log::error!(
+                        "Failed to read shader {} from {}: {e}",
+                        self.label,
+                        self.path
+                    );
+                    return None;
+                }
+            };
+            let composition = match self.compose(&source) {
+                Ok(composition) => composition,
+                Err(e) => {
+                    log::error!(
+                        "Failed to reload shader {}, keeping the last good version: {e}",
+                        self.label
+                    );
+                    return None;
+                }
+            };
+            gpu.push_error_scope(wgpu::ErrorFilter::Validation);
+            let module = gpu.create_shader_module(wgpu::ShaderModuleDescriptor {
+                label: Some(self.label),
+                source: wgpu::ShaderSource::Wgsl(composition.source.as_str().into()),
+            });
+            let built = build(&module);
+            match crate::tokio_runtime::get().block_on(gpu.pop_error_scope()) {
+                Some(error) => {
+                    log::error!(
+                        "Failed to reload shader {}, keeping the last good version{}: {error}",
+                        self.label,
+                        composition.describe_insertions()
+                    );
+                    None
+                }
+                None => {
+                    log::info!("Reloaded shader {}", self.label);
+                    Some(built)
+                }
+            }
+        }
+        #[cfg(not(feature = "shader-hot-reload"))]
+        None
+    }
+
+    /// Resolves the imports of `source` with the appended code.
+    fn compose(&self, source: &str) -> Result<Composition, ShaderImportError> {
+        let mut composition = Composition::default();
+        if self.appended.is_empty() {
+            composition.add(source)?;
+        } else {
+            composition.add(&format!("{source}\n{}", self.appended))?;
+        }
+        Ok(composition)
+    }
+}
+
+#[cfg(feature = "shader-hot-reload")]
+mod watch {
+    use std::{
+        collections::HashMap,
+        path::{Path, PathBuf},
+        sync::{
+            Arc, LazyLock, Weak,
+            atomic::{AtomicBool, Ordering},
+        },
+    };
+
+    use log::{error, warn};
+    use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+    use parking_lot::Mutex;
+
+    use crate::TesseraRuntime;
+
+    /// The flags of the shader sources of each watched file
+    type WatchedFiles = HashMap<PathBuf, Vec<Weak<AtomicBool>>>;
+
+    /// One watcher for every shader, watching the directories of the files since editors
+    /// often replace a file rather than write to it
+    struct ShaderWatcher {
+        watcher: RecommendedWatcher,
+        files: Arc<Mutex<WatchedFiles>>,
+    }
+
+    static WATCHER: LazyLock<Option<Mutex<ShaderWatcher>>> = LazyLock::new(|| {
+        let files = Arc::new(Mutex::new(WatchedFiles::new()));
+        let event_files = files.clone();
+        let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("Shader watcher error: {e}");
+                    return;
+                }
+            };
+            if !matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) {
+                return;
+            }
+            let mut files = event_files.lock();
+            for path in &event.paths {
+                if let Some(flags) = files.get_mut(path) {
+                    flags.retain(|flag| match flag.upgrade() {
+                        Some(flag) => {
+                            flag.store(true, Ordering::Release);
+                            true
+                        }
+                        None => false,
+                    });
+                    TesseraRuntime::request_redraw();
+                }
+            }
+        });
+        match watcher {
+            Ok(watcher) => Some(Mutex::new(ShaderWatcher { watcher, files })),
+            Err(e) => {
+                error!("Failed to start the shader watcher, shaders will not be reloaded: {e}");
+                None
+            }
+        }
+    });
+
+    /// Starts watching `path`, returns the flag set when it changes.
+    pub(super) fn watch(path: &Path) -> Option<Arc<AtomicBool>> {
+        let mut watcher = WATCHER.as_ref()?.lock();
+        let changed = Arc::new(AtomicBool::new(false));
+        let mut files = watcher.files.lock();
+        let newly_watched_dir = path
+            .parent()
+            .is_some_and(|dir| !files.keys().any(|file| file.parent() == Some(dir)));
+        files
+            .entry(path.to_path_buf())
+            .or_default()
+            .push(Arc::downgrade(&changed));
+        drop(files);
+        if newly_watched_dir
+            && let Some(dir) = path.parent()
+            && let Err(e) = watcher.watcher.watch(dir, RecursiveMode::NonRecursive)
+        {
+            warn!("Failed to watch shader {}: {e}", path.display());
+            return None;
+        }
+        Some(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compose_includes_modules_once_in_place() {
+        let source =
+            "struct A { x: f32 };\n#import tessera::noise\nfn f() {}\n  #import tessera::noise;\n";
+        let composed = compose(source).unwrap();
+        assert_eq!(composed.matches("fn rand(").count(), 1);
+        let module_at = composed.find("fn rand(").unwrap();
+        assert!(composed.find("struct A").unwrap() < module_at);
+        assert!(module_at < composed.find("fn f()").unwrap());
+        assert!(!composed.contains("#import"));
+    }
+
+    #[test]
+    fn test_compose_reports_bad_imports() {
+        assert_eq!(
+            compose("fn f() {}\n#import tessera::missing"),
+            Err(ShaderImportError::UnknownModule {
+                module: "tessera::missing".to_string(),
+                line: 2,
+            })
+        );
+        assert_eq!(
+            compose("#import"),
+            Err(ShaderImportError::MissingModule { line: 1 })
+        );
+    }
+
+    #[test]
+    fn test_compose_requires_whitespace_after_import() {
+        assert_eq!(compose("#importfoo\n").unwrap(), "#importfoo\n");
+        assert!(
+            compose("#import\ttessera::noise")
+                .unwrap()
+                .contains("fn rand(")
+        );
+    }
+
+    #[test]
+    fn test_composition_describes_insertions() {
+        let mut composition = Composition::default();
+        composition
+            .add("fn f() {}\n#import tessera::noise\nfn g() {}")
+            .unwrap();
+        let module_lines = MODULES[2].1.lines().count();
+        assert_eq!(
+            composition.insertions,
+            [("tessera::noise", 2, module_lines)]
+        );
+        assert_eq!(
+            composition.source.lines().nth(module_lines + 1),
+            Some("fn g() {}")
+        );
+        assert!(
+            composition
+                .describe_insertions()
+                .contains(&format!("2-{} `tessera::noise`", module_lines + 1))
+        );
+    }
+
+    #[cfg(feature = "shader-hot-reload")]
+    #[test]
+    fn test_watch_flags_changed_file() {
+        use std::{thread, time::Duration};
+
+        let dir = std::env::temp_dir().join(format!("tessera-shader-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.wgsl");
+        std::fs::write(&path, "// v1").unwrap();
+
+        let changed = watch::watch(&path).expect("watcher should start");
+        assert!(!changed.load(Ordering::Acquire));
+        std::fs::write(&path, "// v2").unwrap();
+        let flagged = (0..100).any(|_| {
+            thread::sleep(Duration::from_millis(20));
+            changed.load(Ordering::Acquire)
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(flagged);
+    }
+}
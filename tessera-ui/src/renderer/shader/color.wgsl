@@ -0,0 +1,30 @@
+// tessera::color - color space conversions and adjustments.
+
+// Converts sRGB encoded color to linear light
+fn to_linear_srgb(srgb: vec3<f32>) -> vec3<f32> {
+    let cutoff = vec3<f32>(0.04045);
+    let lower = srgb / vec3<f32>(12.92);
+    let higher = pow((srgb + vec3<f32>(0.055)) / vec3<f32>(1.055), vec3<f32>(2.4));
+    return select(higher, lower, srgb <= cutoff);
+}
+
+// Converts linear light to sRGB encoded color
+fn from_linear_srgb(linear: vec3<f32>) -> vec3<f32> {
+    let cutoff = vec3<f32>(0.0031308);
+    let lower = linear * vec3<f32>(12.92);
+    let higher = vec3<f32>(1.055) * pow(linear, vec3<f32>(1.0 / 2.4)) - vec3<f32>(0.055);
+    return select(higher, lower, linear <= cutoff);
+}
+
+// Relative luminance of a color, with the Rec. 709 coefficients
+fn luminance(color: vec4<f32>) -> f32 {
+    return dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+// Scales the saturation of an sRGB color, 0.0 gives gray and 1.0 the color itself
+fn saturate_color(color: vec4<f32>, amount: f32) -> vec4<f32> {
+    let linear_srgb = to_linear_srgb(color.rgb);
+    let gray = vec3<f32>(luminance(vec4<f32>(linear_srgb, 1.0)));
+    let adjusted_linear_srgb = mix(gray, linear_srgb, amount);
+    return vec4<f32>(from_linear_srgb(adjusted_linear_srgb), color.a);
+}
@@ -0,0 +1,19 @@
+// tessera::noise - cheap pseudo random numbers and noise.
+
+// Pseudo random number in [0, 1) from a 2D coordinate
+fn rand(co: vec2<f32>) -> f32 {
+    return fract(sin(dot(co.xy, vec2<f32>(12.9898, 78.233))) * 43758.5453);
+}
+
+// Smooth value noise in [0, 1), interpolating rand at the integer lattice
+fn value_noise(p: vec2<f32>) -> f32 {
+    let cell = floor(p);
+    let f = fract(p);
+    let u = f * f * (3.0 - 2.0 * f);
+
+    let a = rand(cell);
+    let b = rand(cell + vec2<f32>(1.0, 0.0));
+    let c = rand(cell + vec2<f32>(0.0, 1.0));
+    let d = rand(cell + vec2<f32>(1.0, 1.0));
+    return mix(mix(a, b, u.x), mix(c, d, u.x), u.y);
+}
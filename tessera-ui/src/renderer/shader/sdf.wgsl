@@ -0,0 +1,59 @@
+// tessera::sdf - signed distance functions of the built-in shapes, in pixels.
+// Negative inside the shape, positive outside.
+
+// SDF of a box with G2-like rounded corners, using a p-norm for the corners
+// p: point to sample, relative to the center of the box
+// b: half-size of the box
+// r: corner radius
+// k: exponent of the p-norm (k=2.0 for G1 circular corners, k>2.0 for G2-like superellipses)
+fn sdf_g2_rounded_box(p: vec2<f32>, b: vec2<f32>, r: f32, k: f32) -> f32 {
+    let q = abs(p) - b + r;
+    let v = max(q, vec2<f32>(0.0));
+
+    var dist_corner_shape: f32;
+    // Use a small epsilon for comparing k to 2.0 to handle potential float inaccuracies
+    if abs(k - 2.0) < 0.001 {
+        dist_corner_shape = length(v);
+    } else if v.x == 0.0 && v.y == 0.0 {
+        // pow(0.0, k) is undefined for some drivers
+        dist_corner_shape = 0.0;
+    } else {
+        dist_corner_shape = pow(pow(v.x, k) + pow(v.y, k), 1.0 / k);
+    }
+
+    return dist_corner_shape + min(max(q.x, q.y), 0.0) - r;
+}
+
+// SDF of an ellipse
+// p: point to sample, relative to the center of the ellipse
+// r: radii of the ellipse
+fn sdf_ellipse(p: vec2<f32>, r: vec2<f32>) -> f32 {
+    if r.x <= 0.0 || r.y <= 0.0 {
+        // Return a large value to prevent rendering if radii are invalid
+        return 1.0e6;
+    }
+    // Scales the distance to be in pixel units, which is important for anti-aliasing.
+    return (length(p / r) - 1.0) * min(r.x, r.y);
+}
+
+// Outward normal of sdf_g2_rounded_box at p, with the same parameters
+fn grad_sd_g2_rounded_box(p: vec2<f32>, b: vec2<f32>, r: f32, k: f32) -> vec2<f32> {
+    let corner_coord = abs(p) - (b - r);
+
+    if corner_coord.x >= 0.0 && corner_coord.y >= 0.0 {
+        let grad_dir = vec2<f32>(
+            pow(corner_coord.x + 0.0001, k - 1.0),
+            pow(corner_coord.y + 0.0001, k - 1.0)
+        );
+        return sign(p) * normalize(grad_dir);
+    }
+    if corner_coord.x > corner_coord.y {
+        return sign(p) * vec2<f32>(1.0, 0.0);
+    }
+    return sign(p) * vec2<f32>(0.0, 1.0);
+}
+
+// Outward normal of sdf_ellipse at p, with the same parameters
+fn grad_sd_ellipse(p: vec2<f32>, r: vec2<f32>) -> vec2<f32> {
+    return normalize(p / (r * r));
+}
@@ -48,18 +48,193 @@
 //! - The runtime is optimized for frequent reads and occasional writes
 //! - Component tree operations may involve parallel processing under read locks
 
-use std::sync::OnceLock;
+use std::sync::{
+    Arc, OnceLock,
+    atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering},
+};
 
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use winit::window::Theme;
 
-use crate::component_tree::ComponentTree;
+use crate::{
+    Dp,
+    component_tree::ComponentTree,
+    px::{PxPosition, PxSize},
+    ui_handle::{self, UiHandle},
+};
 
 /// Global singleton instance of the Tessera runtime.
 ///
 /// This static variable ensures that there is exactly one runtime instance per application,
 /// initialized lazily on first access. The [`OnceLock`] provides thread-safe initialization
 /// without the overhead of synchronization after the first initialization.
-static TESSERA_RUNTIME: OnceLock<RwLock<TesseraRuntime>> = OnceLock::new();
+static TESSERA_RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// The runtime state, next to the parts of it that are read without taking its lock.
+struct Runtime {
+    state: RwLock<TesseraRuntime>,
+    window_scale: WindowScale,
+}
+
+fn runtime() -> &'static Runtime {
+    TESSERA_RUNTIME.get_or_init(|| Runtime {
+        state: RwLock::new(TesseraRuntime::default()),
+        window_scale: WindowScale::new(),
+    })
+}
+
+/// Set when some part of the application asked for a new frame.
+///
+/// This lives outside of [`TesseraRuntime`] so background threads can request a redraw
+/// without contending for the runtime lock.
+static REDRAW_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Set when the application asked to exit, see [`TesseraRuntime::exit`].
+static EXIT_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the layout debug overlay is drawn, see [`TesseraRuntime::set_debug_overlay`].
+static DEBUG_OVERLAY: AtomicBool = AtomicBool::new(false);
+
+/// The key of the node highlighted by inspection tools, `0` for none, see
+/// [`TesseraRuntime::set_highlighted_node`].
+static HIGHLIGHTED_NODE: AtomicU64 = AtomicU64::new(0);
+
+/// The user's preferred text size multiplier, stored as the bits of an `f64`.
+static FONT_SCALE: AtomicU64 = AtomicU64::new(1.0f64.to_bits());
+
+/// The light or dark preference reported by the operating system, see [`encode_theme`].
+static SYSTEM_THEME: AtomicU8 = AtomicU8::new(NO_THEME);
+
+/// The [`ThemePreference`] of the application, stored as its discriminant.
+static THEME_PREFERENCE: AtomicU8 = AtomicU8::new(ThemePreference::FollowSystem as u8);
+
+/// The theme last delivered to `on_theme_changed` callbacks, see [`encode_theme`].
+static NOTIFIED_THEME: AtomicU8 = AtomicU8::new(NO_THEME);
+
+/// Stands for "no theme" in the atomics above.
+const NO_THEME: u8 = 0;
+
+fn encode_theme(theme: Option<Theme>) -> u8 {
+    match theme {
+        None => NO_THEME,
+        Some(Theme::Light) => 1,
+        Some(Theme::Dark) => 2,
+    }
+}
+
+fn decode_theme(bits: u8) -> Option<Theme> {
+    match bits {
+        1 => Some(Theme::Light),
+        2 => Some(Theme::Dark),
+        _ => None,
+    }
+}
+
+/// Whether the application follows the light or dark theme of the operating system.
+///
+/// See [`TesseraRuntime::theme`] for the theme resulting from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThemePreference {
+    /// Use the theme of the operating system, and switch whenever it changes.
+    #[default]
+    FollowSystem,
+    /// Always use the light theme.
+    Light,
+    /// Always use the dark theme.
+    Dark,
+}
+
+/// How many physical pixels one dp covers in the window.
+///
+/// This combines the scale factor reported by the window for its monitor with the
+/// application zoom. Every [`Dp`] conversion reads it, including measure functions that
+/// run while the runtime is locked, so it is kept next to the runtime lock instead of
+/// behind it, and its values are read without locking. The scale of the window is returned
+/// by [`TesseraRuntime::window_scale`].
+#[derive(Debug)]
+pub struct WindowScale {
+    /// The scale factor reported by the window, stored as the bits of an `f64`.
+    window_scale_factor: AtomicU64,
+    /// The application zoom multiplier, stored as the bits of an `f64`.
+    zoom: AtomicU64,
+}
+
+impl WindowScale {
+    /// The smallest zoom accepted by [`set_zoom`](Self::set_zoom).
+    pub const MIN_ZOOM: f64 = 0.5;
+    /// The largest zoom accepted by [`set_zoom`](Self::set_zoom).
+    pub const MAX_ZOOM: f64 = 3.0;
+    /// How much the zoom changes per `Ctrl +` / `Ctrl -` key press.
+    pub const ZOOM_STEP: f64 = 0.1;
+
+    /// Creates a scale without scaling, as used before the window is created.
+    pub const fn new() -> Self {
+        Self {
+            window_scale_factor: AtomicU64::new(1.0f64.to_bits()),
+            zoom: AtomicU64::new(1.0f64.to_bits()),
+        }
+    }
+
+    /// Returns the effective scale factor, the window scale factor multiplied by the zoom.
+    pub fn scale_factor(&self) -> f64 {
+        self.window_scale_factor() * self.zoom()
+    }
+
+    /// Returns the scale factor reported by the window for its current monitor.
+    pub fn window_scale_factor(&self) -> f64 {
+        f64::from_bits(self.window_scale_factor.load(Ordering::Acquire))
+    }
+
+    /// Returns the application zoom multiplier.
+    pub fn zoom(&self) -> f64 {
+        f64::from_bits(self.zoom.load(Ordering::Acquire))
+    }
+
+    /// Sets the zoom, clamped to [`MIN_ZOOM`](Self::MIN_ZOOM)..=[`MAX_ZOOM`](Self::MAX_ZOOM).
+    ///
+    /// Returns whether the zoom changed.
+    pub fn set_zoom(&self, zoom: f64) -> bool {
+        let zoom = zoom.clamp(Self::MIN_ZOOM, Self::MAX_ZOOM);
+        self.zoom.swap(zoom.to_bits(), Ordering::AcqRel) != zoom.to_bits()
+    }
+
+    /// Sets the scale factor reported by the window, returning whether it changed.
+    pub fn set_window_scale_factor(&self, scale_factor: f64) -> bool {
+        self.window_scale_factor
+            .swap(scale_factor.to_bits(), Ordering::AcqRel)
+            != scale_factor.to_bits()
+    }
+
+    /// Converts a length in dp to physical pixels.
+    pub fn dp_to_pixels(&self, dp: Dp) -> f64 {
+        dp.0 * self.scale_factor()
+    }
+
+    /// Converts a length in physical pixels to dp.
+    pub fn pixels_to_dp(&self, pixels: f64) -> Dp {
+        Dp(pixels / self.scale_factor())
+    }
+}
+
+impl Default for WindowScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What should happen when the user asks to close the window.
+///
+/// Returned by the callbacks registered with
+/// [`TesseraRuntime::on_close_requested`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CloseDecision {
+    /// Let the window close.
+    #[default]
+    Close,
+    /// Keep the window open, for example to ask the user to save their changes first.
+    /// Call [`TesseraRuntime::exit`] to close it later.
+    Prevent,
+}
 
 /// Central runtime state container for the Tessera UI framework.
 ///
@@ -133,11 +308,27 @@ pub struct TesseraRuntime {
     pub cursor_icon_request: Option<winit::window::CursorIcon>,
 
     /// Called when the window minimize state changes.
-    on_minimize_callbacks: Vec<Box<dyn Fn(bool) + Send + Sync>>,
+    on_minimize_callbacks: Vec<Arc<dyn Fn(bool) + Send + Sync>>,
     /// Called when the window close event is triggered.
-    on_close_callbacks: Vec<Box<dyn Fn() + Send + Sync>>,
+    on_close_callbacks: Vec<Arc<dyn Fn() + Send + Sync>>,
+    /// Called when the user asks to close the window, and can prevent it.
+    on_close_requested_callbacks: Vec<Arc<dyn Fn() -> CloseDecision + Send + Sync>>,
+    /// Called when the window gains or loses keyboard focus.
+    on_focus_changed_callbacks: Vec<Arc<dyn Fn(bool) + Send + Sync>>,
+    /// Called when the window is resized, with its new size in physical pixels.
+    on_resized_callbacks: Vec<Arc<dyn Fn(PxSize) + Send + Sync>>,
+    /// Called when the window is moved, with its new position in physical pixels.
+    on_moved_callbacks: Vec<Arc<dyn Fn(PxPosition) + Send + Sync>>,
+    /// Called when the window becomes hidden or visible again.
+    on_occluded_callbacks: Vec<Arc<dyn Fn(bool) + Send + Sync>>,
+    /// Called when the theme of the application changes.
+    on_theme_changed_callbacks: Vec<Arc<dyn Fn(Theme) + Send + Sync>>,
     /// Whether the window is currently minimized.
     pub(crate) window_minimized: bool,
+    /// Whether the window currently has keyboard focus.
+    pub(crate) window_focused: bool,
+    /// Whether the window is currently fully hidden, for example behind other windows.
+    pub(crate) window_occluded: bool,
 }
 
 impl TesseraRuntime {
@@ -183,9 +374,7 @@ impl TesseraRuntime {
     /// A [`RwLockReadGuard`] that provides read-only access to the runtime state.
     /// The guard automatically releases the lock when dropped.
     pub fn read() -> RwLockReadGuard<'static, Self> {
-        TESSERA_RUNTIME
-            .get_or_init(|| RwLock::new(Self::default()))
-            .read()
+        runtime().state.read()
     }
 
     /// Acquires exclusive write access to the runtime state.
@@ -231,21 +420,361 @@ impl TesseraRuntime {
     /// A [`RwLockWriteGuard`] that provides exclusive read-write access to the
     /// runtime state. The guard automatically releases the lock when dropped.
     pub fn write() -> RwLockWriteGuard<'static, Self> {
-        TESSERA_RUNTIME
-            .get_or_init(|| RwLock::new(Self::default()))
-            .write()
+        runtime().state.write()
     }
 
     /// Registers a per-frame callback for minimize state changes.
     /// Components should call this every frame they wish to be notified.
     pub fn on_minimize(&mut self, callback: impl Fn(bool) + Send + Sync + 'static) {
-        self.on_minimize_callbacks.push(Box::new(callback));
+        self.on_minimize_callbacks.push(Arc::new(callback));
     }
 
     /// Registers a per-frame callback for window close event.
     /// Components should call this every frame they wish to be notified.
     pub fn on_close(&mut self, callback: impl Fn() + Send + Sync + 'static) {
-        self.on_close_callbacks.push(Box::new(callback));
+        self.on_close_callbacks.push(Arc::new(callback));
+    }
+
+    /// Registers a per-frame callback for close requests, for example when the user clicks
+    /// the close button of the window.
+    ///
+    /// If any callback returns [`CloseDecision::Prevent`], the window stays open and the
+    /// `on_close` callbacks are not called. The application can then ask the user what to
+    /// do, and close the window with [`exit`](Self::exit).
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_close_requested(
+        &mut self,
+        callback: impl Fn() -> CloseDecision + Send + Sync + 'static,
+    ) {
+        self.on_close_requested_callbacks.push(Arc::new(callback));
+    }
+
+    /// Registers a per-frame callback for keyboard focus changes of the window.
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_window_focus_changed(&mut self, callback: impl Fn(bool) + Send + Sync + 'static) {
+        self.on_focus_changed_callbacks.push(Arc::new(callback));
+    }
+
+    /// Registers a per-frame callback for window size changes, in physical pixels.
+    /// It is not called when the window is minimized, see [`on_minimize`](Self::on_minimize).
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_resized(&mut self, callback: impl Fn(PxSize) + Send + Sync + 'static) {
+        self.on_resized_callbacks.push(Arc::new(callback));
+    }
+
+    /// Registers a per-frame callback for window moves, with the position of the window's
+    /// top-left corner on the desktop in physical pixels.
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_moved(&mut self, callback: impl Fn(PxPosition) + Send + Sync + 'static) {
+        self.on_moved_callbacks.push(Arc::new(callback));
+    }
+
+    /// Registers a per-frame callback for window occlusion changes.
+    ///
+    /// The callback receives `true` when the window became fully hidden, for example behind
+    /// other windows, and `false` when it became visible again. Nothing is rendered while
+    /// the window is occluded, so expensive work such as animations can be paused.
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_occluded(&mut self, callback: impl Fn(bool) + Send + Sync + 'static) {
+        self.on_occluded_callbacks.push(Arc::new(callback));
+    }
+
+    /// Returns whether the window is currently minimized.
+    pub fn is_window_minimized(&self) -> bool {
+        self.window_minimized
+    }
+
+    /// Returns whether the window currently has keyboard focus.
+    pub fn is_window_focused(&self) -> bool {
+        self.window_focused
+    }
+
+    /// Returns whether the window is currently fully hidden, see
+    /// [`on_occluded`](Self::on_occluded).
+    pub fn is_window_occluded(&self) -> bool {
+        self.window_occluded
+    }
+
+    /// Closes the window and exits the application.
+    ///
+    /// Unlike the user closing the window, this does not call the
+    /// [`on_close_requested`](Self::on_close_requested) callbacks, so it can be used to close
+    /// the window after a close request was prevented. The `on_close` callbacks are called
+    /// before exiting. It can be called from any thread and does not acquire the runtime lock.
+    pub fn exit() {
+        EXIT_REQUESTED.store(true, Ordering::Release);
+        Self::request_redraw();
+    }
+
+    /// Returns whether [`exit`](Self::exit) was called.
+    pub(crate) fn exit_requested() -> bool {
+        EXIT_REQUESTED.load(Ordering::Acquire)
+    }
+
+    /// Registers a per-frame callback for theme changes, see [`theme`](Self::theme).
+    /// Components should call this every frame they wish to be notified.
+    pub fn on_theme_changed(&mut self, callback: impl Fn(Theme) + Send + Sync + 'static) {
+        self.on_theme_changed_callbacks.push(Arc::new(callback));
+    }
+
+    /// Requests a new frame to be rendered.
+    ///
+    /// This can be called from any thread, including async tasks started with
+    /// [`launch`](crate::effect::launch), after they changed state that is visible in the UI.
+    /// It never blocks and does not acquire the runtime lock. If the event loop is idle, it is
+    /// woken up through the [`UiHandle`].
+    pub fn request_redraw() {
+        if !REDRAW_REQUESTED.swap(true, Ordering::AcqRel)
+            && let Some(handle) = ui_handle::installed()
+        {
+            handle.request_redraw();
+        }
+    }
+
+    /// Returns a handle to the running event loop.
+    ///
+    /// The handle can be cloned and sent to other threads to request redraws, run closures
+    /// on the UI thread or send typed application messages. Returns `None` before the
+    /// renderer has started.
+    ///
+    /// ```
+    /// use tessera_ui::TesseraRuntime;
+    ///
+    /// if let Some(handle) = TesseraRuntime::ui_handle() {
+    ///     handle.request_redraw();
+    /// }
+    /// ```
+    pub fn ui_handle() -> Option<UiHandle> {
+        ui_handle::installed()
+    }
+
+    /// The smallest zoom accepted by [`set_zoom`](Self::set_zoom).
+    pub const MIN_ZOOM: f64 = WindowScale::MIN_ZOOM;
+    /// The largest zoom accepted by [`set_zoom`](Self::set_zoom).
+    pub const MAX_ZOOM: f64 = WindowScale::MAX_ZOOM;
+    /// How much the zoom changes per `Ctrl +` / `Ctrl -` key press.
+    pub const ZOOM_STEP: f64 = WindowScale::ZOOM_STEP;
+
+    /// Returns the scale of the window, see [`WindowScale`].
+    ///
+    /// It never blocks and does not acquire the runtime lock.
+    pub fn window_scale() -> &'static WindowScale {
+        &runtime().window_scale
+    }
+
+    /// Returns the effective scale factor, i.e. how many physical pixels one dp covers.
+    ///
+    /// This is the [window scale factor](Self::window_scale_factor) multiplied by the
+    /// application [zoom](Self::zoom). It never blocks and does not acquire the runtime
+    /// lock, so it can be called from measure functions and state handlers.
+    ///
+    /// ```
+    /// use tessera_ui::TesseraRuntime;
+    ///
+    /// assert!(TesseraRuntime::scale_factor() > 0.0);
+    /// ```
+    pub fn scale_factor() -> f64 {
+        Self::window_scale().scale_factor()
+    }
+
+    /// Returns the scale factor reported by the window for its current monitor.
+    ///
+    /// Defaults to `1.0` before the window is created.
+    pub fn window_scale_factor() -> f64 {
+        Self::window_scale().window_scale_factor()
+    }
+
+    /// Returns the application zoom multiplier, `1.0` unless changed by the user.
+    pub fn zoom() -> f64 {
+        Self::window_scale().zoom()
+    }
+
+    /// Sets the application zoom multiplier, used for accessibility zoom.
+    ///
+    /// The value is clamped to [`MIN_ZOOM`](Self::MIN_ZOOM)..=[`MAX_ZOOM`](Self::MAX_ZOOM).
+    /// Changing it relayouts the whole UI in the next frame. The renderer can also change
+    /// the zoom on `Ctrl +`, `Ctrl -` and `Ctrl 0`, see
+    /// [`TesseraConfig::zoom_shortcuts`](crate::renderer::TesseraConfig::zoom_shortcuts).
+    ///
+    /// ```
+    /// use tessera_ui::TesseraRuntime;
+    ///
+    /// TesseraRuntime::set_zoom(1.5);
+    /// assert_eq!(TesseraRuntime::zoom(), 1.5);
+    /// TesseraRuntime::set_zoom(100.0);
+    /// assert_eq!(TesseraRuntime::zoom(), TesseraRuntime::MAX_ZOOM);
+    /// ```
+    pub fn set_zoom(zoom: f64) {
+        if Self::window_scale().set_zoom(zoom) {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns the font scale, the user's preferred text size multiplier.
+    ///
+    /// Font sizes in [`Sp`](crate::Sp) are multiplied by it. Defaults to `1.0`, or to
+    /// [`TesseraConfig::font_scale`](crate::renderer::TesseraConfig::font_scale) once the
+    /// renderer started.
+    pub fn font_scale() -> f64 {
+        f64::from_bits(FONT_SCALE.load(Ordering::Acquire))
+    }
+
+    /// Sets the font scale, for example from a text size setting in the application.
+    ///
+    /// Only text sized in [`Sp`](crate::Sp) is affected. Changing it relayouts the whole
+    /// UI in the next frame. Values that are not positive and finite are ignored.
+    ///
+    /// ```
+    /// use tessera_ui::{Dp, Sp, TesseraRuntime};
+    ///
+    /// TesseraRuntime::set_font_scale(1.3);
+    /// assert_eq!(TesseraRuntime::font_scale(), 1.3);
+    /// assert_eq!(Sp(10.0).to_dp(), Dp(13.0));
+    /// ```
+    pub fn set_font_scale(font_scale: f64) {
+        if !font_scale.is_finite() || font_scale <= 0.0 {
+            return;
+        }
+        if FONT_SCALE.swap(font_scale.to_bits(), Ordering::AcqRel) != font_scale.to_bits() {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns the light or dark theme the application should use.
+    ///
+    /// This is the theme forced by the [theme preference](Self::theme_preference), or the
+    /// [system theme](Self::system_theme) when following the system. It falls back to
+    /// [`Theme::Light`] when the system does not report a preference. It never blocks and
+    /// does not acquire the runtime lock.
+    ///
+    /// ```
+    /// use tessera_ui::{TesseraRuntime, runtime::ThemePreference, winit::window::Theme};
+    ///
+    /// TesseraRuntime::set_theme_preference(ThemePreference::Dark);
+    /// assert_eq!(TesseraRuntime::theme(), Theme::Dark);
+    /// ```
+    pub fn theme() -> Theme {
+        match Self::theme_preference() {
+            ThemePreference::FollowSystem => Self::system_theme().unwrap_or(Theme::Light),
+            ThemePreference::Light => Theme::Light,
+            ThemePreference::Dark => Theme::Dark,
+        }
+    }
+
+    /// Returns the theme reported by the operating system, or `None` if it is unknown.
+    pub fn system_theme() -> Option<Theme> {
+        decode_theme(SYSTEM_THEME.load(Ordering::Acquire))
+    }
+
+    /// Returns whether the application follows the system theme or forces one.
+    ///
+    /// Defaults to [`ThemePreference::FollowSystem`], or to
+    /// [`TesseraConfig::theme_preference`](crate::renderer::TesseraConfig::theme_preference)
+    /// once the renderer started.
+    pub fn theme_preference() -> ThemePreference {
+        match THEME_PREFERENCE.load(Ordering::Acquire) {
+            1 => ThemePreference::Light,
+            2 => ThemePreference::Dark,
+            _ => ThemePreference::FollowSystem,
+        }
+    }
+
+    /// Sets whether the application follows the system theme or forces one, for example
+    /// from a setting in the application.
+    ///
+    /// If the resulting [`theme`](Self::theme) changes, the UI is rebuilt in the next frame
+    /// and `on_theme_changed` callbacks are called.
+    pub fn set_theme_preference(preference: ThemePreference) {
+        if THEME_PREFERENCE.swap(preference as u8, Ordering::AcqRel) != preference as u8 {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns whether the layout debug overlay is drawn on top of the UI.
+    pub fn debug_overlay() -> bool {
+        DEBUG_OVERLAY.load(Ordering::Acquire)
+    }
+
+    /// Shows or hides the layout debug overlay.
+    ///
+    /// The overlay outlines every laid out node with its function name, size and the
+    /// constraint it was measured with. Nodes larger than that constraint are outlined in
+    /// red, nodes reaching outside of their parent in orange. It can also be enabled with
+    /// [`TesseraConfig::debug_overlay`](crate::renderer::TesseraConfig::debug_overlay), or
+    /// toggled with `F12` when opted in, see
+    /// [`TesseraConfig::debug_overlay_shortcut`](crate::renderer::TesseraConfig::debug_overlay_shortcut).
+    ///
+    /// ```
+    /// use tessera_ui::TesseraRuntime;
+    ///
+    /// TesseraRuntime::set_debug_overlay(true);
+    /// assert!(TesseraRuntime::debug_overlay());
+    /// ```
+    pub fn set_debug_overlay(enabled: bool) {
+        if DEBUG_OVERLAY.swap(enabled, Ordering::AcqRel) != enabled {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns the key of the highlighted node, see
+    /// [`set_highlighted_node`](Self::set_highlighted_node).
+    pub fn highlighted_node() -> Option<u64> {
+        match HIGHLIGHTED_NODE.load(Ordering::Acquire) {
+            0 => None,
+            node => Some(node),
+        }
+    }
+
+    /// Highlights the node with the given key on top of the UI, or removes the highlight.
+    ///
+    /// The key is the [`ComponentNode::key`](crate::ComponentNode::key) of the node, which
+    /// stays the same across frames, and the `id` of the node in devtools snapshots. This is
+    /// used by inspection tools such as the devtools server, and draws independently of the
+    /// [debug overlay](Self::set_debug_overlay).
+    ///
+    /// ```
+    /// use tessera_ui::TesseraRuntime;
+    ///
+    /// TesseraRuntime::set_highlighted_node(Some(3));
+    /// assert_eq!(TesseraRuntime::highlighted_node(), Some(3));
+    /// TesseraRuntime::set_highlighted_node(None);
+    /// assert_eq!(TesseraRuntime::highlighted_node(), None);
+    /// ```
+    pub fn set_highlighted_node(node: Option<u64>) {
+        let node = node.unwrap_or(0);
+        if HIGHLIGHTED_NODE.swap(node, Ordering::AcqRel) != node {
+            Self::request_redraw();
+        }
+    }
+
+    /// Stores the theme reported by the operating system, and requests a rebuild if it
+    /// changed.
+    pub(crate) fn set_system_theme(theme: Option<Theme>) {
+        let bits = encode_theme(theme);
+        if SYSTEM_THEME.swap(bits, Ordering::AcqRel) != bits {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns the theme if it changed since the last call.
+    ///
+    /// The first call only records the theme, since there is nobody to notify yet.
+    pub(crate) fn take_theme_change() -> Option<Theme> {
+        let theme = Self::theme();
+        let previous = NOTIFIED_THEME.swap(encode_theme(Some(theme)), Ordering::AcqRel);
+        (previous != NO_THEME && previous != encode_theme(Some(theme))).then_some(theme)
+    }
+
+    /// Stores the scale factor of the window, and requests a relayout if it changed.
+    pub(crate) fn set_window_scale_factor(scale_factor: f64) {
+        if Self::window_scale().set_window_scale_factor(scale_factor) {
+            Self::request_redraw();
+        }
+    }
+
+    /// Returns whether a redraw was requested since the last call, and resets the request.
+    pub(crate) fn take_redraw_request() -> bool {
+        REDRAW_REQUESTED.swap(false, Ordering::AcqRel)
     }
 
     /// Clears all per-frame registered callbacks.
@@ -253,10 +782,19 @@ impl TesseraRuntime {
     pub fn clear_frame_callbacks(&mut self) {
         self.on_minimize_callbacks.clear();
         self.on_close_callbacks.clear();
+        self.on_theme_changed_callbacks.clear();
+        self.on_close_requested_callbacks.clear();
+        self.on_focus_changed_callbacks.clear();
+        self.on_resized_callbacks.clear();
+        self.on_moved_callbacks.clear();
+        self.on_occluded_callbacks.clear();
     }
 
     /// Triggers all registered callbacks (global and per-frame).
     /// Called by the event loop when a minimize event is detected.
+    #[deprecated(
+        note = "Use `TesseraRuntime::call_minimize_callbacks`, which releases the runtime lock first"
+    )]
     pub fn trigger_minimize_callbacks(&self, minimized: bool) {
         for callback in &self.on_minimize_callbacks {
             callback(minimized);
@@ -265,9 +803,133 @@ impl TesseraRuntime {
 
     /// Triggers all registered callbacks (global and per-frame) for window close event.
     /// Called by the event loop when a close event is detected.
+    #[deprecated(
+        note = "Use `TesseraRuntime::call_close_callbacks`, which releases the runtime lock first"
+    )]
     pub fn trigger_close_callbacks(&self) {
         for callback in &self.on_close_callbacks {
             callback();
         }
     }
+
+    /// Returns a copy of the callbacks picked by `select`.
+    ///
+    /// The `call_*_callbacks` functions call the copy once the runtime lock is released,
+    /// so the callbacks can lock the runtime themselves.
+    fn callbacks<F: ?Sized>(select: impl FnOnce(&Self) -> &Vec<Arc<F>>) -> Vec<Arc<F>> {
+        select(&Self::read()).clone()
+    }
+
+    /// Calls all registered callbacks for minimize state changes.
+    /// Called by the event loop when a minimize event is detected.
+    pub fn call_minimize_callbacks(minimized: bool) {
+        for callback in Self::callbacks(|runtime| &runtime.on_minimize_callbacks) {
+            callback(minimized);
+        }
+    }
+
+    /// Calls all registered callbacks for the window close event.
+    /// Called by the event loop when a close event is detected.
+    pub fn call_close_callbacks() {
+        for callback in Self::callbacks(|runtime| &runtime.on_close_callbacks) {
+            callback();
+        }
+    }
+
+    /// Asks all registered close request callbacks whether the window may close.
+    ///
+    /// Every callback is called, and the window may close only if none of them prevented
+    /// it.
+    pub fn call_close_requested_callbacks() -> CloseDecision {
+        Self::callbacks(|runtime| &runtime.on_close_requested_callbacks)
+            .iter()
+            .fold(CloseDecision::Close, |decision, callback| {
+                match (decision, callback()) {
+                    (CloseDecision::Close, CloseDecision::Close) => CloseDecision::Close,
+                    _ => CloseDecision::Prevent,
+                }
+            })
+    }
+
+    /// Calls all registered callbacks for window focus changes.
+    pub fn call_focus_changed_callbacks(focused: bool) {
+        for callback in Self::callbacks(|runtime| &runtime.on_focus_changed_callbacks) {
+            callback(focused);
+        }
+    }
+
+    /// Calls all registered callbacks for window size changes.
+    pub fn call_resized_callbacks(size: PxSize) {
+        for callback in Self::callbacks(|runtime| &runtime.on_resized_callbacks) {
+            callback(size);
+        }
+    }
+
+    /// Calls all registered callbacks for window moves.
+    pub fn call_moved_callbacks(position: PxPosition) {
+        for callback in Self::callbacks(|runtime| &runtime.on_moved_callbacks) {
+            callback(position);
+        }
+    }
+
+    /// Calls all registered callbacks for window occlusion changes.
+    pub fn call_occluded_callbacks(occluded: bool) {
+        for callback in Self::callbacks(|runtime| &runtime.on_occluded_callbacks) {
+            callback(occluded);
+        }
+    }
+
+    /// Calls all registered callbacks for theme changes.
+    /// Called by the event loop when the theme changed since the last frame.
+    pub fn call_theme_changed_callbacks(theme: Theme) {
+        for callback in Self::callbacks(|runtime| &runtime.on_theme_changed_callbacks) {
+            callback(theme);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_scale_combines_factor_and_zoom() {
+        let scale = WindowScale::new();
+        assert_eq!(scale.scale_factor(), 1.0);
+        assert!(scale.set_window_scale_factor(2.0));
+        assert!(!scale.set_window_scale_factor(2.0));
+        assert!(scale.set_zoom(1.5));
+        assert_eq!(scale.scale_factor(), 3.0);
+        assert_eq!(scale.dp_to_pixels(Dp(10.0)), 30.0);
+        assert_eq!(scale.pixels_to_dp(30.0), Dp(10.0));
+    }
+
+    #[test]
+    fn test_window_scale_clamps_zoom() {
+        let scale = WindowScale::new();
+        scale.set_zoom(100.0);
+        assert_eq!(scale.zoom(), WindowScale::MAX_ZOOM);
+        scale.set_zoom(0.0);
+        assert_eq!(scale.zoom(), WindowScale::MIN_ZOOM);
+        assert!(!scale.set_zoom(-1.0));
+    }
+
+    #[test]
+    fn test_close_requested_callbacks_can_prevent_closing() {
+        TesseraRuntime::write().on_close_requested(|| CloseDecision::Close);
+        assert_eq!(
+            TesseraRuntime::call_close_requested_callbacks(),
+            CloseDecision::Close
+        );
+        // The callback locks the runtime itself, which must not deadlock
+        TesseraRuntime::write().on_close_requested(|| {
+            TesseraRuntime::write().window_size = [1, 1];
+            CloseDecision::Prevent
+        });
+        assert_eq!(
+            TesseraRuntime::call_close_requested_callbacks(),
+            CloseDecision::Prevent
+        );
+        TesseraRuntime::write().clear_frame_callbacks();
+    }
 }
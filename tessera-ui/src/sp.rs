@@ -0,0 +1,180 @@
+//! # Scalable Pixels (Sp)
+//!
+//! This module provides the [`Sp`] type, the unit for font sizes in the Tessera framework.
+//!
+//! ## Overview
+//!
+//! Scalable pixels behave like density-independent pixels ([`Dp`]), but they are also
+//! multiplied by the user's preferred text size, the **font scale**. This lets users make
+//! text larger (or smaller) without changing the size of the rest of the UI.
+//!
+//! ## Font Scale
+//!
+//! The font scale is runtime state, read with [`TesseraRuntime::font_scale`]. It starts at
+//! [`TesseraConfig::font_scale`](crate::renderer::TesseraConfig::font_scale) and can be
+//! changed at any time with [`TesseraRuntime::set_font_scale`], which relayouts the UI in
+//! the next frame.
+//!
+//! One sp covers `font_scale * scale_factor` physical pixels, where `scale_factor` is
+//! [`TesseraRuntime::scale_factor`].
+//!
+//! ## Usage
+//!
+//! ```
+//! use tessera_ui::{Dp, Sp};
+//!
+//! let body = Sp(16.0);
+//!
+//! // With a font scale of 1.0, one sp is as large as one dp
+//! assert_eq!(body.to_dp(), Dp(16.0 * tessera_ui::TesseraRuntime::font_scale()));
+//!
+//! let pixels = body.to_pixels_f32();
+//! ```
+
+use crate::{Dp, Px, runtime::TesseraRuntime};
+
+/// Scalable pixels (sp), the unit for font sizes.
+///
+/// `Sp` is a [`Dp`] that is additionally scaled by the user's font scale, see the
+/// [module documentation](self). Use it for text sizes and line heights; use `Dp` for
+/// everything else, so that changing the font scale only affects text.
+///
+/// # Examples
+///
+/// ```
+/// use tessera_ui::{Px, Sp};
+///
+/// let title = Sp(24.0);
+/// let line_height = Sp(title.0 * 1.2);
+/// let px: Px = line_height.into();
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Sp(pub f64);
+
+impl Sp {
+    /// A constant representing zero scalable pixels.
+    pub const ZERO: Self = Self(0.0);
+
+    /// Creates a new `Sp` instance with the specified value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Sp;
+    ///
+    /// let size = Sp::new(14.0);
+    /// assert_eq!(size, Sp(14.0));
+    /// ```
+    pub const fn new(value: f64) -> Self {
+        Sp(value)
+    }
+
+    /// Converts this sp value to density-independent pixels, applying the font scale.
+    pub fn to_dp(&self) -> Dp {
+        self.to_dp_with(TesseraRuntime::font_scale())
+    }
+
+    /// Converts this sp value to dp with the given font scale.
+    fn to_dp_with(self, font_scale: f64) -> Dp {
+        Dp(self.0 * font_scale)
+    }
+
+    /// Converts this sp value to physical pixels as an `f64`, applying the font scale and
+    /// the scale factor.
+    pub fn to_pixels_f64(&self) -> f64 {
+        self.to_dp().to_pixels_f64()
+    }
+
+    /// Converts this sp value to physical pixels as an `f32`, applying the font scale and
+    /// the scale factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Sp;
+    ///
+    /// let pixels = Sp(16.0).to_pixels_f32();
+    /// // Result depends on the current font scale and scale factor
+    /// ```
+    pub fn to_pixels_f32(&self) -> f32 {
+        self.to_pixels_f64() as f32
+    }
+
+    /// Converts this sp value to a [`Px`] value, applying the font scale and the scale
+    /// factor.
+    pub fn to_px(&self) -> Px {
+        Px::from_f32(self.to_pixels_f32())
+    }
+}
+
+impl From<f64> for Sp {
+    /// Creates an `Sp` instance from an `f64` value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::Sp;
+    ///
+    /// let size: Sp = 18.0.into();
+    /// assert_eq!(size, Sp(18.0));
+    /// ```
+    fn from(value: f64) -> Self {
+        Sp::new(value)
+    }
+}
+
+impl From<Dp> for Sp {
+    /// Reinterprets a dp font size as sp, so it follows the font scale.
+    ///
+    /// Font sizes used to be given in `Dp`. This conversion keeps such call sites
+    /// compiling: the number is kept as is, and the font scale is applied on top of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tessera_ui::{Dp, Sp};
+    ///
+    /// assert_eq!(Sp::from(Dp(16.0)), Sp(16.0));
+    /// ```
+    fn from(dp: Dp) -> Self {
+        Sp(dp.0)
+    }
+}
+
+impl From<Sp> for Px {
+    /// Converts sp to physical pixels, see [`Sp::to_px`].
+    fn from(sp: Sp) -> Self {
+        sp.to_px()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::WindowScale;
+
+    #[test]
+    fn test_font_scale_is_applied_on_top_of_dp() {
+        assert_eq!(Sp(10.0).to_dp_with(1.0), Dp(10.0));
+        assert_eq!(Sp(10.0).to_dp_with(1.5), Dp(15.0));
+        assert_eq!(Sp(10.0).to_dp_with(0.5), Dp(5.0));
+    }
+
+    #[test]
+    fn test_sp_to_pixels_at_different_scale_factors() {
+        let scale = WindowScale::new();
+        assert_eq!(scale.dp_to_pixels(Sp(16.0).to_dp_with(1.0)), 16.0);
+        scale.set_window_scale_factor(2.0);
+        assert_eq!(scale.dp_to_pixels(Sp(16.0).to_dp_with(1.0)), 32.0);
+        assert_eq!(scale.dp_to_pixels(Sp(16.0).to_dp_with(1.25)), 40.0);
+        scale.set_zoom(1.5);
+        assert_eq!(scale.dp_to_pixels(Sp(16.0).to_dp_with(1.25)), 60.0);
+    }
+
+    #[test]
+    fn test_from_dp_keeps_the_number() {
+        assert_eq!(Sp::from(Dp(16.0)), Sp(16.0));
+        assert_eq!(Sp::from(Dp(16.0)).to_dp_with(2.0), Dp(32.0));
+        assert_eq!(Sp::from(Dp::ZERO), Sp::ZERO);
+    }
+}
@@ -0,0 +1,340 @@
+//! # Frame-loop Timers
+//!
+//! This module provides [`set_timeout`] and [`set_interval`], timers that run their
+//! callbacks on the UI thread as part of the frame loop.
+//!
+//! ## Overview
+//!
+//! Like [`effect`](crate::effect), timers are identified by a **key** scoped to the calling
+//! component rather than by a handle, because components are rebuilt every frame. A
+//! component calls [`set_timeout`] or [`set_interval`] with the same key on every frame it
+//! wants the timer to stay alive:
+//!
+//! - The first call with a key schedules the timer.
+//! - Later calls with the same key only keep the timer alive; it is not rescheduled, and
+//!   the callback passed on later frames is dropped.
+//! - When a frame finishes building without any call using that key (the component
+//!   disappeared, or stopped asking for the timer), the timer is cancelled.
+//!
+//! Changing the key therefore restarts the timer. Keys only need to be unique within a
+//! component: two components using the same key each get their own timer.
+//!
+//! ## Scheduling
+//!
+//! Due callbacks run at the start of a frame, before the component tree is built, so
+//! whatever they change shows up in that very frame. The renderer wakes up the event loop
+//! exactly when the earliest timer is due, so timers fire on time even when nothing else
+//! requests a redraw.
+//!
+//! Timers read time from the animation [frame clock](crate::animation::now), so they can
+//! be driven deterministically with a [`TestClock`](crate::animation::TestClock).
+//!
+//! ## Usage
+//!
+//! ```
+//! use std::{
+//!     sync::{
+//!         Arc,
+//!         atomic::{AtomicBool, Ordering},
+//!     },
+//!     time::Duration,
+//! };
+//!
+//! use tessera_ui::timer::set_timeout;
+//!
+//! let show_tooltip = Arc::new(AtomicBool::new(false));
+//! let show_tooltip_clone = show_tooltip.clone();
+//! set_timeout("tooltip", Duration::from_millis(500), move || {
+//!     show_tooltip_clone.store(true, Ordering::Release);
+//! });
+//! ```
+//!
+//! ## Thread Safety
+//!
+//! The timer registry is a global singleton protected by a mutex. [`set_timeout`] and
+//! [`set_interval`] should be called while building the component tree, never from inside
+//! a measure function or a state handler.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+
+use parking_lot::{Mutex, MutexGuard};
+
+use crate::{animation, effect::current_scoped_key};
+
+/// Global timer registry.
+///
+/// Timers outlive a single frame, so they are stored outside of the component tree,
+/// which is cleared after every frame.
+static TIMERS: OnceLock<Mutex<TimerRegistry>> = OnceLock::new();
+
+/// A timer callback, shared so it can be called without holding the registry lock.
+type TimerCallback = Arc<Mutex<Box<dyn FnMut() + Send>>>;
+
+/// Bookkeeping for all scheduled timers.
+#[derive(Default)]
+struct TimerRegistry {
+    /// Scheduled (or already fired) timers, indexed by their hashed key.
+    entries: HashMap<u64, TimerEntry>,
+    /// Keys that were used during the current frame.
+    alive: HashSet<u64>,
+}
+
+/// A single timer owned by a key.
+struct TimerEntry {
+    /// When the timer fires next, on the frame clock. `None` once a timeout has fired.
+    deadline: Option<Duration>,
+    /// The interval period, or `None` for a one-shot timeout.
+    period: Option<Duration>,
+    callback: TimerCallback,
+}
+
+fn timers() -> MutexGuard<'static, TimerRegistry> {
+    TIMERS
+        .get_or_init(|| Mutex::new(TimerRegistry::default()))
+        .lock()
+}
+
+impl TimerRegistry {
+    /// Keeps the timer of `key` alive, scheduling it at `now + delay` if there is none yet.
+    fn schedule(
+        &mut self,
+        key: u64,
+        now: Duration,
+        delay: Duration,
+        period: Option<Duration>,
+        callback: Box<dyn FnMut() + Send>,
+    ) {
+        self.alive.insert(key);
+        if self.entries.contains_key(&key) {
+            return;
+        }
+        self.entries.insert(
+            key,
+            TimerEntry {
+                deadline: Some(now + delay),
+                period,
+                callback: Arc::new(Mutex::new(callback)),
+            },
+        );
+    }
+
+    /// Returns the callbacks of the timers due at `now`, and moves their deadlines on.
+    fn take_due(&mut self, now: Duration) -> Vec<TimerCallback> {
+        self.entries
+            .values_mut()
+            .filter_map(|entry| {
+                let deadline = entry.deadline.filter(|deadline| *deadline <= now)?;
+                entry.deadline = entry.period.map(|period| {
+                    let next = deadline + period;
+                    if next <= now { now + period } else { next }
+                });
+                Some(entry.callback.clone())
+            })
+            .collect()
+    }
+
+    fn next_deadline(&self) -> Option<Duration> {
+        self.entries
+            .values()
+            .filter_map(|entry| entry.deadline)
+            .min()
+    }
+
+    /// Cancels the timers whose key was not used since the last call.
+    fn drop_stale(&mut self) {
+        let Self { entries, alive } = self;
+        entries.retain(|key, _| alive.contains(key));
+        alive.clear();
+    }
+}
+
+/// Registers a timer for `key` in the calling component.
+fn schedule<K: Hash + 'static>(
+    key: &K,
+    delay: Duration,
+    period: Option<Duration>,
+    callback: Box<dyn FnMut() + Send>,
+) {
+    let key = current_scoped_key(key);
+    timers().schedule(key, animation::now(), delay, period, callback);
+}
+
+/// Calls `callback` once, `delay` after the calling component first used `key`.
+///
+/// The timeout is cancelled if a frame is built without calling `set_timeout` with this
+/// key before it fires. After it fired, it is not scheduled again while the key stays
+/// alive; use a new key to run it again.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use tessera_ui::timer::set_timeout;
+///
+/// set_timeout(("hide-snackbar", 7), Duration::from_secs(4), || {
+///     println!("snackbar timed out");
+/// });
+/// ```
+pub fn set_timeout<K, F>(key: K, delay: Duration, callback: F)
+where
+    K: Hash + 'static,
+    F: FnOnce() + Send + 'static,
+{
+    let mut callback = Some(callback);
+    schedule(
+        &key,
+        delay,
+        None,
+        Box::new(move || {
+            if let Some(callback) = callback.take() {
+                callback();
+            }
+        }),
+    );
+}
+
+/// Calls `callback` every `period`, starting `period` after the calling component first
+/// used `key`.
+///
+/// The interval keeps running for as long as every frame calls `set_interval` with this
+/// key. If the UI thread falls behind, missed ticks are skipped instead of being fired in
+/// a burst.
+///
+/// # Example
+///
+/// ```
+/// use std::{
+///     sync::{
+///         Arc,
+///         atomic::{AtomicU64, Ordering},
+///     },
+///     time::Duration,
+/// };
+///
+/// use tessera_ui::timer::set_interval;
+///
+/// let seconds = Arc::new(AtomicU64::new(0));
+/// let seconds_clone = seconds.clone();
+/// set_interval("clock", Duration::from_secs(1), move || {
+///     seconds_clone.fetch_add(1, Ordering::Relaxed);
+/// });
+/// ```
+pub fn set_interval<K, F>(key: K, period: Duration, callback: F)
+where
+    K: Hash + 'static,
+    F: FnMut() + Send + 'static,
+{
+    schedule(&key, period, Some(period), Box::new(callback));
+}
+
+/// Runs the callbacks of every timer that is due at the current frame time.
+///
+/// Called by the renderer at the start of a frame, after the frame clock was sampled.
+pub(crate) fn fire_due_timers() {
+    let due = timers().take_due(animation::now());
+    // The lock is released, so callbacks may schedule timers of their own
+    for callback in due {
+        (callback.lock())();
+    }
+}
+
+/// Returns the earliest pending deadline on the frame clock, if any timer is scheduled.
+pub(crate) fn next_deadline() -> Option<Duration> {
+    timers().next_deadline()
+}
+
+/// Cancels every timer whose key was not used during the frame that just finished building.
+///
+/// Called by the renderer right after the entry point has built the component tree.
+pub(crate) fn drop_stale_timers() {
+    timers().drop_stale();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    /// Returns a callback counting its calls in `count`.
+    fn counter(count: &Arc<AtomicUsize>) -> Box<dyn FnMut() + Send> {
+        let count = count.clone();
+        Box::new(move || {
+            count.fetch_add(1, Ordering::Relaxed);
+        })
+    }
+
+    /// Runs the callbacks due at `now`.
+    fn fire(registry: &mut TimerRegistry, now: Duration) {
+        for callback in registry.take_due(now) {
+            (callback.lock())();
+        }
+    }
+
+    #[test]
+    fn test_timeout_fires_once_at_its_deadline() {
+        let mut registry = TimerRegistry::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        registry.schedule(1, ms(0), ms(100), None, counter(&count));
+        fire(&mut registry, ms(99));
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+        fire(&mut registry, ms(100));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        // Keeping the key alive does not schedule it again
+        registry.schedule(1, ms(100), ms(100), None, counter(&count));
+        fire(&mut registry, ms(500));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+        assert_eq!(registry.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_interval_skips_missed_ticks() {
+        let mut registry = TimerRegistry::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        registry.schedule(1, ms(0), ms(100), Some(ms(100)), counter(&count));
+        fire(&mut registry, ms(100));
+        assert_eq!(registry.next_deadline(), Some(ms(200)));
+        // Five periods late: one tick, and the next one a period from now
+        fire(&mut registry, ms(650));
+        assert_eq!(count.load(Ordering::Relaxed), 2);
+        assert_eq!(registry.next_deadline(), Some(ms(750)));
+    }
+
+    #[test]
+    fn test_unused_timers_are_cancelled() {
+        let mut registry = TimerRegistry::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        registry.schedule(1, ms(0), ms(100), None, counter(&count));
+        registry.schedule(2, ms(0), ms(100), None, counter(&count));
+        registry.drop_stale();
+        // Only the first timer is still asked for
+        registry.schedule(1, ms(50), ms(100), None, counter(&count));
+        registry.drop_stale();
+        assert_eq!(registry.entries.len(), 1);
+        fire(&mut registry, ms(100));
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_next_deadline_is_the_earliest() {
+        let mut registry = TimerRegistry::default();
+        let count = Arc::new(AtomicUsize::new(0));
+        assert_eq!(registry.next_deadline(), None);
+        registry.schedule(1, ms(0), ms(300), None, counter(&count));
+        registry.schedule(2, ms(0), ms(100), Some(ms(100)), counter(&count));
+        registry.schedule(3, ms(50), ms(200), None, counter(&count));
+        assert_eq!(registry.next_deadline(), Some(ms(100)));
+        fire(&mut registry, ms(100));
+        assert_eq!(registry.next_deadline(), Some(ms(200)));
+    }
+}
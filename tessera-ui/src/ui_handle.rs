@@ -0,0 +1,247 @@
+//! # UI Handle
+//!
+//! This module provides [`UiHandle`], a cloneable handle that lets any thread talk to
+//! the UI event loop.
+//!
+//! ## Overview
+//!
+//! Tessera's event loop owns the window and runs every build on the UI thread. Code that
+//! runs elsewhere, such as a tokio task or a plain `std::thread`, uses a [`UiHandle`] to:
+//!
+//! - wake the event loop and request a redraw ([`UiHandle::request_redraw`])
+//! - run a closure on the UI thread before the next build ([`UiHandle::run_on_ui_thread`])
+//! - send typed application messages ([`UiHandle::send_message`]) to the handlers
+//!   registered in [`TesseraConfig::message_handlers`](crate::renderer::TesseraConfig::message_handlers)
+//!
+//! The handle is available from [`TesseraRuntime::ui_handle`](crate::TesseraRuntime::ui_handle)
+//! once the renderer has created its event loop.
+//!
+//! ## Usage
+//!
+//! ```
+//! use tessera_ui::TesseraRuntime;
+//!
+//! // `None` until the renderer is running.
+//! if let Some(handle) = TesseraRuntime::ui_handle() {
+//!     std::thread::spawn(move || {
+//!         // ... do some work ...
+//!         handle.run_on_ui_thread(|| println!("back on the UI thread"));
+//!         handle.request_redraw();
+//!     });
+//! }
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::{Arc, OnceLock},
+};
+
+use log::warn;
+use parking_lot::RwLock;
+use winit::event_loop::EventLoopProxy;
+
+/// The handle of the currently running event loop.
+static UI_HANDLE: OnceLock<RwLock<Option<UiHandle>>> = OnceLock::new();
+
+/// User events sent to the Tessera event loop.
+pub enum TesseraEvent {
+    /// Wake the event loop and render a new frame.
+    RedrawRequested,
+    /// Run a closure on the UI thread before the next build.
+    RunOnUiThread(Box<dyn FnOnce() + Send>),
+    /// A typed application message, dispatched to [`MessageHandlers`].
+    AppMessage(AppMessage),
+    /// A request from assistive technology, forwarded by the AccessKit adapter.
+    Accessibility(accesskit_winit::Event),
+}
+
+impl From<accesskit_winit::Event> for TesseraEvent {
+    fn from(event: accesskit_winit::Event) -> Self {
+        Self::Accessibility(event)
+    }
+}
+
+/// A type-erased application message sent through [`UiHandle::send_message`].
+pub struct AppMessage {
+    type_id: TypeId,
+    type_name: &'static str,
+    payload: Box<dyn Any + Send>,
+}
+
+/// A cloneable handle to the UI event loop, usable from any thread.
+///
+/// Every method returns `false` if the event loop has already exited.
+#[derive(Clone)]
+pub struct UiHandle {
+    proxy: EventLoopProxy<TesseraEvent>,
+}
+
+impl UiHandle {
+    pub(crate) fn new(proxy: EventLoopProxy<TesseraEvent>) -> Self {
+        Self { proxy }
+    }
+
+    /// Returns the underlying event loop proxy.
+    pub(crate) fn proxy(&self) -> EventLoopProxy<TesseraEvent> {
+        self.proxy.clone()
+    }
+
+    /// Wakes the event loop and requests a new frame.
+    pub fn request_redraw(&self) -> bool {
+        self.proxy.send_event(TesseraEvent::RedrawRequested).is_ok()
+    }
+
+    /// Runs `f` on the UI thread right before the next component tree build.
+    ///
+    /// This also requests a redraw, so the effects of `f` show up on screen.
+    pub fn run_on_ui_thread(&self, f: impl FnOnce() + Send + 'static) -> bool {
+        self.proxy
+            .send_event(TesseraEvent::RunOnUiThread(Box::new(f)))
+            .is_ok()
+    }
+
+    /// Sends a typed message to the handler registered for `M` in
+    /// [`TesseraConfig::message_handlers`](crate::renderer::TesseraConfig::message_handlers).
+    ///
+    /// The handler runs on the UI thread. Messages without a registered handler are
+    /// logged and dropped.
+    pub fn send_message<M: Send + 'static>(&self, message: M) -> bool {
+        self.proxy
+            .send_event(TesseraEvent::AppMessage(AppMessage {
+                type_id: TypeId::of::<M>(),
+                type_name: std::any::type_name::<M>(),
+                payload: Box::new(message),
+            }))
+            .is_ok()
+    }
+}
+
+/// Installs the handle of the event loop that is about to run.
+pub(crate) fn install(handle: UiHandle) {
+    *UI_HANDLE.get_or_init(|| RwLock::new(None)).write() = Some(handle);
+}
+
+/// Returns the handle of the running event loop, if any.
+pub(crate) fn installed() -> Option<UiHandle> {
+    UI_HANDLE.get().and_then(|handle| handle.read().clone())
+}
+
+type ErasedMessageHandler = Arc<dyn Fn(Box<dyn Any + Send>) + Send + Sync>;
+
+/// Typed application message handlers, keyed by message type.
+///
+/// # Example
+///
+/// ```
+/// use tessera_ui::renderer::TesseraConfig;
+///
+/// enum AppMessage {
+///     DataLoaded(Vec<u8>),
+/// }
+///
+/// let config = TesseraConfig::default().with_message_handler(|message: AppMessage| {
+///     match message {
+///         AppMessage::DataLoaded(bytes) => println!("loaded {} bytes", bytes.len()),
+///     }
+/// });
+/// ```
+#[derive(Clone, Default)]
+pub struct MessageHandlers {
+    handlers: HashMap<TypeId, ErasedMessageHandler>,
+}
+
+impl MessageHandlers {
+    /// Registers the handler for messages of type `M`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handler is already registered for `M`, since only one of them could
+    /// ever receive its messages.
+    pub fn register<M: Send + 'static>(&mut self, handler: impl Fn(M) + Send + Sync + 'static) {
+        if self.contains::<M>() {
+            panic!(
+                "A message handler for {} is already registered",
+                std::any::type_name::<M>()
+            );
+        }
+        self.handlers.insert(
+            TypeId::of::<M>(),
+            Arc::new(move |payload: Box<dyn Any + Send>| {
+                if let Ok(message) = payload.downcast::<M>() {
+                    handler(*message);
+                }
+            }),
+        );
+    }
+
+    /// Returns whether a handler is registered for messages of type `M`.
+    pub fn contains<M: 'static>(&self) -> bool {
+        self.handlers.contains_key(&TypeId::of::<M>())
+    }
+
+    /// Dispatches a message to its handler.
+    pub(crate) fn dispatch(&self, message: AppMessage) {
+        match self.handlers.get(&message.type_id) {
+            Some(handler) => handler(message.payload),
+            None => warn!(
+                "No message handler registered for {}, message dropped",
+                message.type_name
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    struct Increment(u32);
+    struct Reset;
+    struct Unhandled;
+
+    fn message<M: Send + 'static>(message: M) -> AppMessage {
+        AppMessage {
+            type_id: TypeId::of::<M>(),
+            type_name: std::any::type_name::<M>(),
+            payload: Box::new(message),
+        }
+    }
+
+    #[test]
+    fn test_dispatch_reaches_the_handler_of_the_message_type() {
+        let count = Arc::new(AtomicU32::new(0));
+        let mut handlers = MessageHandlers::default();
+        let increment_count = count.clone();
+        handlers.register(move |Increment(by): Increment| {
+            increment_count.fetch_add(by, Ordering::Relaxed);
+        });
+        let reset_count = count.clone();
+        handlers.register(move |_: Reset| reset_count.store(0, Ordering::Relaxed));
+
+        handlers.dispatch(message(Increment(3)));
+        handlers.dispatch(message(Increment(2)));
+        assert_eq!(count.load(Ordering::Relaxed), 5);
+        handlers.dispatch(message(Reset));
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered")]
+    fn test_registering_a_message_type_twice_panics() {
+        let mut handlers = MessageHandlers::default();
+        handlers.register(|_: Reset| {});
+        handlers.register(|_: Reset| {});
+    }
+
+    #[test]
+    fn test_unregistered_messages_are_dropped() {
+        let mut handlers = MessageHandlers::default();
+        handlers.register(|_: Reset| panic!("wrong handler"));
+        assert!(handlers.contains::<Reset>());
+        assert!(!handlers.contains::<Unhandled>());
+        handlers.dispatch(message(Unhandled));
+    }
+}